@@ -1,5 +1,7 @@
 //! Benchmarks for chrono that just depend on std
 
+use std::fmt::Write;
+
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 
 #[cfg(feature = "unstable-locales")]
@@ -87,6 +89,47 @@ fn bench_datetime_to_rfc3339_opts(c: &mut Criterion) {
     });
 }
 
+fn bench_datetime_to_rfc3339_buf(c: &mut Criterion) {
+    let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    let dt = pst
+        .from_local_datetime(
+            &NaiveDate::from_ymd_opt(2018, 1, 11)
+                .unwrap()
+                .and_hms_nano_opt(10, 5, 13, 84_660_000)
+                .unwrap(),
+        )
+        .unwrap();
+    let mut buf = [0; 48];
+    c.bench_function("bench_datetime_to_rfc3339_buf", |b| {
+        b.iter(|| black_box(dt).to_rfc3339_buf(&mut buf, SecondsFormat::Nanos).len())
+    });
+}
+
+fn bench_format_rfc3339(c: &mut Criterion) {
+    let dt = Utc.with_ymd_and_hms(2018, 1, 11, 10, 5, 13).unwrap() + TimeDelta::nanoseconds(84_660);
+    let mut s = String::with_capacity(64);
+    c.bench_function("bench_format_rfc3339", |b| {
+        b.iter(|| {
+            s.clear();
+            write!(s, "{}", black_box(dt).format("%+")).unwrap();
+            s.len()
+        })
+    });
+}
+
+fn bench_format_numeric(c: &mut Criterion) {
+    let dt = Utc.with_ymd_and_hms(2018, 1, 11, 10, 5, 13).unwrap() + TimeDelta::nanoseconds(84_660);
+    let items: Vec<_> = StrftimeItems::new("%Y%m%d%H%M%S%9f %j %s %8Y %_5d").collect();
+    let mut s = String::with_capacity(64);
+    c.bench_function("bench_format_numeric", |b| {
+        b.iter(|| {
+            s.clear();
+            write!(s, "{}", black_box(dt).format_with_items(items.iter())).unwrap();
+            s.len()
+        })
+    });
+}
+
 fn bench_year_flags_from_year(c: &mut Criterion) {
     c.bench_function("bench_year_flags_from_year", |b| {
         b.iter(|| {
@@ -249,6 +292,9 @@ criterion_group!(
     bench_datetime_to_rfc2822,
     bench_datetime_to_rfc3339,
     bench_datetime_to_rfc3339_opts,
+    bench_datetime_to_rfc3339_buf,
+    bench_format_rfc3339,
+    bench_format_numeric,
     bench_year_flags_from_year,
     bench_num_days_from_ce,
     bench_get_local_time,
@@ -0,0 +1,334 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Conversions between chrono types and the temporal types of Apache Arrow and Parquet.
+//!
+//! Arrow stores dates and times as plain integers. This module converts those integers to and
+//! from the matching chrono types, checking that they are in range in the same step:
+//!
+//! | Arrow type               | chrono type             | Functions                                   |
+//! |--------------------------|-------------------------|---------------------------------------------|
+//! | `date32`                 | [`NaiveDate`]           | [`date32_to_naive`], [`naive_to_date32`]    |
+//! | `date64`                 | [`NaiveDate`]           | [`date64_to_naive`], [`naive_to_date64`]    |
+//! | `time32`, `time64`       | [`NaiveTime`]           | [`time_to_naive`], [`naive_to_time`]        |
+//! | `timestamp` without zone | [`NaiveDateTime`]       | [`timestamp_to_naive`], [`naive_to_timestamp`] |
+//! | `timestamp` with zone    | [`DateTime`]            | [`timestamp_to_datetime`], [`datetime_to_timestamp`] |
+//!
+//! Arrow has no leap seconds. A [leap second](crate::NaiveTime#leap-second-handling) is written
+//! as if it were the first second of the next minute.
+//!
+//! ```
+//! use chrono::arrow::{self, TimeUnit};
+//! use chrono::NaiveDate;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+//! assert_eq!(arrow::naive_to_date32(date), 19_850);
+//! assert_eq!(arrow::date32_to_naive(19_850), Some(date));
+//!
+//! let dt = arrow::timestamp_to_datetime(1_715_083_445_500, TimeUnit::Millisecond, Some("+02:00"));
+//! let dt = dt.unwrap();
+//! assert_eq!(dt.naive_local(), date.and_hms_milli_opt(14, 4, 5, 500).unwrap());
+//! assert_eq!(dt.offset().local_minus_utc(), 2 * 3600);
+//! ```
+
+use crate::format::{INVALID, OUT_OF_RANGE, ParseResult};
+use crate::offset::{FixedOffset, TimeZone, Utc};
+use crate::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// The unit of an Arrow `time32`, `time64` or `timestamp` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// Seconds.
+    Second,
+    /// Milliseconds.
+    Millisecond,
+    /// Microseconds.
+    Microsecond,
+    /// Nanoseconds.
+    Nanosecond,
+}
+
+impl TimeUnit {
+    /// Returns the number of nanoseconds in one unit.
+    const fn nanos(self) -> i64 {
+        match self {
+            TimeUnit::Second => 1_000_000_000,
+            TimeUnit::Millisecond => 1_000_000,
+            TimeUnit::Microsecond => 1_000,
+            TimeUnit::Nanosecond => 1,
+        }
+    }
+}
+
+/// Converts an Arrow `date32` value, the number of days since January 1, 1970, to a `NaiveDate`.
+///
+/// # Errors
+///
+/// Returns `None` if the date is out of range.
+#[must_use]
+pub const fn date32_to_naive(days: i32) -> Option<NaiveDate> {
+    NaiveDate::from_days_since_epoch(days)
+}
+
+/// Converts a `NaiveDate` to an Arrow `date32` value.
+#[must_use]
+pub const fn naive_to_date32(date: NaiveDate) -> i32 {
+    date.days_since_epoch()
+}
+
+/// Converts an Arrow `date64` value, the number of milliseconds since January 1, 1970, to a
+/// `NaiveDate`.
+///
+/// # Errors
+///
+/// Returns `None` if `millis` is not a whole number of days, as the Arrow specification
+/// requires, or if the date is out of range.
+#[must_use]
+pub const fn date64_to_naive(millis: i64) -> Option<NaiveDate> {
+    if millis % MILLIS_PER_DAY != 0 {
+        return None;
+    }
+    NaiveDate::from_epoch_millis_utc(millis)
+}
+
+/// Converts a `NaiveDate` to an Arrow `date64` value.
+#[must_use]
+pub const fn naive_to_date64(date: NaiveDate) -> i64 {
+    date.days_since_epoch() as i64 * MILLIS_PER_DAY
+}
+
+/// Converts an Arrow `time32` or `time64` value, the time since midnight in `unit`, to a
+/// `NaiveTime`.
+///
+/// Arrow uses `time32` for seconds and milliseconds, and `time64` for microseconds and
+/// nanoseconds; a `time32` value can be passed with `i64::from`.
+///
+/// # Errors
+///
+/// Returns `None` if `value` is negative or not less than one day.
+///
+/// # Example
+///
+/// ```
+/// use chrono::arrow::{time_to_naive, TimeUnit};
+/// use chrono::NaiveTime;
+///
+/// let time = NaiveTime::from_hms_micro_opt(12, 34, 56, 789).unwrap();
+/// assert_eq!(time_to_naive(45_296_000_789, TimeUnit::Microsecond), Some(time));
+/// assert_eq!(time_to_naive(86_400, TimeUnit::Second), None);
+/// ```
+#[must_use]
+pub const fn time_to_naive(value: i64, unit: TimeUnit) -> Option<NaiveTime> {
+    let per_sec = 1_000_000_000 / unit.nanos();
+    if value < 0 || value >= 86_400 * per_sec {
+        return None;
+    }
+    let secs = (value / per_sec) as u32;
+    let nanos = ((value % per_sec) * unit.nanos()) as u32;
+    NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+}
+
+/// Converts a `NaiveTime` to an Arrow `time32` or `time64` value in `unit`.
+///
+/// Sub-unit precision is truncated. Use `i32::try_from` on the result for a `time32` column.
+///
+/// # Errors
+///
+/// Returns `None` for a leap second in the last minute of the day, which Arrow can not represent.
+#[must_use]
+pub const fn naive_to_time(time: NaiveTime, unit: TimeUnit) -> Option<i64> {
+    let nanos = time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64;
+    if nanos >= 86_400 * 1_000_000_000 {
+        return None;
+    }
+    Some(nanos / unit.nanos())
+}
+
+/// Converts an Arrow `timestamp` value without a time zone to a `NaiveDateTime`.
+///
+/// # Errors
+///
+/// Returns `None` if the date and time are out of range.
+#[must_use]
+pub const fn timestamp_to_naive(value: i64, unit: TimeUnit) -> Option<NaiveDateTime> {
+    match timestamp_to_utc(value, unit) {
+        Some(dt) => Some(dt.naive_utc()),
+        None => None,
+    }
+}
+
+/// Converts a `NaiveDateTime` to an Arrow `timestamp` value without a time zone.
+///
+/// Sub-unit precision is truncated.
+///
+/// # Errors
+///
+/// Returns `None` if the value does not fit in an `i64`, which is only possible with
+/// [`TimeUnit::Nanosecond`] for dates outside the years 1677 to 2262.
+#[must_use]
+pub const fn naive_to_timestamp(dt: NaiveDateTime, unit: TimeUnit) -> Option<i64> {
+    utc_to_timestamp(dt.and_utc(), unit)
+}
+
+/// Converts an Arrow `timestamp` value with an optional time zone to a `DateTime<FixedOffset>`.
+///
+/// The value is the time since January 1, 1970 UTC in `unit`. The time zone only determines the
+/// offset of the result; it can be a fixed offset such as `+02:00`, or `UTC`. Without a time zone
+/// the offset is zero.
+///
+/// # Errors
+///
+/// Returns an [`OutOfRange`](crate::format::ParseErrorKind::OutOfRange) error if the date and
+/// time are out of range, and an error of another kind if the time zone is not a fixed offset.
+/// Time zone names other than `UTC` are not supported.
+///
+/// # Example
+///
+/// ```
+/// use chrono::arrow::{timestamp_to_datetime, TimeUnit};
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+/// let dt = timestamp_to_datetime(1_715_083_445, TimeUnit::Second, Some("-05:00")).unwrap();
+/// assert_eq!(dt, offset.with_ymd_and_hms(2024, 5, 7, 7, 4, 5).unwrap());
+/// assert_eq!(dt.offset(), &offset);
+/// let dt = timestamp_to_datetime(1_715_083_445, TimeUnit::Second, None).unwrap();
+/// assert_eq!(dt.offset().local_minus_utc(), 0);
+/// assert!(timestamp_to_datetime(0, TimeUnit::Second, Some("Europe/Berlin")).is_err());
+/// ```
+pub fn timestamp_to_datetime(
+    value: i64,
+    unit: TimeUnit,
+    tz: Option<&str>,
+) -> ParseResult<DateTime<FixedOffset>> {
+    let offset = match tz {
+        None | Some("UTC" | "Etc/UTC" | "Z") => FixedOffset::east_opt(0).unwrap(),
+        Some(tz) => tz.parse::<FixedOffset>().map_err(|_| INVALID)?,
+    };
+    let utc = timestamp_to_utc(value, unit).ok_or(OUT_OF_RANGE)?;
+    Ok(utc.with_timezone(&offset))
+}
+
+/// Converts a `DateTime` to an Arrow `timestamp` value.
+///
+/// The value is the same for every time zone. Arrow stores the zone once for the whole column;
+/// the `Display` output of `dt.offset().fix()` is a valid Arrow time zone.
+///
+/// # Errors
+///
+/// Returns `None` if the value does not fit in an `i64`, which is only possible with
+/// [`TimeUnit::Nanosecond`] for dates outside the years 1677 to 2262.
+#[must_use]
+pub fn datetime_to_timestamp<Tz: TimeZone>(dt: &DateTime<Tz>, unit: TimeUnit) -> Option<i64> {
+    utc_to_timestamp(dt.to_utc(), unit)
+}
+
+const fn timestamp_to_utc(value: i64, unit: TimeUnit) -> Option<DateTime<Utc>> {
+    match unit {
+        TimeUnit::Second => DateTime::from_timestamp(value, 0),
+        TimeUnit::Millisecond => DateTime::from_timestamp_millis(value),
+        TimeUnit::Microsecond => DateTime::from_timestamp_micros(value),
+        TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(value)),
+    }
+}
+
+const fn utc_to_timestamp(dt: DateTime<Utc>, unit: TimeUnit) -> Option<i64> {
+    match unit {
+        TimeUnit::Second => Some(dt.timestamp()),
+        TimeUnit::Millisecond => Some(dt.timestamp_millis()),
+        TimeUnit::Microsecond => Some(dt.timestamp_micros()),
+        TimeUnit::Nanosecond => dt.timestamp_nanos_opt(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MILLIS_PER_DAY, TimeUnit, date32_to_naive, date64_to_naive, datetime_to_timestamp,
+        naive_to_date32, naive_to_date64, naive_to_time, naive_to_timestamp, time_to_naive,
+        timestamp_to_datetime, timestamp_to_naive,
+    };
+    use crate::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    #[test]
+    fn test_arrow_dates() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(date32_to_naive(-1), Some(ymd(1969, 12, 31)));
+        assert_eq!(date32_to_naive(i32::MAX), None);
+        assert_eq!(naive_to_date32(ymd(2000, 1, 1)), 10_957);
+
+        assert_eq!(date64_to_naive(-MILLIS_PER_DAY), Some(ymd(1969, 12, 31)));
+        assert_eq!(date64_to_naive(MILLIS_PER_DAY + 1), None);
+        assert_eq!(date64_to_naive(i64::MIN / MILLIS_PER_DAY * MILLIS_PER_DAY), None);
+        for date in [NaiveDate::MIN, NaiveDate::MAX, ymd(2024, 2, 29)] {
+            assert_eq!(date32_to_naive(naive_to_date32(date)), Some(date));
+            assert_eq!(date64_to_naive(naive_to_date64(date)), Some(date));
+        }
+    }
+
+    #[test]
+    fn test_arrow_times() {
+        let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 123_456_789).unwrap();
+        assert_eq!(naive_to_time(time, TimeUnit::Second), Some(86_399));
+        assert_eq!(naive_to_time(time, TimeUnit::Millisecond), Some(86_399_123));
+        assert_eq!(naive_to_time(time, TimeUnit::Microsecond), Some(86_399_123_456));
+        assert_eq!(naive_to_time(time, TimeUnit::Nanosecond), Some(86_399_123_456_789));
+        assert_eq!(time_to_naive(86_399_123_456_789, TimeUnit::Nanosecond), Some(time));
+        assert_eq!(
+            time_to_naive(86_399_123, TimeUnit::Millisecond),
+            time.with_nanosecond(123_000_000)
+        );
+
+        assert_eq!(time_to_naive(0, TimeUnit::Second), Some(NaiveTime::MIN));
+        assert_eq!(time_to_naive(-1, TimeUnit::Nanosecond), None);
+        assert_eq!(time_to_naive(86_400_000, TimeUnit::Millisecond), None);
+
+        let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+        assert_eq!(naive_to_time(leap, TimeUnit::Millisecond), None);
+        let leap = NaiveTime::from_hms_milli_opt(11, 59, 59, 1_500).unwrap();
+        assert_eq!(naive_to_time(leap, TimeUnit::Millisecond), Some(43_200_500));
+    }
+
+    #[test]
+    fn test_arrow_timestamps() {
+        let dt = NaiveDate::from_ymd_opt(2024, 5, 7)
+            .unwrap()
+            .and_hms_nano_opt(12, 4, 5, 123_456_789)
+            .unwrap();
+        assert_eq!(naive_to_timestamp(dt, TimeUnit::Second), Some(1_715_083_445));
+        assert_eq!(naive_to_timestamp(dt, TimeUnit::Millisecond), Some(1_715_083_445_123));
+        assert_eq!(naive_to_timestamp(dt, TimeUnit::Microsecond), Some(1_715_083_445_123_456));
+        assert_eq!(naive_to_timestamp(dt, TimeUnit::Nanosecond), Some(1_715_083_445_123_456_789));
+        assert_eq!(timestamp_to_naive(1_715_083_445_123_456_789, TimeUnit::Nanosecond), Some(dt));
+        assert_eq!(timestamp_to_naive(i64::MAX, TimeUnit::Second), None);
+        assert_eq!(naive_to_timestamp(NaiveDateTime::MAX, TimeUnit::Nanosecond), None);
+        assert_eq!(
+            naive_to_timestamp(NaiveDateTime::MAX, TimeUnit::Microsecond),
+            Some(NaiveDateTime::MAX.and_utc().timestamp_micros())
+        );
+
+        let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        let value = 1_715_083_445_123;
+        let expected = dt.and_utc().with_timezone(&tz).with_nanosecond(123_000_000).unwrap();
+        let parsed = timestamp_to_datetime(value, TimeUnit::Millisecond, Some("+05:30")).unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.offset(), &tz);
+        assert_eq!(datetime_to_timestamp(&parsed, TimeUnit::Millisecond), Some(value));
+        assert_eq!(datetime_to_timestamp(&parsed.to_utc(), TimeUnit::Millisecond), Some(value));
+
+        for tz in [None, Some("UTC"), Some("Etc/UTC"), Some("Z"), Some("+00:00")] {
+            let parsed = timestamp_to_datetime(value, TimeUnit::Millisecond, tz).unwrap();
+            assert_eq!(parsed.offset().local_minus_utc(), 0);
+        }
+
+        use crate::format::ParseErrorKind;
+        let err = timestamp_to_datetime(i64::MAX, TimeUnit::Second, None).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::OutOfRange);
+        let err = timestamp_to_datetime(0, TimeUnit::Second, Some("Asia/Tokyo")).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Invalid);
+        let err = timestamp_to_datetime(0, TimeUnit::Second, Some("+25:00")).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Invalid);
+    }
+}
@@ -0,0 +1,147 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Low-level arithmetic of the proleptic Gregorian calendar.
+//!
+//! These are the building blocks chrono itself uses for [`NaiveDate`](crate::NaiveDate), exposed
+//! for crates that implement other calendars on top of chrono. They follow the semver guarantees
+//! of the rest of the public API: their behavior will not change within a major version.
+//!
+//! The Gregorian calendar repeats itself every 400 years, so a year can be split into the number
+//! of its 400-year cycle and the position within that cycle. Cycle 0 starts on January 1 of year
+//! 0 (1 BCE); years are numbered astronomically.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::calendar_math::{cycle_to_year, year_to_cycle};
+//!
+//! // Day 60 of 2024 is February 29.
+//! let (cycle, day) = year_to_cycle(2024, 60).unwrap();
+//! assert_eq!((cycle, day), (5, 8825));
+//!
+//! // The same day 400 years later.
+//! let (cycle, day) = year_to_cycle(2424, 60).unwrap();
+//! assert_eq!((cycle, day), (6, 8825));
+//! assert_eq!(cycle_to_year(cycle, day), Some((2424, 60)));
+//! ```
+
+use crate::naive::date::{cycle_to_yo, yo_to_cycle};
+use crate::try_opt;
+
+/// The number of days in a common year.
+pub const DAYS_IN_COMMON_YEAR: u32 = 365;
+
+/// The number of days in a leap year.
+pub const DAYS_IN_LEAP_YEAR: u32 = 366;
+
+/// The number of years after which the Gregorian calendar repeats itself.
+pub const YEARS_IN_CYCLE: i32 = 400;
+
+/// The number of days in a 400-year cycle of the Gregorian calendar.
+pub const DAYS_IN_400_YEAR_CYCLE: i32 = 146_097;
+
+/// Returns `true` if `year` is a leap year in the proleptic Gregorian calendar.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar_math::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(is_leap_year(2000));
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(0));
+/// assert!(is_leap_year(-4));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_leap_year(year: i32) -> bool {
+    year % 400 == 0 || (year % 4 == 0 && year % 100 != 0)
+}
+
+/// Returns the number of days in `year`, 365 or 366.
+#[inline]
+#[must_use]
+pub const fn days_in_year(year: i32) -> u32 {
+    match is_leap_year(year) {
+        true => DAYS_IN_LEAP_YEAR,
+        false => DAYS_IN_COMMON_YEAR,
+    }
+}
+
+/// Splits a year and day of the year (starting at 1) into a 400-year cycle number and the day
+/// within that cycle (starting at 0).
+///
+/// # Errors
+///
+/// Returns `None` if `ordinal` is not a valid day of `year`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar_math::year_to_cycle;
+///
+/// assert_eq!(year_to_cycle(0, 1), Some((0, 0)));
+/// assert_eq!(year_to_cycle(-1, 365), Some((-1, 146_096)));
+/// assert_eq!(year_to_cycle(2023, 366), None);
+/// ```
+#[must_use]
+pub const fn year_to_cycle(year: i32, ordinal: u32) -> Option<(i32, u32)> {
+    if ordinal == 0 || ordinal > days_in_year(year) {
+        return None;
+    }
+    let cycle = year.div_euclid(YEARS_IN_CYCLE);
+    let year_mod_400 = year.rem_euclid(YEARS_IN_CYCLE) as u32;
+    Some((cycle, yo_to_cycle(year_mod_400, ordinal)))
+}
+
+/// Combines a 400-year cycle number and the day within that cycle (starting at 0) into a year
+/// and day of the year (starting at 1). This is the inverse of [`year_to_cycle`].
+///
+/// # Errors
+///
+/// Returns `None` if `day` is not less than [`DAYS_IN_400_YEAR_CYCLE`], or if the year would
+/// overflow an `i32`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar_math::cycle_to_year;
+///
+/// assert_eq!(cycle_to_year(0, 0), Some((0, 1)));
+/// assert_eq!(cycle_to_year(5, 8825), Some((2024, 60)));
+/// assert_eq!(cycle_to_year(0, 146_097), None);
+/// ```
+#[must_use]
+pub const fn cycle_to_year(cycle: i32, day: u32) -> Option<(i32, u32)> {
+    if day >= DAYS_IN_400_YEAR_CYCLE as u32 {
+        return None;
+    }
+    let (year_mod_400, ordinal) = cycle_to_yo(day);
+    let year = try_opt!(cycle.checked_mul(YEARS_IN_CYCLE));
+    Some((try_opt!(year.checked_add(year_mod_400 as i32)), ordinal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DAYS_IN_400_YEAR_CYCLE, cycle_to_year, days_in_year, is_leap_year, year_to_cycle};
+
+    #[test]
+    fn test_cycle_roundtrip() {
+        let mut total = 0;
+        for year in 0..400 {
+            assert_eq!(days_in_year(year), if is_leap_year(year) { 366 } else { 365 });
+            for ordinal in 1..=days_in_year(year) {
+                let (cycle, day) = year_to_cycle(year, ordinal).unwrap();
+                assert_eq!((cycle, day), (0, total));
+                assert_eq!(cycle_to_year(cycle, day), Some((year, ordinal)));
+                assert_eq!(cycle_to_year(cycle - 3, day), Some((year - 1200, ordinal)));
+                total += 1;
+            }
+        }
+        assert_eq!(total, DAYS_IN_400_YEAR_CYCLE as u32);
+        assert_eq!(year_to_cycle(2024, 0), None);
+        assert_eq!(cycle_to_year(i32::MAX, 0), None);
+    }
+}
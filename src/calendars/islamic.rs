@@ -0,0 +1,197 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The tabular Islamic calendar.
+
+use super::Calendar;
+use crate::NaiveDate;
+
+/// The civil tabular Islamic calendar.
+///
+/// This is an arithmetical approximation of the lunar Islamic calendar. Months alternate between
+/// 30 and 29 days, and in 11 years of every 30-year cycle (years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26
+/// and 29) the last month has 30 instead of 29 days. Year 1 starts on July 16, 622 in the Julian
+/// calendar (the "civil" or Friday epoch).
+///
+/// Actual months in the religious calendar begin with the sighting of the new moon, and can
+/// differ from this calendar by a day or two.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use chrono::calendars::{Calendar, IslamicCivil, IslamicDate};
+///
+/// let date = IslamicDate::from_ymd_opt(1, 1, 1).unwrap();
+/// assert_eq!(IslamicCivil.to_naive_date(&date), NaiveDate::from_julian_calendar(622, 7, 16));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IslamicCivil;
+
+/// A date in the [`IslamicCivil`] calendar.
+///
+/// Dates before year 1 are numbered the same way as years in `NaiveDate`: year 0 is the year
+/// before year 1, year -1 the year before that, and so on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IslamicDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl IslamicDate {
+    /// Makes a new `IslamicDate` from the year, month and day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the month or day is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::calendars::IslamicDate;
+    ///
+    /// assert!(IslamicDate::from_ymd_opt(1446, 12, 29).is_some());
+    /// assert!(IslamicDate::from_ymd_opt(1446, 12, 30).is_none()); // not a leap year
+    /// assert!(IslamicDate::from_ymd_opt(1445, 12, 30).is_some());
+    /// assert!(IslamicDate::from_ymd_opt(1446, 13, 1).is_none());
+    /// ```
+    #[must_use]
+    pub const fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<IslamicDate> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(IslamicDate { year, month, day })
+    }
+
+    /// Returns the year.
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, starting from 1 for Muharram.
+    #[inline]
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from 1.
+    #[inline]
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns `true` if the year of this date is a leap year of 355 days.
+    #[inline]
+    pub const fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+}
+
+impl Calendar for IslamicCivil {
+    type Date = IslamicDate;
+
+    fn to_calendar_date(&self, date: NaiveDate) -> Option<IslamicDate> {
+        let days = i64::from(date.to_jdn()) - EPOCH_JDN;
+        // An estimate that is at most one year off.
+        let mut year = (30 * days + 10_646).div_euclid(10_631);
+        while days < days_before_year(year) {
+            year -= 1;
+        }
+        while days >= days_before_year(year + 1) {
+            year += 1;
+        }
+        let mut day = days - days_before_year(year);
+        let mut month = 1;
+        while day >= i64::from(days_in_month(year as i32, month)) {
+            day -= i64::from(days_in_month(year as i32, month));
+            month += 1;
+        }
+        Some(IslamicDate { year: year as i32, month, day: day as u32 + 1 })
+    }
+
+    fn to_naive_date(&self, date: &IslamicDate) -> Option<NaiveDate> {
+        let month = i64::from(date.month);
+        let days = days_before_year(i64::from(date.year))
+            + 29 * (month - 1)
+            + month / 2
+            + i64::from(date.day)
+            - 1;
+        let jdn = i32::try_from(days + EPOCH_JDN).ok()?;
+        NaiveDate::from_jdn(jdn)
+    }
+}
+
+/// The Julian Day Number of the first day of year 1.
+const EPOCH_JDN: i64 = 1_948_440;
+
+/// The number of days from the first day of year 1 to the first day of `year`.
+const fn days_before_year(year: i64) -> i64 {
+    (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
+
+const fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * (year as i64)).rem_euclid(30) < 11
+}
+
+const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        12 if is_leap_year(year) => 30,
+        _ => 30 - (month + 1) % 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IslamicCivil, IslamicDate};
+    use crate::NaiveDate;
+    use crate::calendars::Calendar;
+
+    #[test]
+    fn test_islamic_civil() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let islamic = |y, m, d| IslamicDate::from_ymd_opt(y, m, d).unwrap();
+        let cases = [
+            (ymd(622, 7, 19), islamic(1, 1, 1)),
+            (ymd(622, 7, 18), islamic(0, 12, 29)),
+            (ymd(2024, 3, 11), islamic(1445, 9, 1)),
+            (ymd(2024, 7, 8), islamic(1446, 1, 1)),
+            (ymd(2024, 7, 7), islamic(1445, 12, 30)),
+            (ymd(1900, 5, 1), islamic(1318, 1, 1)),
+        ];
+        for (date, expected) in cases {
+            assert_eq!(IslamicCivil.to_calendar_date(date), Some(expected), "{}", date);
+            assert_eq!(IslamicCivil.to_naive_date(&expected), Some(date));
+        }
+
+        assert!(islamic(1445, 1, 1).is_leap_year());
+        assert!(!islamic(1446, 1, 1).is_leap_year());
+        assert_eq!(IslamicDate::from_ymd_opt(1445, 2, 30), None);
+        assert_eq!(IslamicDate::from_ymd_opt(1445, 0, 1), None);
+        assert_eq!(IslamicCivil.to_naive_date(&islamic(i32::MAX, 1, 1)), None);
+        assert_eq!(IslamicCivil.to_naive_date(&islamic(i32::MIN, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_islamic_civil_roundtrip() {
+        for date in [NaiveDate::MIN, NaiveDate::MAX] {
+            let islamic = IslamicCivil.to_calendar_date(date).unwrap();
+            assert_eq!(IslamicCivil.to_naive_date(&islamic), Some(date));
+        }
+
+        let mut date = NaiveDate::from_ymd_opt(500, 1, 1).unwrap();
+        let mut prev = IslamicCivil.to_calendar_date(date).unwrap();
+        while date < NaiveDate::from_ymd_opt(2500, 1, 1).unwrap() {
+            date = date.succ_opt().unwrap();
+            let islamic = IslamicCivil.to_calendar_date(date).unwrap();
+            let next = IslamicDate::from_ymd_opt(prev.year(), prev.month(), prev.day() + 1)
+                .or_else(|| IslamicDate::from_ymd_opt(prev.year(), prev.month() + 1, 1))
+                .or_else(|| IslamicDate::from_ymd_opt(prev.year() + 1, 1, 1))
+                .unwrap();
+            assert_eq!(islamic, next);
+            assert_eq!(IslamicCivil.to_naive_date(&islamic), Some(date));
+            prev = islamic;
+        }
+    }
+}
@@ -0,0 +1,254 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The Japanese calendar with era-based year numbering.
+
+use core::fmt;
+
+use super::Calendar;
+use crate::{Datelike, NaiveDate, expect};
+
+/// The Japanese calendar, which numbers years by era (*gengō*).
+///
+/// Months and days are the same as in the Gregorian calendar, which Japan adopted on January 1,
+/// 1873 (Meiji 6). Earlier dates used a lunisolar calendar and can not be represented.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use chrono::calendars::{Calendar, Japanese, JapaneseDate, JapaneseEra};
+///
+/// let date = JapaneseDate::new(JapaneseEra::Heisei, 31, 4, 30).unwrap();
+/// assert_eq!(Japanese.to_naive_date(&date), NaiveDate::from_ymd_opt(2019, 4, 30));
+///
+/// let next = Japanese.to_calendar_date(NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()).unwrap();
+/// assert_eq!(next.to_string(), "Reiwa 1-05-01");
+///
+/// // Before the adoption of the Gregorian calendar.
+/// assert_eq!(Japanese.to_calendar_date(NaiveDate::from_ymd_opt(1872, 12, 31).unwrap()), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Japanese;
+
+/// An era of the [`Japanese`] calendar.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JapaneseEra {
+    /// Meiji (明治), from 1868. Only supported from January 1, 1873 (Meiji 6).
+    Meiji,
+    /// Taishō (大正), from July 30, 1912.
+    Taisho,
+    /// Shōwa (昭和), from December 25, 1926.
+    Showa,
+    /// Heisei (平成), from January 8, 1989.
+    Heisei,
+    /// Reiwa (令和), from May 1, 2019.
+    Reiwa,
+}
+
+impl JapaneseEra {
+    const ALL: [JapaneseEra; 5] = [
+        JapaneseEra::Meiji,
+        JapaneseEra::Taisho,
+        JapaneseEra::Showa,
+        JapaneseEra::Heisei,
+        JapaneseEra::Reiwa,
+    ];
+
+    /// Returns the romanized name of the era, such as `"Reiwa"`.
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            JapaneseEra::Meiji => "Meiji",
+            JapaneseEra::Taisho => "Taisho",
+            JapaneseEra::Showa => "Showa",
+            JapaneseEra::Heisei => "Heisei",
+            JapaneseEra::Reiwa => "Reiwa",
+        }
+    }
+
+    /// Returns the name of the era in Japanese, such as `"令和"`.
+    pub const fn kanji(&self) -> &'static str {
+        match *self {
+            JapaneseEra::Meiji => "明治",
+            JapaneseEra::Taisho => "大正",
+            JapaneseEra::Showa => "昭和",
+            JapaneseEra::Heisei => "平成",
+            JapaneseEra::Reiwa => "令和",
+        }
+    }
+
+    /// Returns the first supported day of the era.
+    ///
+    /// For all eras but [`Meiji`](JapaneseEra::Meiji) this is the day the era started.
+    pub const fn start(&self) -> NaiveDate {
+        let (year, month, day) = match *self {
+            JapaneseEra::Meiji => (1873, 1, 1),
+            JapaneseEra::Taisho => (1912, 7, 30),
+            JapaneseEra::Showa => (1926, 12, 25),
+            JapaneseEra::Heisei => (1989, 1, 8),
+            JapaneseEra::Reiwa => (2019, 5, 1),
+        };
+        expect(NaiveDate::from_ymd_opt(year, month, day), "valid date")
+    }
+
+    /// Returns the Gregorian year in which year 1 of the era falls.
+    const fn first_year(&self) -> i32 {
+        match *self {
+            JapaneseEra::Meiji => 1868,
+            JapaneseEra::Taisho => 1912,
+            JapaneseEra::Showa => 1926,
+            JapaneseEra::Heisei => 1989,
+            JapaneseEra::Reiwa => 2019,
+        }
+    }
+
+    /// Returns the era that follows this one, if it is known.
+    const fn next(&self) -> Option<JapaneseEra> {
+        match *self {
+            JapaneseEra::Meiji => Some(JapaneseEra::Taisho),
+            JapaneseEra::Taisho => Some(JapaneseEra::Showa),
+            JapaneseEra::Showa => Some(JapaneseEra::Heisei),
+            JapaneseEra::Heisei => Some(JapaneseEra::Reiwa),
+            JapaneseEra::Reiwa => None,
+        }
+    }
+}
+
+impl fmt::Display for JapaneseEra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A date in the [`Japanese`] calendar.
+///
+/// The `Display` implementation writes the romanized era name followed by the year, month and
+/// day, such as `Reiwa 6-05-07`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JapaneseDate {
+    era: JapaneseEra,
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl JapaneseDate {
+    /// Makes a new `JapaneseDate` from the era, year of the era, month and day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date does not exist, or does not fall within the given era.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::calendars::{JapaneseDate, JapaneseEra};
+    ///
+    /// // Shōwa 64 lasted only a week.
+    /// assert!(JapaneseDate::new(JapaneseEra::Showa, 64, 1, 7).is_some());
+    /// assert!(JapaneseDate::new(JapaneseEra::Showa, 64, 1, 8).is_none());
+    /// assert!(JapaneseDate::new(JapaneseEra::Heisei, 1, 1, 8).is_some());
+    /// assert!(JapaneseDate::new(JapaneseEra::Reiwa, 0, 5, 1).is_none());
+    /// ```
+    #[must_use]
+    pub fn new(era: JapaneseEra, year: u32, month: u32, day: u32) -> Option<JapaneseDate> {
+        let date = JapaneseDate { era, year, month, day };
+        Japanese.to_naive_date(&date)?;
+        Some(date)
+    }
+
+    /// Returns the era.
+    #[inline]
+    pub const fn era(&self) -> JapaneseEra {
+        self.era
+    }
+
+    /// Returns the year within the era, starting from 1.
+    #[inline]
+    pub const fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the month, starting from 1.
+    #[inline]
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from 1.
+    #[inline]
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+impl fmt::Display for JapaneseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}-{:02}-{:02}", self.era, self.year, self.month, self.day)
+    }
+}
+
+impl Calendar for Japanese {
+    type Date = JapaneseDate;
+
+    fn to_calendar_date(&self, date: NaiveDate) -> Option<JapaneseDate> {
+        let era = *JapaneseEra::ALL.iter().rev().find(|era| date >= era.start())?;
+        let year = (date.year() - era.first_year() + 1) as u32;
+        Some(JapaneseDate { era, year, month: date.month(), day: date.day() })
+    }
+
+    fn to_naive_date(&self, date: &JapaneseDate) -> Option<NaiveDate> {
+        let year = i32::try_from(date.year).ok()?.checked_add(date.era.first_year() - 1)?;
+        let naive = NaiveDate::from_ymd_opt(year, date.month, date.day)?;
+        let next_start = date.era.next().map(|era| era.start());
+        match naive >= date.era.start() && next_start.map_or(true, |next| naive < next) {
+            true => Some(naive),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Japanese, JapaneseDate, JapaneseEra};
+    use crate::calendars::Calendar;
+    use crate::{Datelike, NaiveDate};
+
+    #[test]
+    fn test_japanese() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let cases = [
+            (ymd(1873, 1, 1), JapaneseEra::Meiji, 6),
+            (ymd(1912, 7, 29), JapaneseEra::Meiji, 45),
+            (ymd(1912, 7, 30), JapaneseEra::Taisho, 1),
+            (ymd(1926, 12, 24), JapaneseEra::Taisho, 15),
+            (ymd(1926, 12, 25), JapaneseEra::Showa, 1),
+            (ymd(1989, 1, 7), JapaneseEra::Showa, 64),
+            (ymd(1989, 1, 8), JapaneseEra::Heisei, 1),
+            (ymd(2019, 4, 30), JapaneseEra::Heisei, 31),
+            (ymd(2019, 5, 1), JapaneseEra::Reiwa, 1),
+            (ymd(2024, 5, 7), JapaneseEra::Reiwa, 6),
+        ];
+        for (date, era, year) in cases {
+            let japanese = Japanese.to_calendar_date(date).unwrap();
+            assert_eq!((japanese.era(), japanese.year()), (era, year), "{}", date);
+            assert_eq!((japanese.month(), japanese.day()), (date.month(), date.day()));
+            assert_eq!(Japanese.to_naive_date(&japanese), Some(date));
+            assert_eq!(
+                JapaneseDate::new(era, year, japanese.month(), japanese.day()),
+                Some(japanese)
+            );
+        }
+
+        assert_eq!(Japanese.to_calendar_date(NaiveDate::MIN), None);
+        assert!(Japanese.to_calendar_date(NaiveDate::MAX).is_some());
+        assert_eq!(JapaneseDate::new(JapaneseEra::Meiji, 5, 12, 31), None);
+        assert_eq!(JapaneseDate::new(JapaneseEra::Heisei, 31, 5, 1), None);
+        assert_eq!(JapaneseDate::new(JapaneseEra::Reiwa, 1, 4, 30), None);
+        assert_eq!(JapaneseDate::new(JapaneseEra::Reiwa, 6, 2, 30), None);
+        assert_eq!(JapaneseDate::new(JapaneseEra::Reiwa, u32::MAX, 1, 1), None);
+        assert_eq!(JapaneseEra::Reiwa.to_string(), "Reiwa");
+        assert_eq!(JapaneseEra::Showa.kanji(), "昭和");
+    }
+}
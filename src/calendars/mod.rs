@@ -0,0 +1,60 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Conversion between the proleptic Gregorian calendar of [`NaiveDate`] and other calendars.
+//!
+//! Each calendar implements the [`Calendar`] trait, which converts its own date type to and from a
+//! `NaiveDate`. All arithmetic, formatting and parsing is still done on `NaiveDate`; convert to the
+//! other calendar only to present a date to the user, or to take one in.
+//!
+//! The following calendars are available:
+//!
+//! - [`IslamicCivil`]: the tabular (arithmetical) Islamic calendar.
+//! - [`Japanese`]: the Gregorian calendar with years counted in Japanese eras.
+//!
+//! The `%EC`, `%Ey` and `%EY` specifiers of [`strftime`](crate::format::strftime) format the year
+//! in the era of the formatting locale, such as the Japanese era with the `ja_JP` locale.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use chrono::calendars::{Calendar, IslamicCivil, Japanese, JapaneseEra};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+//!
+//! let islamic = IslamicCivil.to_calendar_date(date).unwrap();
+//! assert_eq!((islamic.year(), islamic.month(), islamic.day()), (1445, 9, 1));
+//!
+//! let japanese = Japanese.to_calendar_date(date).unwrap();
+//! assert_eq!((japanese.era(), japanese.year()), (JapaneseEra::Reiwa, 6));
+//! assert_eq!(Japanese.to_naive_date(&japanese), Some(date));
+//! ```
+
+use crate::NaiveDate;
+
+mod islamic;
+mod japanese;
+
+pub use self::islamic::{IslamicCivil, IslamicDate};
+pub use self::japanese::{Japanese, JapaneseDate, JapaneseEra};
+
+/// A calendar system that can be converted to and from the proleptic Gregorian calendar.
+pub trait Calendar {
+    /// The representation of a date in this calendar.
+    type Date;
+
+    /// Converts a `NaiveDate` to a date in this calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `date` can not be represented in this calendar.
+    fn to_calendar_date(&self, date: NaiveDate) -> Option<Self::Date>;
+
+    /// Converts a date in this calendar to a `NaiveDate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range for `NaiveDate`.
+    fn to_naive_date(&self, date: &Self::Date) -> Option<NaiveDate>;
+}
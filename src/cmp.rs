@@ -0,0 +1,256 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Finding the earliest and latest of a collection of date and time values, and ordering them
+//! newest first.
+//!
+//! Values are always compared by the instant in time they represent, no matter their offset. This
+//! matters for types like `DateTime<FixedOffset>` or `DateTime<Local>` where each value can have
+//! a different offset: comparing their local date and time instead gives the wrong result, which
+//! is easy to do by accident after calling [`DateTime::naive_local`].
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+use crate::{DateTime, TimeZone};
+
+/// Returns the earliest instant in `iter`, or `None` if it is empty.
+///
+/// If several values represent the same instant, the first one is returned.
+///
+/// # Example
+///
+/// ```
+/// use chrono::cmp::earliest;
+/// use chrono::DateTime;
+///
+/// let values = [
+///     DateTime::parse_from_rfc3339("2024-05-07T10:00:00+02:00").unwrap(),
+///     DateTime::parse_from_rfc3339("2024-05-07T09:00:00+00:00").unwrap(),
+/// ];
+/// // 08:00 UTC comes before 09:00 UTC, even though the local time 10:00 comes after 09:00.
+/// assert_eq!(earliest(&values), Some(values[0]));
+/// assert_eq!(earliest(Vec::<DateTime<chrono::Utc>>::new()), None);
+/// ```
+pub fn earliest<Tz, I>(iter: I) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    I: IntoIterator,
+    I::Item: Borrow<DateTime<Tz>>,
+{
+    let mut iter = iter.into_iter();
+    let mut min = iter.next()?;
+    for dt in iter {
+        if dt.borrow() < min.borrow() {
+            min = dt;
+        }
+    }
+    Some(min.borrow().clone())
+}
+
+/// Returns the latest instant in `iter`, or `None` if it is empty.
+///
+/// If several values represent the same instant, the last one is returned.
+///
+/// # Example
+///
+/// ```
+/// use chrono::cmp::latest;
+/// use chrono::DateTime;
+///
+/// let values = [
+///     DateTime::parse_from_rfc3339("2024-05-07T10:00:00+02:00").unwrap(),
+///     DateTime::parse_from_rfc3339("2024-05-07T09:00:00+00:00").unwrap(),
+/// ];
+/// assert_eq!(latest(values), Some(values[1]));
+/// ```
+pub fn latest<Tz, I>(iter: I) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    I: IntoIterator,
+    I::Item: Borrow<DateTime<Tz>>,
+{
+    let mut iter = iter.into_iter();
+    let mut max = iter.next()?;
+    for dt in iter {
+        if dt.borrow() >= max.borrow() {
+            max = dt;
+        }
+    }
+    Some(max.borrow().clone())
+}
+
+/// Extension methods for iterators over [`DateTime`] values.
+///
+/// This is implemented for all iterators over `DateTime<Tz>` or `&DateTime<Tz>`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::cmp::DateTimeIterator;
+/// use chrono::{TimeZone, Utc};
+///
+/// let values = [
+///     Utc.with_ymd_and_hms(2024, 5, 7, 9, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+/// ];
+/// assert_eq!(values.iter().min_datetime(), Some(values[1]));
+/// assert_eq!(values.iter().max_datetime(), Some(values[0]));
+/// ```
+pub trait DateTimeIterator<Tz: TimeZone>: Iterator + Sized
+where
+    Self::Item: Borrow<DateTime<Tz>>,
+{
+    /// Returns the earliest instant, or `None` if the iterator is empty.
+    ///
+    /// See [`earliest`] for details.
+    fn min_datetime(self) -> Option<DateTime<Tz>> {
+        earliest(self)
+    }
+
+    /// Returns the latest instant, or `None` if the iterator is empty.
+    ///
+    /// See [`latest`] for details.
+    fn max_datetime(self) -> Option<DateTime<Tz>> {
+        latest(self)
+    }
+}
+
+impl<Tz, I> DateTimeIterator<Tz> for I
+where
+    Tz: TimeZone,
+    I: Iterator,
+    I::Item: Borrow<DateTime<Tz>>,
+{
+}
+
+/// A wrapper that orders date and time values newest first.
+///
+/// This is like [`core::cmp::Reverse`], but says what the ordering is for. Put it around the values
+/// in a [`BinaryHeap`](std::collections::BinaryHeap), which is a max-heap, to pop the oldest value
+/// first, or sort by it to get the most recent values first.
+///
+/// # Example
+///
+/// ```
+/// use chrono::cmp::Newest;
+/// use chrono::{TimeZone, Utc};
+/// use std::collections::BinaryHeap;
+///
+/// let a = Utc.with_ymd_and_hms(2024, 5, 7, 9, 0, 0).unwrap();
+/// let b = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+/// let c = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+///
+/// let mut values = vec![a, b, c];
+/// values.sort_by_key(|&dt| Newest(dt));
+/// assert_eq!(values, [a, c, b]);
+///
+/// // A scheduler queue that pops the next due time first.
+/// let mut queue: BinaryHeap<_> = [a, b, c].into_iter().map(Newest).collect();
+/// assert_eq!(queue.pop().map(Newest::into_inner), Some(b));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Newest<T>(pub T);
+
+impl<T> Newest<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Newest<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl<T: Ord> Ord for Newest<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Compares two values so that the most recent one comes first.
+///
+/// This can be passed to methods such as [`slice::sort_by`] directly, instead of writing a closure
+/// with the arguments in the right order.
+///
+/// # Example
+///
+/// ```
+/// use chrono::cmp::cmp_recent_first;
+/// use chrono::NaiveDate;
+///
+/// let mut dates = [
+///     NaiveDate::from_ymd_opt(2023, 5, 7).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 5, 7).unwrap(),
+/// ];
+/// dates.sort_by(cmp_recent_first);
+/// assert_eq!(dates[0], NaiveDate::from_ymd_opt(2024, 5, 7).unwrap());
+/// ```
+pub fn cmp_recent_first<T: Ord>(a: &T, b: &T) -> Ordering {
+    b.cmp(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::{DateTimeIterator, Newest, cmp_recent_first, earliest, latest};
+    use crate::{DateTime, FixedOffset, TimeZone, Utc};
+
+    #[test]
+    fn test_earliest_latest() {
+        let east = FixedOffset::east_opt(5 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+        // Sorted by local time these are the other way around.
+        let a = east.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap(); // 07:00 UTC
+        let b = Utc.with_ymd_and_hms(2024, 5, 7, 10, 0, 0).unwrap().fixed_offset();
+        let c = west.with_ymd_and_hms(2024, 5, 7, 8, 0, 0).unwrap(); // 13:00 UTC
+        let values = [b, c, a];
+
+        assert_eq!(earliest(values), Some(a));
+        assert_eq!(latest(values), Some(c));
+        assert_eq!(earliest(values.iter()), Some(a));
+        assert_eq!(values.iter().min_datetime(), Some(a));
+        assert_eq!(values.into_iter().max_datetime(), Some(c));
+        assert_eq!(latest(&values[..1]), Some(b));
+
+        let empty: [DateTime<FixedOffset>; 0] = [];
+        assert_eq!(earliest(empty), None);
+        assert_eq!(empty.iter().max_datetime(), None);
+    }
+
+    #[test]
+    fn test_earliest_latest_ties() {
+        let utc = Utc.with_ymd_and_hms(2024, 5, 7, 10, 0, 0).unwrap().fixed_offset();
+        let east = utc.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+        let west = utc.with_timezone(&FixedOffset::west_opt(3600).unwrap());
+        let values = [east, utc, west];
+
+        let min = earliest(values).unwrap();
+        assert_eq!(min.offset(), east.offset());
+        let max = latest(values).unwrap();
+        assert_eq!(max.offset(), west.offset());
+    }
+
+    #[test]
+    fn test_newest() {
+        let east = FixedOffset::east_opt(5 * 3600).unwrap();
+        let a = east.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap(); // 07:00 UTC
+        let b = Utc.with_ymd_and_hms(2024, 5, 7, 10, 0, 0).unwrap().fixed_offset();
+
+        assert!(Newest(b) < Newest(a));
+        assert_eq!(Newest(a).cmp(&Newest(b)), Ordering::Greater);
+        assert_eq!(Newest(a).partial_cmp(&Newest(a)), Some(Ordering::Equal));
+        assert_eq!(cmp_recent_first(&a, &b), Ordering::Greater);
+        assert_eq!(cmp_recent_first(&b, &a), Ordering::Less);
+
+        let mut values = [a, b, a.with_timezone(&Utc).fixed_offset()];
+        values.sort_by(cmp_recent_first);
+        assert_eq!(values, [b, a, a]);
+        assert_eq!(Newest(b).into_inner(), b);
+    }
+}
@@ -0,0 +1,137 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A `DateTime<FixedOffset>` with a stable in-memory layout.
+
+use crate::{DateTime, FixedOffset, try_opt};
+
+/// A [`DateTime<FixedOffset>`] with a stable, C-compatible memory layout.
+///
+/// The layout of `DateTime` is an implementation detail that may change between releases.
+/// `CompactDateTime` is a plain struct of 16 bytes without padding, which makes it suitable for
+/// shared memory, memory-mapped files and other places where the bytes themselves are the
+/// interface. Every bit pattern is a valid `CompactDateTime`, but not every value is a valid
+/// `DateTime`; [`CompactDateTime::to_datetime`] checks the fields.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{CompactDateTime, DateTime};
+///
+/// let dt = DateTime::parse_from_rfc3339("2024-05-07T13:04:05.5+02:00").unwrap();
+/// let compact = CompactDateTime::from_datetime(dt);
+/// assert_eq!(compact.secs, 1_715_079_845);
+/// assert_eq!(compact.nanos, 500_000_000);
+/// assert_eq!(compact.offset, 7200);
+///
+/// let bytes = compact.to_le_bytes();
+/// assert_eq!(CompactDateTime::from_le_bytes(bytes).to_datetime(), Some(dt));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct CompactDateTime {
+    /// The number of non-leap seconds since January 1, 1970 0:00:00 UTC.
+    pub secs: i64,
+    /// The number of nanoseconds since the last whole non-leap second.
+    ///
+    /// This is 1,000,000,000 or more during a [leap second](crate::NaiveTime#leap-second-handling).
+    pub nanos: u32,
+    /// The offset from UTC in seconds.
+    pub offset: i32,
+}
+
+impl CompactDateTime {
+    /// Makes a new `CompactDateTime` from a `DateTime<FixedOffset>`.
+    #[must_use]
+    pub const fn from_datetime(dt: DateTime<FixedOffset>) -> CompactDateTime {
+        CompactDateTime {
+            secs: dt.timestamp(),
+            nanos: dt.timestamp_subsec_nanos(),
+            offset: dt.offset().local_minus_utc(),
+        }
+    }
+
+    /// Converts this value back into a `DateTime<FixedOffset>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the timestamp is out of range for a `DateTime`, if `nanos` is not a valid
+    /// nanosecond or if `offset` is not a valid [`FixedOffset`].
+    #[must_use]
+    pub const fn to_datetime(&self) -> Option<DateTime<FixedOffset>> {
+        let utc = try_opt!(DateTime::from_timestamp(self.secs, self.nanos));
+        let offset = try_opt!(FixedOffset::east_opt(self.offset));
+        Some(DateTime::from_naive_utc_and_offset(utc.naive_utc(), offset))
+    }
+
+    /// Returns the memory representation of this value as a byte array in little-endian order.
+    #[must_use]
+    pub const fn to_le_bytes(&self) -> [u8; 16] {
+        let secs = self.secs.to_le_bytes();
+        let nanos = self.nanos.to_le_bytes();
+        let offset = self.offset.to_le_bytes();
+        let mut bytes = [0; 16];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = secs[i];
+            i += 1;
+        }
+        while i < 12 {
+            bytes[i] = nanos[i - 8];
+            bytes[i + 4] = offset[i - 8];
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Makes a `CompactDateTime` from its representation as a byte array in little-endian order.
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> CompactDateTime {
+        let b = bytes;
+        CompactDateTime {
+            secs: i64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]),
+            nanos: u32::from_le_bytes([b[8], b[9], b[10], b[11]]),
+            offset: i32::from_le_bytes([b[12], b[13], b[14], b[15]]),
+        }
+    }
+}
+
+impl From<DateTime<FixedOffset>> for CompactDateTime {
+    fn from(dt: DateTime<FixedOffset>) -> CompactDateTime {
+        CompactDateTime::from_datetime(dt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompactDateTime;
+    use crate::{DateTime, FixedOffset, NaiveDate};
+
+    #[test]
+    fn test_compact_datetime() {
+        assert_eq!(core::mem::size_of::<CompactDateTime>(), 16);
+        assert_eq!(core::mem::align_of::<CompactDateTime>(), 8);
+
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_250_000_000)
+            .unwrap()
+            .and_local_timezone(FixedOffset::west_opt(5 * 3600).unwrap())
+            .unwrap();
+        let compact = CompactDateTime::from(leap);
+        assert_eq!(compact.nanos, 1_250_000_000);
+        assert_eq!(compact.offset, -5 * 3600);
+        assert_eq!(compact.to_datetime(), Some(leap));
+        assert_eq!(CompactDateTime::from_le_bytes(compact.to_le_bytes()), compact);
+
+        let epoch = DateTime::UNIX_EPOCH.fixed_offset();
+        assert_eq!(CompactDateTime::default().to_datetime(), Some(epoch));
+
+        let invalid = CompactDateTime { secs: i64::MAX, ..Default::default() };
+        assert_eq!(invalid.to_datetime(), None);
+        let invalid = CompactDateTime { nanos: 2_000_000_000, ..Default::default() };
+        assert_eq!(invalid.to_datetime(), None);
+        let invalid = CompactDateTime { offset: 86_400, ..Default::default() };
+        assert_eq!(invalid.to_datetime(), None);
+    }
+}
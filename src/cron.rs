@@ -0,0 +1,530 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Evaluation of cron expressions.
+//!
+//! A [`Schedule`] is parsed from the classic five field cron syntax and computes the times at
+//! which it fires in any [`TimeZone`].
+//!
+//! ```
+//! use chrono::cron::Schedule;
+//! use chrono::{TimeZone, Utc};
+//!
+//! // Every five minutes past midnight, on mondays.
+//! let schedule: Schedule = "*/5 0 * * MON".parse().unwrap();
+//! let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap(); // a Tuesday
+//!
+//! let mut upcoming = schedule.after(&dt);
+//! assert_eq!(upcoming.next(), Some(Utc.with_ymd_and_hms(2024, 5, 13, 0, 0, 0).unwrap()));
+//! assert_eq!(upcoming.next(), Some(Utc.with_ymd_and_hms(2024, 5, 13, 0, 5, 0).unwrap()));
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "now")]
+use crate::offset::Utc;
+use crate::offset::{MappedLocalTime, TimeZone};
+use crate::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+/// The number of years to search for a matching date before giving up.
+///
+/// The Gregorian calendar repeats every 400 years, so a schedule that does not fire within
+/// this period will never fire.
+const MAX_YEARS_TO_SEARCH: i32 = 400;
+
+const MONTH_NAMES: [&str; 12] =
+    ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A schedule parsed from a cron expression.
+///
+/// The expression consists of five whitespace-separated fields:
+///
+/// | Field        | Allowed values                     |
+/// |--------------|------------------------------------|
+/// | minute       | `0-59`                             |
+/// | hour         | `0-23`                             |
+/// | day of month | `1-31`                             |
+/// | month        | `1-12` or `JAN-DEC`                |
+/// | day of week  | `0-7` or `SUN-SAT` (0 and 7 are Sunday) |
+///
+/// Every field accepts `*` for all values, a single value, a range such as `1-5`, a step such
+/// as `*/15` or `10-40/10`, and comma-separated lists of those. Names are case-insensitive.
+/// The nicknames `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and
+/// `@hourly` are also supported.
+///
+/// As with the traditional cron implementations a date matches a schedule if both the day of
+/// month and the day of week match; if neither field starts with `*`, matching either one is
+/// enough.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    dom_star: bool,
+    dow_star: bool,
+}
+
+impl Schedule {
+    /// Parses a cron expression.
+    ///
+    /// This is equivalent to `s.parse::<Schedule>()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseScheduleError`] if the expression does not consist of five valid fields
+    /// or a known nickname.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::cron::Schedule;
+    ///
+    /// assert!(Schedule::parse("*/5 0 * * MON").is_ok());
+    /// assert!(Schedule::parse("0 0 * * MON-FRI").is_ok());
+    /// assert!(Schedule::parse("60 0 * * *").is_err());
+    /// assert!(Schedule::parse("0 0 * *").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Schedule, ParseScheduleError> {
+        let s = s.trim();
+        let expanded = match s {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ if s.starts_with('@') => return Err(ParseScheduleError(ErrorKind::UnknownNickname)),
+            _ => s,
+        };
+
+        let mut fields = expanded.split_whitespace();
+        let mut next_field = || fields.next().ok_or(ParseScheduleError(ErrorKind::FieldCount));
+        let minute = next_field()?;
+        let hour = next_field()?;
+        let dom = next_field()?;
+        let month = next_field()?;
+        let dow = next_field()?;
+        if fields.next().is_some() {
+            return Err(ParseScheduleError(ErrorKind::FieldCount));
+        }
+
+        let days_of_week = parse_field(dow, 0, 7, &WEEKDAY_NAMES)?;
+        // Both 0 and 7 represent Sunday.
+        let days_of_week = ((days_of_week | (days_of_week >> 7)) & 0x7f) as u8;
+        Ok(Schedule {
+            minutes: parse_field(minute, 0, 59, &[])?,
+            hours: parse_field(hour, 0, 23, &[])? as u32,
+            days_of_month: parse_field(dom, 1, 31, &[])? as u32,
+            months: parse_field(month, 1, 12, &MONTH_NAMES)? as u16,
+            days_of_week,
+            dom_star: dom.starts_with('*'),
+            dow_star: dow.starts_with('*'),
+        })
+    }
+
+    /// Returns the first time after `dt` at which the schedule fires.
+    ///
+    /// Schedules are evaluated against the local time in the time zone of `dt` with a resolution
+    /// of one minute. A local time that occurs twice because of a daylight saving time
+    /// transition fires only once, at its earliest occurrence. A local time that is skipped by a
+    /// transition does not fire.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the schedule never fires after `dt`, for example for `0 0 30 2 *`, or if
+    /// the next occurrence would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::cron::Schedule;
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let schedule = Schedule::parse("30 9 * * MON-FRI").unwrap();
+    /// let tz = FixedOffset::east_opt(2 * 60 * 60).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 5, 10, 9, 30, 0).unwrap(); // a Friday
+    /// let next = tz.with_ymd_and_hms(2024, 5, 13, 9, 30, 0).unwrap();
+    /// assert_eq!(schedule.next_after(&dt), Some(next));
+    ///
+    /// assert_eq!(Schedule::parse("0 0 30 2 *").unwrap().next_after(&dt), None);
+    /// ```
+    #[must_use]
+    pub fn next_after<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = dt.timezone();
+        let mut local = dt.overflowing_naive_local();
+        loop {
+            local = self.next_naive_after(local)?;
+            match tz.from_local_datetime(&local) {
+                MappedLocalTime::Single(next) if next > *dt => return Some(next),
+                MappedLocalTime::Ambiguous(earliest, _) if earliest > *dt => return Some(earliest),
+                // Either the local time was skipped, or the time zone folded back and the
+                // earliest occurrence has already passed.
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns an iterator over the times at which the schedule fires after `dt`.
+    ///
+    /// See [`Schedule::next_after`] for details.
+    pub fn after<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> Upcoming<'_, Tz> {
+        Upcoming { schedule: self, last: Some(dt.clone()) }
+    }
+
+    /// Returns an iterator over the times at which the schedule fires from now on, in the time
+    /// zone `tz`.
+    ///
+    /// See [`Schedule::next_after`] for details.
+    #[cfg(feature = "now")]
+    pub fn upcoming<Tz: TimeZone>(&self, tz: Tz) -> Upcoming<'_, Tz> {
+        self.after(&Utc::now().with_timezone(&tz))
+    }
+
+    /// Returns `true` if the schedule fires at the local date and time of `dt`.
+    ///
+    /// Seconds and fractional seconds are ignored.
+    pub fn includes<T: Datelike + Timelike>(&self, dt: &T) -> bool {
+        self.months & (1 << dt.month()) != 0
+            && self.hours & (1 << dt.hour()) != 0
+            && self.minutes & (1 << dt.minute()) != 0
+            && self.matches_day(dt.day(), dt.weekday().num_days_from_sunday())
+    }
+
+    /// Returns the first matching local datetime strictly after `after`, truncated to minutes.
+    fn next_naive_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let max_year = after.year().saturating_add(MAX_YEARS_TO_SEARCH);
+        let (mut year, mut month, mut day) = (after.year(), after.month(), after.day());
+        let (mut hour, mut minute) = (after.hour(), after.minute() + 1);
+        loop {
+            if minute > 59 {
+                minute = 0;
+                hour += 1;
+            }
+            if hour > 23 {
+                hour = 0;
+                day += 1;
+            }
+            if day > 31 {
+                day = 1;
+                month += 1;
+            }
+            if month > 12 {
+                month = 1;
+                year += 1;
+            }
+            if year > max_year {
+                return None;
+            }
+
+            if self.months & (1 << month) == 0 {
+                (month, day, hour, minute) = (month + 1, 1, 0, 0);
+                continue;
+            }
+            let date = match NaiveDate::from_ymd_opt(year, month, day) {
+                Some(date) => date,
+                // Past the end of the month.
+                None if day > 28 => {
+                    (month, day, hour, minute) = (month + 1, 1, 0, 0);
+                    continue;
+                }
+                None => return None,
+            };
+            if !self.matches_day(day, date.weekday().num_days_from_sunday()) {
+                (day, hour, minute) = (day + 1, 0, 0);
+                continue;
+            }
+            match next_set_bit(self.hours.into(), hour) {
+                Some(h) if h == hour => {}
+                Some(h) => (hour, minute) = (h, 0),
+                None => {
+                    (day, hour, minute) = (day + 1, 0, 0);
+                    continue;
+                }
+            }
+            match next_set_bit(self.minutes, minute) {
+                Some(m) => return date.and_hms_opt(hour, m, 0),
+                None => {
+                    (hour, minute) = (hour + 1, 0);
+                    continue;
+                }
+            }
+        }
+    }
+
+    fn matches_day(&self, day: u32, weekday_from_sunday: u32) -> bool {
+        let dom = self.days_of_month & (1 << day) != 0;
+        let dow = self.days_of_week & (1 << weekday_from_sunday) != 0;
+        if self.dom_star || self.dow_star { dom && dow } else { dom || dow }
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = ParseScheduleError;
+
+    fn from_str(s: &str) -> Result<Schedule, ParseScheduleError> {
+        Schedule::parse(s)
+    }
+}
+
+/// Iterator over the times at which a [`Schedule`] fires.
+///
+/// Created by [`Schedule::after`] and [`Schedule::upcoming`].
+#[derive(Clone, Debug)]
+pub struct Upcoming<'a, Tz: TimeZone> {
+    schedule: &'a Schedule,
+    last: Option<DateTime<Tz>>,
+}
+
+impl<Tz: TimeZone> Iterator for Upcoming<'_, Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        let next = self.schedule.next_after(self.last.as_ref()?);
+        self.last = next.clone();
+        next
+    }
+}
+
+impl<Tz: TimeZone> core::iter::FusedIterator for Upcoming<'_, Tz> {}
+
+/// Returns the position of the lowest set bit that is at least `from`.
+fn next_set_bit(mask: u64, from: u32) -> Option<u32> {
+    let remaining = mask.checked_shr(from)?;
+    match remaining {
+        0 => None,
+        _ => Some(from + remaining.trailing_zeros()),
+    }
+}
+
+/// Parses a single field of a cron expression into a bitmask of the allowed values.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, ParseScheduleError> {
+    let mut mask = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => {
+                (parse_value(start, min, max, names)?, parse_value(end, min, max, names)?)
+            }
+            // A single value with a step runs to the end of the range, as in `10/15`.
+            None if step.is_some() => (parse_value(range, min, max, names)?, max),
+            None => {
+                let value = parse_value(range, min, max, names)?;
+                (value, value)
+            }
+        };
+        let step = match step {
+            Some(step) => match step.parse::<u32>() {
+                Ok(step) if step > 0 => step,
+                _ => return Err(ParseScheduleError(ErrorKind::InvalidStep)),
+            },
+            None => 1,
+        };
+        if start > end {
+            return Err(ParseScheduleError(ErrorKind::InvalidRange));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(s: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, ParseScheduleError> {
+    let value = match s.parse::<u32>() {
+        Ok(value) => value,
+        Err(_) => match names.iter().position(|name| name.eq_ignore_ascii_case(s)) {
+            Some(pos) => pos as u32 + min,
+            None => return Err(ParseScheduleError(ErrorKind::InvalidValue)),
+        },
+    };
+    if value < min || value > max {
+        return Err(ParseScheduleError(ErrorKind::InvalidValue));
+    }
+    Ok(value)
+}
+
+/// An error resulting from parsing a cron expression.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseScheduleError(ErrorKind);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ErrorKind {
+    FieldCount,
+    UnknownNickname,
+    InvalidValue,
+    InvalidRange,
+    InvalidStep,
+}
+
+impl fmt::Display for ParseScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self.0 {
+            ErrorKind::FieldCount => "expected five fields",
+            ErrorKind::UnknownNickname => "unknown nickname",
+            ErrorKind::InvalidValue => "value out of range or unknown name",
+            ErrorKind::InvalidRange => "range start is after its end",
+            ErrorKind::InvalidStep => "invalid step",
+        };
+        write!(f, "invalid cron expression: {}", description)
+    }
+}
+
+impl fmt::Debug for ParseScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ParseScheduleError({})", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseScheduleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::Schedule;
+    use crate::datetime::tests::DstTester;
+    use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
+    use crate::{DateTime, NaiveDate, TimeDelta};
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let all = Schedule::parse("* * * * *").unwrap();
+        assert_eq!(all.minutes, (1 << 60) - 1);
+        assert_eq!(all.hours, (1 << 24) - 1);
+        assert_eq!(all.days_of_month, ((1u64 << 32) - 2) as u32);
+        assert_eq!(all.months, (1 << 13) - 2);
+        assert_eq!(all.days_of_week, 0x7f);
+
+        let s = Schedule::parse("*/15 9-17/4 1,15 jan-MAR 7").unwrap();
+        assert_eq!(s.minutes, 1 | 1 << 15 | 1 << 30 | 1 << 45);
+        assert_eq!(s.hours, 1 << 9 | 1 << 13 | 1 << 17);
+        assert_eq!(s.days_of_month, 1 << 1 | 1 << 15);
+        assert_eq!(s.months, 1 << 1 | 1 << 2 | 1 << 3);
+        assert_eq!(s.days_of_week, 1);
+        assert!(!s.dom_star && !s.dow_star);
+
+        assert_eq!(Schedule::parse("50/5 * * * *").unwrap().minutes, 1 << 50 | 1 << 55);
+        assert_eq!(Schedule::parse("@daily"), Schedule::parse("0 0 * * *"));
+        assert_eq!(Schedule::parse(" 0  0 * * * "), Schedule::parse("0 0 * * *"));
+        assert_eq!("0 0 * * *".parse::<Schedule>(), Schedule::parse("0 0 * * *"));
+
+        for invalid in [
+            "",
+            "* * * *",
+            "* * * * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * 32 * *",
+            "* * * 0 *",
+            "* * * 13 *",
+            "* * * * 8",
+            "* * * * MONDAY",
+            "*/0 * * * *",
+            "*/x * * * *",
+            "5-1 * * * *",
+            "-1 * * * *",
+            "1,,2 * * * *",
+            "@never",
+        ] {
+            assert!(Schedule::parse(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_next_after() {
+        let schedule = Schedule::parse("*/5 0 * * MON").unwrap();
+        // A Tuesday.
+        assert_eq!(schedule.next_after(&utc(2024, 5, 7, 12, 0)), Some(utc(2024, 5, 13, 0, 0)));
+        assert_eq!(schedule.next_after(&utc(2024, 5, 13, 0, 0)), Some(utc(2024, 5, 13, 0, 5)));
+        let dt = Utc.with_ymd_and_hms(2024, 5, 13, 0, 4, 59).unwrap();
+        assert_eq!(schedule.next_after(&dt), Some(utc(2024, 5, 13, 0, 5)));
+        assert_eq!(schedule.next_after(&utc(2024, 5, 13, 0, 55)), Some(utc(2024, 5, 20, 0, 0)));
+
+        // Month and year boundaries.
+        let schedule = Schedule::parse("0 0 31 * *").unwrap();
+        assert_eq!(schedule.next_after(&utc(2024, 4, 1, 0, 0)), Some(utc(2024, 5, 31, 0, 0)));
+        assert_eq!(schedule.next_after(&utc(2024, 12, 31, 0, 0)), Some(utc(2025, 1, 31, 0, 0)));
+        let schedule = Schedule::parse("0 12 29 2 *").unwrap();
+        assert_eq!(schedule.next_after(&utc(2024, 3, 1, 0, 0)), Some(utc(2028, 2, 29, 12, 0)));
+        assert_eq!(schedule.next_after(&utc(2096, 3, 1, 0, 0)), Some(utc(2104, 2, 29, 12, 0)));
+
+        // Never fires.
+        assert_eq!(Schedule::parse("0 0 30 2 *").unwrap().next_after(&utc(2024, 1, 1, 0, 0)), None);
+        // Out of range.
+        let every_minute = Schedule::parse("* * * * *").unwrap();
+        assert_eq!(every_minute.next_after(&DateTime::<Utc>::MAX_UTC), None);
+        let dt = DateTime::<Utc>::MIN_UTC;
+        assert_eq!(every_minute.next_after(&dt), Some(dt + TimeDelta::minutes(1)));
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // Both restricted: the 13th or any Friday.
+        let schedule = Schedule::parse("0 0 13 * FRI").unwrap();
+        let fires: Vec<_> = schedule.after(&utc(2024, 9, 1, 0, 0)).take(3).collect();
+        assert_eq!(fires, [utc(2024, 9, 6, 0, 0), utc(2024, 9, 13, 0, 0), utc(2024, 9, 20, 0, 0)]);
+
+        // Day of month starts with `*`: only Fridays.
+        let schedule = Schedule::parse("0 0 */1 * FRI").unwrap();
+        let fires: Vec<_> = schedule.after(&utc(2024, 9, 1, 0, 0)).take(2).collect();
+        assert_eq!(fires, [utc(2024, 9, 6, 0, 0), utc(2024, 9, 13, 0, 0)]);
+    }
+
+    #[test]
+    fn test_includes() {
+        let schedule = Schedule::parse("30 9 * * MON-FRI").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+        assert!(schedule.includes(&date.and_hms_opt(9, 30, 59).unwrap()));
+        assert!(!schedule.includes(&date.and_hms_opt(9, 31, 0).unwrap()));
+        assert!(!schedule.includes(&date.succ_opt().unwrap().and_hms_opt(9, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_fixed_offset() {
+        let tz = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+        let schedule = Schedule::parse("@daily").unwrap();
+        let dt = Utc.with_ymd_and_hms(2024, 5, 7, 3, 0, 0).unwrap().with_timezone(&tz);
+        let next = schedule.next_after(&dt).unwrap();
+        assert_eq!(next, tz.with_ymd_and_hms(2024, 5, 7, 0, 0, 0).unwrap());
+        assert_eq!(next.offset(), &tz);
+    }
+
+    #[test]
+    fn test_dst_transitions() {
+        // Clocks go back from 02:00 to 01:00 on April 15, and forward from 02:00 to 03:00 on
+        // September 15.
+        let tz = DstTester;
+        let local = |m: u32, h: u32, min: u32| {
+            NaiveDate::from_ymd_opt(2024, m, 15).unwrap().and_hms_opt(h, min, 0)
+        };
+
+        // Times in the gap are skipped.
+        let schedule = Schedule::parse("30 * * * *").unwrap();
+        let start = tz.from_local_datetime(&local(9, 1, 0).unwrap()).unwrap();
+        let fires: Vec<_> = schedule.after(&start).take(2).map(|dt| dt.naive_local()).collect();
+        assert_eq!(fires, [local(9, 1, 30).unwrap(), local(9, 3, 30).unwrap()]);
+
+        // Times in the fold fire once, at the earliest occurrence.
+        let start = tz.from_local_datetime(&local(4, 0, 0).unwrap()).unwrap();
+        let fires: Vec<_> = schedule.after(&start).take(3).collect();
+        assert_eq!(fires[0].naive_local(), local(4, 0, 30).unwrap());
+        assert_eq!(fires[1].naive_local(), local(4, 1, 30).unwrap());
+        assert_eq!(fires[1].offset().fix().local_minus_utc(), 9 * 3600);
+        assert_eq!(fires[2].naive_local(), local(4, 2, 30).unwrap());
+
+        // Starting from the second occurrence of the fold doesn't fire again.
+        let second = tz.from_local_datetime(&local(4, 1, 0).unwrap()).latest().unwrap();
+        assert_eq!(schedule.next_after(&second).unwrap().naive_local(), local(4, 2, 30).unwrap());
+    }
+}
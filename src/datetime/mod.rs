@@ -16,19 +16,26 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 #[allow(deprecated)]
 use crate::Date;
-#[cfg(all(feature = "unstable-locales", feature = "alloc"))]
-use crate::format::Locale;
+use crate::format::{BufWriter, Rfc3339Options, write_rfc3339};
 #[cfg(feature = "alloc")]
-use crate::format::{DelayedFormat, SecondsFormat, write_rfc2822, write_rfc3339};
 use crate::format::{
-    Fixed, Item, ParseError, ParseResult, Parsed, StrftimeItems, TOO_LONG, parse,
-    parse_and_remainder, parse_rfc3339,
+    DelayedFormat, Iso8601BasicOptions, SecondsFormat, write_iso8601_basic, write_rfc2822,
+    write_rfc2822_opts,
 };
-use crate::naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
+use crate::format::{
+    Fixed, IMPOSSIBLE, INVALID, Item, Numeric, OUT_OF_RANGE, Pad, ParseError, ParseOptions,
+    ParseResult, Parsed, Rfc2822Zone, StrftimeItems, TOO_LONG, TOO_SHORT, parse,
+    parse_and_remainder, parse_iso8601_basic, parse_rfc2822, parse_rfc2822_with_comment,
+    parse_rfc3339, parse_rfc3339_fast, parse_rfc3339_relaxed, parse_with_options,
+};
+#[cfg(feature = "unstable-locales")]
+use crate::format::{Locale, parse_and_remainder_localized, parse_localized};
+use crate::naive::date::UNIX_EPOCH_DAY;
+use crate::naive::{DateFields, Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, TimeFields};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
-use crate::{Datelike, Months, TimeDelta, Timelike, Weekday};
+use crate::offset::{FixedOffset, LocalResult, MappedLocalTime, Offset, TimeZone, Utc};
+use crate::{CalendarFields, Datelike, Months, TimeDelta, Timelike, Weekday};
 use crate::{expect, try_opt};
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
@@ -39,7 +46,7 @@ use rkyv::{Archive, Deserialize, Serialize};
 pub(super) mod serde;
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
 /// ISO 8601 combined date and time with time zone.
 ///
@@ -98,6 +105,35 @@ impl<Tz: TimeZone> DateTime<Tz> {
         DateTime { datetime, offset }
     }
 
+    /// Makes a new `DateTime` in the time zone `tz` from the number of non-leap seconds since
+    /// January 1, 1970 0:00:00 UTC and the number of nanoseconds since the last whole non-leap
+    /// second.
+    ///
+    /// This is [`DateTime::from_timestamp`] followed by [`with_timezone`](DateTime::with_timezone)
+    /// in one step. A UTC timestamp always corresponds to exactly one local time, so unlike
+    /// [`TimeZone::timestamp_opt`] this returns an `Option` instead of a [`MappedLocalTime`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` on an out-of-range number of seconds and/or an invalid nanosecond, see
+    /// [`DateTime::from_timestamp`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let dt = DateTime::from_timestamp_in(1_715_079_845, 0, &tz).unwrap();
+    /// assert_eq!(dt, tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap());
+    /// assert_eq!(dt.offset(), &tz);
+    /// assert_eq!(DateTime::from_timestamp_in(i64::MAX, 0, &tz), None);
+    /// ```
+    #[must_use]
+    pub fn from_timestamp_in(secs: i64, nsecs: u32, tz: &Tz) -> Option<DateTime<Tz>> {
+        DateTime::from_timestamp(secs, nsecs).map(|dt| dt.with_timezone(tz))
+    }
+
     /// Makes a new `DateTime` from its components: a `NaiveDateTime` in UTC and an `Offset`.
     #[inline]
     #[must_use]
@@ -363,6 +399,35 @@ impl<Tz: TimeZone> DateTime<Tz> {
         try_opt!(timestamp.checked_mul(1_000_000_000)).checked_add(subsec_nanos)
     }
 
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC as an `i128`.
+    ///
+    /// Unlike [`timestamp_nanos_opt`](DateTime::timestamp_nanos_opt) this covers the full range
+    /// of `DateTime`, and never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, NaiveDate, Utc};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2001, 9, 9)
+    ///     .unwrap()
+    ///     .and_hms_nano_opt(1, 46, 40, 555)
+    ///     .unwrap()
+    ///     .and_local_timezone(Utc)
+    ///     .unwrap();
+    /// assert_eq!(dt.timestamp_nanos_i128(), 1_000_000_000_000_000_555);
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(1500, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc();
+    /// assert_eq!(dt.timestamp_nanos_opt(), None);
+    /// assert_eq!(dt.timestamp_nanos_i128(), -14_831_769_600_000_000_000);
+    /// assert_eq!(DateTime::from_timestamp_nanos_i128(dt.timestamp_nanos_i128()), Some(dt));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn timestamp_nanos_i128(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000_000 + self.timestamp_subsec_nanos() as i128
+    }
+
     /// Returns the number of milliseconds since the last second boundary.
     ///
     /// In event of a leap second this may exceed 999.
@@ -423,6 +488,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Turn this `DateTime` into a `DateTime<Utc>`, dropping the offset and associated timezone
     /// information.
+    ///
+    /// The datetime is stored in UTC internally, so this only copies it and never has to consult
+    /// the time zone.
     #[inline]
     #[must_use]
     pub const fn to_utc(&self) -> DateTime<Utc> {
@@ -557,6 +625,84 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.signed_duration_since(rhs.borrow().datetime)
     }
 
+    /// Returns the amount of time elapsed from `earlier` to `self`.
+    ///
+    /// Unlike [`signed_duration_since`](DateTime::signed_duration_since) this can not return a
+    /// negative `TimeDelta`, like `Instant::checked_duration_since` in the standard library.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `earlier` is later than `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let earlier = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+    /// let later = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 30).unwrap();
+    /// assert_eq!(later.checked_duration_since(&earlier), Some(TimeDelta::seconds(30)));
+    /// assert_eq!(earlier.checked_duration_since(&later), None);
+    /// ```
+    #[must_use]
+    pub fn checked_duration_since<Tz2: TimeZone>(
+        &self,
+        earlier: &DateTime<Tz2>,
+    ) -> Option<TimeDelta> {
+        let duration = self.datetime.signed_duration_since(earlier.datetime);
+        if duration >= TimeDelta::zero() { Some(duration) } else { None }
+    }
+
+    /// Returns the amount of time elapsed since this `DateTime`, like `SystemTime::elapsed` in the
+    /// standard library.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ElapsedError`] if `self` is later than the current time, for example because
+    /// the system clock was adjusted or because the value comes from a machine with a clock
+    /// that is ahead.
+    #[cfg(feature = "clock")]
+    pub fn elapsed(&self) -> Result<TimeDelta, ElapsedError> {
+        let now = Utc::now();
+        now.checked_duration_since(self)
+            .ok_or_else(|| ElapsedError(self.datetime.signed_duration_since(now.datetime)))
+    }
+
+    /// Aligns the `DateTime` down to a whole multiple of `step` since the Unix epoch.
+    ///
+    /// This maps every value within a bucket of size `step` to the start of that bucket, for
+    /// example flooring to the nearest 5 minutes. Alignment is done on the UTC timestamp; use
+    /// [`DurationRound::duration_trunc`](crate::DurationRound::duration_trunc) to align the local
+    /// time instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `step` is zero or negative, or if the resulting date would be out of
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{TimeDelta, TimeZone, Utc};
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 34, 56).unwrap();
+    /// assert_eq!(
+    ///     dt.align_down(TimeDelta::minutes(5)),
+    ///     Some(Utc.with_ymd_and_hms(2024, 5, 7, 12, 30, 0).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn align_down(self, step: TimeDelta) -> Option<DateTime<Tz>> {
+        if step <= TimeDelta::zero() {
+            return None;
+        }
+        let since_epoch = self.datetime.signed_duration_since(NaiveDateTime::UNIX_EPOCH);
+        let mut rem = since_epoch.checked_rem(&step)?;
+        if rem < TimeDelta::zero() {
+            rem += step;
+        }
+        self.checked_sub_signed(rem)
+    }
+
     /// Returns a view to the naive UTC datetime.
     #[inline]
     #[must_use]
@@ -612,255 +758,1284 @@ impl<Tz: TimeZone> DateTime<Tz> {
         }
     }
 
-    /// Returns an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`.
+    /// Retrieve the number of whole months elapsed from `base` to `self`.
     ///
-    /// # Panics
+    /// Like [`years_since`](DateTime::years_since) this compares the local date and time fields,
+    /// so a month has passed when the same day of the month and the same wall clock time are
+    /// reached again, regardless of any offset changes in between. If that day doesn't exist in
+    /// the final month, the month is only complete once the next month starts.
     ///
-    /// Panics if the date can not be represented in this format: the year may not be negative and
-    /// can not have more than 4 digits.
-    #[cfg(feature = "alloc")]
-    #[must_use]
-    pub fn to_rfc2822(&self) -> String {
-        let mut result = String::with_capacity(32);
-        write_rfc2822(&mut result, self.overflowing_naive_local(), self.offset.fix())
-            .expect("writing rfc2822 datetime to string should never fail");
-        result
-    }
-
-    /// Returns an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`.
-    #[cfg(feature = "alloc")]
+    /// # Errors
+    ///
+    /// Returns `None` if `base > self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let base = Utc.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+    /// let at = |m, d, h| Utc.with_ymd_and_hms(2024, m, d, h, 0, 0).unwrap();
+    /// assert_eq!(at(3, 31, 11).months_since(base), Some(1));
+    /// assert_eq!(at(3, 31, 12).months_since(base), Some(2));
+    /// assert_eq!(at(2, 29, 23).months_since(base), Some(0));
+    /// assert_eq!(at(1, 1, 0).months_since(base), None);
+    /// ```
     #[must_use]
-    pub fn to_rfc3339(&self) -> String {
-        // For some reason a string with a capacity less than 32 is ca 20% slower when benchmarking.
-        let mut result = String::with_capacity(32);
-        let naive = self.overflowing_naive_local();
-        let offset = self.offset.fix();
-        write_rfc3339(&mut result, naive, offset, SecondsFormat::AutoSi, false)
-            .expect("writing rfc3339 datetime to string should never fail");
-        result
+    pub fn months_since(&self, base: Self) -> Option<u32> {
+        let mut months =
+            (self.year() - base.year()) * 12 + self.month() as i32 - base.month() as i32;
+        if (self.day(), self.time()) < (base.day(), base.time()) {
+            months -= 1;
+        }
+        u32::try_from(months).ok()
     }
 
-    /// Return an RFC 3339 and ISO 8601 date and time string with subseconds
-    /// formatted as per `SecondsFormat`.
+    /// Retrieve the number of whole weeks elapsed from `base` to `self`.
     ///
-    /// If `use_z` is true and the timezone is UTC (offset 0), uses `Z` as
-    /// per [`Fixed::TimezoneOffsetColonZ`]. If `use_z` is false, uses
-    /// [`Fixed::TimezoneOffsetColon`]
-    ///
-    /// # Examples
+    /// This is the number of whole [days](DateTime::days_since) divided by seven.
     ///
-    /// ```rust
-    /// # use chrono::{FixedOffset, SecondsFormat, TimeZone, NaiveDate};
-    /// let dt = NaiveDate::from_ymd_opt(2018, 1, 26)
-    ///     .unwrap()
-    ///     .and_hms_micro_opt(18, 30, 9, 453_829)
-    ///     .unwrap()
-    ///     .and_utc();
-    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, false), "2018-01-26T18:30:09.453+00:00");
-    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, true), "2018-01-26T18:30:09.453Z");
-    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true), "2018-01-26T18:30:09Z");
+    /// # Errors
     ///
-    /// let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
-    /// let dt = pst
-    ///     .from_local_datetime(
-    ///         &NaiveDate::from_ymd_opt(2018, 1, 26)
-    ///             .unwrap()
-    ///             .and_hms_micro_opt(10, 30, 9, 453_829)
-    ///             .unwrap(),
-    ///     )
-    ///     .unwrap();
-    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true), "2018-01-26T10:30:09+08:00");
-    /// ```
-    #[cfg(feature = "alloc")]
+    /// Returns `None` if `base > self`.
     #[must_use]
-    pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
-        let mut result = String::with_capacity(38);
-        write_rfc3339(&mut result, self.naive_local(), self.offset.fix(), secform, use_z)
-            .expect("writing rfc3339 datetime to string should never fail");
-        result
+    pub fn weeks_since(&self, base: Self) -> Option<u32> {
+        self.days_since(base).map(|days| days / 7)
     }
 
-    /// Set the time to a new fixed time on the existing date.
+    /// Retrieve the number of whole days elapsed from `base` to `self`.
+    ///
+    /// This compares the local date and time fields, so a day has passed when the same wall clock
+    /// time is reached again, even if the day was 23 or 25 hours long because of a change in the
+    /// offset from UTC.
     ///
     /// # Errors
     ///
-    /// Returns `LocalResult::None` if the datetime is at the edge of the representable range for a
-    /// `DateTime`, and `with_time` would push the value in UTC out of range.
+    /// Returns `None` if `base > self`.
     ///
     /// # Example
     ///
     /// ```
-    /// # #[cfg(feature = "clock")] {
-    /// use chrono::{Local, NaiveTime};
+    /// use chrono::{FixedOffset, TimeZone};
     ///
-    /// let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
-    /// let today_noon = Local::now().with_time(noon);
-    /// let today_midnight = Local::now().with_time(NaiveTime::MIN);
-    ///
-    /// assert_eq!(today_noon.single().unwrap().time(), noon);
-    /// assert_eq!(today_midnight.single().unwrap().time(), NaiveTime::MIN);
-    /// # }
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let base = tz.with_ymd_and_hms(2024, 5, 7, 18, 0, 0).unwrap();
+    /// assert_eq!(tz.with_ymd_and_hms(2024, 5, 8, 17, 59, 59).unwrap().days_since(base), Some(0));
+    /// assert_eq!(tz.with_ymd_and_hms(2024, 5, 22, 18, 0, 0).unwrap().days_since(base), Some(15));
+    /// assert_eq!(tz.with_ymd_and_hms(2024, 5, 22, 18, 0, 0).unwrap().weeks_since(base), Some(2));
     /// ```
     #[must_use]
-    pub fn with_time(&self, time: NaiveTime) -> LocalResult<Self> {
-        self.timezone().from_local_datetime(&self.overflowing_naive_local().date().and_time(time))
+    pub fn days_since(&self, base: Self) -> Option<u32> {
+        let (local, base) = (self.naive_local(), base.naive_local());
+        let mut days = local.date().signed_duration_since(base.date()).num_days();
+        if local.time() < base.time() {
+            days -= 1;
+        }
+        u32::try_from(days).ok()
     }
 
-    /// The minimum possible `DateTime<Utc>`.
-    pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
-    /// The maximum possible `DateTime<Utc>`.
-    pub const MAX_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MAX, offset: Utc };
-}
-
-impl DateTime<Utc> {
-    /// Makes a new `DateTime<Utc>` from the number of non-leap seconds
-    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp")
-    /// and the number of nanoseconds since the last whole non-leap second.
+    /// Returns `true` if `self` and `other` fall on the same calendar day in the time zone `tz`.
     ///
-    /// This is guaranteed to round-trip with regard to [`timestamp`](DateTime::timestamp) and
-    /// [`timestamp_subsec_nanos`](DateTime::timestamp_subsec_nanos).
-    ///
-    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
-    /// [`TimeZone::timestamp_opt`] or [`DateTime::with_timezone`].
+    /// # Example
     ///
-    /// The nanosecond part can exceed 1,000,000,000 in order to represent a
-    /// [leap second](NaiveTime#leap-second-handling), but only when `secs % 60 == 59`.
-    /// (The true "UNIX timestamp" cannot represent a leap second unambiguously.)
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
     ///
-    /// # Errors
+    /// let a = Utc.with_ymd_and_hms(2024, 5, 7, 20, 0, 0).unwrap();
+    /// let b = Utc.with_ymd_and_hms(2024, 5, 8, 2, 0, 0).unwrap();
+    /// assert!(!a.same_day_as(&b, &Utc));
     ///
-    /// Returns `None` on out-of-range number of seconds and/or
-    /// invalid nanosecond, otherwise returns `Some(DateTime {...})`.
+    /// // In New York both are on May 7.
+    /// let new_york = FixedOffset::west_opt(4 * 3600).unwrap();
+    /// assert!(a.same_day_as(&b, &new_york));
+    /// ```
+    #[must_use]
+    pub fn same_day_as<Tz2: TimeZone, Tz3: TimeZone>(
+        &self,
+        other: &DateTime<Tz2>,
+        tz: &Tz3,
+    ) -> bool {
+        self.with_timezone(tz).date_naive() == other.with_timezone(tz).date_naive()
+    }
+
+    /// Returns `true` if `self` and `other` fall in the same [ISO week](IsoWeek) in the time zone
+    /// `tz`.
     ///
     /// # Example
     ///
     /// ```
-    /// use chrono::DateTime;
-    ///
-    /// let dt = DateTime::from_timestamp(1431648000, 0).expect("invalid timestamp");
+    /// use chrono::{TimeZone, Utc};
     ///
-    /// assert_eq!(dt.to_string(), "2015-05-15 00:00:00 UTC");
-    /// assert_eq!(DateTime::from_timestamp(dt.timestamp(), dt.timestamp_subsec_nanos()).unwrap(), dt);
+    /// // Sunday and the following Monday are in different weeks.
+    /// let sunday = Utc.with_ymd_and_hms(2024, 5, 12, 12, 0, 0).unwrap();
+    /// let monday = Utc.with_ymd_and_hms(2024, 5, 13, 12, 0, 0).unwrap();
+    /// assert!(!sunday.same_week_as(&monday, &Utc));
+    /// assert!(monday.same_week_as(&Utc.with_ymd_and_hms(2024, 5, 19, 23, 59, 59).unwrap(), &Utc));
     /// ```
-    #[inline]
     #[must_use]
-    pub const fn from_timestamp(secs: i64, nsecs: u32) -> Option<Self> {
-        let days = secs.div_euclid(86_400) + UNIX_EPOCH_DAY;
-        let secs = secs.rem_euclid(86_400);
-        if days < i32::MIN as i64 || days > i32::MAX as i64 {
-            return None;
-        }
-        let date = try_opt!(NaiveDate::from_num_days_from_ce_opt(days as i32));
-        let time = try_opt!(NaiveTime::from_num_seconds_from_midnight_opt(secs as u32, nsecs));
-        Some(date.and_time(time).and_utc())
+    pub fn same_week_as<Tz2: TimeZone, Tz3: TimeZone>(
+        &self,
+        other: &DateTime<Tz2>,
+        tz: &Tz3,
+    ) -> bool {
+        self.with_timezone(tz).iso_week() == other.with_timezone(tz).iso_week()
     }
 
-    /// Makes a new `DateTime<Utc>` from the number of non-leap milliseconds
-    /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
+    /// Returns `true` if `self` and `other` fall in the same month of the same year in the time
+    /// zone `tz`.
     ///
-    /// This is guaranteed to round-trip with [`timestamp_millis`](DateTime::timestamp_millis).
+    /// # Example
     ///
-    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
-    /// [`TimeZone::timestamp_millis_opt`] or [`DateTime::with_timezone`].
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
     ///
-    /// # Errors
+    /// let a = Utc.with_ymd_and_hms(2024, 5, 31, 23, 0, 0).unwrap();
+    /// let b = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+    /// assert!(a.same_month_as(&b, &Utc));
+    /// assert!(!a.same_month_as(&Utc.with_ymd_and_hms(2023, 5, 31, 23, 0, 0).unwrap(), &Utc));
     ///
-    /// Returns `None` on out-of-range number of milliseconds, otherwise returns `Some(DateTime {...})`.
+    /// // In Berlin `a` is already in June.
+    /// let berlin = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// assert!(!a.same_month_as(&b, &berlin));
+    /// ```
+    #[must_use]
+    pub fn same_month_as<Tz2: TimeZone, Tz3: TimeZone>(
+        &self,
+        other: &DateTime<Tz2>,
+        tz: &Tz3,
+    ) -> bool {
+        let (a, b) = (self.with_timezone(tz), other.with_timezone(tz));
+        (a.year(), a.month()) == (b.year(), b.month())
+    }
+
+    /// Returns a hash of the instant that is the same on every platform and in every version of
+    /// chrono, see [`NaiveDate::stable_hash`].
+    ///
+    /// Like the [`PartialEq`] implementation it ignores the time zone: it is the
+    /// [`NaiveDateTime::stable_hash`] of the date and time in UTC.
     ///
     /// # Example
     ///
     /// ```
-    /// use chrono::DateTime;
-    ///
-    /// let dt = DateTime::from_timestamp_millis(947638923004).expect("invalid timestamp");
+    /// use chrono::{FixedOffset, TimeZone, Utc};
     ///
-    /// assert_eq!(dt.to_string(), "2000-01-12 01:02:03.004 UTC");
-    /// assert_eq!(DateTime::from_timestamp_millis(dt.timestamp_millis()).unwrap(), dt);
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap();
+    /// assert_eq!(dt.stable_hash(), dt.naive_utc().stable_hash());
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// assert_eq!(dt.with_timezone(&tz).stable_hash(), dt.stable_hash());
     /// ```
-    #[inline]
     #[must_use]
-    pub const fn from_timestamp_millis(millis: i64) -> Option<Self> {
-        let secs = millis.div_euclid(1000);
-        let nsecs = millis.rem_euclid(1000) as u32 * 1_000_000;
-        Self::from_timestamp(secs, nsecs)
+    pub const fn stable_hash(&self) -> u64 {
+        self.datetime.stable_hash()
     }
 
-    /// Creates a new `DateTime<Utc>` from the number of non-leap microseconds
-    /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
+    /// Returns the number of seconds since the UNIX epoch as an `f64`, for interoperability with
+    /// plotting and charting libraries that need a floating point axis.
     ///
-    /// This is guaranteed to round-trip with [`timestamp_micros`](DateTime::timestamp_micros).
+    /// See [`NaiveDateTime::to_ordered_f64_seconds_since`] for how the result is ordered and how
+    /// precise it is.
     ///
-    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
-    /// [`TimeZone::timestamp_micros`] or [`DateTime::with_timezone`].
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use chrono::{TimeZone, Utc};
     ///
-    /// Returns `None` if the number of microseconds would be out of range for a `NaiveDateTime`
-    /// (more than ca. 262,000 years away from common era)
+    /// let dt = Utc.timestamp_millis_opt(1_715_083_445_500).unwrap();
+    /// assert_eq!(dt.to_ordered_f64_seconds(), 1_715_083_445.5);
+    /// ```
+    #[must_use]
+    pub fn to_ordered_f64_seconds(&self) -> f64 {
+        self.datetime.to_ordered_f64_seconds()
+    }
+
+    /// Returns the number of seconds since `epoch` as an `f64`.
+    ///
+    /// See [`NaiveDateTime::to_ordered_f64_seconds_since`] for how the result is ordered and how
+    /// precise it is, and [`DateTime::f64_epoch_for`] to pick an epoch.
     ///
     /// # Example
     ///
     /// ```
-    /// use chrono::DateTime;
-    ///
-    /// let timestamp_micros: i64 = 1662921288000000; // Sun, 11 Sep 2022 18:34:48 UTC
-    /// let dt = DateTime::from_timestamp_micros(timestamp_micros);
-    /// assert!(dt.is_some());
-    /// assert_eq!(timestamp_micros, dt.expect("invalid timestamp").timestamp_micros());
+    /// use chrono::{FixedOffset, TimeZone, Utc};
     ///
-    /// // Negative timestamps (before the UNIX epoch) are supported as well.
-    /// let timestamp_micros: i64 = -2208936075000000; // Mon, 1 Jan 1900 14:38:45 UTC
-    /// let dt = DateTime::from_timestamp_micros(timestamp_micros);
-    /// assert!(dt.is_some());
-    /// assert_eq!(timestamp_micros, dt.expect("invalid timestamp").timestamp_micros());
+    /// let epoch = Utc.with_ymd_and_hms(2024, 5, 7, 0, 0, 0).unwrap();
+    /// let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 5, 7, 1, 0, 30).unwrap();
+    /// assert_eq!(dt.to_ordered_f64_seconds_since(&epoch), 30.0);
     /// ```
-    #[inline]
     #[must_use]
-    pub const fn from_timestamp_micros(micros: i64) -> Option<Self> {
-        let secs = micros.div_euclid(1_000_000);
-        let nsecs = micros.rem_euclid(1_000_000) as u32 * 1000;
-        Self::from_timestamp(secs, nsecs)
+    pub fn to_ordered_f64_seconds_since<Tz2: TimeZone>(&self, epoch: &DateTime<Tz2>) -> f64 {
+        self.datetime.to_ordered_f64_seconds_since(&epoch.datetime)
     }
 
-    /// Creates a new [`DateTime<Utc>`] from the number of non-leap nanoseconds
-    /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
+    /// Returns an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date can not be represented in this format: the year may not be negative and
+    /// can not have more than 4 digits.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc2822(&self) -> String {
+        let mut result = String::with_capacity(32);
+        write_rfc2822(&mut result, self.overflowing_naive_local(), self.offset.fix())
+            .expect("writing rfc2822 datetime to string should never fail");
+        result
+    }
+
+    /// Returns an RFC 2822 date and time string, with control over its shape.
+    ///
+    /// If `with_weekday` is false the optional day of the week is left out, as in
+    /// `1 Jul 2003 10:52:37 +0200`.
+    /// If `use_gmt` is true and the offset is zero, the obsolete zone name `GMT` is written instead
+    /// of `+0000`. This is what HTTP dates and many mail readers expect, but note that the zone
+    /// names are only part of the obsolete syntax of RFC 2822.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date can not be represented in this format: the year may not be negative and
+    /// can not have more than 4 digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, TimeZone, Utc};
+    /// let dt = Utc.with_ymd_and_hms(2003, 7, 1, 10, 52, 37).unwrap();
+    /// assert_eq!(dt.to_rfc2822_opts(true, false), "Tue, 1 Jul 2003 10:52:37 +0000");
+    /// assert_eq!(dt.to_rfc2822_opts(true, true), "Tue, 1 Jul 2003 10:52:37 GMT");
+    /// assert_eq!(dt.to_rfc2822_opts(false, true), "1 Jul 2003 10:52:37 GMT");
+    ///
+    /// let dt = dt.with_timezone(&FixedOffset::east_opt(2 * 3600).unwrap());
+    /// assert_eq!(dt.to_rfc2822_opts(false, true), "1 Jul 2003 12:52:37 +0200");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc2822_opts(&self, with_weekday: bool, use_gmt: bool) -> String {
+        let mut result = String::with_capacity(32);
+        write_rfc2822_opts(
+            &mut result,
+            self.overflowing_naive_local(),
+            self.offset.fix(),
+            with_weekday,
+            use_gmt,
+        )
+        .expect("writing rfc2822 datetime to string should never fail");
+        result
+    }
+
+    /// Returns an RFC 2822 date and time string followed by a comment, such as
+    /// `Tue, 1 Jul 2003 10:52:37 -0230 (Newfoundland Time)`.
+    ///
+    /// The comment is usually a human-readable hint of the time zone. Parentheses and backslashes
+    /// in `comment` are escaped with a backslash, so the comment can be read back with
+    /// [`DateTime::parse_from_rfc2822_with_comment`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date can not be represented in this format: the year may not be negative and
+    /// can not have more than 4 digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, TimeZone};
+    /// let tz = FixedOffset::west_opt(2 * 3600 + 30 * 60).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2003, 7, 1, 10, 52, 37).unwrap();
+    /// assert_eq!(
+    ///     dt.to_rfc2822_with_comment("Newfoundland Time"),
+    ///     "Tue, 1 Jul 2003 10:52:37 -0230 (Newfoundland Time)"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc2822_with_comment(&self, comment: &str) -> String {
+        let mut result = String::with_capacity(35 + comment.len());
+        write_rfc2822(&mut result, self.overflowing_naive_local(), self.offset.fix())
+            .expect("writing rfc2822 datetime to string should never fail");
+        result.push_str(" (");
+        for c in comment.chars() {
+            if matches!(c, '(' | ')' | '\\') {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        result.push(')');
+        result
+    }
+
+    /// Returns a date and time string in the ISO 8601 basic format, such as
+    /// `20240507T123456+0200`.
+    ///
+    /// All non-zero sub-second digits are written, with a full stop as the decimal separator, and
+    /// a zero offset is written as `Z`. See [`NaiveDateTime::to_iso8601_basic`] for years outside
+    /// the range 0 to 9999.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, TimeZone, Utc};
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 34, 56).unwrap();
+    /// assert_eq!(dt.to_iso8601_basic(), "20240507T123456Z");
+    ///
+    /// let dt = dt.with_timezone(&FixedOffset::east_opt(2 * 3600).unwrap());
+    /// assert_eq!(dt.to_iso8601_basic(), "20240507T143456+0200");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_iso8601_basic(&self) -> String {
+        self.to_iso8601_basic_opts(Iso8601BasicOptions::new())
+    }
+
+    /// Returns a date and time string in the ISO 8601 basic format, written with the given
+    /// options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::{format::Iso8601BasicOptions, SecondsFormat, TimeZone, Utc};
+    /// let dt = Utc.timestamp_millis_opt(1_715_085_296_789).unwrap();
+    /// let opts = Iso8601BasicOptions::new().with_decimal_comma(true).with_use_z(false);
+    /// assert_eq!(dt.to_iso8601_basic_opts(opts), "20240507T123456,789+0000");
+    /// let opts = opts.with_seconds_format(SecondsFormat::Secs);
+    /// assert_eq!(dt.to_iso8601_basic_opts(opts), "20240507T123456+0000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_iso8601_basic_opts(&self, opts: Iso8601BasicOptions) -> String {
+        let mut result = String::with_capacity(30);
+        write_iso8601_basic(&mut result, self.naive_local(), Some(self.offset.fix()), opts)
+            .expect("writing an iso 8601 datetime to string should never fail");
+        result
+    }
+
+    /// Returns an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        // For some reason a string with a capacity less than 32 is ca 20% slower when benchmarking.
+        let mut result = String::with_capacity(32);
+        let naive = self.overflowing_naive_local();
+        let offset = self.offset.fix();
+        write_rfc3339(&mut result, naive, offset, Rfc3339Options::new())
+            .expect("writing rfc3339 datetime to string should never fail");
+        result
+    }
+
+    /// Writes an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`
+    /// into `buf` and returns it, with subseconds formatted as per `SecondsFormat`.
+    ///
+    /// This is the same string as [`DateTime::to_rfc3339`] (for [`SecondsFormat::AutoSi`]), but
+    /// it does not need an allocator. 48 bytes are enough for any `DateTime`.
+    ///
+    /// `opts` can also be [`Rfc3339Options`] to control the other parts of the format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, SecondsFormat, TimeZone};
+    ///
+    /// let tz = FixedOffset::west_opt(8 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(1996, 12, 19, 16, 39, 57).unwrap();
+    /// let mut buf = [0; 48];
+    /// assert_eq!(dt.to_rfc3339_buf(&mut buf, SecondsFormat::AutoSi), "1996-12-19T16:39:57-08:00");
+    /// let s = dt.to_rfc3339_buf(&mut buf, SecondsFormat::Millis);
+    /// assert_eq!(s, "1996-12-19T16:39:57.000-08:00");
+    /// ```
+    pub fn to_rfc3339_buf<'a>(
+        &self,
+        buf: &'a mut [u8; 48],
+        opts: impl Into<Rfc3339Options>,
+    ) -> &'a str {
+        let mut w = BufWriter::new(buf);
+        let naive = self.overflowing_naive_local();
+        write_rfc3339(&mut w, naive, self.offset.fix(), opts.into())
+            .expect("an rfc3339 datetime should always fit in 48 bytes");
+        w.into_str()
+    }
+
+    /// Writes an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`
+    /// directly into `w`.
+    ///
+    /// This writes the same string as [`DateTime::to_rfc3339`] without allocating it first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::west_opt(8 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(1996, 12, 19, 16, 39, 57).unwrap();
+    /// let mut buf = Vec::new();
+    /// dt.write_rfc3339(&mut buf)?;
+    /// assert_eq!(buf, b"1996-12-19T16:39:57-08:00");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_rfc3339(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.write_rfc3339_with(w, Rfc3339Options::new())
+    }
+
+    /// Writes an RFC 3339 date and time string formatted as per `opts` directly into `w`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::Rfc3339Options;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(1996, 12, 19, 16, 39, 57).unwrap();
+    /// let mut buf = Vec::new();
+    /// dt.write_rfc3339_with(&mut buf, Rfc3339Options::new().with_use_z(true))?;
+    /// assert_eq!(buf, b"1996-12-19T16:39:57Z");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_rfc3339_with(
+        &self,
+        w: &mut impl std::io::Write,
+        opts: Rfc3339Options,
+    ) -> std::io::Result<()> {
+        struct Rfc3339(NaiveDateTime, FixedOffset, Rfc3339Options);
+
+        impl fmt::Display for Rfc3339 {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_rfc3339(f, self.0, self.1, self.2)
+            }
+        }
+
+        write!(w, "{}", Rfc3339(self.overflowing_naive_local(), self.offset.fix(), opts))
+    }
+
+    /// Return an RFC 3339 and ISO 8601 date and time string with subseconds
+    /// formatted as per `SecondsFormat`.
+    ///
+    /// If `use_z` is true and the timezone is UTC (offset 0), uses `Z` as
+    /// per [`Fixed::TimezoneOffsetColonZ`]. If `use_z` is false, uses
+    /// [`Fixed::TimezoneOffsetColon`]
+    ///
+    /// See [`DateTime::to_rfc3339_with`] for more formatting options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, SecondsFormat, TimeZone, NaiveDate};
+    /// let dt = NaiveDate::from_ymd_opt(2018, 1, 26)
+    ///     .unwrap()
+    ///     .and_hms_micro_opt(18, 30, 9, 453_829)
+    ///     .unwrap()
+    ///     .and_utc();
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, false), "2018-01-26T18:30:09.453+00:00");
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, true), "2018-01-26T18:30:09.453Z");
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true), "2018-01-26T18:30:09Z");
+    ///
+    /// let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    /// let dt = pst
+    ///     .from_local_datetime(
+    ///         &NaiveDate::from_ymd_opt(2018, 1, 26)
+    ///             .unwrap()
+    ///             .and_hms_micro_opt(10, 30, 9, 453_829)
+    ///             .unwrap(),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true), "2018-01-26T10:30:09+08:00");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
+        self.to_rfc3339_with(Rfc3339Options::new().with_seconds_format(secform).with_use_z(use_z))
+    }
+
+    /// Return an RFC 3339 date and time string formatted as per `opts`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::Rfc3339Options;
+    /// use chrono::{FixedOffset, SecondsFormat, TimeZone};
+    ///
+    /// let tz = FixedOffset::west_opt(8 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(1996, 12, 19, 16, 39, 57).unwrap();
+    /// let opts = Rfc3339Options::new().with_seconds_format(SecondsFormat::Millis);
+    /// assert_eq!(dt.to_rfc3339_with(opts), "1996-12-19T16:39:57.000-08:00");
+    /// let opts = opts.with_space_separator(true);
+    /// assert_eq!(dt.to_rfc3339_with(opts), "1996-12-19 16:39:57.000-08:00");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc3339_with(&self, opts: Rfc3339Options) -> String {
+        let mut result = String::with_capacity(38);
+        write_rfc3339(&mut result, self.naive_local(), self.offset.fix(), opts)
+            .expect("writing rfc3339 datetime to string should never fail");
+        result
+    }
+
+    /// Returns an RFC 3164 (BSD syslog) timestamp of the local time, such as `Oct  1 22:14:15`.
+    ///
+    /// The day is padded with a space and the timestamp does not include the year or the offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 10, 1, 22, 14, 15).unwrap();
+    /// assert_eq!(dt.to_rfc3164(), "Oct  1 22:14:15");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc3164(&self) -> String {
+        let mut result = String::with_capacity(15);
+        write!(result, "{}", self.naive_local().format("%b %e %H:%M:%S"))
+            .expect("writing rfc3164 timestamp to string should never fail");
+        result
+    }
+
+    /// Returns an RFC 5424 (syslog) timestamp such as `2003-10-11T22:14:15.003000Z`.
+    ///
+    /// This is an RFC 3339 timestamp with exactly 6 digits for the fractional seconds and `Z`
+    /// for UTC, the highest precision RFC 5424 allows. RFC 5424 doesn't allow leap seconds, they
+    /// are written as `:60` the same as [`DateTime::to_rfc3339`] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2003, 10, 11, 22, 14, 15).unwrap();
+    /// assert_eq!(dt.to_rfc5424(), "2003-10-11T22:14:15.000000Z");
+    ///
+    /// let tz = FixedOffset::west_opt(7 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2003, 8, 24, 5, 14, 15).unwrap();
+    /// assert_eq!(dt.to_rfc5424(), "2003-08-24T05:14:15.000000-07:00");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc5424(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::Micros, true)
+    }
+
+    /// Returns a Common Log Format timestamp, as written by web servers in their access logs,
+    /// such as `10/Oct/2000:13:55:36 -0700`.
+    ///
+    /// The surrounding `[` and `]` of the log line are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::west_opt(7 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2000, 10, 10, 13, 55, 36).unwrap();
+    /// assert_eq!(dt.to_clf_string(), "10/Oct/2000:13:55:36 -0700");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_clf_string(&self) -> String {
+        let offset = self.offset.fix().local_minus_utc();
+        let (sign, offset) = if offset < 0 { ('-', -offset) } else { ('+', offset) };
+        let mut result = String::with_capacity(26);
+        write!(
+            result,
+            "{} {}{:02}{:02}",
+            self.naive_local().format("%d/%b/%Y:%H:%M:%S"),
+            sign,
+            offset / 3600,
+            offset / 60 % 60
+        )
+        .expect("writing a clf timestamp to string should never fail");
+        result
+    }
+
+    /// Parses an RFC 3164 (BSD syslog) timestamp such as `Oct 11 22:14:15` in the time zone `tz`.
+    ///
+    /// RFC 3164 timestamps don't include a year or an offset. The year is taken from
+    /// `assumed_year`, which usually is the current year or the year the log file was written.
+    /// The day may be padded with a space or not at all.
+    ///
+    /// If the local time is ambiguous because of a time zone transition, the earliest of the
+    /// possible times is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not an RFC 3164 timestamp, if the date doesn't exist in
+    /// `assumed_year` (like February 29 in a year that is not a leap year), or if the local time
+    /// doesn't exist in `tz`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = DateTime::parse_from_rfc3164("Oct  1 22:14:15", 2024, &Utc);
+    /// assert_eq!(dt, Ok(Utc.with_ymd_and_hms(2024, 10, 1, 22, 14, 15).unwrap()));
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = DateTime::parse_from_rfc3164("Feb 29 01:02:03", 2024, &tz);
+    /// assert_eq!(dt, Ok(tz.with_ymd_and_hms(2024, 2, 29, 1, 2, 3).unwrap()));
+    /// assert!(DateTime::parse_from_rfc3164("Feb 29 01:02:03", 2023, &tz).is_err());
+    /// ```
+    pub fn parse_from_rfc3164(s: &str, assumed_year: i32, tz: &Tz) -> ParseResult<DateTime<Tz>> {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Fixed(Fixed::ShortMonthName),
+            Item::Space(" "),
+            Item::Numeric(Numeric::Day, Pad::Space),
+            Item::Space(" "),
+            Item::Numeric(Numeric::Hour, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Minute, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Second, Pad::Zero),
+        ];
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        parsed.set_year(i64::from(assumed_year))?;
+        let naive = parsed.to_naive_date()?.and_time(parsed.to_naive_time()?);
+        tz.from_local_datetime(&naive).earliest().ok_or(IMPOSSIBLE)
+    }
+
+    /// Returns the local date and time and the offset in the EXIF formats, such as
+    /// `("2023:09:08 07:03:25", "+09:00")`.
+    ///
+    /// These are the values of the `DateTimeOriginal` and `OffsetTimeOriginal` tags, or of their
+    /// counterparts for the other EXIF dates. They are parsed back with [`DateTime::parse_exif`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::west_opt(4 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap();
+    /// let (datetime, offset) = dt.to_exif_strings();
+    /// assert_eq!((datetime.as_str(), offset.as_str()), ("2023:09:08 07:03:25", "-04:00"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_exif_strings(&self) -> (String, String) {
+        let offset = self.offset.fix().local_minus_utc();
+        let (sign, offset) = if offset < 0 { ('-', -offset) } else { ('+', offset) };
+        let mut offset_string = String::with_capacity(6);
+        write!(offset_string, "{}{:02}:{:02}", sign, offset / 3600, offset / 60 % 60)
+            .expect("writing an EXIF offset to a string should never fail");
+        (self.naive_local().to_exif_string(), offset_string)
+    }
+
+    /// Parses an iCalendar `DATE-TIME` value in the time zone `tz`, such as `20240315T093000` for
+    /// a `DTSTART;TZID=Europe/Amsterdam:20240315T093000` property.
+    ///
+    /// The format is defined in [RFC 5545, section 3.3.5]. The local time is resolved as the
+    /// RFC requires: if it is ambiguous the first occurrence is used, and if it doesn't exist
+    /// because of a gap it is interpreted with the offset from before the gap. A value in UTC,
+    /// ending with a `Z`, is converted to `tz`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not in this format, or if the date and time do not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = DateTime::parse_ical_in("20240315T093000", &tz).unwrap();
+    /// assert_eq!(dt, tz.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap());
+    ///
+    /// let dt = DateTime::parse_ical_in("20240315T093000Z", &tz).unwrap();
+    /// assert_eq!(dt, tz.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap());
+    /// ```
+    ///
+    /// [RFC 5545, section 3.3.5]: https://www.rfc-editor.org/rfc/rfc5545#section-3.3.5
+    pub fn parse_ical_in(s: &str, tz: &Tz) -> ParseResult<DateTime<Tz>> {
+        if s.ends_with('Z') {
+            return DateTime::<Utc>::parse_ical(s).map(|dt| dt.with_timezone(tz));
+        }
+        let local = NaiveDateTime::parse_ical(s)?;
+        match tz.from_local_datetime(&local) {
+            MappedLocalTime::Single(dt) | MappedLocalTime::Ambiguous(dt, _) => Ok(dt),
+            MappedLocalTime::None => {
+                // Use the offset from before the gap, assuming it started less than a day earlier.
+                let before = local.checked_sub_signed(TimeDelta::days(1)).ok_or(OUT_OF_RANGE)?;
+                let offset = tz.offset_from_utc_datetime(&before).fix();
+                let utc = local.checked_sub_offset(offset).ok_or(OUT_OF_RANGE)?;
+                Ok(tz.from_utc_datetime(&utc))
+            }
+        }
+    }
+
+    /// Returns the instant as an iCalendar `DATE-TIME` value in UTC, such as `20240315T093000Z`.
+    ///
+    /// iCalendar has no fractional seconds, so they are dropped. This form can be read by
+    /// any iCalendar consumer, without a `VTIMEZONE` component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
+    /// assert_eq!(dt.to_ical_string(), "20240315T093000Z");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_ical_string(&self) -> String {
+        let mut result = self.datetime.to_ical_string();
+        result.push('Z');
+        result
+    }
+
+    /// Set the time to a new fixed time on the existing date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LocalResult::None` if the datetime is at the edge of the representable range for a
+    /// `DateTime`, and `with_time` would push the value in UTC out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "clock")] {
+    /// use chrono::{Local, NaiveTime};
+    ///
+    /// let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    /// let today_noon = Local::now().with_time(noon);
+    /// let today_midnight = Local::now().with_time(NaiveTime::MIN);
+    ///
+    /// assert_eq!(today_noon.single().unwrap().time(), noon);
+    /// assert_eq!(today_midnight.single().unwrap().time(), NaiveTime::MIN);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_time(&self, time: NaiveTime) -> LocalResult<Self> {
+        self.timezone().from_local_datetime(&self.overflowing_naive_local().date().and_time(time))
+    }
+
+    /// The minimum possible `DateTime<Utc>`.
+    pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
+    /// The maximum possible `DateTime<Utc>`.
+    pub const MAX_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MAX, offset: Utc };
+}
+
+impl DateTime<Utc> {
+    /// Makes a new `DateTime<Utc>` from the number of non-leap seconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp")
+    /// and the number of nanoseconds since the last whole non-leap second.
+    ///
+    /// This is guaranteed to round-trip with regard to [`timestamp`](DateTime::timestamp) and
+    /// [`timestamp_subsec_nanos`](DateTime::timestamp_subsec_nanos).
+    ///
+    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
+    /// [`TimeZone::timestamp_opt`] or [`DateTime::with_timezone`].
+    ///
+    /// The nanosecond part can exceed 1,000,000,000 in order to represent a
+    /// [leap second](NaiveTime#leap-second-handling), but only when `secs % 60 == 59`.
+    /// (The true "UNIX timestamp" cannot represent a leap second unambiguously.)
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` on out-of-range number of seconds and/or
+    /// invalid nanosecond, otherwise returns `Some(DateTime {...})`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// let dt = DateTime::from_timestamp(1431648000, 0).expect("invalid timestamp");
+    ///
+    /// assert_eq!(dt.to_string(), "2015-05-15 00:00:00 UTC");
+    /// assert_eq!(DateTime::from_timestamp(dt.timestamp(), dt.timestamp_subsec_nanos()).unwrap(), dt);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_timestamp(secs: i64, nsecs: u32) -> Option<Self> {
+        let days = secs.div_euclid(86_400) + UNIX_EPOCH_DAY;
+        let secs = secs.rem_euclid(86_400);
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        let date = try_opt!(NaiveDate::from_num_days_from_ce_opt(days as i32));
+        let time = try_opt!(NaiveTime::from_num_seconds_from_midnight_opt(secs as u32, nsecs));
+        Some(date.and_time(time).and_utc())
+    }
+
+    /// Makes a new `DateTime<Utc>` from the number of non-leap milliseconds
+    /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
+    ///
+    /// This is guaranteed to round-trip with [`timestamp_millis`](DateTime::timestamp_millis).
+    ///
+    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
+    /// [`TimeZone::timestamp_millis_opt`] or [`DateTime::with_timezone`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` on out-of-range number of milliseconds, otherwise returns `Some(DateTime {...})`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// let dt = DateTime::from_timestamp_millis(947638923004).expect("invalid timestamp");
+    ///
+    /// assert_eq!(dt.to_string(), "2000-01-12 01:02:03.004 UTC");
+    /// assert_eq!(DateTime::from_timestamp_millis(dt.timestamp_millis()).unwrap(), dt);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_timestamp_millis(millis: i64) -> Option<Self> {
+        let secs = millis.div_euclid(1000);
+        let nsecs = millis.rem_euclid(1000) as u32 * 1_000_000;
+        Self::from_timestamp(secs, nsecs)
+    }
+
+    /// Creates a new `DateTime<Utc>` from the number of non-leap microseconds
+    /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
+    ///
+    /// This is guaranteed to round-trip with [`timestamp_micros`](DateTime::timestamp_micros).
+    ///
+    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
+    /// [`TimeZone::timestamp_micros`] or [`DateTime::with_timezone`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the number of microseconds would be out of range for a `NaiveDateTime`
+    /// (more than ca. 262,000 years away from common era)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// let timestamp_micros: i64 = 1662921288000000; // Sun, 11 Sep 2022 18:34:48 UTC
+    /// let dt = DateTime::from_timestamp_micros(timestamp_micros);
+    /// assert!(dt.is_some());
+    /// assert_eq!(timestamp_micros, dt.expect("invalid timestamp").timestamp_micros());
+    ///
+    /// // Negative timestamps (before the UNIX epoch) are supported as well.
+    /// let timestamp_micros: i64 = -2208936075000000; // Mon, 1 Jan 1900 14:38:45 UTC
+    /// let dt = DateTime::from_timestamp_micros(timestamp_micros);
+    /// assert!(dt.is_some());
+    /// assert_eq!(timestamp_micros, dt.expect("invalid timestamp").timestamp_micros());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_timestamp_micros(micros: i64) -> Option<Self> {
+        let secs = micros.div_euclid(1_000_000);
+        let nsecs = micros.rem_euclid(1_000_000) as u32 * 1000;
+        Self::from_timestamp(secs, nsecs)
+    }
+
+    /// Creates a new [`DateTime<Utc>`] from the number of non-leap nanoseconds
+    /// since January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
     ///
     /// This is guaranteed to round-trip with [`timestamp_nanos`](DateTime::timestamp_nanos).
     ///
-    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
-    /// [`TimeZone::timestamp_nanos`] or [`DateTime::with_timezone`].
+    /// If you need to create a `DateTime` with a [`TimeZone`] different from [`Utc`], use
+    /// [`TimeZone::timestamp_nanos`] or [`DateTime::with_timezone`].
+    ///
+    /// The UNIX epoch starts on midnight, January 1, 1970, UTC.
+    ///
+    /// An `i64` with nanosecond precision can span a range of ~584 years. Because all values can
+    /// be represented as a `DateTime` this method never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// let timestamp_nanos: i64 = 1662921288_000_000_000; // Sun, 11 Sep 2022 18:34:48 UTC
+    /// let dt = DateTime::from_timestamp_nanos(timestamp_nanos);
+    /// assert_eq!(timestamp_nanos, dt.timestamp_nanos_opt().unwrap());
+    ///
+    /// // Negative timestamps (before the UNIX epoch) are supported as well.
+    /// let timestamp_nanos: i64 = -2208936075_000_000_000; // Mon, 1 Jan 1900 14:38:45 UTC
+    /// let dt = DateTime::from_timestamp_nanos(timestamp_nanos);
+    /// assert_eq!(timestamp_nanos, dt.timestamp_nanos_opt().unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_timestamp_nanos(nanos: i64) -> Self {
+        let secs = nanos.div_euclid(1_000_000_000);
+        let nsecs = nanos.rem_euclid(1_000_000_000) as u32;
+        expect(Self::from_timestamp(secs, nsecs), "timestamp in nanos is always in range")
+    }
+
+    /// Creates a new [`DateTime<Utc>`] from the number of non-leap nanoseconds
+    /// since January 1, 1970 0:00:00.000 UTC, as an `i128`.
+    ///
+    /// This is guaranteed to round-trip with
+    /// [`timestamp_nanos_i128`](DateTime::timestamp_nanos_i128), and covers the full range of
+    /// `DateTime` instead of the ~584 years around the UNIX epoch that fit in an `i64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the number of nanoseconds would be out of range for a `NaiveDateTime`
+    /// (more than ca. 262,000 years away from common era).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// let dt = DateTime::from_timestamp_nanos_i128(-14_831_769_600_000_000_000).unwrap();
+    /// assert_eq!(dt.to_string(), "1500-01-01 00:00:00 UTC");
+    ///
+    /// let dt = DateTime::from_timestamp_nanos_i128(1_000_000_000_000_000_555).unwrap();
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_000_000_000_555));
+    ///
+    /// assert!(DateTime::from_timestamp_nanos_i128(i128::MAX).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_timestamp_nanos_i128(nanos: i128) -> Option<Self> {
+        let secs = nanos.div_euclid(1_000_000_000);
+        let nsecs = nanos.rem_euclid(1_000_000_000) as u32;
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            return None;
+        }
+        Self::from_timestamp(secs as i64, nsecs)
+    }
+
+    /// Makes a new `DateTime<Utc>` from the 48-bit timestamp of a [ULID], the number of
+    /// milliseconds since January 1, 1970 0:00:00.000 UTC.
+    ///
+    /// This is guaranteed to round-trip with [`to_ulid_time_ms`](DateTime::to_ulid_time_ms).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `millis` does not fit in 48 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// // The timestamp of the ULID `01ARZ3NDEKTSV4RRFFQ69G5FAV`.
+    /// let dt = DateTime::from_ulid_time_ms(1_469_922_850_259).unwrap();
+    /// assert_eq!(dt.to_string(), "2016-07-30 23:54:10.259 UTC");
+    /// assert_eq!(DateTime::from_ulid_time_ms(1 << 48), None);
+    /// ```
+    ///
+    /// [ULID]: https://github.com/ulid/spec
+    #[inline]
+    #[must_use]
+    pub const fn from_ulid_time_ms(millis: u64) -> Option<Self> {
+        if millis > ULID_TIME_MAX {
+            return None;
+        }
+        Self::from_timestamp_millis(millis as i64)
+    }
+
+    /// Returns the 48-bit timestamp for a [ULID], the number of milliseconds since
+    /// January 1, 1970 0:00:00.000 UTC.
+    ///
+    /// Any precision beyond milliseconds is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the `DateTime` is before the UNIX epoch, or after the year 10889 when
+    /// the timestamp no longer fits in 48 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2016, 7, 30, 23, 54, 10).unwrap();
+    /// assert_eq!(dt.to_ulid_time_ms(), Some(1_469_922_850_000));
+    /// assert_eq!(Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap().to_ulid_time_ms(), None);
+    /// ```
+    ///
+    /// [ULID]: https://github.com/ulid/spec
+    #[inline]
+    #[must_use]
+    pub const fn to_ulid_time_ms(&self) -> Option<u64> {
+        let millis = self.timestamp_millis();
+        if millis < 0 || millis as u64 > ULID_TIME_MAX {
+            return None;
+        }
+        Some(millis as u64)
+    }
+
+    /// Makes a new `DateTime<Utc>` from the 32-bit timestamp of a [KSUID], the number of seconds
+    /// since the KSUID epoch of May 13, 2014 16:53:20 UTC.
+    ///
+    /// This is guaranteed to round-trip with [`to_ksuid_time`](DateTime::to_ksuid_time). Because
+    /// all values can be represented as a `DateTime` this method never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    ///
+    /// assert_eq!(DateTime::from_ksuid_time(0).to_string(), "2014-05-13 16:53:20 UTC");
+    /// assert_eq!(DateTime::from_ksuid_time(u32::MAX).to_string(), "2150-06-19 23:21:35 UTC");
+    /// ```
+    ///
+    /// [KSUID]: https://github.com/segmentio/ksuid
+    #[inline]
+    #[must_use]
+    pub const fn from_ksuid_time(secs: u32) -> Self {
+        let dt = Self::from_timestamp(KSUID_EPOCH + secs as i64, 0);
+        expect(dt, "KSUID timestamp is always in range")
+    }
+
+    /// Returns the 32-bit timestamp for a [KSUID], the number of seconds since the KSUID epoch of
+    /// May 13, 2014 16:53:20 UTC.
+    ///
+    /// Any fraction of a second is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the `DateTime` is before the KSUID epoch, or after June 19, 2150 when
+    /// the timestamp no longer fits in 32 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+    /// assert_eq!(dt.to_ksuid_time(), Some(315_083_200));
+    /// assert_eq!(Utc.with_ymd_and_hms(2014, 1, 1, 0, 0, 0).unwrap().to_ksuid_time(), None);
+    /// ```
+    ///
+    /// [KSUID]: https://github.com/segmentio/ksuid
+    #[inline]
+    #[must_use]
+    pub const fn to_ksuid_time(&self) -> Option<u32> {
+        let secs = self.timestamp() - KSUID_EPOCH;
+        if secs < 0 || secs > u32::MAX as i64 {
+            return None;
+        }
+        Some(secs as u32)
+    }
+
+    /// Parses a UNIX timestamp in the given unit, such as `1715083445` or `1715083445123.5`.
+    ///
+    /// The string is an integer with an optional sign and an optional fractional part after a
+    /// full stop. Digits beyond nanosecond precision are truncated. With [`TimestampUnit::Auto`]
+    /// the unit is inferred from the magnitude of the integer part, see its documentation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a number in this form, or if the timestamp is out of range
+    /// for a `DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, TimestampUnit, Utc};
+    ///
+    /// let dt = Utc.timestamp_millis_opt(1_715_083_445_123).unwrap();
+    /// let parse = DateTime::parse_unix_timestamp;
+    /// assert_eq!(parse("1715083445.123", TimestampUnit::Secs), Ok(dt));
+    /// assert_eq!(parse("1715083445123", TimestampUnit::Millis), Ok(dt));
+    /// assert_eq!(parse("1715083445123000", TimestampUnit::Auto), Ok(dt));
+    /// assert_eq!(parse("-1.5", TimestampUnit::Secs)?.to_string(), "1969-12-31 23:59:58.500 UTC");
+    /// assert!(parse("1715083445 ", TimestampUnit::Secs).is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_unix_timestamp(s: &str, unit: TimestampUnit) -> ParseResult<Self> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (integer, fraction) = match s.find('.') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() || fraction == Some("") {
+            return Err(TOO_SHORT);
+        }
+        if !all_digits(integer) || !fraction.map_or(true, all_digits) {
+            return Err(INVALID);
+        }
+        // more digits can't be in range, and could overflow below
+        if integer.trim_start_matches('0').len() > 20 {
+            return Err(OUT_OF_RANGE);
+        }
+
+        let integer = integer.bytes().fold(0i128, |n, b| n * 10 + i128::from(b - b'0'));
+        let nanos_per_unit: i128 = match unit {
+            TimestampUnit::Secs => 1_000_000_000,
+            TimestampUnit::Millis => 1_000_000,
+            TimestampUnit::Micros => 1_000,
+            TimestampUnit::Nanos => 1,
+            TimestampUnit::Auto => match integer {
+                0..=99_999_999_999 => 1_000_000_000,
+                100_000_000_000..=99_999_999_999_999 => 1_000_000,
+                100_000_000_000_000..=99_999_999_999_999_999 => 1_000,
+                _ => 1,
+            },
+        };
+        let mut nanos = integer * nanos_per_unit;
+        let mut scale = nanos_per_unit;
+        for b in fraction.unwrap_or("").bytes() {
+            scale /= 10;
+            if scale == 0 {
+                break;
+            }
+            nanos += i128::from(b - b'0') * scale;
+        }
+        if negative {
+            nanos = -nanos;
+        }
+
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).map_err(|_| OUT_OF_RANGE)?;
+        let nsecs = nanos.rem_euclid(1_000_000_000) as u32;
+        DateTime::from_timestamp(secs, nsecs).ok_or(OUT_OF_RANGE)
+    }
+
+    /// Makes a new `DateTime<Utc>` from a number of seconds since the UNIX epoch as an `f64`.
+    ///
+    /// This is the inverse of [`DateTime::to_ordered_f64_seconds`], but it is lossy, see
+    /// [`NaiveDateTime::from_f64_seconds_since`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `secs` is not finite or the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.timestamp_millis_opt(1_715_083_445_500).unwrap();
+    /// assert_eq!(DateTime::from_f64_seconds(1_715_083_445.5), Some(dt));
+    /// ```
+    #[must_use]
+    pub fn from_f64_seconds(secs: f64) -> Option<Self> {
+        Some(NaiveDateTime::from_f64_seconds(secs)?.and_utc())
+    }
+
+    /// Makes a new `DateTime<Utc>` from a number of seconds since `epoch` as an `f64`.
+    ///
+    /// This is the inverse of [`DateTime::to_ordered_f64_seconds_since`], but it is lossy, see
+    /// [`NaiveDateTime::from_f64_seconds_since`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `secs` is not finite or the result would be out of range.
+    #[must_use]
+    pub fn from_f64_seconds_since<Tz: TimeZone>(epoch: &DateTime<Tz>, secs: f64) -> Option<Self> {
+        Some(NaiveDateTime::from_f64_seconds_since(&epoch.datetime, secs)?.and_utc())
+    }
+
+    /// Returns an epoch for [`DateTime::to_ordered_f64_seconds_since`] that preserves the most
+    /// precision for values between `start` and `end`.
+    ///
+    /// See [`NaiveDateTime::f64_epoch_for`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 5, 7, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 5, 8, 0, 0, 0).unwrap();
+    /// let epoch = DateTime::f64_epoch_for(&start, &end);
+    /// assert_eq!(epoch, Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn f64_epoch_for<Tz1: TimeZone, Tz2: TimeZone>(
+        start: &DateTime<Tz1>,
+        end: &DateTime<Tz2>,
+    ) -> Self {
+        NaiveDateTime::f64_epoch_for(&start.datetime, &end.datetime).and_utc()
+    }
+
+    /// Converts this `DateTime<Utc>` into a `DateTime<FixedOffset>` with an offset of `+00:00`.
+    ///
+    /// This is the `const` equivalent of [`DateTime::fixed_offset`] for `DateTime<Utc>`. As the
+    /// offset is zero, the UTC datetime is copied as-is and no arithmetic is involved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, Utc};
+    ///
+    /// const EPOCH: DateTime<FixedOffset> = DateTime::<Utc>::UNIX_EPOCH.to_fixed_offset();
+    /// assert_eq!(EPOCH, DateTime::<Utc>::UNIX_EPOCH.fixed_offset());
+    /// assert_eq!(EPOCH.offset(), &FixedOffset::east_opt(0).unwrap());
+    /// assert_eq!(EPOCH.timestamp(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_fixed_offset(&self) -> DateTime<FixedOffset> {
+        DateTime { datetime: self.datetime, offset: FixedOffset::UTC }
+    }
+
+    /// Parses an iCalendar `DATE-TIME` value in UTC, such as `20240315T093000Z`.
+    ///
+    /// The format is defined in [RFC 5545, section 3.3.5]. See
+    /// [`NaiveDateTime::parse_ical`] for floating values without the `Z` suffix, and
+    /// [`DateTime::parse_ical_in`] for values that reference a `TZID` parameter.
     ///
-    /// The UNIX epoch starts on midnight, January 1, 1970, UTC.
+    /// # Errors
     ///
-    /// An `i64` with nanosecond precision can span a range of ~584 years. Because all values can
-    /// be represented as a `DateTime` this method never fails.
+    /// Returns an error if `s` is not in exactly this format, or if the date and time do not
+    /// exist.
     ///
     /// # Example
     ///
     /// ```
-    /// use chrono::DateTime;
-    ///
-    /// let timestamp_nanos: i64 = 1662921288_000_000_000; // Sun, 11 Sep 2022 18:34:48 UTC
-    /// let dt = DateTime::from_timestamp_nanos(timestamp_nanos);
-    /// assert_eq!(timestamp_nanos, dt.timestamp_nanos_opt().unwrap());
+    /// use chrono::{DateTime, TimeZone, Utc};
     ///
-    /// // Negative timestamps (before the UNIX epoch) are supported as well.
-    /// let timestamp_nanos: i64 = -2208936075_000_000_000; // Mon, 1 Jan 1900 14:38:45 UTC
-    /// let dt = DateTime::from_timestamp_nanos(timestamp_nanos);
-    /// assert_eq!(timestamp_nanos, dt.timestamp_nanos_opt().unwrap());
+    /// let dt = DateTime::parse_ical("20240315T093000Z");
+    /// assert_eq!(dt, Ok(Utc.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap()));
+    /// assert!(DateTime::parse_ical("20240315T093000").is_err());
     /// ```
-    #[inline]
-    #[must_use]
-    pub const fn from_timestamp_nanos(nanos: i64) -> Self {
-        let secs = nanos.div_euclid(1_000_000_000);
-        let nsecs = nanos.rem_euclid(1_000_000_000) as u32;
-        expect(Self::from_timestamp(secs, nsecs), "timestamp in nanos is always in range")
+    ///
+    /// [RFC 5545, section 3.3.5]: https://www.rfc-editor.org/rfc/rfc5545#section-3.3.5
+    pub fn parse_ical(s: &str) -> ParseResult<DateTime<Utc>> {
+        let s = s.strip_suffix('Z').ok_or(if s.len() < 16 { TOO_SHORT } else { INVALID })?;
+        NaiveDateTime::parse_ical(s).map(|dt| dt.and_utc())
     }
 
     /// The Unix Epoch, 1970-01-01 00:00:00 UTC.
     pub const UNIX_EPOCH: Self = Self { datetime: NaiveDateTime::UNIX_EPOCH, offset: Utc };
 }
 
+/// The unit of a UNIX timestamp, for [`DateTime::parse_unix_timestamp`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimestampUnit {
+    /// Seconds since the UNIX epoch.
+    Secs,
+    /// Milliseconds since the UNIX epoch.
+    Millis,
+    /// Microseconds since the UNIX epoch.
+    Micros,
+    /// Nanoseconds since the UNIX epoch.
+    Nanos,
+    /// Infer the unit from the magnitude of the integer part of the timestamp.
+    ///
+    /// An integer part of up to 11 digits is taken to be seconds, up to 14 digits milliseconds,
+    /// up to 17 digits microseconds, and anything larger nanoseconds. This picks the right unit
+    /// for timestamps between March 1973 and the year 5138, but can be wrong for timestamps close
+    /// to the UNIX epoch.
+    Auto,
+}
+
+/// The largest timestamp of a ULID, in milliseconds since the UNIX epoch.
+const ULID_TIME_MAX: u64 = (1 << 48) - 1;
+
+/// The KSUID epoch, in seconds since the UNIX epoch.
+const KSUID_EPOCH: i64 = 1_400_000_000;
+
 impl Default for DateTime<Utc> {
     fn default() -> Self {
         Utc.from_utc_datetime(&NaiveDateTime::default())
@@ -887,7 +2062,7 @@ impl From<DateTime<Utc>> for DateTime<FixedOffset> {
     /// Conversion is done via [`DateTime::with_timezone`]. Note that the converted value returned by
     /// this will be created with a fixed timezone offset of 0.
     fn from(src: DateTime<Utc>) -> Self {
-        src.with_timezone(&FixedOffset::east_opt(0).unwrap())
+        src.to_fixed_offset()
     }
 }
 
@@ -998,6 +2173,136 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses an RFC 2822 date-and-time string into a `DateTime<FixedOffset>` value, together
+    /// with the kind of zone it was given in.
+    ///
+    /// This is the same as [`DateTime::parse_from_rfc2822`], but also returns the
+    /// [`Rfc2822Zone`]. RFC 2822 uses `-0000` to say that the time is in UTC while nothing is known
+    /// about the local time zone; [`Rfc2822Zone::is_unknown_local`] can tell it apart from
+    /// `+0000`, which both parse to an offset of zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, TimeZone, Utc, format::Rfc2822Zone};
+    /// let (dt, zone) =
+    ///     DateTime::parse_from_rfc2822_with_zone("Wed, 18 Feb 2015 23:16:09 -0000").unwrap();
+    /// assert_eq!(dt, Utc.with_ymd_and_hms(2015, 2, 18, 23, 16, 9).unwrap());
+    /// assert_eq!(zone, Rfc2822Zone::UnknownLocal);
+    /// assert!(zone.is_unknown_local());
+    ///
+    /// let (_, zone) =
+    ///     DateTime::parse_from_rfc2822_with_zone("Wed, 18 Feb 2015 23:16:09 GMT").unwrap();
+    /// assert_eq!(zone, Rfc2822Zone::Universal);
+    /// assert!(!zone.is_unknown_local());
+    /// ```
+    pub fn parse_from_rfc2822_with_zone(
+        s: &str,
+    ) -> ParseResult<(DateTime<FixedOffset>, Rfc2822Zone)> {
+        let mut parsed = Parsed::new();
+        let (s, zone) = parse_rfc2822(&mut parsed, s)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        parsed.to_datetime().map(|dt| (dt, zone))
+    }
+
+    /// Parses an RFC 2822 date-and-time string into a `DateTime<FixedOffset>` value, together
+    /// with the comment that follows it.
+    ///
+    /// This is the same as [`DateTime::parse_from_rfc2822`], but instead of skipping the comments
+    /// after the offset it returns the contents of the first one, without the enclosing
+    /// parentheses. Such a comment is often a human-readable hint of the time zone, as in
+    /// `Tue, 1 Jul 2003 10:52:37 -0230 (Newfoundland Time)`. Escaped characters in the comment
+    /// are returned as they appear in the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// let (dt, comment) = DateTime::parse_from_rfc2822_with_comment(
+    ///     "Tue, 1 Jul 2003 10:52:37 -0230 (Newfoundland Time)",
+    /// )?;
+    /// let offset = FixedOffset::west_opt(2 * 3600 + 30 * 60).unwrap();
+    /// assert_eq!(dt, offset.with_ymd_and_hms(2003, 7, 1, 10, 52, 37).unwrap());
+    /// assert_eq!(dt.offset(), &offset);
+    /// assert_eq!(comment, Some("Newfoundland Time"));
+    ///
+    /// let (_, comment) = DateTime::parse_from_rfc2822_with_comment("Tue, 1 Jul 2003 10:52:37 GMT")?;
+    /// assert_eq!(comment, None);
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_from_rfc2822_with_comment(
+        s: &str,
+    ) -> ParseResult<(DateTime<FixedOffset>, Option<&str>)> {
+        let mut parsed = Parsed::new();
+        let (s, _, comment) = parse_rfc2822_with_comment(&mut parsed, s)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        parsed.to_datetime().map(|dt| (dt, comment))
+    }
+
+    /// Parses a date and time in the ISO 8601 basic format with an offset, such as
+    /// `20240507T123456Z` or `20240507T123456.789+0200`, into a `DateTime<FixedOffset>` value.
+    ///
+    /// The year must have four digits and the seconds can not be left out. A fraction of a second
+    /// may follow, with either a full stop or a comma as the decimal separator. The offset is
+    /// either `Z` or a sign followed by hours and optionally minutes, without a colon.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a date and time in the ISO 8601 basic format with an
+    /// offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2024, 5, 7, 12, 34, 56).unwrap();
+    /// assert_eq!(DateTime::parse_from_iso8601_basic("20240507T123456+0200"), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_iso8601_basic("20240507T123456+02"), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_iso8601_basic("20240507T103456Z"), Ok(dt));
+    /// assert!(DateTime::parse_from_iso8601_basic("20240507T123456").is_err());
+    /// assert!(DateTime::parse_from_iso8601_basic("20240507T123456+02:00").is_err());
+    /// ```
+    pub fn parse_from_iso8601_basic(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        let (s, _) = parse_iso8601_basic(&mut parsed, s, true)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        parsed.to_datetime()
+    }
+
+    /// Parses an RFC 2822 date-and-time string at the start of `s` into a `DateTime<FixedOffset>`
+    /// value, and returns it with the rest of the string.
+    ///
+    /// This accepts the same input as [`DateTime::parse_from_rfc2822`], but allows trailing
+    /// text. Comments after the offset are part of the date and time, and are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` does not start with a valid RFC 2822 date and time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// let (dt, rest) =
+    ///     DateTime::parse_from_rfc2822_and_remainder("Wed, 18 Feb 2015 23:16:09 GMT; next")?;
+    /// let tz = FixedOffset::east_opt(0).unwrap();
+    /// assert_eq!(dt, tz.with_ymd_and_hms(2015, 2, 18, 23, 16, 9).unwrap());
+    /// assert_eq!(rest, "; next");
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_from_rfc2822_and_remainder(s: &str) -> ParseResult<(DateTime<FixedOffset>, &str)> {
+        let mut parsed = Parsed::new();
+        let (s, _) = parse_rfc2822(&mut parsed, s)?;
+        parsed.to_datetime().map(|dt| (dt, s))
+    }
+
     /// Parses an RFC 3339 date-and-time string into a `DateTime<FixedOffset>` value.
     ///
     /// Parses all valid RFC 3339 values (as well as the subset of valid ISO 8601 values that are
@@ -1012,14 +2317,187 @@ impl DateTime<FixedOffset> {
     /// also simultaneously valid RFC 3339 values, but not all RFC 3339 values are valid ISO 8601
     /// values (or the other way around).
     pub fn parse_from_rfc3339(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        parse_rfc3339_fast(s)
+    }
+
+    /// Parses an RFC 3339 date-and-time string at the start of `s` into a `DateTime<FixedOffset>`
+    /// value, and returns it with the rest of the string.
+    ///
+    /// This accepts the same input as [`DateTime::parse_from_rfc3339`], but allows trailing
+    /// text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` does not start with a valid RFC 3339 date and time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// let (dt, rest) =
+    ///     DateTime::parse_from_rfc3339_and_remainder("1996-12-19T16:39:57-08:00 GET /")?;
+    /// let tz = FixedOffset::west_opt(8 * 3600).unwrap();
+    /// assert_eq!(dt, tz.with_ymd_and_hms(1996, 12, 19, 16, 39, 57).unwrap());
+    /// assert_eq!(rest, " GET /");
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_from_rfc3339_and_remainder(s: &str) -> ParseResult<(DateTime<FixedOffset>, &str)> {
         let mut parsed = Parsed::new();
         let (s, _) = parse_rfc3339(&mut parsed, s)?;
-        if !s.is_empty() {
-            return Err(TOO_LONG);
+        parsed.to_datetime().map(|dt| (dt, s))
+    }
+
+    /// Parses a date and time in the format of the `FromStr` implementation at the start of `s`
+    /// into a `DateTime<FixedOffset>` value, and returns it with the rest of the string.
+    ///
+    /// This format is a relaxed form of RFC 3339, which for example allows a space before the
+    /// offset and accepts `UTC` as offset, so it can read the `Display` and `Debug` output of a
+    /// `DateTime`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` does not start with a date and time in this format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// let dt = Utc.with_ymd_and_hms(2015, 2, 18, 23, 16, 9).unwrap();
+    /// let s = format!("{} and more", dt);
+    /// let (parsed, rest) = DateTime::parse_relaxed_and_remainder(&s)?;
+    /// assert_eq!(parsed, dt);
+    /// assert_eq!(rest, " and more");
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_relaxed_and_remainder(s: &str) -> ParseResult<(DateTime<FixedOffset>, &str)> {
+        let mut parsed = Parsed::new();
+        let (s, _) = parse_rfc3339_relaxed(&mut parsed, s)?;
+        parsed.to_datetime().map(|dt| (dt, s))
+    }
+
+    /// Parses an RFC 5424 (syslog) timestamp such as `2003-10-11T22:14:15.003Z`.
+    ///
+    /// RFC 5424 timestamps are a strict subset of RFC 3339: the `T` and `Z` must be uppercase, the
+    /// fractional seconds can have at most 6 digits, the offset is required and leap seconds are
+    /// not allowed. The `-` that RFC 5424 uses for an unknown time is not accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid RFC 5424 timestamp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone, Timelike};
+    ///
+    /// let dt = DateTime::parse_from_rfc5424("2003-08-24T05:14:15.000003-07:00").unwrap();
+    /// let tz = FixedOffset::west_opt(7 * 3600).unwrap();
+    /// let expected = tz.with_ymd_and_hms(2003, 8, 24, 5, 14, 15).unwrap();
+    /// assert_eq!(dt, expected.with_nanosecond(3_000).unwrap());
+    ///
+    /// assert!(DateTime::parse_from_rfc5424("2003-10-11T22:14:15.003Z").is_ok());
+    /// assert!(DateTime::parse_from_rfc5424("2003-10-11t22:14:15.003Z").is_err());
+    /// assert!(DateTime::parse_from_rfc5424("2003-10-11T22:14:15.000000003Z").is_err());
+    /// assert!(DateTime::parse_from_rfc5424("-").is_err());
+    /// ```
+    pub fn parse_from_rfc5424(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let bytes = s.as_bytes();
+        if bytes.len() < 20 {
+            return Err(TOO_SHORT);
+        }
+        if bytes[10] != b'T' {
+            return Err(INVALID);
+        }
+        let mut rest = &bytes[19..];
+        if let Some((b'.', fraction)) = rest.split_first() {
+            let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+            if digits == 0 || digits > 6 {
+                return Err(INVALID);
+            }
+            rest = &fraction[digits..];
         }
+        match rest {
+            b"Z" => {}
+            [b'+' | b'-', _, _, b':', _, _] => {}
+            _ => return Err(INVALID),
+        }
+        let dt = DateTime::parse_from_rfc3339(s)?;
+        if dt.nanosecond() >= 1_000_000_000 {
+            return Err(OUT_OF_RANGE);
+        }
+        Ok(dt)
+    }
+
+    /// Parses a Common Log Format timestamp, as written by web servers in their access logs, such
+    /// as `10/Oct/2000:13:55:36 -0700`.
+    ///
+    /// The timestamp may be enclosed in `[` and `]`, the way it appears in a log line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid Common Log Format timestamp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::west_opt(7 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2000, 10, 10, 13, 55, 36).unwrap();
+    /// assert_eq!(DateTime::parse_from_clf("10/Oct/2000:13:55:36 -0700"), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_clf("[10/Oct/2000:13:55:36 -0700]"), Ok(dt));
+    /// ```
+    pub fn parse_from_clf(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Numeric(Numeric::Day, Pad::Zero),
+            Item::Literal("/"),
+            Item::Fixed(Fixed::ShortMonthName),
+            Item::Literal("/"),
+            Item::Numeric(Numeric::Year, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Hour, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Minute, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Second, Pad::Zero),
+            Item::Space(" "),
+            Item::Fixed(Fixed::TimezoneOffset),
+        ];
+        let s = match s.strip_prefix('[') {
+            Some(inner) => inner.strip_suffix(']').ok_or(INVALID)?,
+            None => s,
+        };
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
         parsed.to_datetime()
     }
 
+    /// Parses an EXIF date and time together with its offset, such as the `DateTimeOriginal` and
+    /// `OffsetTimeOriginal` tags of a photo.
+    ///
+    /// `datetime` is parsed with [`NaiveDateTime::parse_exif`]. `offset` is in the form `+09:00`,
+    /// as written in the `OffsetTime`, `OffsetTimeOriginal` and `OffsetTimeDigitized` tags.
+    /// Trailing NUL characters are ignored in both.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either value is invalid, or if the offset is unknown (blank).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let dt = DateTime::parse_exif("2023:09:08 07:03:25", "+09:00");
+    /// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// assert_eq!(dt, Ok(tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap()));
+    /// assert!(DateTime::parse_exif("2023:09:08 07:03:25", "   :  ").is_err());
+    /// ```
+    pub fn parse_exif(datetime: &str, offset: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let offset: FixedOffset = offset.trim_end_matches('\0').parse()?;
+        NaiveDateTime::parse_exif(datetime)?.and_fixed_offset(offset).ok_or(OUT_OF_RANGE)
+    }
+
     /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value.
     ///
     /// Note that this method *requires a timezone* in the input string. See
@@ -1055,6 +2533,35 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses a string with the specified format string and parsing options into a new
+    /// `DateTime<FixedOffset>` value.
+    ///
+    /// This can be used to parse more leniently, or to limit the size of the input. See
+    /// [`ParseOptions`] for the available options, and [`parse_from_str`](#method.parse_from_str)
+    /// for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::ParseOptions;
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let parse = |s| DateTime::parse_from_str_with_options(s, "%d/%m/%Y %H:%M %z", &options);
+    /// let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    /// let expected = tz.with_ymd_and_hms(2015, 9, 5, 23, 56, 0).unwrap();
+    /// assert_eq!(parse("05/09/2015 23:56 +5:30"), Ok(expected));
+    /// ```
+    pub fn parse_from_str_with_options(
+        s: &str,
+        fmt: &str,
+        options: &ParseOptions,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse_with_options(&mut parsed, s, StrftimeItems::new(fmt), options)?;
+        parsed.to_datetime()
+    }
+
     /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value, and a
     /// slice with the remaining portion of the string.
     ///
@@ -1091,6 +2598,71 @@ impl DateTime<FixedOffset> {
         let remainder = parse_and_remainder(&mut parsed, s, StrftimeItems::new(fmt))?;
         parsed.to_datetime().map(|d| (d, remainder))
     }
+
+    /// Parses a string with the specified format string and locale into a new
+    /// `DateTime<FixedOffset>` value, and a slice with the remaining portion of the string.
+    ///
+    /// Similar to [`parse_from_str_localized`](#method.parse_from_str_localized).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let (datetime, remainder) = DateTime::parse_and_remainder_localized(
+    ///     "11 juli 2023 00:34:59 +0200 (CEST)",
+    ///     "%d %B %Y %T %z",
+    ///     Locale::nl_NL,
+    /// )
+    /// .unwrap();
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// assert_eq!(datetime, tz.with_ymd_and_hms(2023, 7, 11, 0, 34, 59).unwrap());
+    /// assert_eq!(remainder, " (CEST)");
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_and_remainder_localized<'a>(
+        s: &'a str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<(DateTime<FixedOffset>, &'a str)> {
+        let mut parsed = Parsed::new();
+        let items = StrftimeItems::new_with_locale(fmt, locale);
+        let remainder = parse_and_remainder_localized(&mut parsed, s, items, locale)?;
+        parsed.to_datetime().map(|d| (d, remainder))
+    }
+
+    /// Parses a string with the specified format string and locale into a
+    /// `DateTime<FixedOffset>` value.
+    ///
+    /// This is the counterpart of [`format_localized`](#method.format_localized): the localized
+    /// `%x`, `%X`, `%c` and `%r` formats, month and weekday names and AM/PM are read in the form
+    /// of `locale`. See [`parse_from_str`](#method.parse_from_str) for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let dt = DateTime::parse_from_str_localized(
+    ///     "mardi 11 juillet 2023 00:34:59 +0900",
+    ///     "%A %d %B %Y %X %z",
+    ///     Locale::fr_FR,
+    /// );
+    /// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// assert_eq!(dt, Ok(tz.with_ymd_and_hms(2023, 7, 11, 0, 34, 59).unwrap()));
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(
+        s: &str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_datetime()
+    }
 }
 
 impl<Tz: TimeZone> DateTime<Tz>
@@ -1129,6 +2701,36 @@ where
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Formats the combined date and time per the specified format string directly into `w`.
+    ///
+    /// This is the same as writing [`DateTime::format`] with [`write!`], but does not go through
+    /// [`fmt::Display`]. It can write into any buffer that implements [`fmt::Write`], such as a
+    /// reused `String` or a fixed-size buffer.
+    ///
+    /// See the [`crate::format::strftime`] module for the supported escape sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `w` returns an error, or if the format string contains an invalid
+    /// specifier.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    ///
+    /// let date_time: DateTime<Utc> = Utc.with_ymd_and_hms(2017, 04, 02, 12, 50, 32).unwrap();
+    /// let mut buf = String::with_capacity(32);
+    /// date_time.format_into(&mut buf, "%d/%m/%Y %H:%M")?;
+    /// assert_eq!(buf, "02/04/2017 12:50");
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn format_into(&self, w: &mut impl fmt::Write, fmt: &str) -> fmt::Result {
+        self.format(fmt).write_to(w)
+    }
+
     /// Formats the combined date and time with the specified formatting items and locale.
     #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
     #[inline]
@@ -1169,6 +2771,20 @@ where
     }
 }
 
+impl<Tz: TimeZone> CalendarFields for DateTime<Tz> {
+    fn date_fields(&self) -> Option<DateFields> {
+        Some(DateFields::from(self.overflowing_naive_local().date()))
+    }
+
+    fn time_fields(&self) -> Option<TimeFields> {
+        Some(TimeFields::from(self.overflowing_naive_local().time()))
+    }
+
+    fn utc_offset(&self) -> Option<FixedOffset> {
+        Some(self.offset.fix())
+    }
+}
+
 impl<Tz: TimeZone> Datelike for DateTime<Tz> {
     #[inline]
     fn year(&self) -> i32 {
@@ -1464,6 +3080,27 @@ impl<Tz: TimeZone> hash::Hash for DateTime<Tz> {
     }
 }
 
+/// The error returned by [`DateTime::elapsed`] if the `DateTime` is in the future.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ElapsedError(TimeDelta);
+
+impl ElapsedError {
+    /// Returns how far the `DateTime` is ahead of the current time.
+    #[must_use]
+    pub const fn duration(&self) -> TimeDelta {
+        self.0
+    }
+}
+
+impl fmt::Display for ElapsedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "datetime is {} later than the current time", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ElapsedError {}
+
 /// Add `TimeDelta` to `DateTime`.
 ///
 /// As a part of Chrono's [leap second handling], the addition assumes that **there is no leap
@@ -1914,6 +3551,46 @@ impl From<DateTime<Utc>> for js_sys::Date {
     }
 }
 
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "wasmbind",
+    feature = "clock",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+))]
+impl From<js_sys::Date> for DateTime<Local> {
+    fn from(date: js_sys::Date) -> DateTime<Local> {
+        DateTime::<Local>::from(&date)
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "wasmbind",
+    feature = "clock",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+))]
+impl From<&js_sys::Date> for DateTime<Local> {
+    /// Converts a JS `Date` to a `DateTime<Local>`, with the offset of the time zone of the
+    /// JavaScript environment at that moment.
+    fn from(date: &js_sys::Date) -> DateTime<Local> {
+        DateTime::<Utc>::from(date).with_timezone(&Local)
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "wasmbind",
+    feature = "clock",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+))]
+impl From<DateTime<Local>> for js_sys::Date {
+    /// Converts a `DateTime<Local>` to a JS `Date`. The same limits apply as for the conversion
+    /// from a `DateTime<Utc>`.
+    fn from(date: DateTime<Local>) -> js_sys::Date {
+        js_sys::Date::from(date.to_utc())
+    }
+}
+
 // Note that implementation of Arbitrary cannot be simply derived for DateTime<Tz>, due to
 // the nontrivial bound <Tz as TimeZone>::Offset: Arbitrary.
 #[cfg(all(feature = "arbitrary", feature = "std"))]
@@ -1928,13 +3605,3 @@ where
         Ok(DateTime::from_naive_utc_and_offset(datetime, offset))
     }
 }
-
-/// Number of days between Januari 1, 1970 and December 31, 1 BCE which we define to be day 0.
-/// 4 full leap year cycles until December 31, 1600     4 * 146097 = 584388
-/// 1 day until January 1, 1601                                           1
-/// 369 years until Januari 1, 1970                      369 * 365 = 134685
-/// of which floor(369 / 4) are leap years          floor(369 / 4) =     92
-/// except for 1700, 1800 and 1900                                       -3 +
-///                                                                  --------
-///                                                                  719163
-const UNIX_EPOCH_DAY: i64 = 719_163;
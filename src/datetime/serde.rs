@@ -2,7 +2,7 @@ use core::fmt;
 use serde::{de, ser};
 
 use super::DateTime;
-use crate::format::{SecondsFormat, write_rfc3339};
+use crate::format::{Rfc3339Options, write_rfc3339};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
 use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
@@ -23,6 +23,130 @@ pub struct MicroSecondsTimestampVisitor;
 #[derive(Debug)]
 pub struct MilliSecondsTimestampVisitor;
 
+/// Generates a pair of modules that ser/de a value to/from an integer timestamp in `$unit`,
+/// one for the value itself and one for an `Option` of it.
+///
+/// `$to` returns the timestamp of a value in `$unit`, or `None` if it doesn't fit in an `i64`.
+/// `$from` makes the value from a `DateTime<Utc>`. The `visitor` is implemented in the first
+/// module and shared with the second. The docs of the modules and their functions are passed in
+/// by the caller.
+macro_rules! timestamp_serde_modules {
+    (
+        $ty:ty, $visitor:ident, $unit:literal, $per_sec:literal,
+        to: |$to_arg:ident| $to:expr,
+        from: |$from_arg:ident| $from:expr;
+
+        $(#[$attr:meta])*
+        pub mod $name:ident {
+            $(#[$ser_attr:meta])*
+            fn serialize;
+            $(#[$de_attr:meta])*
+            fn deserialize;
+        }
+
+        $(#[$opt_attr:meta])*
+        pub mod $opt_name:ident {
+            $(#[$opt_ser_attr:meta])*
+            fn serialize;
+            $(#[$opt_de_attr:meta])*
+            fn deserialize;
+        }
+    ) => {
+        $(#[$attr])*
+        pub mod $name {
+            use core::fmt;
+            use serde::{de, ser};
+
+            use super::*;
+            use crate::serde::invalid_ts;
+
+            $(#[$ser_attr])*
+            pub fn serialize<S>(dt: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                serializer.serialize_i64(to_timestamp(dt)?)
+            }
+
+            $(#[$de_attr])*
+            pub fn deserialize<'de, D>(d: D) -> Result<$ty, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                d.deserialize_i64($visitor)
+            }
+
+            pub(super) fn to_timestamp<E: ser::Error>($to_arg: &$ty) -> Result<i64, E> {
+                let message = concat!("value out of range for a timestamp in ", $unit);
+                $to.ok_or_else(|| E::custom(message))
+            }
+
+            fn from_timestamp(secs: Option<i64>, subsec: i64) -> Option<$ty> {
+                let nsecs = (subsec * (1_000_000_000 / $per_sec)) as u32;
+                let $from_arg = crate::DateTime::from_timestamp(secs?, nsecs)?;
+                Some($from)
+            }
+
+            impl de::Visitor<'_> for $visitor {
+                type Value = $ty;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(concat!("a unix timestamp in ", $unit))
+                }
+
+                #[doc = concat!("Deserialize a timestamp in ", $unit, " since the epoch")]
+                fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    from_timestamp(Some(value.div_euclid($per_sec)), value.rem_euclid($per_sec))
+                        .ok_or_else(|| invalid_ts(value))
+                }
+
+                #[doc = concat!("Deserialize a timestamp in ", $unit, " since the epoch")]
+                fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    let secs = i64::try_from(value.div_euclid($per_sec)).ok();
+                    from_timestamp(secs, value.rem_euclid($per_sec) as i64)
+                        .ok_or_else(|| invalid_ts(value))
+                }
+            }
+        }
+
+        $(#[$opt_attr])*
+        pub mod $opt_name {
+            use serde::{de, ser};
+
+            use super::*;
+            use super::$name::to_timestamp;
+            use crate::serde::OptionVisitor;
+
+            $(#[$opt_ser_attr])*
+            pub fn serialize<S>(opt: &Option<$ty>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                match *opt {
+                    Some(ref dt) => serializer.serialize_some(&to_timestamp::<S::Error>(dt)?),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            $(#[$opt_de_attr])*
+            pub fn deserialize<'de, D>(d: D) -> Result<Option<$ty>, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                d.deserialize_option(OptionVisitor($visitor))
+            }
+        }
+    };
+}
+
+pub(crate) use timestamp_serde_modules;
+
 /// Serialize to an RFC 3339 formatted string
 ///
 /// As an extension to RFC 3339 this can serialize `DateTime`s outside the range of 0-9999 years
@@ -42,7 +166,7 @@ impl<Tz: TimeZone> ser::Serialize for DateTime<Tz> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let naive = self.inner.naive_local();
                 let offset = self.inner.offset.fix();
-                write_rfc3339(f, naive, offset, SecondsFormat::AutoSi, true)
+                write_rfc3339(f, naive, offset, Rfc3339Options::new().with_use_z(true))
             }
         }
 
@@ -118,1090 +242,886 @@ impl<'de> de::Deserialize<'de> for DateTime<Local> {
     }
 }
 
-/// Ser/de to/from timestamps in nanoseconds
-///
-/// Intended for use with `serde`'s `with` attribute.
-///
-/// # Example:
-///
-/// ```rust
-/// # use chrono::{DateTime, Utc, NaiveDate};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_nanoseconds;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_nanoseconds")]
-///     time: DateTime<Utc>,
-/// }
-///
-/// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
-///     .unwrap()
-///     .and_hms_nano_opt(02, 04, 59, 918355733)
-///     .unwrap()
-///     .and_utc();
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_nanoseconds {
-    use core::fmt;
-    use serde::{de, ser};
-
-    use crate::serde::invalid_ts;
-    use crate::{DateTime, Utc};
+timestamp_serde_modules! {
+    DateTime<Utc>, NanoSecondsTimestampVisitor, "nanoseconds", 1_000_000_000,
+    to: |dt| dt.timestamp_nanos_opt(),
+    from: |dt| dt;
 
-    use super::NanoSecondsTimestampVisitor;
-
-    /// Serialize a UTC datetime into an integer number of nanoseconds since the epoch
-    ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
+    /// Ser/de to/from timestamps in nanoseconds
     ///
-    /// # Errors
-    ///
-    /// An `i64` with nanosecond precision can span a range of ~584 years. This function returns an
-    /// error on an out of range `DateTime`.
-    ///
-    /// The dates that can be represented as nanoseconds are between 1677-09-21T00:12:44.0 and
-    /// 2262-04-11T23:47:16.854775804.
+    /// Intended for use with `serde`'s `with` attribute.
     ///
     /// # Example:
     ///
     /// ```rust
     /// # use chrono::{DateTime, Utc, NaiveDate};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_nanoseconds::serialize as to_nano_ts;
-    /// #[derive(Serialize)]
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_nanoseconds;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(serialize_with = "to_nano_ts")]
+    ///     #[serde(with = "ts_nanoseconds")]
     ///     time: DateTime<Utc>,
     /// }
     ///
-    /// let my_s = S {
-    ///     time: NaiveDate::from_ymd_opt(2018, 5, 17)
-    ///         .unwrap()
-    ///         .and_hms_nano_opt(02, 04, 59, 918355733)
-    ///         .unwrap()
-    ///         .and_utc(),
-    /// };
+    /// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
+    ///     .unwrap()
+    ///     .and_hms_nano_opt(02, 04, 59, 918355733)
+    ///     .unwrap()
+    ///     .and_utc();
+    /// let my_s = S { time: time.clone() };
+    ///
     /// let as_string = serde_json::to_string(&my_s)?;
     /// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        serializer.serialize_i64(dt.timestamp_nanos_opt().ok_or(ser::Error::custom(
-            "value out of range for a timestamp with nanosecond precision",
-        ))?)
+    pub mod ts_nanoseconds {
+        /// Serialize a UTC datetime into an integer number of nanoseconds since the epoch
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Errors
+        ///
+        /// An `i64` with nanosecond precision can span a range of ~584 years. This function returns
+        /// an error on an out of range `DateTime`.
+        ///
+        /// The dates that can be represented as nanoseconds are between 1677-09-21T00:12:44.0 and
+        /// 2262-04-11T23:47:16.854775804.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, Utc, NaiveDate};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_nanoseconds::serialize as to_nano_ts;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_nano_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s = S {
+        ///     time: NaiveDate::from_ymd_opt(2018, 5, 17)
+        ///         .unwrap()
+        ///         .and_hms_nano_opt(02, 04, 59, 918355733)
+        ///         .unwrap()
+        ///         .and_utc(),
+        /// };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a [`DateTime`] from a nanosecond timestamp
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_nanoseconds::deserialize as from_nano_ts;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_nano_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355733 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355733).unwrap() });
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": -1 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 999_999_999).unwrap() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 
-    /// Deserialize a [`DateTime`] from a nanosecond timestamp
+    /// Ser/de to/from optional timestamps in nanoseconds
     ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
+    /// Intended for use with `serde`'s `with` attribute.
     ///
     /// # Example:
     ///
     /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_nanoseconds::deserialize as from_nano_ts;
-    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// # use chrono::{DateTime, Utc, NaiveDate};
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_nanoseconds_option;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(deserialize_with = "from_nano_ts")]
-    ///     time: DateTime<Utc>,
+    ///     #[serde(with = "ts_nanoseconds_option")]
+    ///     time: Option<DateTime<Utc>>,
     /// }
     ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355733 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355733).unwrap() });
+    /// let time = Some(
+    ///     NaiveDate::from_ymd_opt(2018, 5, 17)
+    ///         .unwrap()
+    ///         .and_hms_nano_opt(02, 04, 59, 918355733)
+    ///         .unwrap()
+    ///         .and_utc(),
+    /// );
+    /// let my_s = S { time: time.clone() };
     ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": -1 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 999_999_999).unwrap() });
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_i64(NanoSecondsTimestampVisitor)
-    }
-
-    impl de::Visitor<'_> for NanoSecondsTimestampVisitor {
-        type Value = DateTime<Utc>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in nanoseconds")
-        }
-
-        /// Deserialize a timestamp in nanoseconds since the epoch
-        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            DateTime::from_timestamp(
-                value.div_euclid(1_000_000_000),
-                (value.rem_euclid(1_000_000_000)) as u32,
-            )
-            .ok_or_else(|| invalid_ts(value))
-        }
-
-        /// Deserialize a timestamp in nanoseconds since the epoch
-        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            DateTime::from_timestamp((value / 1_000_000_000) as i64, (value % 1_000_000_000) as u32)
-                .ok_or_else(|| invalid_ts(value))
-        }
+    pub mod ts_nanoseconds_option {
+        /// Serialize a UTC datetime into an integer number of nanoseconds since the epoch or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Errors
+        ///
+        /// An `i64` with nanosecond precision can span a range of ~584 years. This function returns
+        /// an error on an out of range `DateTime`.
+        ///
+        /// The dates that can be represented as nanoseconds are between 1677-09-21T00:12:44.0 and
+        /// 2262-04-11T23:47:16.854775804.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, Utc, NaiveDate};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_nanoseconds_option::serialize as to_nano_tsopt;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_nano_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s = S {
+        ///     time: Some(
+        ///         NaiveDate::from_ymd_opt(2018, 5, 17)
+        ///             .unwrap()
+        ///             .and_hms_nano_opt(02, 04, 59, 918355733)
+        ///             .unwrap()
+        ///             .and_utc(),
+        ///     ),
+        /// };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a nanosecond timestamp or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_nanoseconds_option::deserialize as from_nano_tsopt;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_nano_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355733 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355733).single() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 }
 
-/// Ser/de to/from optional timestamps in nanoseconds
-///
-/// Intended for use with `serde`'s `with` attribute.
-///
-/// # Example:
-///
-/// ```rust
-/// # use chrono::{DateTime, Utc, NaiveDate};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_nanoseconds_option;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_nanoseconds_option")]
-///     time: Option<DateTime<Utc>>,
-/// }
-///
-/// let time = Some(
-///     NaiveDate::from_ymd_opt(2018, 5, 17)
-///         .unwrap()
-///         .and_hms_nano_opt(02, 04, 59, 918355733)
-///         .unwrap()
-///         .and_utc(),
-/// );
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_nanoseconds_option {
-    use core::fmt;
-    use serde::{de, ser};
-
-    use crate::{DateTime, Utc};
-
-    use super::NanoSecondsTimestampVisitor;
+timestamp_serde_modules! {
+    DateTime<Utc>, MicroSecondsTimestampVisitor, "microseconds", 1_000_000,
+    to: |dt| Some(dt.timestamp_micros()),
+    from: |dt| dt;
 
-    /// Serialize a UTC datetime into an integer number of nanoseconds since the epoch or none
-    ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
+    /// Ser/de to/from timestamps in microseconds
     ///
-    /// # Errors
-    ///
-    /// An `i64` with nanosecond precision can span a range of ~584 years. This function returns an
-    /// error on an out of range `DateTime`.
-    ///
-    /// The dates that can be represented as nanoseconds are between 1677-09-21T00:12:44.0 and
-    /// 2262-04-11T23:47:16.854775804.
+    /// Intended for use with `serde`'s `with` attribute.
     ///
     /// # Example:
     ///
     /// ```rust
     /// # use chrono::{DateTime, Utc, NaiveDate};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_nanoseconds_option::serialize as to_nano_tsopt;
-    /// #[derive(Serialize)]
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_microseconds;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(serialize_with = "to_nano_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
+    ///     #[serde(with = "ts_microseconds")]
+    ///     time: DateTime<Utc>,
     /// }
     ///
-    /// let my_s = S {
-    ///     time: Some(
-    ///         NaiveDate::from_ymd_opt(2018, 5, 17)
-    ///             .unwrap()
-    ///             .and_hms_nano_opt(02, 04, 59, 918355733)
-    ///             .unwrap()
-    ///             .and_utc(),
-    ///     ),
-    /// };
-    /// let as_string = serde_json::to_string(&my_s)?;
-    /// assert_eq!(as_string, r#"{"time":1526522699918355733}"#);
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn serialize<S>(opt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        match *opt {
-            Some(ref dt) => serializer.serialize_some(&dt.timestamp_nanos_opt().ok_or(
-                ser::Error::custom("value out of range for a timestamp with nanosecond precision"),
-            )?),
-            None => serializer.serialize_none(),
-        }
-    }
-
-    /// Deserialize a `DateTime` from a nanosecond timestamp or none
-    ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
+    /// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
+    ///     .unwrap()
+    ///     .and_hms_micro_opt(02, 04, 59, 918355)
+    ///     .unwrap()
+    ///     .and_utc();
+    /// let my_s = S { time: time.clone() };
     ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_nanoseconds_option::deserialize as from_nano_tsopt;
-    /// #[derive(Debug, PartialEq, Deserialize)]
-    /// struct S {
-    ///     #[serde(deserialize_with = "from_nano_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
-    /// }
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355733 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355733).single() });
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_option(OptionNanoSecondsTimestampVisitor)
-    }
-
-    struct OptionNanoSecondsTimestampVisitor;
-
-    impl<'de> de::Visitor<'de> for OptionNanoSecondsTimestampVisitor {
-        type Value = Option<DateTime<Utc>>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in nanoseconds or none")
-        }
-
-        /// Deserialize a timestamp in nanoseconds since the epoch
-        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
-        where
-            D: de::Deserializer<'de>,
-        {
-            d.deserialize_i64(NanoSecondsTimestampVisitor).map(Some)
-        }
-
-        /// Deserialize a timestamp in nanoseconds since the epoch
-        fn visit_none<E>(self) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(None)
-        }
-
-        /// Deserialize a timestamp in nanoseconds since the epoch
-        fn visit_unit<E>(self) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(None)
-        }
+    pub mod ts_microseconds {
+        /// Serialize a UTC datetime into an integer number of microseconds since the epoch
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, Utc, NaiveDate};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_microseconds::serialize as to_micro_ts;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_micro_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s = S {
+        ///     time: NaiveDate::from_ymd_opt(2018, 5, 17)
+        ///         .unwrap()
+        ///         .and_hms_micro_opt(02, 04, 59, 918355)
+        ///         .unwrap()
+        ///         .and_utc(),
+        /// };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a microsecond timestamp
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_microseconds::deserialize as from_micro_ts;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_micro_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355000).unwrap() });
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": -1 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 999_999_000).unwrap() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
-}
-
-/// Ser/de to/from timestamps in microseconds
-///
-/// Intended for use with `serde`'s `with` attribute.
-///
-/// # Example:
-///
-/// ```rust
-/// # use chrono::{DateTime, Utc, NaiveDate};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_microseconds;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_microseconds")]
-///     time: DateTime<Utc>,
-/// }
-///
-/// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
-///     .unwrap()
-///     .and_hms_micro_opt(02, 04, 59, 918355)
-///     .unwrap()
-///     .and_utc();
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_microseconds {
-    use core::fmt;
-    use serde::{de, ser};
 
-    use crate::serde::invalid_ts;
-    use crate::{DateTime, Utc};
-
-    use super::MicroSecondsTimestampVisitor;
-
-    /// Serialize a UTC datetime into an integer number of microseconds since the epoch
+    /// Ser/de to/from optional timestamps in microseconds
     ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
+    /// Intended for use with `serde`'s `with` attribute.
     ///
     /// # Example:
     ///
     /// ```rust
     /// # use chrono::{DateTime, Utc, NaiveDate};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_microseconds::serialize as to_micro_ts;
-    /// #[derive(Serialize)]
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_microseconds_option;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(serialize_with = "to_micro_ts")]
-    ///     time: DateTime<Utc>,
+    ///     #[serde(with = "ts_microseconds_option")]
+    ///     time: Option<DateTime<Utc>>,
     /// }
     ///
-    /// let my_s = S {
-    ///     time: NaiveDate::from_ymd_opt(2018, 5, 17)
+    /// let time = Some(
+    ///     NaiveDate::from_ymd_opt(2018, 5, 17)
     ///         .unwrap()
     ///         .and_hms_micro_opt(02, 04, 59, 918355)
     ///         .unwrap()
     ///         .and_utc(),
-    /// };
+    /// );
+    /// let my_s = S { time: time.clone() };
+    ///
     /// let as_string = serde_json::to_string(&my_s)?;
     /// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        serializer.serialize_i64(dt.timestamp_micros())
-    }
-
-    /// Deserialize a `DateTime` from a microsecond timestamp
-    ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_microseconds::deserialize as from_micro_ts;
-    /// #[derive(Debug, PartialEq, Deserialize)]
-    /// struct S {
-    ///     #[serde(deserialize_with = "from_micro_ts")]
-    ///     time: DateTime<Utc>,
-    /// }
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355000).unwrap() });
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": -1 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 999_999_000).unwrap() });
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_i64(MicroSecondsTimestampVisitor)
-    }
-
-    impl de::Visitor<'_> for MicroSecondsTimestampVisitor {
-        type Value = DateTime<Utc>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in microseconds")
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            DateTime::from_timestamp(
-                value.div_euclid(1_000_000),
-                (value.rem_euclid(1_000_000) * 1000) as u32,
-            )
-            .ok_or_else(|| invalid_ts(value))
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            DateTime::from_timestamp(
-                (value / 1_000_000) as i64,
-                ((value % 1_000_000) * 1_000) as u32,
-            )
-            .ok_or_else(|| invalid_ts(value))
-        }
+    pub mod ts_microseconds_option {
+        /// Serialize a UTC datetime into an integer number of microseconds since the epoch or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, Utc, NaiveDate};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_microseconds_option::serialize as to_micro_tsopt;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_micro_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s = S {
+        ///     time: Some(
+        ///         NaiveDate::from_ymd_opt(2018, 5, 17)
+        ///             .unwrap()
+        ///             .and_hms_micro_opt(02, 04, 59, 918355)
+        ///             .unwrap()
+        ///             .and_utc(),
+        ///     ),
+        /// };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a microsecond timestamp or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_microseconds_option::deserialize as from_micro_tsopt;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_micro_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355000).single() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 }
 
-/// Ser/de to/from optional timestamps in microseconds
-///
-/// Intended for use with `serde`'s `with` attribute.
-///
-/// # Example:
-///
-/// ```rust
-/// # use chrono::{DateTime, Utc, NaiveDate};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_microseconds_option;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_microseconds_option")]
-///     time: Option<DateTime<Utc>>,
-/// }
-///
-/// let time = Some(
-///     NaiveDate::from_ymd_opt(2018, 5, 17)
-///         .unwrap()
-///         .and_hms_micro_opt(02, 04, 59, 918355)
-///         .unwrap()
-///         .and_utc(),
-/// );
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_microseconds_option {
-    use core::fmt;
-    use serde::{de, ser};
-
-    use super::MicroSecondsTimestampVisitor;
-    use crate::{DateTime, Utc};
+timestamp_serde_modules! {
+    DateTime<Utc>, MilliSecondsTimestampVisitor, "milliseconds", 1_000,
+    to: |dt| Some(dt.timestamp_millis()),
+    from: |dt| dt;
 
-    /// Serialize a UTC datetime into an integer number of microseconds since the epoch or none
+    /// Ser/de to/from timestamps in milliseconds
     ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
+    /// Intended for use with `serde`s `with` attribute.
     ///
-    /// # Example:
+    /// # Example
     ///
     /// ```rust
     /// # use chrono::{DateTime, Utc, NaiveDate};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_microseconds_option::serialize as to_micro_tsopt;
-    /// #[derive(Serialize)]
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_milliseconds;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(serialize_with = "to_micro_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
+    ///     #[serde(with = "ts_milliseconds")]
+    ///     time: DateTime<Utc>,
     /// }
     ///
-    /// let my_s = S {
-    ///     time: Some(
-    ///         NaiveDate::from_ymd_opt(2018, 5, 17)
-    ///             .unwrap()
-    ///             .and_hms_micro_opt(02, 04, 59, 918355)
-    ///             .unwrap()
-    ///             .and_utc(),
-    ///     ),
-    /// };
+    /// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
+    ///     .unwrap()
+    ///     .and_hms_milli_opt(02, 04, 59, 918)
+    ///     .unwrap()
+    ///     .and_utc();
+    /// let my_s = S { time: time.clone() };
+    ///
     /// let as_string = serde_json::to_string(&my_s)?;
-    /// assert_eq!(as_string, r#"{"time":1526522699918355}"#);
+    /// assert_eq!(as_string, r#"{"time":1526522699918}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn serialize<S>(opt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        match *opt {
-            Some(ref dt) => serializer.serialize_some(&dt.timestamp_micros()),
-            None => serializer.serialize_none(),
-        }
+    pub mod ts_milliseconds {
+        /// Serialize a UTC datetime into an integer number of milliseconds since the epoch
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, Utc, NaiveDate};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_milliseconds::serialize as to_milli_ts;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_milli_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s = S {
+        ///     time: NaiveDate::from_ymd_opt(2018, 5, 17)
+        ///         .unwrap()
+        ///         .and_hms_milli_opt(02, 04, 59, 918)
+        ///         .unwrap()
+        ///         .and_utc(),
+        /// };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1526522699918}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a millisecond timestamp
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_milliseconds::deserialize as from_milli_ts;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_milli_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918000000).unwrap() });
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": -1 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 999_000_000).unwrap() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 
-    /// Deserialize a `DateTime` from a microsecond timestamp or none
+    /// Ser/de to/from optional timestamps in milliseconds
     ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
+    /// Intended for use with `serde`s `with` attribute.
     ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_microseconds_option::deserialize as from_micro_tsopt;
-    /// #[derive(Debug, PartialEq, Deserialize)]
-    /// struct S {
-    ///     #[serde(deserialize_with = "from_micro_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
-    /// }
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918355 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918355000).single() });
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_option(OptionMicroSecondsTimestampVisitor)
-    }
-
-    struct OptionMicroSecondsTimestampVisitor;
-
-    impl<'de> de::Visitor<'de> for OptionMicroSecondsTimestampVisitor {
-        type Value = Option<DateTime<Utc>>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in microseconds or none")
-        }
-
-        /// Deserialize a timestamp in microseconds since the epoch
-        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
-        where
-            D: de::Deserializer<'de>,
-        {
-            d.deserialize_i64(MicroSecondsTimestampVisitor).map(Some)
-        }
-
-        /// Deserialize a timestamp in microseconds since the epoch
-        fn visit_none<E>(self) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(None)
-        }
-
-        /// Deserialize a timestamp in microseconds since the epoch
-        fn visit_unit<E>(self) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(None)
-        }
-    }
-}
-
-/// Ser/de to/from timestamps in milliseconds
-///
-/// Intended for use with `serde`s `with` attribute.
-///
-/// # Example
-///
-/// ```rust
-/// # use chrono::{DateTime, Utc, NaiveDate};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_milliseconds;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_milliseconds")]
-///     time: DateTime<Utc>,
-/// }
-///
-/// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
-///     .unwrap()
-///     .and_hms_milli_opt(02, 04, 59, 918)
-///     .unwrap()
-///     .and_utc();
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1526522699918}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_milliseconds {
-    use core::fmt;
-    use serde::{de, ser};
-
-    use crate::serde::invalid_ts;
-    use crate::{DateTime, Utc};
-
-    use super::MilliSecondsTimestampVisitor;
-
-    /// Serialize a UTC datetime into an integer number of milliseconds since the epoch
-    ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
-    ///
-    /// # Example:
+    /// # Example
     ///
     /// ```rust
     /// # use chrono::{DateTime, Utc, NaiveDate};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_milliseconds::serialize as to_milli_ts;
-    /// #[derive(Serialize)]
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_milliseconds_option;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(serialize_with = "to_milli_ts")]
-    ///     time: DateTime<Utc>,
+    ///     #[serde(with = "ts_milliseconds_option")]
+    ///     time: Option<DateTime<Utc>>,
     /// }
     ///
-    /// let my_s = S {
-    ///     time: NaiveDate::from_ymd_opt(2018, 5, 17)
+    /// let time = Some(
+    ///     NaiveDate::from_ymd_opt(2018, 5, 17)
     ///         .unwrap()
     ///         .and_hms_milli_opt(02, 04, 59, 918)
     ///         .unwrap()
     ///         .and_utc(),
-    /// };
+    /// );
+    /// let my_s = S { time: time.clone() };
+    ///
     /// let as_string = serde_json::to_string(&my_s)?;
     /// assert_eq!(as_string, r#"{"time":1526522699918}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        serializer.serialize_i64(dt.timestamp_millis())
-    }
-
-    /// Deserialize a `DateTime` from a millisecond timestamp
-    ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_milliseconds::deserialize as from_milli_ts;
-    /// #[derive(Debug, PartialEq, Deserialize)]
-    /// struct S {
-    ///     #[serde(deserialize_with = "from_milli_ts")]
-    ///     time: DateTime<Utc>,
-    /// }
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1526522699918 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1526522699, 918000000).unwrap() });
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": -1 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 999_000_000).unwrap() });
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_i64(MilliSecondsTimestampVisitor).map(|dt| dt.with_timezone(&Utc))
-    }
-
-    impl de::Visitor<'_> for MilliSecondsTimestampVisitor {
-        type Value = DateTime<Utc>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in milliseconds")
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            DateTime::from_timestamp_millis(value).ok_or_else(|| invalid_ts(value))
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            DateTime::from_timestamp((value / 1000) as i64, ((value % 1000) * 1_000_000) as u32)
-                .ok_or_else(|| invalid_ts(value))
-        }
+    pub mod ts_milliseconds_option {
+        /// Serialize a UTC datetime into an integer number of milliseconds since the epoch or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, Utc, NaiveDate};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_milliseconds_option::serialize as to_milli_tsopt;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_milli_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s = S {
+        ///     time: Some(
+        ///         NaiveDate::from_ymd_opt(2018, 5, 17)
+        ///             .unwrap()
+        ///             .and_hms_milli_opt(02, 04, 59, 918)
+        ///             .unwrap()
+        ///             .and_utc(),
+        ///     ),
+        /// };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1526522699918}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a millisecond timestamp or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{TimeZone, DateTime, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_milliseconds_option::deserialize as from_milli_tsopt;
+        ///
+        /// #[derive(Deserialize, PartialEq, Debug)]
+        /// #[serde(untagged)]
+        /// enum E<T> {
+        ///     V(T),
+        /// }
+        ///
+        /// #[derive(Deserialize, PartialEq, Debug)]
+        /// struct S {
+        ///     #[serde(default, deserialize_with = "from_milli_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s: E<S> = serde_json::from_str(r#"{ "time": 1526522699918 }"#)?;
+        /// assert_eq!(my_s, E::V(S { time: Some(Utc.timestamp_opt(1526522699, 918000000).unwrap()) }));
+        /// let s: E<S> = serde_json::from_str(r#"{ "time": null }"#)?;
+        /// assert_eq!(s, E::V(S { time: None }));
+        /// let t: E<S> = serde_json::from_str(r#"{}"#)?;
+        /// assert_eq!(t, E::V(S { time: None }));
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 }
 
-/// Ser/de to/from optional timestamps in milliseconds
-///
-/// Intended for use with `serde`s `with` attribute.
-///
-/// # Example
-///
-/// ```rust
-/// # use chrono::{DateTime, Utc, NaiveDate};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_milliseconds_option;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_milliseconds_option")]
-///     time: Option<DateTime<Utc>>,
-/// }
-///
-/// let time = Some(
-///     NaiveDate::from_ymd_opt(2018, 5, 17)
-///         .unwrap()
-///         .and_hms_milli_opt(02, 04, 59, 918)
-///         .unwrap()
-///         .and_utc(),
-/// );
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1526522699918}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_milliseconds_option {
-    use core::fmt;
-    use serde::{de, ser};
-
-    use super::MilliSecondsTimestampVisitor;
-    use crate::{DateTime, Utc};
+timestamp_serde_modules! {
+    DateTime<Utc>, SecondsTimestampVisitor, "seconds", 1,
+    to: |dt| Some(dt.timestamp()),
+    from: |dt| dt;
 
-    /// Serialize a UTC datetime into an integer number of milliseconds since the epoch or none
+    /// Ser/de to/from timestamps in seconds
     ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
+    /// Intended for use with `serde`'s `with` attribute.
     ///
     /// # Example:
     ///
     /// ```rust
-    /// # use chrono::{DateTime, Utc, NaiveDate};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_milliseconds_option::serialize as to_milli_tsopt;
-    /// #[derive(Serialize)]
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_seconds;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(serialize_with = "to_milli_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
+    ///     #[serde(with = "ts_seconds")]
+    ///     time: DateTime<Utc>,
     /// }
     ///
-    /// let my_s = S {
-    ///     time: Some(
-    ///         NaiveDate::from_ymd_opt(2018, 5, 17)
-    ///             .unwrap()
-    ///             .and_hms_milli_opt(02, 04, 59, 918)
-    ///             .unwrap()
-    ///             .and_utc(),
-    ///     ),
-    /// };
+    /// let time = Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap();
+    /// let my_s = S { time: time.clone() };
+    ///
     /// let as_string = serde_json::to_string(&my_s)?;
-    /// assert_eq!(as_string, r#"{"time":1526522699918}"#);
+    /// assert_eq!(as_string, r#"{"time":1431684000}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn serialize<S>(opt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        match *opt {
-            Some(ref dt) => serializer.serialize_some(&dt.timestamp_millis()),
-            None => serializer.serialize_none(),
-        }
+    pub mod ts_seconds {
+        /// Serialize a UTC datetime into an integer number of seconds since the epoch
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{TimeZone, DateTime, Utc};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_seconds::serialize as to_ts;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s = S { time: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap() };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1431684000}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a seconds timestamp
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_seconds::deserialize as from_ts;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_ts")]
+        ///     time: DateTime<Utc>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1431684000 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1431684000, 0).unwrap() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 
-    /// Deserialize a `DateTime` from a millisecond timestamp or none
+    /// Ser/de to/from optional timestamps in seconds
     ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
+    /// Intended for use with `serde`'s `with` attribute.
     ///
     /// # Example:
     ///
     /// ```rust
     /// # use chrono::{TimeZone, DateTime, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_milliseconds_option::deserialize as from_milli_tsopt;
-    ///
-    /// #[derive(Deserialize, PartialEq, Debug)]
-    /// #[serde(untagged)]
-    /// enum E<T> {
-    ///     V(T),
-    /// }
-    ///
-    /// #[derive(Deserialize, PartialEq, Debug)]
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::ts_seconds_option;
+    /// #[derive(Deserialize, Serialize)]
     /// struct S {
-    ///     #[serde(default, deserialize_with = "from_milli_tsopt")]
+    ///     #[serde(with = "ts_seconds_option")]
     ///     time: Option<DateTime<Utc>>,
     /// }
     ///
-    /// let my_s: E<S> = serde_json::from_str(r#"{ "time": 1526522699918 }"#)?;
-    /// assert_eq!(my_s, E::V(S { time: Some(Utc.timestamp_opt(1526522699, 918000000).unwrap()) }));
-    /// let s: E<S> = serde_json::from_str(r#"{ "time": null }"#)?;
-    /// assert_eq!(s, E::V(S { time: None }));
-    /// let t: E<S> = serde_json::from_str(r#"{}"#)?;
-    /// assert_eq!(t, E::V(S { time: None }));
+    /// let time = Some(Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap());
+    /// let my_s = S { time: time.clone() };
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":1431684000}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
     /// # Ok::<(), serde_json::Error>(())
     /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_option(OptionMilliSecondsTimestampVisitor)
-            .map(|opt| opt.map(|dt| dt.with_timezone(&Utc)))
-    }
-
-    struct OptionMilliSecondsTimestampVisitor;
-
-    impl<'de> de::Visitor<'de> for OptionMilliSecondsTimestampVisitor {
-        type Value = Option<DateTime<Utc>>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in milliseconds or none")
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
-        where
-            D: de::Deserializer<'de>,
-        {
-            d.deserialize_i64(MilliSecondsTimestampVisitor).map(Some)
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_none<E>(self) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(None)
-        }
-
-        /// Deserialize a timestamp in milliseconds since the epoch
-        fn visit_unit<E>(self) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(None)
-        }
+    pub mod ts_seconds_option {
+        /// Serialize a UTC datetime into an integer number of seconds since the epoch or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{TimeZone, DateTime, Utc};
+        /// # use serde_derive::Serialize;
+        /// use chrono::serde::ts_seconds_option::serialize as to_tsopt;
+        /// #[derive(Serialize)]
+        /// struct S {
+        ///     #[serde(serialize_with = "to_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s = S { time: Some(Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap()) };
+        /// let as_string = serde_json::to_string(&my_s)?;
+        /// assert_eq!(as_string, r#"{"time":1431684000}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn serialize;
+        /// Deserialize a `DateTime` from a seconds timestamp or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        ///
+        /// # Example:
+        ///
+        /// ```rust
+        /// # use chrono::{DateTime, TimeZone, Utc};
+        /// # use serde_derive::Deserialize;
+        /// use chrono::serde::ts_seconds_option::deserialize as from_tsopt;
+        /// #[derive(Debug, PartialEq, Deserialize)]
+        /// struct S {
+        ///     #[serde(deserialize_with = "from_tsopt")]
+        ///     time: Option<DateTime<Utc>>,
+        /// }
+        ///
+        /// let my_s: S = serde_json::from_str(r#"{ "time": 1431684000 }"#)?;
+        /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1431684000, 0).single() });
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        fn deserialize;
     }
 }
 
-/// Ser/de to/from timestamps in seconds
+/// Ser/de to/from compact tuples of integers instead of strings
+///
+/// These modules are intended for use with `serde`'s `with` attribute on fields that are
+/// serialized with a binary format. Each value is encoded as integers that round-trip exactly,
+/// including leap seconds:
 ///
-/// Intended for use with `serde`'s `with` attribute.
+/// - [`datetime`]: a `DateTime` as `(i64, u32, i32)`, the UNIX timestamp in seconds, the
+///   nanoseconds and the offset from UTC in seconds.
+/// - [`naive_datetime`]: a `NaiveDateTime` as `(i64, u32)`, the seconds since the UNIX epoch and
+///   the nanoseconds, as if the value were in UTC.
+/// - [`naive_date`]: a `NaiveDate` as an `i32`, the number of days since January 1, 1 CE.
+/// - [`naive_time`]: a `NaiveTime` as `(u32, u32)`, the seconds since midnight and the
+///   nanoseconds.
+///
+/// The default `Serialize` implementations keep using strings, even for formats where
+/// `is_human_readable()` is `false`. Switching them to this encoding would make existing binary
+/// data unreadable, so the compact encoding is opt-in per field instead.
 ///
 /// # Example:
 ///
 /// ```rust
-/// # use chrono::{TimeZone, DateTime, Utc};
+/// # use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
 /// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_seconds;
-/// #[derive(Deserialize, Serialize)]
+/// use chrono::serde::compact;
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
 /// struct S {
-///     #[serde(with = "ts_seconds")]
-///     time: DateTime<Utc>,
+///     #[serde(with = "compact::datetime")]
+///     time: DateTime<FixedOffset>,
+///     #[serde(with = "compact::naive_date")]
+///     date: NaiveDate,
 /// }
 ///
-/// let time = Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap();
-/// let my_s = S { time: time.clone() };
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let my_s = S {
+///     time: offset.with_ymd_and_hms(2018, 5, 17, 2, 4, 59).unwrap(),
+///     date: NaiveDate::from_ymd_opt(2018, 5, 17).unwrap(),
+/// };
 ///
 /// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1431684000}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
+/// assert_eq!(as_string, r#"{"time":[1526519099,0,3600],"date":736831}"#);
+/// let my_s2: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s, my_s2);
 /// # Ok::<(), serde_json::Error>(())
 /// ```
-pub mod ts_seconds {
-    use core::fmt;
-    use serde::{de, ser};
-
-    use crate::serde::invalid_ts;
-    use crate::{DateTime, Utc};
-
-    use super::SecondsTimestampVisitor;
-
-    /// Serialize a UTC datetime into an integer number of seconds since the epoch
-    ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{TimeZone, DateTime, Utc};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_seconds::serialize as to_ts;
-    /// #[derive(Serialize)]
-    /// struct S {
-    ///     #[serde(serialize_with = "to_ts")]
-    ///     time: DateTime<Utc>,
-    /// }
-    ///
-    /// let my_s = S { time: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap() };
-    /// let as_string = serde_json::to_string(&my_s)?;
-    /// assert_eq!(as_string, r#"{"time":1431684000}"#);
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        serializer.serialize_i64(dt.timestamp())
-    }
-
-    /// Deserialize a `DateTime` from a seconds timestamp
-    ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_seconds::deserialize as from_ts;
-    /// #[derive(Debug, PartialEq, Deserialize)]
-    /// struct S {
-    ///     #[serde(deserialize_with = "from_ts")]
-    ///     time: DateTime<Utc>,
-    /// }
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1431684000 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1431684000, 0).unwrap() });
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_i64(SecondsTimestampVisitor)
-    }
-
-    impl de::Visitor<'_> for SecondsTimestampVisitor {
-        type Value = DateTime<Utc>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in seconds")
-        }
-
-        /// Deserialize a timestamp in seconds since the epoch
-        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+pub mod compact {
+    /// Ser/de a `DateTime` to/from `(i64, u32, i32)`: the UNIX timestamp in seconds, the
+    /// nanoseconds and the offset from UTC in seconds
+    ///
+    /// Any time zone is serialized through its fixed offset. Deserializing works for every time
+    /// zone that can be converted from a `DateTime<FixedOffset>`.
+    pub mod datetime {
+        use serde::{de, ser};
+
+        use crate::serde::invalid_ts;
+        use crate::{DateTime, FixedOffset, Offset, TimeZone};
+
+        /// Serialize a `DateTime` into a tuple of the timestamp, the nanoseconds and the offset
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<Tz, S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
         where
-            E: de::Error,
+            Tz: TimeZone,
+            S: ser::Serializer,
         {
-            DateTime::from_timestamp(value, 0).ok_or_else(|| invalid_ts(value))
+            let offset = dt.offset().fix().local_minus_utc();
+            ser::Serialize::serialize(
+                &(dt.timestamp(), dt.timestamp_subsec_nanos(), offset),
+                serializer,
+            )
         }
 
-        /// Deserialize a timestamp in seconds since the epoch
-        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        /// Deserialize a `DateTime` from a tuple of the timestamp, the nanoseconds and the offset
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, Tz, D>(d: D) -> Result<DateTime<Tz>, D::Error>
         where
-            E: de::Error,
+            Tz: TimeZone,
+            DateTime<Tz>: From<DateTime<FixedOffset>>,
+            D: de::Deserializer<'de>,
         {
-            if value > i64::MAX as u64 {
-                Err(invalid_ts(value))
-            } else {
-                DateTime::from_timestamp(value as i64, 0).ok_or_else(|| invalid_ts(value))
-            }
+            let (secs, nsecs, offset): (i64, u32, i32) = de::Deserialize::deserialize(d)?;
+            let offset = FixedOffset::east_opt(offset)
+                .ok_or_else(|| de::Error::custom("offset out of range"))?;
+            let dt = DateTime::from_timestamp(secs, nsecs).ok_or_else(|| invalid_ts(secs))?;
+            Ok(dt.with_timezone(&offset).into())
         }
     }
-}
 
-/// Ser/de to/from optional timestamps in seconds
-///
-/// Intended for use with `serde`'s `with` attribute.
-///
-/// # Example:
-///
-/// ```rust
-/// # use chrono::{TimeZone, DateTime, Utc};
-/// # use serde_derive::{Deserialize, Serialize};
-/// use chrono::serde::ts_seconds_option;
-/// #[derive(Deserialize, Serialize)]
-/// struct S {
-///     #[serde(with = "ts_seconds_option")]
-///     time: Option<DateTime<Utc>>,
-/// }
-///
-/// let time = Some(Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap());
-/// let my_s = S { time: time.clone() };
-///
-/// let as_string = serde_json::to_string(&my_s)?;
-/// assert_eq!(as_string, r#"{"time":1431684000}"#);
-/// let my_s: S = serde_json::from_str(&as_string)?;
-/// assert_eq!(my_s.time, time);
-/// # Ok::<(), serde_json::Error>(())
-/// ```
-pub mod ts_seconds_option {
-    use core::fmt;
-    use serde::{de, ser};
+    /// Ser/de a `NaiveDateTime` to/from `(i64, u32)`: the seconds since the UNIX epoch and the
+    /// nanoseconds, as if the value were in UTC
+    pub mod naive_datetime {
+        use serde::{de, ser};
 
-    use super::SecondsTimestampVisitor;
-    use crate::{DateTime, Utc};
+        use crate::serde::invalid_ts;
+        use crate::{DateTime, NaiveDateTime};
 
-    /// Serialize a UTC datetime into an integer number of seconds since the epoch or none
-    ///
-    /// Intended for use with `serde`s `serialize_with` attribute.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{TimeZone, DateTime, Utc};
-    /// # use serde_derive::Serialize;
-    /// use chrono::serde::ts_seconds_option::serialize as to_tsopt;
-    /// #[derive(Serialize)]
-    /// struct S {
-    ///     #[serde(serialize_with = "to_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
-    /// }
-    ///
-    /// let my_s = S { time: Some(Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap()) };
-    /// let as_string = serde_json::to_string(&my_s)?;
-    /// assert_eq!(as_string, r#"{"time":1431684000}"#);
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn serialize<S>(opt: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        match *opt {
-            Some(ref dt) => serializer.serialize_some(&dt.timestamp()),
-            None => serializer.serialize_none(),
+        /// Serialize a `NaiveDateTime` into a tuple of the seconds and the nanoseconds
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(dt: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            let dt = dt.and_utc();
+            ser::Serialize::serialize(&(dt.timestamp(), dt.timestamp_subsec_nanos()), serializer)
         }
-    }
 
-    /// Deserialize a `DateTime` from a seconds timestamp or none
-    ///
-    /// Intended for use with `serde`s `deserialize_with` attribute.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// # use chrono::{DateTime, TimeZone, Utc};
-    /// # use serde_derive::Deserialize;
-    /// use chrono::serde::ts_seconds_option::deserialize as from_tsopt;
-    /// #[derive(Debug, PartialEq, Deserialize)]
-    /// struct S {
-    ///     #[serde(deserialize_with = "from_tsopt")]
-    ///     time: Option<DateTime<Utc>>,
-    /// }
-    ///
-    /// let my_s: S = serde_json::from_str(r#"{ "time": 1431684000 }"#)?;
-    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1431684000, 0).single() });
-    /// # Ok::<(), serde_json::Error>(())
-    /// ```
-    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        d.deserialize_option(OptionSecondsTimestampVisitor)
+        /// Deserialize a `NaiveDateTime` from a tuple of the seconds and the nanoseconds
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveDateTime, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let (secs, nsecs): (i64, u32) = de::Deserialize::deserialize(d)?;
+            let dt = DateTime::from_timestamp(secs, nsecs).ok_or_else(|| invalid_ts(secs))?;
+            Ok(dt.naive_utc())
+        }
     }
 
-    struct OptionSecondsTimestampVisitor;
+    /// Ser/de a `NaiveDate` to/from an `i32`: the number of days since January 1, 1 CE
+    pub mod naive_date {
+        use serde::{de, ser};
 
-    impl<'de> de::Visitor<'de> for OptionSecondsTimestampVisitor {
-        type Value = Option<DateTime<Utc>>;
+        use crate::NaiveDate;
+        use crate::serde::invalid_ts;
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a unix timestamp in seconds or none")
+        /// Serialize a `NaiveDate` into the number of days since January 1, 1 CE
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i32(date.num_days_from_ce())
         }
 
-        /// Deserialize a timestamp in seconds since the epoch
-        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        /// Deserialize a `NaiveDate` from the number of days since January 1, 1 CE
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveDate, D::Error>
         where
             D: de::Deserializer<'de>,
         {
-            d.deserialize_i64(SecondsTimestampVisitor).map(Some)
+            let days: i32 = de::Deserialize::deserialize(d)?;
+            NaiveDate::from_num_days_from_ce_opt(days).ok_or_else(|| invalid_ts(days))
         }
+    }
+
+    /// Ser/de a `NaiveTime` to/from `(u32, u32)`: the seconds since midnight and the nanoseconds
+    pub mod naive_time {
+        use serde::{de, ser};
 
-        /// Deserialize a timestamp in seconds since the epoch
-        fn visit_none<E>(self) -> Result<Self::Value, E>
+        use crate::NaiveTime;
+        use crate::serde::invalid_ts;
+
+        /// Serialize a `NaiveTime` into a tuple of the seconds since midnight and the nanoseconds
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
         where
-            E: de::Error,
+            S: ser::Serializer,
         {
-            Ok(None)
+            ser::Serialize::serialize(
+                &(time.num_seconds_from_midnight(), time.nanosecond()),
+                serializer,
+            )
         }
 
-        /// Deserialize a timestamp in seconds since the epoch
-        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        /// Deserialize a `NaiveTime` from a tuple of the seconds since midnight and the
+        /// nanoseconds
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveTime, D::Error>
         where
-            E: de::Error,
+            D: de::Deserializer<'de>,
         {
-            Ok(None)
+            let (secs, nsecs): (u32, u32) = de::Deserialize::deserialize(d)?;
+            NaiveTime::from_num_seconds_from_midnight_opt(secs, nsecs)
+                .ok_or_else(|| invalid_ts(secs))
         }
     }
 }
@@ -1211,6 +1131,8 @@ mod tests {
     #[cfg(feature = "clock")]
     use crate::Local;
     use crate::{DateTime, FixedOffset, TimeZone, Utc};
+    #[cfg(feature = "alloc")]
+    use crate::{NaiveDateTime, NaiveTime, Timelike};
     use core::fmt;
 
     #[test]
@@ -1294,6 +1216,73 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "alloc")]
+    const DT_FORMAT: &str = "%Y-%m-%d at %H:%M:%S%.3f";
+    #[cfg(feature = "alloc")]
+    crate::serde_format!(mod time_hm, NaiveTime, "%H:%M");
+    #[cfg(feature = "alloc")]
+    crate::serde_format!(pub(crate) mod with_offset, DateTime<FixedOffset>, "%Y%m%d %H%M%S %z");
+    #[cfg(feature = "alloc")]
+    crate::serde_format!(mod naive_dt, NaiveDateTime, DT_FORMAT);
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_serde_format_macro() {
+        #[derive(Debug, PartialEq, serde_derive::Deserialize, serde_derive::Serialize)]
+        struct S {
+            #[serde(with = "time_hm")]
+            time: NaiveTime,
+            #[serde(with = "with_offset")]
+            dt: DateTime<FixedOffset>,
+            #[serde(with = "naive_dt")]
+            naive: NaiveDateTime,
+        }
+
+        let offset = FixedOffset::east_opt(-5 * 3600).unwrap();
+        let dt = offset.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap();
+        let s = S {
+            time: NaiveTime::from_hms_opt(13, 4, 0).unwrap(),
+            dt,
+            naive: dt.naive_local().with_nanosecond(120_000_000).unwrap(),
+        };
+        let json =
+            r#"{"time":"13:04","dt":"20240507 130405 -0500","naive":"2024-05-07 at 13:04:05.120"}"#;
+        assert_eq!(serde_json::to_string(&s).unwrap(), json);
+        let de = serde_json::from_str::<S>(json).unwrap();
+        assert_eq!(de, s);
+        assert_eq!(de.dt.offset(), &offset);
+
+        let encoded = bincode::serialize(&s).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), s);
+
+        let json = r#"{"time":"13:04:05","dt":"20240507 130405 -0500","naive":"2024-05-07"}"#;
+        let err = serde_json::from_str::<S>(json).unwrap_err();
+        assert!(err.to_string().starts_with("trailing input"), "{}", err);
+    }
+
+    #[test]
+    fn test_serde_option_timestamps() {
+        #[derive(Debug, PartialEq, serde_derive::Deserialize)]
+        struct S {
+            #[serde(default, with = "super::ts_seconds_option")]
+            secs: Option<DateTime<Utc>>,
+            #[serde(default, with = "super::ts_milliseconds_option")]
+            millis: Option<DateTime<Utc>>,
+            #[serde(default, with = "super::ts_microseconds_option")]
+            micros: Option<DateTime<Utc>>,
+            #[serde(default, with = "super::ts_nanoseconds_option")]
+            nanos: Option<DateTime<Utc>>,
+        }
+
+        let dt = Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap();
+        let json = r#"{"secs":1431684000,"millis":1431684000000,"micros":null}"#;
+        let s: S = serde_json::from_str(json).unwrap();
+        assert_eq!(s, S { secs: Some(dt), millis: Some(dt), micros: None, nanos: None });
+
+        let err = serde_json::from_str::<S>(r#"{"nanos":"x"}"#).unwrap_err();
+        assert!(err.to_string().contains("expected a unix timestamp in nanoseconds"));
+    }
+
     #[test]
     fn test_serde_bincode() {
         // Bincode is relevant to test separately from JSON because
@@ -1307,6 +1296,58 @@ mod tests {
         assert_eq!(dt.offset(), decoded.offset());
     }
 
+    #[test]
+    fn test_serde_compact() {
+        use super::compact;
+        use crate::{NaiveDate, NaiveDateTime, NaiveTime};
+        use bincode::{deserialize, serialize};
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "compact::datetime")]
+            dt: DateTime<FixedOffset>,
+            #[serde(with = "compact::datetime")]
+            utc: DateTime<Utc>,
+            #[serde(with = "compact::naive_datetime")]
+            naive: NaiveDateTime,
+            #[serde(with = "compact::naive_date")]
+            date: NaiveDate,
+            #[serde(with = "compact::naive_time")]
+            time: NaiveTime,
+        }
+
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap();
+        let offset = FixedOffset::east_opt(-5 * 3600).unwrap();
+        let s = S {
+            dt: offset.from_utc_datetime(&leap),
+            utc: leap.and_utc(),
+            naive: leap,
+            date: NaiveDate::MIN,
+            time: leap.time(),
+        };
+        let encoded = serialize(&s).unwrap();
+        assert_eq!(encoded.len(), 16 + 16 + 12 + 4 + 8);
+        let decoded: S = deserialize(&encoded).unwrap();
+        assert_eq!(decoded, s);
+        assert_eq!(decoded.dt.offset(), &offset);
+
+        // out of range values
+        fn de(s: &str) -> serde_json::Deserializer<serde_json::de::StrRead<'_>> {
+            serde_json::Deserializer::from_str(s)
+        }
+        assert!(
+            compact::datetime::deserialize::<Utc, _>(&mut de("[9223372036854775807,0,0]")).is_err()
+        );
+        assert!(compact::datetime::deserialize::<Utc, _>(&mut de("[0,0,86400]")).is_err());
+        assert!(compact::naive_datetime::deserialize(&mut de("[0,2000000000]")).is_err());
+        assert!(compact::naive_date::deserialize(&mut de("2147483647")).is_err());
+        assert!(compact::naive_time::deserialize(&mut de("[86400,0]")).is_err());
+    }
+
     #[test]
     fn test_serde_no_offset_debug() {
         use crate::{MappedLocalTime, NaiveDate, NaiveDateTime, Offset};
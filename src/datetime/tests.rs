@@ -6,7 +6,7 @@ use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
 use crate::{Datelike, Days, MappedLocalTime, Months, NaiveDateTime, TimeDelta, Timelike, Weekday};
 
 #[derive(Clone)]
-struct DstTester;
+pub(crate) struct DstTester;
 
 impl DstTester {
     fn winter_offset() -> FixedOffset {
@@ -80,7 +80,7 @@ impl TimeZone for DstTester {
         } else if *local >= local_to_winter_transition_end
             && *local < local_to_winter_transition_start
         {
-            MappedLocalTime::Ambiguous(DstTester::winter_offset(), DstTester::summer_offset())
+            MappedLocalTime::Ambiguous(DstTester::summer_offset(), DstTester::winter_offset())
         } else if *local >= local_to_summer_transition_start
             && *local < local_to_summer_transition_end
         {
@@ -123,6 +123,46 @@ impl TimeZone for DstTester {
     }
 }
 
+#[test]
+fn test_datetime_ulid_time_ms() {
+    let max = (1u64 << 48) - 1;
+    let dt = DateTime::from_ulid_time_ms(max).unwrap();
+    assert_eq!(dt.to_string(), "+10889-08-02 05:31:50.655 UTC");
+    assert_eq!(dt.to_ulid_time_ms(), Some(max));
+    assert_eq!((dt + TimeDelta::milliseconds(1)).to_ulid_time_ms(), None);
+    assert_eq!(DateTime::from_ulid_time_ms(max + 1), None);
+    assert_eq!(DateTime::from_ulid_time_ms(u64::MAX), None);
+
+    assert_eq!(DateTime::from_ulid_time_ms(0), Some(DateTime::UNIX_EPOCH));
+    assert_eq!(DateTime::UNIX_EPOCH.to_ulid_time_ms(), Some(0));
+    assert_eq!(DateTime::<Utc>::MIN_UTC.to_ulid_time_ms(), None);
+    assert_eq!(DateTime::<Utc>::MAX_UTC.to_ulid_time_ms(), None);
+
+    let dt = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+    assert_eq!(dt.to_ulid_time_ms(), Some(1_700_000_000_123));
+    let truncated = DateTime::from_ulid_time_ms(1_700_000_000_123).unwrap();
+    assert_eq!(truncated, DateTime::from_timestamp(1_700_000_000, 123_000_000).unwrap());
+}
+
+#[test]
+fn test_datetime_ksuid_time() {
+    let epoch = Utc.with_ymd_and_hms(2014, 5, 13, 16, 53, 20).unwrap();
+    assert_eq!(DateTime::from_ksuid_time(0), epoch);
+    assert_eq!(epoch.to_ksuid_time(), Some(0));
+    assert_eq!((epoch - TimeDelta::seconds(1)).to_ksuid_time(), None);
+    assert_eq!((epoch + TimeDelta::milliseconds(999)).to_ksuid_time(), Some(0));
+
+    let max = DateTime::from_ksuid_time(u32::MAX);
+    assert_eq!(max.to_ksuid_time(), Some(u32::MAX));
+    assert_eq!((max + TimeDelta::seconds(1)).to_ksuid_time(), None);
+    assert_eq!(DateTime::<Utc>::MIN_UTC.to_ksuid_time(), None);
+    assert_eq!(DateTime::<Utc>::MAX_UTC.to_ksuid_time(), None);
+
+    for secs in [1, 1_000, 315_083_200, u32::MAX / 2] {
+        assert_eq!(DateTime::from_ksuid_time(secs).to_ksuid_time(), Some(secs));
+    }
+}
+
 #[test]
 fn test_datetime_from_timestamp_millis() {
     let valid_map = [
@@ -247,6 +287,33 @@ fn test_datetime_from_timestamp_nanos() {
     }
 }
 
+#[test]
+fn test_datetime_timestamp_nanos_i128() {
+    for nanos in [0, 1, -1, 1662921288123456789, i64::MIN, i64::MAX] {
+        let dt = DateTime::from_timestamp_nanos(nanos);
+        assert_eq!(dt.timestamp_nanos_i128(), i128::from(nanos));
+        assert_eq!(DateTime::from_timestamp_nanos_i128(i128::from(nanos)), Some(dt));
+    }
+
+    // outside the range of `i64` nanoseconds
+    for dt in [DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC] {
+        assert_eq!(dt.timestamp_nanos_opt(), None);
+        let nanos = dt.timestamp_nanos_i128();
+        assert_eq!(DateTime::from_timestamp_nanos_i128(nanos), Some(dt));
+    }
+    let min = DateTime::<Utc>::MIN_UTC.timestamp_nanos_i128();
+    let max = DateTime::<Utc>::MAX_UTC.timestamp_nanos_i128();
+    assert_eq!(DateTime::from_timestamp_nanos_i128(min - 1), None);
+    assert_eq!(DateTime::from_timestamp_nanos_i128(max + 1), None);
+    assert_eq!(DateTime::from_timestamp_nanos_i128(i128::MIN), None);
+    assert_eq!(DateTime::from_timestamp_nanos_i128(i128::MAX), None);
+
+    // the offset does not change the timestamp
+    let dt = DateTime::from_timestamp_nanos_i128(-14_831_769_600_000_000_001).unwrap();
+    let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+    assert_eq!(dt.with_timezone(&offset).timestamp_nanos_i128(), -14_831_769_600_000_000_001);
+}
+
 #[test]
 fn test_datetime_from_timestamp() {
     let from_timestamp = |secs| DateTime::from_timestamp(secs, 0);
@@ -311,6 +378,117 @@ fn test_nanosecond_range() {
     assert!(beyond_max.timestamp_nanos_opt().is_none());
 }
 
+#[test]
+fn test_transitions_in_year() {
+    let transitions: Vec<_> = DstTester.transitions_in_year(2024).collect();
+    assert_eq!(
+        transitions,
+        [
+            (
+                Utc.with_ymd_and_hms(2024, 4, 14, 17, 0, 0).unwrap(),
+                DstTester::summer_offset(),
+                DstTester::winter_offset()
+            ),
+            (
+                Utc.with_ymd_and_hms(2024, 9, 14, 18, 0, 0).unwrap(),
+                DstTester::winter_offset(),
+                DstTester::summer_offset()
+            ),
+        ]
+    );
+    for (instant, before, after) in transitions {
+        let instant = instant.naive_utc();
+        assert_eq!(DstTester.offset_from_utc_datetime(&(instant - TimeDelta::seconds(1))), before);
+        assert_eq!(DstTester.offset_from_utc_datetime(&instant), after);
+    }
+
+    assert_eq!(Utc.transitions_in_year(2024).count(), 0);
+    assert_eq!(FixedOffset::east_opt(3600).unwrap().transitions_in_year(2024).count(), 0);
+    assert_eq!(DstTester.transitions_in_year(NaiveDate::MAX.year() + 1).count(), 0);
+    assert_eq!(DstTester.transitions_in_year(NaiveDate::MAX.year()).count(), 2);
+}
+
+#[test]
+fn test_parse_ical() {
+    use crate::format::ParseErrorKind;
+
+    let dt = Utc.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap();
+    assert_eq!(DateTime::parse_ical("20240315T093000Z"), Ok(dt));
+    let kind = |s| DateTime::parse_ical(s).map_err(|e| e.kind());
+    assert_eq!(kind("20240315T093000"), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind("20240315T0930000"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240315T093000z"), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("20240315T093000ZZ"), Err(ParseErrorKind::TooLong));
+
+    // regular local time, and UTC converted to the time zone
+    let summer = |m, d, h, min| DstTester.with_ymd_and_hms(2024, m, d, h, min, 0).unwrap();
+    assert_eq!(DateTime::parse_ical_in("20240101T120000", &DstTester), Ok(summer(1, 1, 12, 0)));
+    assert_eq!(DateTime::parse_ical_in("20240101T030000Z", &DstTester), Ok(summer(1, 1, 12, 0)));
+
+    // ambiguous: the earliest result of the time zone
+    let dt = DateTime::parse_ical_in("20240415T013000", &DstTester).unwrap();
+    let local = NaiveDate::from_ymd_opt(2024, 4, 15).unwrap().and_hms_opt(1, 30, 0).unwrap();
+    assert_eq!(Some(dt), DstTester.from_local_datetime(&local).earliest());
+
+    // in the gap: interpreted with the winter offset from before the gap
+    let dt = DateTime::parse_ical_in("20240915T023000", &DstTester).unwrap();
+    assert_eq!(dt, summer(9, 15, 3, 30));
+    assert_eq!(dt.naive_utc(), Utc.with_ymd_and_hms(2024, 9, 14, 18, 30, 0).unwrap().naive_utc());
+
+    assert!(DateTime::parse_ical_in("20240915T023000+0800", &DstTester).is_err());
+    assert!(DateTime::parse_ical_in("20240230T023000", &DstTester).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_ical_string() {
+    let dt = DstTester.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    assert_eq!(dt.to_ical_string(), "20240101T030000Z");
+    assert_eq!(DateTime::parse_ical_in(&dt.to_ical_string(), &DstTester), Ok(dt));
+
+    let dt = Utc.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap().with_nanosecond(5).unwrap();
+    assert_eq!(dt.to_ical_string(), "20240315T093000Z");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_vtimezone() {
+    assert_eq!(
+        DstTester.to_vtimezone("Test/DST", 2023..=2024),
+        concat!(
+            "BEGIN:VTIMEZONE\r\n",
+            "TZID:Test/DST\r\n",
+            "BEGIN:STANDARD\r\n",
+            "DTSTART:20230415T020000\r\n",
+            "TZOFFSETFROM:+0900\r\n",
+            "TZOFFSETTO:+0800\r\n",
+            "END:STANDARD\r\n",
+            "BEGIN:DAYLIGHT\r\n",
+            "DTSTART:20230915T020000\r\n",
+            "TZOFFSETFROM:+0800\r\n",
+            "TZOFFSETTO:+0900\r\n",
+            "END:DAYLIGHT\r\n",
+            "BEGIN:STANDARD\r\n",
+            "DTSTART:20240415T020000\r\n",
+            "TZOFFSETFROM:+0900\r\n",
+            "TZOFFSETTO:+0800\r\n",
+            "END:STANDARD\r\n",
+            "BEGIN:DAYLIGHT\r\n",
+            "DTSTART:20240915T020000\r\n",
+            "TZOFFSETFROM:+0800\r\n",
+            "TZOFFSETTO:+0900\r\n",
+            "END:DAYLIGHT\r\n",
+            "END:VTIMEZONE\r\n",
+        )
+    );
+
+    let tz = FixedOffset::west_opt(3 * 3600 + 30 * 60 + 15).unwrap();
+    let vtimezone = tz.to_vtimezone("Test/Fixed", 2024..=2024);
+    assert!(vtimezone.contains("\r\nTZOFFSETFROM:-033015\r\nTZOFFSETTO:-033015\r\n"));
+    let empty = core::ops::RangeInclusive::new(2024, 2023);
+    assert_eq!(Utc.to_vtimezone("UTC", empty), Utc.to_vtimezone("UTC", 2024..=2024));
+}
+
 #[test]
 fn test_datetime_add_days() {
     let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
@@ -761,6 +939,194 @@ fn test_datetime_rfc2822() {
     assert!(DateTime::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09 +0000   ").is_err());
 }
 
+#[test]
+fn test_datetime_rfc2822_with_zone() {
+    use crate::format::Rfc2822Zone;
+
+    let cases = [
+        ("Wed, 18 Feb 2015 23:16:09 +0500", 5 * 3600, Rfc2822Zone::Offset),
+        ("Wed, 18 Feb 2015 23:16:09 +0000", 0, Rfc2822Zone::Offset),
+        ("Wed, 18 Feb 2015 23:16:09 -0000", 0, Rfc2822Zone::UnknownLocal),
+        ("Wed, 18 Feb 2015 23:16:09 GMT", 0, Rfc2822Zone::Universal),
+        ("Wed, 18 Feb 2015 23:16:09 UT", 0, Rfc2822Zone::Universal),
+        ("Wed, 18 Feb 2015 23:16:09 z", 0, Rfc2822Zone::Military),
+        ("Wed, 18 Feb 2015 23:16:09 PDT", -7 * 3600, Rfc2822Zone::NorthAmerican),
+        ("Wed, 18 Feb 2015 23:16:09 A (military)", 0, Rfc2822Zone::Military),
+    ];
+    for (s, offset, zone) in cases {
+        let expected = ymdhms(&FixedOffset::east_opt(offset).unwrap(), 2015, 2, 18, 23, 16, 9);
+        assert_eq!(DateTime::parse_from_rfc2822_with_zone(s), Ok((expected, zone)), "{}", s);
+        assert_eq!(DateTime::parse_from_rfc2822(s), Ok(expected));
+    }
+    assert!(Rfc2822Zone::UnknownLocal.is_unknown_local());
+    assert!(Rfc2822Zone::Military.is_unknown_local());
+    assert!(!Rfc2822Zone::Offset.is_unknown_local());
+    assert!(!Rfc2822Zone::Universal.is_unknown_local());
+
+    assert!(DateTime::parse_from_rfc2822_with_zone("Wed, 18 Feb 2015 23:16:09 +0000   ").is_err());
+    assert!(DateTime::parse_from_rfc2822_with_zone("Wed, 18 Feb 2015 23:16:09 +00:00").is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_datetime_to_rfc2822_opts() {
+    let dt = ymdhms(&FixedOffset::east_opt(0).unwrap(), 2015, 2, 8, 23, 16, 9);
+    assert_eq!(dt.to_rfc2822_opts(true, false), dt.to_rfc2822());
+    assert_eq!(dt.to_rfc2822_opts(false, false), "8 Feb 2015 23:16:09 +0000");
+    assert_eq!(dt.to_rfc2822_opts(true, true), "Sun, 8 Feb 2015 23:16:09 GMT");
+    assert_eq!(dt.to_rfc2822_opts(false, true), "8 Feb 2015 23:16:09 GMT");
+
+    let dt = ymdhms(&FixedOffset::west_opt(5 * 3600).unwrap(), 2015, 2, 18, 23, 16, 9);
+    assert_eq!(dt.to_rfc2822_opts(true, true), "Wed, 18 Feb 2015 23:16:09 -0500");
+    assert_eq!(dt.to_rfc2822_opts(false, true), "18 Feb 2015 23:16:09 -0500");
+
+    for with_weekday in [true, false] {
+        for use_gmt in [true, false] {
+            let s = dt.to_rfc2822_opts(with_weekday, use_gmt);
+            assert_eq!(DateTime::parse_from_rfc2822(&s), Ok(dt));
+        }
+    }
+}
+
+#[test]
+fn test_datetime_rfc2822_with_comment() {
+    let parse = DateTime::parse_from_rfc2822_with_comment;
+    let dt = ymdhms(&FixedOffset::west_opt(9000).unwrap(), 2003, 7, 1, 10, 52, 37);
+    assert_eq!(parse("Tue, 1 Jul 2003 10:52:37 -0230"), Ok((dt, None)));
+    assert_eq!(parse("Tue, 1 Jul 2003 10:52:37 -0230 (NST)"), Ok((dt, Some("NST"))));
+    assert_eq!(parse("Tue, 1 Jul 2003 10:52:37 -0230()"), Ok((dt, Some(""))));
+    assert_eq!(parse("Tue, 1 Jul 2003 10:52:37 -0230 (a (b) c) (d)"), Ok((dt, Some("a (b) c"))));
+    assert_eq!(parse("Tue, 1 Jul 2003 10:52:37 -0230 (a \\) b)"), Ok((dt, Some("a \\) b"))));
+    assert!(parse("Tue, 1 Jul 2003 10:52:37 -0230 (NST) x").is_err());
+    assert!(parse("Tue, 1 Jul 2003 10:52:37 -0230 (NST").is_err());
+
+    #[cfg(feature = "alloc")]
+    {
+        let s = dt.to_rfc2822_with_comment("Newfoundland (Canada)");
+        assert_eq!(s, "Tue, 1 Jul 2003 10:52:37 -0230 (Newfoundland \\(Canada\\))");
+        assert_eq!(parse(&s), Ok((dt, Some("Newfoundland \\(Canada\\)"))));
+    }
+}
+
+#[test]
+fn test_datetime_iso8601_basic() {
+    let parse = DateTime::parse_from_iso8601_basic;
+    let east = |secs| FixedOffset::east_opt(secs).unwrap();
+
+    let dt = ymdhms(&east(0), 2024, 5, 7, 12, 34, 56);
+    assert_eq!(parse("20240507T123456Z"), Ok(dt));
+    assert_eq!(parse("20240507T123456z"), Ok(dt));
+    assert_eq!(parse("20240507T123456+0000"), Ok(dt));
+    assert_eq!(parse("20240507T123456-00"), Ok(dt));
+    let dt = ymdhms(&east(-(5 * 3600 + 30 * 60)), 2024, 5, 7, 12, 34, 56);
+    assert_eq!(parse("20240507T123456-0530"), Ok(dt));
+    let dt = ymdhms_milli(&east(3600), 2024, 5, 7, 12, 34, 56, 250);
+    assert_eq!(parse("20240507T123456,25+01"), Ok(dt));
+
+    for s in [
+        "20240507T123456",
+        "20240507T123456+02:00",
+        "20240507T123456+2",
+        "20240507T123456 Z",
+        "20240507T123456Zx",
+        "2024-05-07T12:34:56Z",
+    ] {
+        assert!(parse(s).is_err(), "{:?}", s);
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        use crate::format::Iso8601BasicOptions;
+
+        assert_eq!(dt.to_iso8601_basic(), "20240507T123456.250+0100");
+        assert_eq!(parse(&dt.to_iso8601_basic()), Ok(dt));
+        let utc = dt.with_timezone(&Utc);
+        assert_eq!(utc.to_iso8601_basic(), "20240507T113456.250Z");
+        let opts = Iso8601BasicOptions::new().with_use_z(false).with_decimal_comma(true);
+        assert_eq!(utc.to_iso8601_basic_opts(opts), "20240507T113456,250+0000");
+        assert_eq!(parse(&utc.to_iso8601_basic_opts(opts)), Ok(utc.fixed_offset()));
+    }
+}
+
+#[test]
+fn test_datetime_parse_unix_timestamp() {
+    use crate::TimestampUnit::{Auto, Micros, Millis, Nanos, Secs};
+    use crate::format::ParseErrorKind;
+
+    let parse = DateTime::parse_unix_timestamp;
+    let nanos = |n| DateTime::from_timestamp_nanos(n);
+    let ts = 1_715_083_445_123_456_789;
+
+    assert_eq!(parse("0", Secs), Ok(DateTime::UNIX_EPOCH));
+    assert_eq!(parse("-0.0", Auto), Ok(DateTime::UNIX_EPOCH));
+    assert_eq!(parse("1715083445.123456789", Secs), Ok(nanos(ts)));
+    assert_eq!(parse("1715083445.1234567899", Secs), Ok(nanos(ts)));
+    assert_eq!(parse("+1715083445123.456789", Millis), Ok(nanos(ts)));
+    assert_eq!(parse("1715083445123456.789", Micros), Ok(nanos(ts)));
+    assert_eq!(parse("1715083445123456789", Nanos), Ok(nanos(ts)));
+    assert_eq!(parse("1715083445123456789.9", Nanos), Ok(nanos(ts)));
+    assert_eq!(parse("-1.000000001", Secs), Ok(nanos(-1_000_000_001)));
+    assert_eq!(parse("-1", Millis), Ok(nanos(-1_000_000)));
+    assert_eq!(parse("0001", Micros), Ok(nanos(1_000)));
+
+    assert_eq!(parse("1715083445", Auto), Ok(nanos(1_715_083_445_000_000_000)));
+    assert_eq!(parse("1715083445.5", Auto), Ok(nanos(1_715_083_445_500_000_000)));
+    assert_eq!(parse("1715083445123", Auto), Ok(nanos(1_715_083_445_123_000_000)));
+    assert_eq!(parse("1715083445123456", Auto), Ok(nanos(1_715_083_445_123_456_000)));
+    assert_eq!(parse("1715083445123456789", Auto), Ok(nanos(ts)));
+    assert_eq!(parse("-1715083445123", Auto), Ok(nanos(-1_715_083_445_123_000_000)));
+    assert_eq!(
+        parse("99999999999", Auto),
+        Ok(DateTime::from_timestamp(99_999_999_999, 0).unwrap())
+    );
+
+    let kind = |s, unit| parse(s, unit).map_err(|e| e.kind());
+    assert_eq!(kind("", Secs), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind("-", Secs), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind("1.", Secs), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind(".5", Secs), Err(ParseErrorKind::TooShort));
+    assert_eq!(kind(" 1", Secs), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("1e9", Secs), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("1.5.0", Secs), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("--1", Secs), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("1,5", Secs), Err(ParseErrorKind::Invalid));
+    assert_eq!(kind("99999999999999999999", Secs), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(kind("999999999999999999999999", Nanos), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(
+        kind("340282366920938463463374607431768211456", Secs),
+        Err(ParseErrorKind::OutOfRange)
+    );
+}
+
+#[test]
+fn test_parse_and_remainder_fixed_formats() {
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let dt = ymdhms(&utc, 2015, 2, 18, 23, 16, 9);
+
+    let rfc2822 = DateTime::parse_from_rfc2822_and_remainder;
+    assert_eq!(rfc2822("Wed, 18 Feb 2015 23:16:09 GMT"), Ok((dt, "")));
+    assert_eq!(rfc2822("Wed, 18 Feb 2015 23:16:09 +0000 (UTC) rest"), Ok((dt, " rest")));
+    assert_eq!(rfc2822("18 Feb 2015 23:16:09 Z, rest"), Ok((dt, ", rest")));
+    assert!(rfc2822("Thu, 18 Feb 2015 23:16:09 GMT, rest").is_err());
+    assert!(rfc2822("Wed, 18 Feb 2015 23:16").is_err());
+
+    let rfc3339 = DateTime::parse_from_rfc3339_and_remainder;
+    assert_eq!(rfc3339("2015-02-18T23:16:09Z"), Ok((dt, "")));
+    assert_eq!(rfc3339("2015-02-18 23:16:09.000+00:00 rest"), Ok((dt, " rest")));
+    assert_eq!(rfc3339("2015-02-18T23:16:09+00:001"), Ok((dt, "1")));
+    assert!(rfc3339("2015-02-18T23:16:09 +00:00").is_err());
+    assert!(rfc3339("2015-02-18T23:16:09").is_err());
+
+    let relaxed = DateTime::parse_relaxed_and_remainder;
+    assert_eq!(relaxed("2015-02-18T23:16:09Z"), Ok((dt, "")));
+    assert_eq!(relaxed("2015-02-18 23:16:09 UTC, rest"), Ok((dt, ", rest")));
+    assert_eq!(relaxed("2015-2-18T23:16:9 +0000 rest"), Ok((dt, " rest")));
+    assert!(relaxed("2015-02-18 23:16:09").is_err());
+    for s in ["2015-02-18T23:16:09Z", "2015-02-18 23:16:09 +00:00", "2015-02-18 23:16:09UTC"] {
+        assert_eq!(relaxed(s).map(|(dt, _)| dt), s.parse::<DateTime<FixedOffset>>(), "{}", s);
+    }
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn test_datetime_rfc3339() {
@@ -877,6 +1243,36 @@ fn test_rfc3339_opts() {
     assert_eq!(ut.to_rfc3339_opts(AutoSi, true), "2018-01-11T02:05:13.084660Z");
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn test_rfc3339_with() {
+    use crate::SecondsFormat::*;
+    use crate::format::Rfc3339Options;
+
+    let dt = Utc.with_ymd_and_hms(2018, 1, 11, 2, 5, 13).unwrap().with_nanosecond(84_660_000);
+    let dt = dt.unwrap();
+    assert_eq!(dt.to_rfc3339_with(Rfc3339Options::new()), dt.to_rfc3339());
+    assert_eq!(dt.to_rfc3339_with(Rfc3339Options::default()), "2018-01-11T02:05:13.084660+00:00");
+
+    let opts = Rfc3339Options::new().with_seconds_format(Millis).with_use_z(true);
+    assert_eq!(dt.to_rfc3339_with(opts), dt.to_rfc3339_opts(Millis, true));
+    assert_eq!(dt.to_rfc3339_with(opts), "2018-01-11T02:05:13.084Z");
+    assert_eq!(dt.to_rfc3339_with(opts.with_uppercase(false)), "2018-01-11t02:05:13.084z");
+    assert_eq!(dt.to_rfc3339_with(opts.with_space_separator(true)), "2018-01-11 02:05:13.084Z");
+    let opts = opts.with_space_separator(true).with_uppercase(false);
+    assert_eq!(dt.to_rfc3339_with(opts), "2018-01-11 02:05:13.084z");
+    let opts = opts.with_use_z(false).with_seconds_format(Secs);
+    assert_eq!(dt.to_rfc3339_with(opts), "2018-01-11 02:05:13+00:00");
+
+    let tz = FixedOffset::west_opt(8 * 3600).unwrap();
+    let opts = Rfc3339Options::new().with_use_z(true).with_uppercase(false);
+    assert_eq!(dt.with_timezone(&tz).to_rfc3339_with(opts), "2018-01-10t18:05:13.084660-08:00");
+
+    let mut buf = [0; 48];
+    assert_eq!(dt.to_rfc3339_buf(&mut buf, opts), "2018-01-11t02:05:13.084660z");
+    assert_eq!(Rfc3339Options::from(Nanos), Rfc3339Options::new().with_seconds_format(Nanos));
+}
+
 #[test]
 #[should_panic]
 #[cfg(feature = "alloc")]
@@ -1502,6 +1898,112 @@ fn test_years_elapsed() {
     assert_eq!(Utc::now().date_naive().years_since(future), None);
 }
 
+#[test]
+fn test_from_timestamp_in() {
+    let dt = DateTime::from_timestamp_in(1_365_956_400, 0, &DstTester).unwrap();
+    assert_eq!(dt, DateTime::from_timestamp(1_365_956_400, 0).unwrap());
+    assert_eq!(dt.offset(), &DstTester::summer_offset());
+    let dt = DateTime::from_timestamp_in(1_365_964_200, 5, &DstTester).unwrap();
+    assert_eq!(dt.offset(), &DstTester::winter_offset());
+    assert_eq!(dt.timestamp_subsec_nanos(), 5);
+
+    // leap second
+    let leap = DateTime::from_timestamp_in(1_483_228_799, 1_500_000_000, &Utc).unwrap();
+    assert_eq!(leap.nanosecond(), 1_500_000_000);
+
+    assert_eq!(DateTime::from_timestamp_in(0, 2_000_000_000, &Utc), None);
+    assert_eq!(DateTime::from_timestamp_in(i64::MIN, 0, &Utc), None);
+}
+
+#[test]
+fn test_datetime_size() {
+    use core::mem::size_of;
+
+    // The size of a `DateTime` is that of its `NaiveDateTime` plus the offset.
+    assert_eq!(size_of::<DateTime<Utc>>(), size_of::<NaiveDateTime>());
+    assert_eq!(size_of::<DateTime<FixedOffset>>(), size_of::<NaiveDateTime>() + 4);
+
+    // `NaiveDate` is never zero internally, which leaves a niche for `Option`.
+    assert_eq!(size_of::<Option<NaiveDate>>(), size_of::<NaiveDate>());
+    assert_eq!(size_of::<Option<NaiveDateTime>>(), size_of::<NaiveDateTime>());
+    assert_eq!(size_of::<Option<DateTime<Utc>>>(), size_of::<DateTime<Utc>>());
+    assert_eq!(size_of::<Option<DateTime<FixedOffset>>>(), size_of::<DateTime<FixedOffset>>());
+}
+
+#[test]
+fn test_checked_duration_since() {
+    let earlier = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+    let later =
+        FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 5, 7, 13, 0, 1).unwrap();
+    assert_eq!(later.checked_duration_since(&earlier), Some(TimeDelta::try_seconds(1).unwrap()));
+    assert_eq!(earlier.checked_duration_since(&later), None);
+    assert_eq!(earlier.checked_duration_since(&earlier), Some(TimeDelta::zero()));
+}
+
+#[test]
+#[cfg(feature = "clock")]
+fn test_elapsed() {
+    let past = Utc::now() - TimeDelta::try_hours(1).unwrap();
+    assert!(past.elapsed().unwrap() >= TimeDelta::try_hours(1).unwrap());
+
+    let future = Local::now() + TimeDelta::try_hours(1).unwrap();
+    let err = future.elapsed().unwrap_err();
+    assert!(err.duration() > TimeDelta::zero());
+    assert!(err.duration() <= TimeDelta::try_hours(1).unwrap());
+}
+
+#[test]
+fn test_calendar_units_since() {
+    let local = |m, d, h, min| DstTester.with_ymd_and_hms(2023, m, d, h, min, 0).single().unwrap();
+
+    // April 14 is a day of 25 hours.
+    let base = local(4, 14, 12, 0);
+    assert_eq!(local(4, 15, 11, 30) - base, TimeDelta::try_minutes(24 * 60 + 30).unwrap());
+    assert_eq!(local(4, 15, 11, 30).days_since(base), Some(0));
+    assert_eq!(local(4, 15, 12, 0).days_since(base), Some(1));
+    assert_eq!(local(4, 21, 12, 0).days_since(base), Some(7));
+    assert_eq!(local(4, 21, 11, 0).weeks_since(base), Some(0));
+    assert_eq!(local(4, 21, 12, 0).weeks_since(base), Some(1));
+    assert_eq!(local(4, 14, 11, 0).days_since(base), None);
+    assert_eq!(local(4, 14, 11, 0).weeks_since(base), None);
+
+    assert_eq!(local(5, 14, 11, 59).months_since(base), Some(0));
+    assert_eq!(local(5, 14, 12, 0).months_since(base), Some(1));
+    assert_eq!(local(3, 14, 12, 0).months_since(local(2, 14, 12, 0)), Some(1));
+    assert_eq!(local(3, 31, 12, 0).months_since(local(1, 31, 12, 0)), Some(2));
+    assert_eq!(local(3, 30, 12, 0).months_since(local(1, 31, 12, 0)), Some(1));
+    assert_eq!(base.months_since(local(5, 14, 12, 0)), None);
+
+    let dt = Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap();
+    assert_eq!(Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap().months_since(dt), Some(48));
+    assert_eq!(Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap().days_since(dt), Some(1461));
+}
+
+#[test]
+fn test_same_calendar_unit() {
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let a = Utc.with_ymd_and_hms(2023, 12, 31, 14, 0, 0).unwrap();
+    let b = tokyo.with_ymd_and_hms(2023, 12, 31, 23, 30, 0).unwrap();
+    let c = Utc.with_ymd_and_hms(2023, 12, 31, 15, 0, 0).unwrap();
+
+    // `a` and `b` are on December 31 in Tokyo, `c` is on January 1.
+    assert!(a.same_day_as(&b, &tokyo));
+    assert!(!a.same_day_as(&c, &tokyo));
+    assert!(a.same_day_as(&c, &Utc));
+    assert!(!b.same_day_as(&c, &tokyo));
+
+    assert!(a.same_month_as(&b, &tokyo));
+    assert!(!a.same_month_as(&c, &tokyo));
+    assert!(a.same_month_as(&c, &Utc));
+
+    // Sunday December 31, 2023 is in ISO week 2023-W52, Monday January 1, 2024 in 2024-W01.
+    assert!(a.same_week_as(&b, &tokyo));
+    assert!(!a.same_week_as(&c, &tokyo));
+    assert!(a.same_week_as(&c, &Utc));
+    assert!(a.same_week_as(&(a - Days::new(6)), &Utc));
+    assert!(!a.same_week_as(&(a - Days::new(7)), &Utc));
+}
+
 #[test]
 fn test_datetime_add_assign() {
     let naivedatetime = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
@@ -1809,6 +2311,44 @@ fn test_datetime_to_utc() {
     assert_eq!(dt, dt_utc);
 }
 
+#[test]
+fn test_datetime_const_conversions() {
+    const UTC: DateTime<Utc> = DateTime::<Utc>::UNIX_EPOCH;
+    const NAIVE: NaiveDateTime = UTC.naive_utc();
+    const FIXED: DateTime<FixedOffset> = UTC.to_fixed_offset();
+    const BACK: DateTime<Utc> = FIXED.to_utc();
+
+    assert_eq!(NAIVE, NaiveDateTime::UNIX_EPOCH);
+    assert_eq!(FIXED, UTC.fixed_offset());
+    assert_eq!(FIXED.offset().local_minus_utc(), 0);
+    assert_eq!(BACK, UTC);
+    assert_eq!(DateTime::<FixedOffset>::from(UTC), FIXED);
+}
+
+#[test]
+fn test_datetime_align_down() {
+    let step = TimeDelta::try_minutes(5).unwrap();
+    let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 34, 56).unwrap();
+    assert_eq!(dt.align_down(step), Some(Utc.with_ymd_and_hms(2024, 5, 7, 12, 30, 0).unwrap()));
+    let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 30, 0).unwrap();
+    assert_eq!(dt.align_down(step), Some(dt));
+
+    // Before the epoch values are aligned towards the past, not towards the epoch.
+    let dt = Utc.with_ymd_and_hms(1969, 12, 31, 23, 58, 0).unwrap();
+    assert_eq!(dt.align_down(step), Some(Utc.with_ymd_and_hms(1969, 12, 31, 23, 55, 0).unwrap()));
+
+    // Alignment happens on the UTC timestamp, the offset is preserved.
+    let tz = FixedOffset::east_opt(5 * 60 * 60 + 30 * 60).unwrap();
+    let dt = tz.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+    let aligned = dt.align_down(TimeDelta::try_hours(1).unwrap()).unwrap();
+    assert_eq!(aligned, tz.with_ymd_and_hms(2024, 5, 7, 11, 30, 0).unwrap());
+    assert_eq!(aligned.offset(), &tz);
+
+    assert_eq!(dt.align_down(TimeDelta::zero()), None);
+    assert_eq!(dt.align_down(-step), None);
+    assert_eq!(DateTime::<Utc>::MIN_UTC.align_down(TimeDelta::try_days(3).unwrap()), None);
+}
+
 #[test]
 fn test_add_sub_months() {
     let utc_dt = Utc.with_ymd_and_hms(2018, 9, 5, 23, 58, 0).unwrap();
@@ -1868,6 +2408,33 @@ fn locale_decimal_point() {
     assert_eq!(dt.format_localized("%T%.9f", ar_SY).to_string(), "18:58:00.123456780");
 }
 
+#[test]
+#[cfg(all(feature = "unstable-locales", feature = "alloc"))]
+fn test_parse_and_remainder_localized() {
+    use crate::Locale::{fr_FR, nl_NL};
+
+    let tz = FixedOffset::east_opt(3600).unwrap();
+    for month in 1..=12 {
+        let dt = tz.with_ymd_and_hms(2023, month, 11, 18, 58, 0).unwrap();
+        let s = dt.format_localized("%A %d %B %Y %T %z", fr_FR).to_string() + " (CET)";
+        assert_eq!(
+            DateTime::parse_and_remainder_localized(&s, "%A %d %B %Y %T %z", fr_FR),
+            Ok((dt, " (CET)")),
+            "{}",
+            s
+        );
+    }
+
+    let s = "vendredi 11 AOÛT 2023 18:58:00,5 +0100";
+    let dt = DateTime::parse_from_str_localized(s, "%A %d %B %Y %T%.f %z", fr_FR).unwrap();
+    assert_eq!(
+        dt,
+        tz.with_ymd_and_hms(2023, 8, 11, 18, 58, 0).unwrap() + TimeDelta::milliseconds(500)
+    );
+    let err = DateTime::parse_and_remainder_localized(s, "%A %d %B %Y", nl_NL).unwrap_err();
+    assert_eq!(err.kind(), crate::format::ParseErrorKind::Invalid);
+}
+
 /// This is an extended test for <https://github.com/chronotope/chrono/issues/1289>.
 #[test]
 fn nano_roundrip() {
@@ -1900,3 +2467,190 @@ fn nano_roundrip() {
         assert_eq!(nanos, nanos2);
     }
 }
+
+#[test]
+fn test_parse_exif() {
+    let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap();
+    assert_eq!(DateTime::parse_exif("2023:09:08 07:03:25", "+05:30"), Ok(dt));
+    assert_eq!(DateTime::parse_exif("2023:09:08 07:03:25\0", "+05:30\0"), Ok(dt));
+    assert!(DateTime::parse_exif("2023:09:08 07:03:25", "").is_err());
+    assert!(DateTime::parse_exif("2023:09:08 07:03:25", "+24:00").is_err());
+    assert!(DateTime::parse_exif("2023-09-08 07:03:25", "+05:30").is_err());
+    let err = DateTime::parse_exif("+262142:12:31 23:59:59", "-01:00").unwrap_err();
+    assert_eq!(err.kind(), crate::format::ParseErrorKind::TooLong);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_exif_strings() {
+    let tz = FixedOffset::west_opt(9 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap();
+    let (datetime, offset) = dt.to_exif_strings();
+    assert_eq!((datetime.as_str(), offset.as_str()), ("2023:09:08 07:03:25", "-09:30"));
+    assert_eq!(DateTime::parse_exif(&datetime, &offset), Ok(dt));
+
+    let (datetime, offset) = Utc.with_ymd_and_hms(2000, 1, 2, 3, 4, 5).unwrap().to_exif_strings();
+    assert_eq!((datetime.as_str(), offset.as_str()), ("2000:01:02 03:04:05", "+00:00"));
+}
+
+#[test]
+fn test_parse_from_rfc3164() {
+    let dt = Utc.with_ymd_and_hms(2024, 10, 1, 22, 14, 15).unwrap();
+    assert_eq!(DateTime::parse_from_rfc3164("Oct  1 22:14:15", 2024, &Utc), Ok(dt));
+    assert_eq!(DateTime::parse_from_rfc3164("Oct 1 22:14:15", 2024, &Utc), Ok(dt));
+    assert_eq!(DateTime::parse_from_rfc3164("Oct 01 22:14:15", 2024, &Utc), Ok(dt));
+
+    let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+    let dt = DateTime::parse_from_rfc3164("Dec 31 23:59:59", 1999, &tz).unwrap();
+    assert_eq!(dt, tz.with_ymd_and_hms(1999, 12, 31, 23, 59, 59).unwrap());
+
+    assert!(DateTime::parse_from_rfc3164("Feb 29 00:00:00", 2023, &Utc).is_err());
+    assert!(DateTime::parse_from_rfc3164("Oct  1 22:14", 2024, &Utc).is_err());
+    assert!(DateTime::parse_from_rfc3164("Oct  1 22:14:15 host", 2024, &Utc).is_err());
+    assert!(DateTime::parse_from_rfc3164("2024-10-01 22:14:15", 2024, &Utc).is_err());
+}
+
+#[test]
+fn test_parse_from_rfc5424() {
+    let tz = FixedOffset::east_opt(0).unwrap();
+    let dt = tz.with_ymd_and_hms(1985, 4, 12, 23, 20, 50).unwrap();
+    assert_eq!(DateTime::parse_from_rfc5424("1985-04-12T23:20:50Z"), Ok(dt));
+    assert_eq!(
+        DateTime::parse_from_rfc5424("1985-04-12T23:20:50.52Z"),
+        Ok(dt.with_nanosecond(520_000_000).unwrap())
+    );
+    let tz = FixedOffset::west_opt(7 * 3600).unwrap();
+    assert_eq!(
+        DateTime::parse_from_rfc5424("2003-08-24T05:14:15.000003-07:00"),
+        Ok(tz.with_ymd_and_hms(2003, 8, 24, 5, 14, 15).unwrap().with_nanosecond(3_000).unwrap())
+    );
+
+    for s in [
+        "-",
+        "1985-04-12",
+        "1985-04-12T23:20:50",
+        "1985-04-12t23:20:50Z",
+        "1985-04-12 23:20:50Z",
+        "1985-04-12T23:20:50z",
+        "1985-04-12T23:20:50.Z",
+        "1985-04-12T23:20:50.1234567Z",
+        "1985-04-12T23:20:50+0000",
+        "1985-04-12T23:20:50Z ",
+        "1990-12-31T23:59:60Z",
+        "1985-04-12T23:20:50é",
+    ] {
+        assert!(DateTime::parse_from_rfc5424(s).is_err(), "{}", s);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_rfc3164_and_rfc5424() {
+    let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap().with_nanosecond(1_234_567).unwrap();
+    assert_eq!(dt.to_rfc3164(), "Sep  8 07:03:25");
+    assert_eq!(dt.to_rfc5424(), "2023-09-08T07:03:25.001234+05:30");
+    let expected = dt.with_nanosecond(1_234_000).unwrap();
+    assert_eq!(DateTime::parse_from_rfc5424(&dt.to_rfc5424()), Ok(expected));
+
+    let dt = Utc.with_ymd_and_hms(2023, 12, 25, 0, 0, 0).unwrap();
+    assert_eq!(dt.to_rfc3164(), "Dec 25 00:00:00");
+    assert_eq!(dt.to_rfc5424(), "2023-12-25T00:00:00.000000Z");
+    assert_eq!(DateTime::parse_from_rfc3164(&dt.to_rfc3164(), 2023, &Utc), Ok(dt));
+}
+
+#[test]
+fn test_parse_from_clf() {
+    let tz = FixedOffset::west_opt(7 * 3600).unwrap();
+    let dt = tz.with_ymd_and_hms(2000, 10, 10, 13, 55, 36).unwrap();
+    assert_eq!(DateTime::parse_from_clf("10/Oct/2000:13:55:36 -0700"), Ok(dt));
+    assert_eq!(DateTime::parse_from_clf("[10/Oct/2000:13:55:36 -0700]"), Ok(dt));
+
+    let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+    assert_eq!(DateTime::parse_from_clf("29/Feb/2024:00:00:00 +0530"), Ok(dt));
+
+    for s in [
+        "[10/Oct/2000:13:55:36 -0700",
+        "10/Oct/2000:13:55:36 -0700]",
+        "10/Oct/2000:13:55:36",
+        "10/Oct/2000 13:55:36 -0700",
+        "10/10/2000:13:55:36 -0700",
+        "29/Feb/2023:00:00:00 +0000",
+        "10/Oct/2000:13:55:36 -0700 extra",
+    ] {
+        assert!(DateTime::parse_from_clf(s).is_err(), "{}", s);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_clf_string() {
+    let tz = FixedOffset::west_opt(9 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap();
+    assert_eq!(dt.to_clf_string(), "08/Sep/2023:07:03:25 -0930");
+    assert_eq!(DateTime::parse_from_clf(&dt.to_clf_string()), Ok(dt));
+
+    let dt = Utc.with_ymd_and_hms(2000, 1, 2, 3, 4, 5).unwrap();
+    assert_eq!(dt.to_clf_string(), "02/Jan/2000:03:04:05 +0000");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_format_into() {
+    let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap();
+    let mut buf = String::new();
+    dt.format_into(&mut buf, "%Y-%m-%d %H:%M:%S %z").unwrap();
+    dt.format_into(&mut buf, " [%a]").unwrap();
+    assert_eq!(buf, "2023-09-08 07:03:25 +0530 [Fri]");
+    assert!(dt.format_into(&mut String::new(), "%Y-%").is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_write_rfc3339() {
+    let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 9, 8, 7, 3, 25).unwrap().with_nanosecond(1_500_000).unwrap();
+    let mut buf = Vec::new();
+    dt.write_rfc3339(&mut buf).unwrap();
+    assert_eq!(buf, dt.to_rfc3339().as_bytes());
+
+    let opts = crate::format::Rfc3339Options::new().with_space_separator(true);
+    let mut buf = Vec::new();
+    dt.write_rfc3339_with(&mut buf, opts).unwrap();
+    assert_eq!(buf, b"2023-09-08 07:03:25.001500+05:30");
+
+    let mut small = [0u8; 10];
+    assert!(dt.write_rfc3339(&mut &mut small[..]).is_err());
+    assert_eq!(&small, b"2023-09-08");
+}
+
+#[test]
+fn test_to_rfc3339_buf() {
+    use crate::SecondsFormat;
+
+    let mut buf = [0; 48];
+    let dt = DateTime::<Utc>::MAX_UTC.with_timezone(&FixedOffset::east_opt(86_340).unwrap());
+    assert_eq!(
+        dt.to_rfc3339_buf(&mut buf, SecondsFormat::Nanos),
+        "+262143-01-01T23:58:59.999999999+23:59"
+    );
+    let dt = DateTime::<Utc>::MIN_UTC.with_timezone(&FixedOffset::west_opt(86_340).unwrap());
+    assert_eq!(dt.to_rfc3339_buf(&mut buf, SecondsFormat::Secs), "-262144-12-31T00:01:00-23:59");
+
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+        .unwrap()
+        .and_utc();
+    assert_eq!(
+        leap.to_rfc3339_buf(&mut buf, SecondsFormat::AutoSi),
+        "2016-12-31T23:59:60.500+00:00"
+    );
+    #[cfg(feature = "alloc")]
+    for secform in [SecondsFormat::Secs, SecondsFormat::Micros, SecondsFormat::AutoSi] {
+        assert_eq!(leap.to_rfc3339_buf(&mut buf, secform), leap.to_rfc3339_opts(secform, false));
+    }
+}
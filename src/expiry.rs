@@ -0,0 +1,161 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A point in time after which something is no longer valid.
+
+use crate::{DateTime, TimeDelta, Utc};
+
+/// The moment a cache entry, token or other value expires.
+///
+/// An `Expiry` wraps a [`DateTime<Utc>`]. It is expired from that moment on: [`is_expired`]
+/// returns `true` when `now` is equal to or later than the expiry time.
+///
+/// With the `serde` feature `Expiry` serializes the same way as the wrapped `DateTime<Utc>`.
+///
+/// [`is_expired`]: Expiry::is_expired
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Expiry, TimeDelta, TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 5, 7, 13, 0, 0).unwrap();
+/// let expiry = Expiry::after(now, TimeDelta::minutes(15)).unwrap();
+/// assert_eq!(expiry.expires_at(), Utc.with_ymd_and_hms(2024, 5, 7, 13, 15, 0).unwrap());
+///
+/// let later = now + TimeDelta::minutes(10);
+/// assert!(!expiry.is_expired(later));
+/// assert_eq!(expiry.remaining(later), TimeDelta::minutes(5));
+///
+/// let much_later = now + TimeDelta::hours(1);
+/// assert!(expiry.is_expired(much_later));
+/// assert_eq!(expiry.remaining(much_later), TimeDelta::zero());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Expiry(DateTime<Utc>);
+
+impl Expiry {
+    /// Makes a new `Expiry` that expires at `at`.
+    #[must_use]
+    pub const fn at(at: DateTime<Utc>) -> Expiry {
+        Expiry(at)
+    }
+
+    /// Makes a new `Expiry` that expires `ttl` after `now`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the expiry time would be out of range for a `DateTime`.
+    #[must_use]
+    pub fn after(now: DateTime<Utc>, ttl: TimeDelta) -> Option<Expiry> {
+        now.checked_add_signed(ttl).map(Expiry)
+    }
+
+    /// Makes a new `Expiry` that expires `ttl` after the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the expiry time would be out of range for a `DateTime`.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn from_ttl(ttl: TimeDelta) -> Option<Expiry> {
+        Expiry::after(Utc::now(), ttl)
+    }
+
+    /// Returns the moment this `Expiry` expires.
+    #[must_use]
+    pub const fn expires_at(&self) -> DateTime<Utc> {
+        self.0
+    }
+
+    /// Returns `true` if this `Expiry` has expired at `now`.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.0
+    }
+
+    /// Returns the time left until this `Expiry` expires, or zero if it has already expired.
+    #[must_use]
+    pub fn remaining(&self, now: DateTime<Utc>) -> TimeDelta {
+        match self.is_expired(now) {
+            true => TimeDelta::zero(),
+            false => self.0.signed_duration_since(now),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for Expiry {
+    fn from(at: DateTime<Utc>) -> Expiry {
+        Expiry(at)
+    }
+}
+
+impl From<Expiry> for DateTime<Utc> {
+    fn from(expiry: Expiry) -> DateTime<Utc> {
+        expiry.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod expiry_serde {
+    use super::Expiry;
+    use crate::{DateTime, Utc};
+    use serde::{de, ser};
+
+    impl ser::Serialize for Expiry {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Expiry {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            <DateTime<Utc> as de::Deserialize>::deserialize(deserializer).map(Expiry)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expiry;
+    use crate::{DateTime, TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn test_expiry() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 7, 13, 0, 0).unwrap();
+        let expiry = Expiry::after(now, TimeDelta::seconds(30)).unwrap();
+        assert!(!expiry.is_expired(now));
+        assert_eq!(expiry.remaining(now), TimeDelta::seconds(30));
+
+        let at = now + TimeDelta::seconds(30);
+        assert_eq!(expiry, Expiry::at(at));
+        assert!(expiry.is_expired(at));
+        assert_eq!(expiry.remaining(at), TimeDelta::zero());
+        assert!(!expiry.is_expired(at - TimeDelta::nanoseconds(1)));
+        assert_eq!(expiry.remaining(at - TimeDelta::nanoseconds(1)), TimeDelta::nanoseconds(1));
+
+        let expired = Expiry::after(now, TimeDelta::seconds(-30)).unwrap();
+        assert!(expired.is_expired(now));
+        assert_eq!(expired.remaining(now), TimeDelta::zero());
+
+        assert_eq!(Expiry::after(DateTime::<Utc>::MAX_UTC, TimeDelta::seconds(1)), None);
+        assert_eq!(DateTime::<Utc>::from(Expiry::from(now)), now);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_expiry() {
+        use serde_json::{from_str, to_string};
+
+        let expiry = Expiry::at(Utc.with_ymd_and_hms(2024, 5, 7, 13, 0, 0).unwrap());
+        assert_eq!(to_string(&expiry).unwrap(), r#""2024-05-07T13:00:00Z""#);
+        assert_eq!(from_str::<Expiry>(r#""2024-05-07T13:00:00Z""#).unwrap(), expiry);
+        assert!(from_str::<Expiry>("0").is_err());
+    }
+}
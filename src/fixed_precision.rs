@@ -0,0 +1,155 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A UTC timestamp with a fixed number of fractional digits.
+
+use core::fmt;
+
+use crate::{DateTime, SubsecRound, Timelike, Utc};
+
+/// A [`DateTime<Utc>`] with at most `N` fractional digits of seconds.
+///
+/// Some protocols, such as FIX, require timestamps with an exact number of fractional digits.
+/// `FixedPrecision<3>` holds a time with millisecond precision, `FixedPrecision<6>` one with
+/// microsecond precision, and so on. Its [`Display`](fmt::Display) implementation always writes
+/// exactly `N` digits, using the RFC 3339 format with a `Z` suffix.
+///
+/// `N` must be between 0 and 9. Using a larger value fails to compile:
+///
+/// ```compile_fail
+/// # use chrono::{DateTime, FixedPrecision};
+/// let _ = FixedPrecision::<10>::truncate(DateTime::UNIX_EPOCH);
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedPrecision, NaiveDate};
+///
+/// let dt = NaiveDate::from_ymd_opt(2024, 5, 7)
+///     .unwrap()
+///     .and_hms_nano_opt(13, 4, 5, 120_456_789)
+///     .unwrap()
+///     .and_utc();
+///
+/// let millis = FixedPrecision::<3>::truncate(dt);
+/// assert_eq!(millis.to_string(), "2024-05-07T13:04:05.120Z");
+/// assert_eq!(FixedPrecision::<6>::truncate(dt).to_string(), "2024-05-07T13:04:05.120456Z");
+///
+/// // `new` does not silently drop precision.
+/// assert_eq!(FixedPrecision::<3>::new(dt), None);
+/// assert_eq!(FixedPrecision::<3>::new(millis.into_inner()), Some(millis));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedPrecision<const N: u8>(DateTime<Utc>);
+
+impl<const N: u8> FixedPrecision<N> {
+    // Evaluating this constant fails to compile if `N` is out of range.
+    const DIGITS: u16 = {
+        assert!(N <= 9, "`FixedPrecision` supports at most 9 fractional digits");
+        N as u16
+    };
+
+    /// Makes a new `FixedPrecision` from a `DateTime<Utc>` that has at most `N` fractional digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `dt` is more precise than `N` fractional digits.
+    #[must_use]
+    pub fn new(dt: DateTime<Utc>) -> Option<FixedPrecision<N>> {
+        match dt.trunc_subsecs(Self::DIGITS) == dt {
+            true => Some(FixedPrecision(dt)),
+            false => None,
+        }
+    }
+
+    /// Makes a new `FixedPrecision` from a `DateTime<Utc>`, dropping any digits beyond `N`.
+    #[must_use]
+    pub fn truncate(dt: DateTime<Utc>) -> FixedPrecision<N> {
+        FixedPrecision(dt.trunc_subsecs(Self::DIGITS))
+    }
+
+    /// Returns a reference to the wrapped `DateTime<Utc>`.
+    #[must_use]
+    pub const fn as_datetime(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+
+    /// Returns the wrapped `DateTime<Utc>`.
+    #[must_use]
+    pub const fn into_inner(self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl<const N: u8> From<FixedPrecision<N>> for DateTime<Utc> {
+    fn from(value: FixedPrecision<N>) -> DateTime<Utc> {
+        value.0
+    }
+}
+
+impl<const N: u8> fmt::Display for FixedPrecision<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dt = self.0.naive_utc();
+        let (nano, leap) = match dt.nanosecond() {
+            n if n >= 1_000_000_000 => (n - 1_000_000_000, 1),
+            n => (n, 0),
+        };
+        write!(f, "{}T{:02}:{:02}:{:02}", dt.date(), dt.hour(), dt.minute(), dt.second() + leap)?;
+        if N > 0 {
+            let digits = nano / 10u32.pow(9 - u32::from(Self::DIGITS));
+            write!(f, ".{:01$}", digits, N as usize)?;
+        }
+        f.write_str("Z")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedPrecision;
+    use crate::{NaiveDate, TimeZone, Timelike, Utc};
+
+    #[test]
+    fn test_fixed_precision_construction() {
+        let dt = Utc.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap();
+        assert!(FixedPrecision::<0>::new(dt).is_some());
+
+        let dt = dt.with_nanosecond(123_400_000).unwrap();
+        assert_eq!(FixedPrecision::<0>::new(dt), None);
+        assert_eq!(FixedPrecision::<3>::new(dt), None);
+        assert_eq!(FixedPrecision::<4>::new(dt).map(|p| p.into_inner()), Some(dt));
+        assert_eq!(FixedPrecision::<9>::new(dt).map(|p| p.into_inner()), Some(dt));
+
+        assert_eq!(
+            FixedPrecision::<2>::truncate(dt).into_inner(),
+            dt.with_nanosecond(120_000_000).unwrap()
+        );
+        assert_eq!(
+            *FixedPrecision::<0>::truncate(dt).as_datetime(),
+            dt.with_nanosecond(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fixed_precision_display() {
+        let dt = NaiveDate::from_ymd_opt(2024, 5, 7)
+            .unwrap()
+            .and_hms_nano_opt(13, 4, 5, 1_002_003)
+            .unwrap()
+            .and_utc();
+        assert_eq!(FixedPrecision::<0>::truncate(dt).to_string(), "2024-05-07T13:04:05Z");
+        assert_eq!(FixedPrecision::<1>::truncate(dt).to_string(), "2024-05-07T13:04:05.0Z");
+        assert_eq!(FixedPrecision::<3>::truncate(dt).to_string(), "2024-05-07T13:04:05.001Z");
+        assert_eq!(FixedPrecision::<6>::truncate(dt).to_string(), "2024-05-07T13:04:05.001002Z");
+        assert_eq!(FixedPrecision::<9>::truncate(dt).to_string(), "2024-05-07T13:04:05.001002003Z");
+
+        // leap second
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_250_000_000)
+            .unwrap()
+            .and_utc();
+        assert_eq!(FixedPrecision::<0>::truncate(leap).to_string(), "2016-12-31T23:59:60Z");
+        assert_eq!(FixedPrecision::<2>::truncate(leap).to_string(), "2016-12-31T23:59:60.25Z");
+    }
+}
@@ -0,0 +1,326 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Localized rendering of durations, such as `2 Stunden 3 Minuten`.
+
+use core::fmt;
+
+use super::Locale;
+use crate::TimeDelta;
+
+/// How to write the units of a duration formatted with [`TimeDelta::format_localized`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnitWidth {
+    /// The shortest form, such as `2h 3m` in English.
+    Narrow,
+    /// Abbreviated units, such as `2 hr 3 min` in English.
+    Short,
+    /// Units written out in full, such as `2 hours 3 minutes` in English.
+    Long,
+}
+
+/// A duration that is displayed in the language of a locale, created by
+/// [`TimeDelta::format_localized`].
+///
+/// The duration is written as days, hours, minutes, seconds and milliseconds, leaving out the
+/// units that are zero. Anything smaller than a millisecond is ignored. Languages without
+/// translations fall back to English.
+#[derive(Clone, Copy, Debug)]
+pub struct LocalizedDuration {
+    duration: TimeDelta,
+    locale: Locale,
+    width: UnitWidth,
+}
+
+impl LocalizedDuration {
+    pub(crate) const fn new(duration: TimeDelta, locale: Locale, width: UnitWidth) -> Self {
+        LocalizedDuration { duration, locale, width }
+    }
+}
+
+impl fmt::Display for LocalizedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let language = Language::of(self.locale);
+        let names = match self.width {
+            UnitWidth::Narrow => &language.narrow,
+            UnitWidth::Short => &language.short,
+            UnitWidth::Long => &language.long,
+        };
+
+        let abs = self.duration.abs();
+        let secs = abs.num_seconds().unsigned_abs();
+        let values = [
+            secs / 86_400,
+            secs / 3600 % 24,
+            secs / 60 % 60,
+            secs % 60,
+            u64::from(abs.subsec_nanos().unsigned_abs() / 1_000_000),
+        ];
+        if self.duration < TimeDelta::zero() && values.iter().any(|&v| v > 0) {
+            f.write_str("-")?;
+        }
+
+        let mut first = true;
+        for (&value, unit) in values.iter().zip(names.units.iter()) {
+            if value == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            write_unit(f, value, unit, names.space, language.plural)?;
+        }
+        if first {
+            // A duration of zero is written in seconds.
+            write_unit(f, 0, &names.units[3], names.space, language.plural)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_unit(
+    f: &mut fmt::Formatter,
+    value: u64,
+    unit: &[&str; 2],
+    space: bool,
+    plural: fn(u64) -> bool,
+) -> fmt::Result {
+    let name = if plural(value) { unit[1] } else { unit[0] };
+    let space = if space { " " } else { "" };
+    write!(f, "{}{}{}", value, space, name)
+}
+
+/// The singular and plural names of days, hours, minutes, seconds and milliseconds.
+struct Names {
+    units: [[&'static str; 2]; 5],
+    /// Whether there is a space between the number and the unit.
+    space: bool,
+}
+
+struct Language {
+    narrow: Names,
+    short: Names,
+    long: Names,
+    plural: fn(u64) -> bool,
+}
+
+impl Language {
+    fn of(locale: Locale) -> &'static Language {
+        let mut code = LanguageCode::default();
+        // Locale names start with the language code, such as `de` in `de_AT` or `POSIX`.
+        let _ = fmt::write(&mut code, format_args!("{}", locale));
+        match &code.buf[..code.len] {
+            b"de" => &GERMAN,
+            b"es" => &SPANISH,
+            b"fr" => &FRENCH,
+            b"it" => &ITALIAN,
+            b"nl" => &DUTCH,
+            b"pt" => &PORTUGUESE,
+            _ => &ENGLISH,
+        }
+    }
+}
+
+/// Collects the language code of a locale name: the lowercase letters before the first `_`.
+#[derive(Default)]
+struct LanguageCode {
+    buf: [u8; 3],
+    len: usize,
+    done: bool,
+}
+
+impl fmt::Write for LanguageCode {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.bytes() {
+            if self.done || !c.is_ascii_lowercase() || self.len == self.buf.len() {
+                self.done = true;
+                return Ok(());
+            }
+            self.buf[self.len] = c;
+            self.len += 1;
+        }
+        Ok(())
+    }
+}
+
+fn one_singular(n: u64) -> bool {
+    n != 1
+}
+
+fn zero_and_one_singular(n: u64) -> bool {
+    n > 1
+}
+
+const ENGLISH: Language = Language {
+    narrow: Names { units: [["d"; 2], ["h"; 2], ["m"; 2], ["s"; 2], ["ms"; 2]], space: false },
+    short: Names {
+        units: [["day", "days"], ["hr"; 2], ["min"; 2], ["sec"; 2], ["ms"; 2]],
+        space: true,
+    },
+    long: Names {
+        units: [
+            ["day", "days"],
+            ["hour", "hours"],
+            ["minute", "minutes"],
+            ["second", "seconds"],
+            ["millisecond", "milliseconds"],
+        ],
+        space: true,
+    },
+    plural: one_singular,
+};
+
+const GERMAN: Language = Language {
+    narrow: Names { units: [["T"; 2], ["Std."; 2], ["Min."; 2], ["s"; 2], ["ms"; 2]], space: true },
+    short: Names {
+        units: [["Tg."; 2], ["Std."; 2], ["Min."; 2], ["Sek."; 2], ["ms"; 2]],
+        space: true,
+    },
+    long: Names {
+        units: [
+            ["Tag", "Tage"],
+            ["Stunde", "Stunden"],
+            ["Minute", "Minuten"],
+            ["Sekunde", "Sekunden"],
+            ["Millisekunde", "Millisekunden"],
+        ],
+        space: true,
+    },
+    plural: one_singular,
+};
+
+const SPANISH: Language = Language {
+    narrow: Names { units: [["d"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: false },
+    short: Names { units: [["d"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: true },
+    long: Names {
+        units: [
+            ["día", "días"],
+            ["hora", "horas"],
+            ["minuto", "minutos"],
+            ["segundo", "segundos"],
+            ["milisegundo", "milisegundos"],
+        ],
+        space: true,
+    },
+    plural: one_singular,
+};
+
+const FRENCH: Language = Language {
+    narrow: Names { units: [["j"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: false },
+    short: Names { units: [["j"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: true },
+    long: Names {
+        units: [
+            ["jour", "jours"],
+            ["heure", "heures"],
+            ["minute", "minutes"],
+            ["seconde", "secondes"],
+            ["milliseconde", "millisecondes"],
+        ],
+        space: true,
+    },
+    plural: zero_and_one_singular,
+};
+
+const ITALIAN: Language = Language {
+    narrow: Names { units: [["g"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: false },
+    short: Names { units: [["g"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: true },
+    long: Names {
+        units: [
+            ["giorno", "giorni"],
+            ["ora", "ore"],
+            ["minuto", "minuti"],
+            ["secondo", "secondi"],
+            ["millisecondo", "millisecondi"],
+        ],
+        space: true,
+    },
+    plural: one_singular,
+};
+
+const DUTCH: Language = Language {
+    narrow: Names { units: [["d"; 2], ["u"; 2], ["m"; 2], ["s"; 2], ["ms"; 2]], space: false },
+    short: Names { units: [["d"; 2], ["u"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: true },
+    long: Names {
+        units: [
+            ["dag", "dagen"],
+            ["uur"; 2],
+            ["minuut", "minuten"],
+            ["seconde", "seconden"],
+            ["milliseconde", "milliseconden"],
+        ],
+        space: true,
+    },
+    plural: one_singular,
+};
+
+const PORTUGUESE: Language = Language {
+    narrow: Names { units: [["d"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: false },
+    short: Names { units: [["d"; 2], ["h"; 2], ["min"; 2], ["s"; 2], ["ms"; 2]], space: true },
+    long: Names {
+        units: [
+            ["dia", "dias"],
+            ["hora", "horas"],
+            ["minuto", "minutos"],
+            ["segundo", "segundos"],
+            ["milissegundo", "milissegundos"],
+        ],
+        space: true,
+    },
+    plural: one_singular,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::UnitWidth::{Long, Narrow, Short};
+    use crate::TimeDelta;
+    use crate::format::Locale;
+
+    #[test]
+    fn test_format_localized() {
+        let d = TimeDelta::hours(2) + TimeDelta::minutes(3);
+        let cases = [
+            (Locale::de_DE, Long, "2 Stunden 3 Minuten"),
+            (Locale::de_AT, Short, "2 Std. 3 Min."),
+            (Locale::fr_FR, Long, "2 heures 3 minutes"),
+            (Locale::fr_CA, Narrow, "2h 3min"),
+            (Locale::en_US, Long, "2 hours 3 minutes"),
+            (Locale::en_GB, Short, "2 hr 3 min"),
+            (Locale::POSIX, Narrow, "2h 3m"),
+            (Locale::es_ES, Long, "2 horas 3 minutos"),
+            (Locale::it_IT, Long, "2 ore 3 minuti"),
+            (Locale::nl_NL, Long, "2 uur 3 minuten"),
+            (Locale::pt_BR, Short, "2 h 3 min"),
+            (Locale::ja_JP, Long, "2 hours 3 minutes"),
+        ];
+        for (locale, width, expected) in cases {
+            assert_eq!(d.format_localized(locale, width).to_string(), expected, "{}", locale);
+        }
+    }
+
+    #[test]
+    fn test_format_localized_units() {
+        let d = TimeDelta::days(1) + TimeDelta::seconds(1) + TimeDelta::milliseconds(250);
+        assert_eq!(
+            d.format_localized(Locale::en_US, Long).to_string(),
+            "1 day 1 second 250 milliseconds"
+        );
+        assert_eq!(
+            (-d).format_localized(Locale::de_DE, Long).to_string(),
+            "-1 Tag 1 Sekunde 250 Millisekunden"
+        );
+        assert_eq!(d.format_localized(Locale::es_ES, Narrow).to_string(), "1d 1s 250ms");
+
+        // French uses the singular for 0 and 1.
+        let zero = TimeDelta::zero();
+        assert_eq!(zero.format_localized(Locale::fr_FR, Long).to_string(), "0 seconde");
+        assert_eq!(zero.format_localized(Locale::en_US, Long).to_string(), "0 seconds");
+        let tiny = TimeDelta::nanoseconds(-5);
+        assert_eq!(tiny.format_localized(Locale::en_US, Short).to_string(), "0 sec");
+
+        let max = TimeDelta::MAX.format_localized(Locale::en_US, Narrow).to_string();
+        assert_eq!(max, "106751991167d 7h 12m 55s 807ms");
+        let min = TimeDelta::MIN.format_localized(Locale::en_US, Narrow).to_string();
+        assert_eq!(min, "-106751991167d 7h 12m 55s 807ms");
+    }
+}
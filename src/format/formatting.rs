@@ -13,14 +13,12 @@ use core::fmt::{self, Write};
 
 #[cfg(feature = "alloc")]
 use crate::offset::Offset;
-#[cfg(any(feature = "alloc", feature = "serde"))]
 use crate::{Datelike, FixedOffset, NaiveDateTime, Timelike};
 #[cfg(feature = "alloc")]
 use crate::{NaiveDate, NaiveTime, Weekday};
 
 #[cfg(feature = "alloc")]
 use super::locales;
-#[cfg(any(feature = "alloc", feature = "serde"))]
 use super::{Colons, OffsetFormat, OffsetPrecision, Pad};
 #[cfg(feature = "alloc")]
 use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric};
@@ -127,11 +125,17 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
     fn format_numeric(&self, w: &mut impl Write, spec: &Numeric, pad: Pad) -> fmt::Result {
         use self::Numeric::*;
 
-        fn write_one(w: &mut impl Write, v: u8) -> fmt::Result {
+        fn write_one(w: &mut impl Write, v: u8, pad: Pad, width: Option<u8>) -> fmt::Result {
+            if width.is_some() {
+                return write_n(w, 1, i64::from(v), pad, width, false);
+            }
             w.write_char((b'0' + v) as char)
         }
 
-        fn write_two(w: &mut impl Write, v: u8, pad: Pad) -> fmt::Result {
+        fn write_two(w: &mut impl Write, v: u8, pad: Pad, width: Option<u8>) -> fmt::Result {
+            if width.is_some() {
+                return write_n(w, 2, i64::from(v), pad, width, false);
+            }
             let ones = b'0' + v % 10;
             match (v / 10, pad) {
                 (0, Pad::None) => {}
@@ -142,30 +146,43 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         }
 
         #[inline]
-        fn write_year(w: &mut impl Write, year: i32, pad: Pad) -> fmt::Result {
-            if (1000..=9999).contains(&year) {
+        fn write_year(w: &mut impl Write, year: i32, pad: Pad, width: Option<u8>) -> fmt::Result {
+            if (1000..=9999).contains(&year) && width.is_none() {
                 // fast path
                 write_hundreds(w, (year / 100) as u8)?;
                 write_hundreds(w, (year % 100) as u8)
             } else {
-                write_n(w, 4, year as i64, pad, !(0..10_000).contains(&year))
+                write_n(w, 4, year as i64, pad, width, !(0..10_000).contains(&year))
             }
         }
 
+        // An explicit `width` replaces the formatting width `n`, including the sign.
         fn write_n(
             w: &mut impl Write,
             n: usize,
             v: i64,
             pad: Pad,
+            width: Option<u8>,
             always_sign: bool,
         ) -> fmt::Result {
+            let zero_width = match pad {
+                Pad::None => 1,
+                Pad::Zero => width.map_or(n, usize::from),
+                Pad::Space => 0,
+            };
+            if !always_sign && v >= 0 && (1..=MAX_DIGITS).contains(&zero_width) {
+                // fast path
+                return write_zero_padded(w, v as u64, zero_width);
+            }
             if always_sign {
+                let n = width.map_or(n + 1, usize::from);
                 match pad {
                     Pad::None => write!(w, "{:+}", v),
-                    Pad::Zero => write!(w, "{:+01$}", v, n + 1),
-                    Pad::Space => write!(w, "{:+1$}", v, n + 1),
+                    Pad::Zero => write!(w, "{:+01$}", v, n),
+                    Pad::Space => write!(w, "{:+1$}", v, n),
                 }
             } else {
+                let n = width.map_or(n, usize::from);
                 match pad {
                     Pad::None => write!(w, "{}", v),
                     Pad::Zero => write!(w, "{:01$}", v, n),
@@ -174,45 +191,94 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             }
         }
 
+        let (spec, width) = match spec.explicit_width() {
+            Some((numeric, width)) => (numeric, Some(width)),
+            None => (spec, None),
+        };
         match (spec, self.date, self.time) {
-            (Year, Some(d), _) => write_year(w, d.year(), pad),
-            (YearDiv100, Some(d), _) => write_two(w, d.year().div_euclid(100) as u8, pad),
-            (YearMod100, Some(d), _) => write_two(w, d.year().rem_euclid(100) as u8, pad),
-            (IsoYear, Some(d), _) => write_year(w, d.iso_week().year(), pad),
+            (Year, Some(d), _) => write_year(w, d.year(), pad, width),
+            (YearDiv100, Some(d), _) => write_two(w, d.year().div_euclid(100) as u8, pad, width),
+            (YearMod100, Some(d), _) => write_two(w, d.year().rem_euclid(100) as u8, pad, width),
+            (IsoYear, Some(d), _) => write_year(w, d.iso_week().year(), pad, width),
             (IsoYearDiv100, Some(d), _) => {
-                write_two(w, d.iso_week().year().div_euclid(100) as u8, pad)
+                write_two(w, d.iso_week().year().div_euclid(100) as u8, pad, width)
             }
             (IsoYearMod100, Some(d), _) => {
-                write_two(w, d.iso_week().year().rem_euclid(100) as u8, pad)
-            }
-            (Quarter, Some(d), _) => write_one(w, d.quarter() as u8),
-            (Month, Some(d), _) => write_two(w, d.month() as u8, pad),
-            (Day, Some(d), _) => write_two(w, d.day() as u8, pad),
-            (WeekFromSun, Some(d), _) => write_two(w, d.weeks_from(Weekday::Sun) as u8, pad),
-            (WeekFromMon, Some(d), _) => write_two(w, d.weeks_from(Weekday::Mon) as u8, pad),
-            (IsoWeek, Some(d), _) => write_two(w, d.iso_week().week() as u8, pad),
-            (NumDaysFromSun, Some(d), _) => write_one(w, d.weekday().num_days_from_sunday() as u8),
-            (WeekdayFromMon, Some(d), _) => write_one(w, d.weekday().number_from_monday() as u8),
-            (Ordinal, Some(d), _) => write_n(w, 3, d.ordinal() as i64, pad, false),
-            (Hour, _, Some(t)) => write_two(w, t.hour() as u8, pad),
-            (Hour12, _, Some(t)) => write_two(w, t.hour12().1 as u8, pad),
-            (Minute, _, Some(t)) => write_two(w, t.minute() as u8, pad),
+                write_two(w, d.iso_week().year().rem_euclid(100) as u8, pad, width)
+            }
+            (Quarter, Some(d), _) => write_one(w, d.quarter() as u8, pad, width),
+            (Month, Some(d), _) => write_two(w, d.month() as u8, pad, width),
+            (Day, Some(d), _) => write_two(w, d.day() as u8, pad, width),
+            (WeekFromSun, Some(d), _) => write_two(w, d.weeks_from(Weekday::Sun) as u8, pad, width),
+            (WeekFromMon, Some(d), _) => write_two(w, d.weeks_from(Weekday::Mon) as u8, pad, width),
+            (IsoWeek, Some(d), _) => write_two(w, d.iso_week().week() as u8, pad, width),
+            (NumDaysFromSun, Some(d), _) => {
+                write_one(w, d.weekday().num_days_from_sunday() as u8, pad, width)
+            }
+            (WeekdayFromMon, Some(d), _) => {
+                write_one(w, d.weekday().number_from_monday() as u8, pad, width)
+            }
+            (Ordinal, Some(d), _) => write_n(w, 3, d.ordinal() as i64, pad, width, false),
+            (Hour, _, Some(t)) => write_two(w, t.hour() as u8, pad, width),
+            (Hour12, _, Some(t)) => write_two(w, t.hour12().1 as u8, pad, width),
+            (Minute, _, Some(t)) => write_two(w, t.minute() as u8, pad, width),
             (Second, _, Some(t)) => {
-                write_two(w, (t.second() + t.nanosecond() / 1_000_000_000) as u8, pad)
+                write_two(w, (t.second() + t.nanosecond() / 1_000_000_000) as u8, pad, width)
             }
             (Nanosecond, _, Some(t)) => {
-                write_n(w, 9, (t.nanosecond() % 1_000_000_000) as i64, pad, false)
+                write_n(w, 9, (t.nanosecond() % 1_000_000_000) as i64, pad, width, false)
             }
             (Timestamp, Some(d), Some(t)) => {
                 let offset = self.off.as_ref().map(|(_, o)| i64::from(o.local_minus_utc()));
                 let timestamp = d.and_time(t).and_utc().timestamp() - offset.unwrap_or(0);
-                write_n(w, 9, timestamp, pad, false)
+                write_n(w, 9, timestamp, pad, width, false)
             }
             (Internal(_), _, _) => Ok(()), // for future expansion
             _ => Err(fmt::Error),          // insufficient arguments for given format
         }
     }
 
+    /// Formats the year of `d` in the era of the locale, for `%EC`, `%Ey` and `%EY`.
+    ///
+    /// Dates outside the eras of the locale are formatted like `%C`, `%y` and `%Y`.
+    #[cfg(all(feature = "alloc", feature = "unstable-locales"))]
+    fn format_era(&self, w: &mut impl Write, spec: &InternalInternal, d: NaiveDate) -> fmt::Result {
+        use InternalInternal::*;
+
+        let era = match era(self.locale, d.year(), d.month(), d.day()) {
+            Some(era) => era,
+            None => {
+                let numeric = match spec {
+                    EraName => Numeric::YearDiv100,
+                    EraYear => Numeric::YearMod100,
+                    _ => Numeric::Year,
+                };
+                return self.format_numeric(w, &numeric, Pad::Zero);
+            }
+        };
+        match spec {
+            EraName => w.write_str(era.name),
+            EraYear => write!(w, "{}", era.year),
+            _ => {
+                let mut format = era.format;
+                while !format.is_empty() {
+                    if let Some(rest) = format.strip_prefix("%EC") {
+                        w.write_str(era.name)?;
+                        format = rest;
+                    } else if let Some(rest) = format.strip_prefix("%Ey") {
+                        write!(w, "{}", era.year)?;
+                        format = rest;
+                    } else {
+                        let c = format.chars().next().unwrap();
+                        w.write_char(c)?;
+                        format = &format[c.len_utf8()..];
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
     #[cfg(feature = "alloc")]
     fn format_fixed(&self, w: &mut impl Write, spec: &Fixed) -> fmt::Result {
         use Fixed::*;
@@ -248,36 +314,57 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
                     Ok(())
                 } else {
                     w.write_str(decimal_point(self.locale))?;
-                    if nano % 1_000_000 == 0 {
-                        write!(w, "{:03}", nano / 1_000_000)
-                    } else if nano % 1_000 == 0 {
-                        write!(w, "{:06}", nano / 1_000)
-                    } else {
-                        write!(w, "{:09}", nano)
-                    }
+                    write_fraction(w, nano, fraction_digits(nano))
                 }
             }
             (Nanosecond3, _, Some(t), _) => {
                 w.write_str(decimal_point(self.locale))?;
-                write!(w, "{:03}", t.nanosecond() / 1_000_000 % 1000)
+                write_fraction(w, t.nanosecond() % 1_000_000_000, 3)
             }
             (Nanosecond6, _, Some(t), _) => {
                 w.write_str(decimal_point(self.locale))?;
-                write!(w, "{:06}", t.nanosecond() / 1_000 % 1_000_000)
+                write_fraction(w, t.nanosecond() % 1_000_000_000, 6)
             }
             (Nanosecond9, _, Some(t), _) => {
                 w.write_str(decimal_point(self.locale))?;
-                write!(w, "{:09}", t.nanosecond() % 1_000_000_000)
+                write_fraction(w, t.nanosecond() % 1_000_000_000, 9)
             }
             (Internal(InternalFixed { val: Nanosecond3NoDot }), _, Some(t), _) => {
-                write!(w, "{:03}", t.nanosecond() / 1_000_000 % 1_000)
+                write_fraction(w, t.nanosecond() % 1_000_000_000, 3)
             }
             (Internal(InternalFixed { val: Nanosecond6NoDot }), _, Some(t), _) => {
-                write!(w, "{:06}", t.nanosecond() / 1_000 % 1_000_000)
+                write_fraction(w, t.nanosecond() % 1_000_000_000, 6)
             }
             (Internal(InternalFixed { val: Nanosecond9NoDot }), _, Some(t), _) => {
-                write!(w, "{:09}", t.nanosecond() % 1_000_000_000)
+                write_fraction(w, t.nanosecond() % 1_000_000_000, 9)
             }
+            (Internal(InternalFixed { val: TimestampFraction }), Some(d), Some(t), _) => {
+                let offset = self.off.as_ref().map(|(_, o)| i64::from(o.local_minus_utc()));
+                let timestamp = d.and_time(t).and_utc().timestamp() - offset.unwrap_or(0);
+                let nano = t.nanosecond() % 1_000_000_000;
+                // A negative timestamp is rounded towards negative infinity, with a positive
+                // nanosecond part. As a decimal number the fraction counts the other way.
+                let (secs, nano) = match timestamp < 0 && nano > 0 {
+                    true => (timestamp + 1, 1_000_000_000 - nano),
+                    false => (timestamp, nano),
+                };
+                if secs == 0 && timestamp < 0 {
+                    w.write_char('-')?;
+                }
+                write!(w, "{}", secs)?;
+                if nano == 0 {
+                    Ok(())
+                } else {
+                    w.write_char('.')?;
+                    write_fraction(w, nano, fraction_digits(nano))
+                }
+            }
+            #[cfg(feature = "unstable-locales")]
+            (
+                Internal(InternalFixed { val: val @ (EraName | EraYear | EraFullYear) }),
+                Some(d),
+                ..,
+            ) => self.format_era(w, val, d),
             (TimezoneName, _, _, Some((tz_name, _))) => write!(w, "{}", tz_name),
             (TimezoneOffset | TimezoneOffsetZ, _, _, Some((_, off))) => {
                 let offset_format = OffsetFormat {
@@ -318,13 +405,9 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             (RFC2822, Some(d), Some(t), Some((_, off))) => {
                 write_rfc2822(w, crate::NaiveDateTime::new(d, t), *off)
             }
-            (RFC3339, Some(d), Some(t), Some((_, off))) => write_rfc3339(
-                w,
-                crate::NaiveDateTime::new(d, t),
-                *off,
-                SecondsFormat::AutoSi,
-                false,
-            ),
+            (RFC3339, Some(d), Some(t), Some((_, off))) => {
+                write_rfc3339(w, crate::NaiveDateTime::new(d, t), *off, Rfc3339Options::new())
+            }
             _ => Err(fmt::Error), // insufficient arguments for given format
         }
     }
@@ -384,7 +467,6 @@ pub fn format_item(
     .fmt(w)
 }
 
-#[cfg(any(feature = "alloc", feature = "serde"))]
 impl OffsetFormat {
     /// Writes an offset from UTC with the format defined by `self`.
     fn format(&self, w: &mut impl Write, off: FixedOffset) -> fmt::Result {
@@ -492,15 +574,228 @@ pub enum SecondsFormat {
     __NonExhaustive,
 }
 
+/// Options for writing a date and time in the ISO 8601 basic format, such as
+/// `20240507T123456.789Z`.
+///
+/// The basic format is the compact form of ISO 8601 without separators between the fields.
+/// By default all non-zero sub-second digits are written with a full stop as the decimal
+/// separator, and a zero offset is written as `Z`.
+///
+/// See [`NaiveDateTime::to_iso8601_basic_opts`] and
+/// [`DateTime::to_iso8601_basic_opts`](crate::DateTime::to_iso8601_basic_opts) for usage.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use chrono::format::Iso8601BasicOptions;
+/// use chrono::{NaiveDate, SecondsFormat};
+///
+/// let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_milli_opt(12, 34, 56, 789).unwrap();
+/// let opts = Iso8601BasicOptions::new().with_seconds_format(SecondsFormat::Micros);
+/// assert_eq!(dt.to_iso8601_basic_opts(opts), "20240507T123456.789000");
+/// assert_eq!(dt.to_iso8601_basic_opts(opts.with_decimal_comma(true)), "20240507T123456,789000");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Iso8601BasicOptions {
+    secform: SecondsFormat,
+    decimal_comma: bool,
+    use_z: bool,
+}
+
+impl Iso8601BasicOptions {
+    /// Makes new `Iso8601BasicOptions` with the default options.
+    #[must_use]
+    pub const fn new() -> Iso8601BasicOptions {
+        Iso8601BasicOptions { secform: SecondsFormat::AutoSi, decimal_comma: false, use_z: true }
+    }
+
+    /// Sets how many sub-second digits are written.
+    #[must_use]
+    pub const fn with_seconds_format(mut self, secform: SecondsFormat) -> Iso8601BasicOptions {
+        self.secform = secform;
+        self
+    }
+
+    /// Writes a comma instead of a full stop as the decimal separator, which ISO 8601 prefers.
+    #[must_use]
+    pub const fn with_decimal_comma(mut self, decimal_comma: bool) -> Iso8601BasicOptions {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Writes a zero offset as `Z` if `use_z` is true, and as `+0000` otherwise.
+    #[must_use]
+    pub const fn with_use_z(mut self, use_z: bool) -> Iso8601BasicOptions {
+        self.use_z = use_z;
+        self
+    }
+}
+
+impl Default for Iso8601BasicOptions {
+    fn default() -> Self {
+        Iso8601BasicOptions::new()
+    }
+}
+
+/// Options for writing a date and time in the RFC 3339 format, such as
+/// `2024-05-07T12:34:56.789+02:00`.
+///
+/// By default all non-zero sub-second digits are written, a zero offset is written as `+00:00`,
+/// and the date and time are separated by an uppercase `T`. This is the same as
+/// [`DateTime::to_rfc3339`](crate::DateTime::to_rfc3339).
+///
+/// Every RFC 3339 method on `DateTime` that takes a [`SecondsFormat`] also accepts
+/// `Rfc3339Options`.
+///
+/// See [`DateTime::to_rfc3339_with`](crate::DateTime::to_rfc3339_with) for usage.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use chrono::format::Rfc3339Options;
+/// use chrono::{SecondsFormat, TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 5, 7, 12, 34, 56).unwrap();
+/// let opts = Rfc3339Options::new().with_seconds_format(SecondsFormat::Millis).with_use_z(true);
+/// assert_eq!(dt.to_rfc3339_with(opts), "2024-05-07T12:34:56.000Z");
+/// let opts = opts.with_space_separator(true);
+/// assert_eq!(dt.to_rfc3339_with(opts), "2024-05-07 12:34:56.000Z");
+/// let opts = opts.with_space_separator(false).with_uppercase(false);
+/// assert_eq!(dt.to_rfc3339_with(opts), "2024-05-07t12:34:56.000z");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rfc3339Options {
+    secform: SecondsFormat,
+    use_z: bool,
+    space_separator: bool,
+    uppercase: bool,
+}
+
+impl Rfc3339Options {
+    /// Makes new `Rfc3339Options` with the default options.
+    #[must_use]
+    pub const fn new() -> Rfc3339Options {
+        Rfc3339Options {
+            secform: SecondsFormat::AutoSi,
+            use_z: false,
+            space_separator: false,
+            uppercase: true,
+        }
+    }
+
+    /// Sets how many sub-second digits are written.
+    #[must_use]
+    pub const fn with_seconds_format(mut self, secform: SecondsFormat) -> Rfc3339Options {
+        self.secform = secform;
+        self
+    }
+
+    /// Writes a zero offset as `Z` if `use_z` is true, and as `+00:00` otherwise.
+    #[must_use]
+    pub const fn with_use_z(mut self, use_z: bool) -> Rfc3339Options {
+        self.use_z = use_z;
+        self
+    }
+
+    /// Separates the date and the time with a space instead of a `T`.
+    ///
+    /// RFC 3339 allows this for readability, but ISO 8601 does not.
+    #[must_use]
+    pub const fn with_space_separator(mut self, space_separator: bool) -> Rfc3339Options {
+        self.space_separator = space_separator;
+        self
+    }
+
+    /// Writes the `T` separator and the `Z` of a zero offset in uppercase if `uppercase` is true,
+    /// and as `t` and `z` otherwise.
+    #[must_use]
+    pub const fn with_uppercase(mut self, uppercase: bool) -> Rfc3339Options {
+        self.uppercase = uppercase;
+        self
+    }
+}
+
+impl Default for Rfc3339Options {
+    fn default() -> Self {
+        Rfc3339Options::new()
+    }
+}
+
+impl From<SecondsFormat> for Rfc3339Options {
+    fn from(secform: SecondsFormat) -> Self {
+        Rfc3339Options::new().with_seconds_format(secform)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Writes the date, time and optionally the offset in the ISO 8601 basic format, such as
+/// `20240507T123456.789+0200`.
+pub(crate) fn write_iso8601_basic(
+    w: &mut impl Write,
+    dt: NaiveDateTime,
+    off: Option<FixedOffset>,
+    opts: Iso8601BasicOptions,
+) -> fmt::Result {
+    let year = dt.date().year();
+    if (0..=9999).contains(&year) {
+        write_hundreds(w, (year / 100) as u8)?;
+        write_hundreds(w, (year % 100) as u8)?;
+    } else {
+        // ISO 8601 requires the explicit sign for out-of-range years
+        write!(w, "{:+05}", year)?;
+    }
+    write_hundreds(w, dt.date().month() as u8)?;
+    write_hundreds(w, dt.date().day() as u8)?;
+
+    w.write_char('T')?;
+
+    let (hour, min, mut sec) = dt.time().hms();
+    let mut nano = dt.nanosecond();
+    if nano >= 1_000_000_000 {
+        sec += 1;
+        nano -= 1_000_000_000;
+    }
+    write_hundreds(w, hour as u8)?;
+    write_hundreds(w, min as u8)?;
+    write_hundreds(w, sec as u8)?;
+
+    let digits = match opts.secform {
+        SecondsFormat::Secs => 0,
+        SecondsFormat::Millis => 3,
+        SecondsFormat::Micros => 6,
+        SecondsFormat::Nanos => 9,
+        SecondsFormat::AutoSi if nano == 0 => 0,
+        SecondsFormat::AutoSi => fraction_digits(nano),
+        SecondsFormat::__NonExhaustive => unreachable!(),
+    };
+    if digits > 0 {
+        w.write_char(if opts.decimal_comma { ',' } else { '.' })?;
+        write_fraction(w, nano, digits)?;
+    }
+
+    match off {
+        Some(off) => OffsetFormat {
+            precision: OffsetPrecision::Minutes,
+            colons: Colons::None,
+            allow_zulu: opts.use_z,
+            padding: Pad::Zero,
+        }
+        .format(w, off),
+        None => Ok(()),
+    }
+}
+
 /// Writes the date, time and offset to the string. same as `%Y-%m-%dT%H:%M:%S%.f%:z`
 #[inline]
-#[cfg(any(feature = "alloc", feature = "serde"))]
 pub(crate) fn write_rfc3339(
     w: &mut impl Write,
     dt: NaiveDateTime,
     off: FixedOffset,
-    secform: SecondsFormat,
-    use_z: bool,
+    opts: Rfc3339Options,
 ) -> fmt::Result {
     let year = dt.date().year();
     if (0..=9999).contains(&year) {
@@ -515,7 +810,11 @@ pub(crate) fn write_rfc3339(
     w.write_char('-')?;
     write_hundreds(w, dt.date().day() as u8)?;
 
-    w.write_char('T')?;
+    w.write_char(match (opts.space_separator, opts.uppercase) {
+        (true, _) => ' ',
+        (false, true) => 'T',
+        (false, false) => 't',
+    })?;
 
     let (hour, min, mut sec) = dt.time().hms();
     let mut nano = dt.nanosecond();
@@ -530,28 +829,27 @@ pub(crate) fn write_rfc3339(
     let sec = sec;
     write_hundreds(w, sec as u8)?;
 
-    match secform {
-        SecondsFormat::Secs => {}
-        SecondsFormat::Millis => write!(w, ".{:03}", nano / 1_000_000)?,
-        SecondsFormat::Micros => write!(w, ".{:06}", nano / 1000)?,
-        SecondsFormat::Nanos => write!(w, ".{:09}", nano)?,
-        SecondsFormat::AutoSi => {
-            if nano == 0 {
-            } else if nano % 1_000_000 == 0 {
-                write!(w, ".{:03}", nano / 1_000_000)?
-            } else if nano % 1_000 == 0 {
-                write!(w, ".{:06}", nano / 1_000)?
-            } else {
-                write!(w, ".{:09}", nano)?
-            }
-        }
+    let digits = match opts.secform {
+        SecondsFormat::Secs => 0,
+        SecondsFormat::Millis => 3,
+        SecondsFormat::Micros => 6,
+        SecondsFormat::Nanos => 9,
+        SecondsFormat::AutoSi if nano == 0 => 0,
+        SecondsFormat::AutoSi => fraction_digits(nano),
         SecondsFormat::__NonExhaustive => unreachable!(),
     };
+    if digits > 0 {
+        w.write_char('.')?;
+        write_fraction(w, nano, digits)?;
+    }
 
+    if opts.use_z && !opts.uppercase && off.local_minus_utc() == 0 {
+        return w.write_char('z');
+    }
     OffsetFormat {
         precision: OffsetPrecision::Minutes,
         colons: Colons::Colon,
-        allow_zulu: use_z,
+        allow_zulu: opts.use_z,
         padding: Pad::Zero,
     }
     .format(w, off)
@@ -563,6 +861,19 @@ pub(crate) fn write_rfc2822(
     w: &mut impl Write,
     dt: NaiveDateTime,
     off: FixedOffset,
+) -> fmt::Result {
+    write_rfc2822_opts(w, dt, off, true, false)
+}
+
+#[cfg(feature = "alloc")]
+/// write datetimes like `write_rfc2822`, optionally without the day of the week and with `GMT` as
+/// the zone of a zero offset
+pub(crate) fn write_rfc2822_opts(
+    w: &mut impl Write,
+    dt: NaiveDateTime,
+    off: FixedOffset,
+    with_weekday: bool,
+    use_gmt: bool,
 ) -> fmt::Result {
     let year = dt.year();
     // RFC2822 is only defined on years 0 through 9999
@@ -572,8 +883,10 @@ pub(crate) fn write_rfc2822(
 
     let english = default_locale();
 
-    w.write_str(short_weekdays(english)[dt.weekday().num_days_from_sunday() as usize])?;
-    w.write_str(", ")?;
+    if with_weekday {
+        w.write_str(short_weekdays(english)[dt.weekday().num_days_from_sunday() as usize])?;
+        w.write_str(", ")?;
+    }
     let day = dt.day();
     if day < 10 {
         w.write_char((b'0' + day as u8) as char)?;
@@ -595,6 +908,9 @@ pub(crate) fn write_rfc2822(
     let sec = sec + dt.nanosecond() / 1_000_000_000;
     write_hundreds(w, sec as u8)?;
     w.write_char(' ')?;
+    if use_gmt && off.local_minus_utc() == 0 {
+        return w.write_str("GMT");
+    }
     OffsetFormat {
         precision: OffsetPrecision::Minutes,
         colons: Colons::None,
@@ -604,16 +920,107 @@ pub(crate) fn write_rfc2822(
     .format(w, off)
 }
 
+/// A writer into a fixed-size byte buffer, for formatting without allocating.
+///
+/// Writing fails if the buffer is full; the buffer then still contains only complete writes, so it
+/// is always valid UTF-8.
+pub(crate) struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> BufWriter<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        BufWriter { buf, len: 0 }
+    }
+
+    /// Returns the string written so far.
+    pub(crate) fn into_str(self) -> &'a str {
+        let buf: &'a [u8] = self.buf;
+        core::str::from_utf8(&buf[..self.len]).expect("only complete strs are written")
+    }
+}
+
+impl Write for BufWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        self.buf.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// The decimal digits of all numbers below 100, two digits each.
+const DIGIT_PAIRS: &str = "\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+/// The number of decimal digits of `u64::MAX`.
+const MAX_DIGITS: usize = 20;
+
+/// Zero padding up to `MAX_DIGITS` digits.
+const ZEROS: &str = "00000000000000000000";
+
+/// Equivalent to `{:0width$}` formatting, for `width <= MAX_DIGITS`.
+///
+/// The digits are split into pairs from the end and then written from the start, each pair
+/// straight from `DIGIT_PAIRS`.
+fn write_zero_padded(w: &mut impl Write, mut v: u64, width: usize) -> fmt::Result {
+    let mut pairs = [0u8; MAX_DIGITS / 2];
+    let mut len = 0;
+    while v >= 100 {
+        pairs[len] = (v % 100) as u8;
+        v /= 100;
+        len += 1;
+    }
+    let digits = len * 2 + if v >= 10 { 2 } else { 1 };
+    w.write_str(ZEROS.get(..width.saturating_sub(digits)).ok_or(fmt::Error)?)?;
+    if v >= 10 {
+        write_hundreds(w, v as u8)?;
+    } else {
+        w.write_char((b'0' + v as u8) as char)?;
+    }
+    for &pair in pairs[..len].iter().rev() {
+        write_hundreds(w, pair)?;
+    }
+    Ok(())
+}
+
+/// Writes the first `digits` digits of `nano` zero-padded to 9 digits, for `nano < 10^9`.
+///
+/// This is equivalent to `{:03}` formatting of the milliseconds for 3 digits, `{:06}` of the
+/// microseconds for 6 digits, and `{:09}` of the nanoseconds for 9 digits.
+fn write_fraction(w: &mut impl Write, nano: u32, digits: usize) -> fmt::Result {
+    let v = match digits {
+        3 => nano / 1_000_000,
+        6 => nano / 1_000,
+        _ => nano,
+    };
+    write_zero_padded(w, u64::from(v), digits)
+}
+
+/// The smallest number of digits out of 3, 6 or 9 that shows `nano` exactly.
+fn fraction_digits(nano: u32) -> usize {
+    if nano % 1_000_000 == 0 {
+        3
+    } else if nano % 1_000 == 0 {
+        6
+    } else {
+        9
+    }
+}
+
 /// Equivalent to `{:02}` formatting for n < 100.
 pub(crate) fn write_hundreds(w: &mut impl Write, n: u8) -> fmt::Result {
     if n >= 100 {
         return Err(fmt::Error);
     }
 
-    let tens = b'0' + n / 10;
-    let ones = b'0' + n % 10;
-    w.write_char(tens as char)?;
-    w.write_char(ones as char)
+    let pair = usize::from(n) * 2;
+    w.write_str(&DIGIT_PAIRS[pair..pair + 2])
 }
 
 #[cfg(test)]
@@ -624,6 +1031,39 @@ mod tests {
     #[cfg(feature = "alloc")]
     use crate::{NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
 
+    #[test]
+    fn test_write_digits() {
+        use super::{write_fraction, write_hundreds, write_zero_padded};
+
+        for n in 0..100 {
+            let mut s = String::new();
+            write_hundreds(&mut s, n).unwrap();
+            assert_eq!(s, format!("{:02}", n));
+        }
+        assert!(write_hundreds(&mut String::new(), 100).is_err());
+
+        let values = [0, 1, 9, 10, 99, 100, 101, 999, 1000, 12_345, 999_999_999, u64::MAX];
+        for v in values {
+            for width in 1..=20 {
+                let mut s = String::new();
+                write_zero_padded(&mut s, v, width).unwrap();
+                assert_eq!(s, format!("{:01$}", v, width));
+            }
+        }
+
+        for nano in [0, 1, 999, 1_000, 123_456_789, 100_000_000, 999_999_999] {
+            for (digits, expected) in [
+                (3, format!("{:03}", nano / 1_000_000)),
+                (6, format!("{:06}", nano / 1_000)),
+                (9, format!("{:09}", nano)),
+            ] {
+                let mut s = String::new();
+                write_fraction(&mut s, nano, digits).unwrap();
+                assert_eq!(s, expected);
+            }
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn test_delayed_write_to() {
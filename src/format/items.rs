@@ -0,0 +1,395 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A builder for constructing formatting items without a format string.
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use super::{Fixed, Item, Numeric, Pad, ParseResult, Parsed, parse, parse_and_remainder};
+
+/// Builder for a list of formatting [`Item`]s.
+///
+/// This is an alternative to `strftime`-like format strings that is checked by the compiler:
+/// every method appends a single well-formed item. The result can be used with the
+/// `format_with_items` methods and with [`format::parse()`].
+///
+/// [`format::parse()`]: crate::format::parse()
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{Items, Parsed, StrftimeItems, parse};
+/// use chrono::NaiveDate;
+///
+/// let items = Items::new().year4().lit("-").month2().lit("-").day2().build();
+/// assert_eq!(items, StrftimeItems::new("%Y-%m-%d").parse().unwrap());
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+/// assert_eq!(date.format_with_items(items.iter()).to_string(), "2024-05-07");
+///
+/// let mut parsed = Parsed::new();
+/// parse(&mut parsed, "2024-05-07", items.iter())?;
+/// assert_eq!(parsed.to_naive_date(), Ok(date));
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Items<'a> {
+    items: Vec<Item<'a>>,
+}
+
+impl<'a> Items<'a> {
+    /// Creates an empty builder.
+    #[must_use]
+    pub const fn new() -> Items<'a> {
+        Items { items: Vec::new() }
+    }
+
+    /// Appends an arbitrary formatting item.
+    #[must_use]
+    pub fn item(mut self, item: Item<'a>) -> Items<'a> {
+        self.items.push(item);
+        self
+    }
+
+    /// Appends a numeric item with the given padding.
+    #[must_use]
+    pub fn numeric(self, numeric: Numeric, pad: Pad) -> Items<'a> {
+        self.item(Item::Numeric(numeric, pad))
+    }
+
+    /// Appends a fixed-format item.
+    #[must_use]
+    pub fn fixed(self, fixed: Fixed) -> Items<'a> {
+        self.item(Item::Fixed(fixed))
+    }
+
+    /// Appends a literal, the same as [`Item::Literal`].
+    #[must_use]
+    pub fn lit(self, s: &'a str) -> Items<'a> {
+        self.item(Item::Literal(s))
+    }
+
+    /// Appends whitespace, the same as [`Item::Space`].
+    #[must_use]
+    pub fn space(self, s: &'a str) -> Items<'a> {
+        self.item(Item::Space(s))
+    }
+
+    /// Appends the full year, zero-padded to 4 digits (`%Y`).
+    #[must_use]
+    pub fn year4(self) -> Items<'a> {
+        self.numeric(Numeric::Year, Pad::Zero)
+    }
+
+    /// Appends the year modulo 100, zero-padded to 2 digits (`%y`).
+    #[must_use]
+    pub fn year2(self) -> Items<'a> {
+        self.numeric(Numeric::YearMod100, Pad::Zero)
+    }
+
+    /// Appends the year of the ISO week date, zero-padded to 4 digits (`%G`).
+    #[must_use]
+    pub fn iso_year4(self) -> Items<'a> {
+        self.numeric(Numeric::IsoYear, Pad::Zero)
+    }
+
+    /// Appends the month number, zero-padded to 2 digits (`%m`).
+    #[must_use]
+    pub fn month2(self) -> Items<'a> {
+        self.numeric(Numeric::Month, Pad::Zero)
+    }
+
+    /// Appends the month number without padding (`%-m`).
+    #[must_use]
+    pub fn month(self) -> Items<'a> {
+        self.numeric(Numeric::Month, Pad::None)
+    }
+
+    /// Appends the abbreviated month name (`%b`).
+    #[must_use]
+    pub fn short_month_name(self) -> Items<'a> {
+        self.fixed(Fixed::ShortMonthName)
+    }
+
+    /// Appends the full month name (`%B`).
+    #[must_use]
+    pub fn long_month_name(self) -> Items<'a> {
+        self.fixed(Fixed::LongMonthName)
+    }
+
+    /// Appends the day of the month, zero-padded to 2 digits (`%d`).
+    #[must_use]
+    pub fn day2(self) -> Items<'a> {
+        self.numeric(Numeric::Day, Pad::Zero)
+    }
+
+    /// Appends the day of the month without padding (`%-d`).
+    #[must_use]
+    pub fn day(self) -> Items<'a> {
+        self.numeric(Numeric::Day, Pad::None)
+    }
+
+    /// Appends the day of the year, zero-padded to 3 digits (`%j`).
+    #[must_use]
+    pub fn ordinal3(self) -> Items<'a> {
+        self.numeric(Numeric::Ordinal, Pad::Zero)
+    }
+
+    /// Appends the week number of the ISO week date, zero-padded to 2 digits (`%V`).
+    #[must_use]
+    pub fn iso_week2(self) -> Items<'a> {
+        self.numeric(Numeric::IsoWeek, Pad::Zero)
+    }
+
+    /// Appends the abbreviated weekday name (`%a`).
+    #[must_use]
+    pub fn short_weekday_name(self) -> Items<'a> {
+        self.fixed(Fixed::ShortWeekdayName)
+    }
+
+    /// Appends the full weekday name (`%A`).
+    #[must_use]
+    pub fn long_weekday_name(self) -> Items<'a> {
+        self.fixed(Fixed::LongWeekdayName)
+    }
+
+    /// Appends the hour in the 24-hour clock, zero-padded to 2 digits (`%H`).
+    #[must_use]
+    pub fn hour2(self) -> Items<'a> {
+        self.numeric(Numeric::Hour, Pad::Zero)
+    }
+
+    /// Appends the hour in the 12-hour clock, zero-padded to 2 digits (`%I`).
+    #[must_use]
+    pub fn hour12_2(self) -> Items<'a> {
+        self.numeric(Numeric::Hour12, Pad::Zero)
+    }
+
+    /// Appends `AM` or `PM` (`%p`).
+    #[must_use]
+    pub fn am_pm(self) -> Items<'a> {
+        self.fixed(Fixed::UpperAmPm)
+    }
+
+    /// Appends the minute, zero-padded to 2 digits (`%M`).
+    #[must_use]
+    pub fn minute2(self) -> Items<'a> {
+        self.numeric(Numeric::Minute, Pad::Zero)
+    }
+
+    /// Appends the second, zero-padded to 2 digits (`%S`).
+    #[must_use]
+    pub fn second2(self) -> Items<'a> {
+        self.numeric(Numeric::Second, Pad::Zero)
+    }
+
+    /// Appends an optional dot and the fractional seconds with as many digits as needed (`%.f`).
+    #[must_use]
+    pub fn nanosecond(self) -> Items<'a> {
+        self.fixed(Fixed::Nanosecond)
+    }
+
+    /// Appends a dot and the fractional seconds with exactly 3 digits (`%.3f`).
+    #[must_use]
+    pub fn millis3(self) -> Items<'a> {
+        self.fixed(Fixed::Nanosecond3)
+    }
+
+    /// Appends a dot and the fractional seconds with exactly 6 digits (`%.6f`).
+    #[must_use]
+    pub fn micros6(self) -> Items<'a> {
+        self.fixed(Fixed::Nanosecond6)
+    }
+
+    /// Appends a dot and the fractional seconds with exactly 9 digits (`%.9f`).
+    #[must_use]
+    pub fn nanos9(self) -> Items<'a> {
+        self.fixed(Fixed::Nanosecond9)
+    }
+
+    /// Appends the number of non-leap seconds since the Unix epoch (`%s`).
+    #[must_use]
+    pub fn timestamp(self) -> Items<'a> {
+        self.numeric(Numeric::Timestamp, Pad::None)
+    }
+
+    /// Appends the offset from UTC with a colon, such as `+09:00` (`%:z`).
+    #[must_use]
+    pub fn offset(self) -> Items<'a> {
+        self.fixed(Fixed::TimezoneOffsetColon)
+    }
+
+    /// Appends the offset from UTC with a colon, or `Z` for UTC.
+    #[must_use]
+    pub fn offset_z(self) -> Items<'a> {
+        self.fixed(Fixed::TimezoneOffsetColonZ)
+    }
+
+    /// Appends the time zone name (`%Z`). This item can not be parsed.
+    #[must_use]
+    pub fn timezone_name(self) -> Items<'a> {
+        self.fixed(Fixed::TimezoneName)
+    }
+
+    /// Returns the formatting items built so far.
+    #[must_use]
+    pub fn build(self) -> Vec<Item<'a>> {
+        self.items
+    }
+
+    /// Returns the formatting items built so far as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Item<'a>] {
+        &self.items
+    }
+
+    /// Parses `s` with the formatting items built so far and updates `parsed`.
+    ///
+    /// This is the same as [`format::parse()`], but the items can be reused for any number of
+    /// inputs without re-parsing a format string.
+    ///
+    /// [`format::parse()`]: crate::format::parse()
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` does not match the items, or if a parsed field is inconsistent with
+    /// a value already present in `parsed`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{Items, Parsed};
+    /// use chrono::NaiveTime;
+    ///
+    /// let items = Items::new().hour2().lit(":").minute2();
+    /// for (s, expected) in [("09:30", (9, 30)), ("23:05", (23, 5))] {
+    ///     let mut parsed = Parsed::new();
+    ///     items.parse(&mut parsed, s)?;
+    ///     let (h, m) = expected;
+    ///     assert_eq!(parsed.to_naive_time(), Ok(NaiveTime::from_hms_opt(h, m, 0).unwrap()));
+    /// }
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse(&self, parsed: &mut Parsed, s: &str) -> ParseResult<()> {
+        parse(parsed, s, self.items.iter())
+    }
+
+    /// Parses the start of `s` with the formatting items built so far, updates `parsed` and
+    /// returns the remaining input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start of `s` does not match the items, or if a parsed field is
+    /// inconsistent with a value already present in `parsed`.
+    pub fn parse_and_remainder<'b>(&self, parsed: &mut Parsed, s: &'b str) -> ParseResult<&'b str> {
+        parse_and_remainder(parsed, s, self.items.iter())
+    }
+
+    /// Returns an iterator over the formatting items built so far.
+    pub fn iter(&self) -> core::slice::Iter<'_, Item<'a>> {
+        self.items.iter()
+    }
+}
+
+impl<'a> From<Items<'a>> for Vec<Item<'a>> {
+    fn from(items: Items<'a>) -> Vec<Item<'a>> {
+        items.items
+    }
+}
+
+impl<'a> IntoIterator for Items<'a> {
+    type Item = Item<'a>;
+    type IntoIter = alloc::vec::IntoIter<Item<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b Items<'a> {
+    type Item = &'b Item<'a>;
+    type IntoIter = core::slice::Iter<'b, Item<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a> Extend<Item<'a>> for Items<'a> {
+    fn extend<I: IntoIterator<Item = Item<'a>>>(&mut self, iter: I) {
+        self.items.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Items;
+    use crate::format::{Parsed, StrftimeItems};
+    use crate::{FixedOffset, NaiveDate, TimeZone};
+
+    #[test]
+    fn test_items_equal_strftime() {
+        let cases = [
+            (Items::new().year4().lit("-").month2().lit("-").day2(), "%Y-%m-%d"),
+            (
+                Items::new().hour2().lit(":").minute2().lit(":").second2().nanosecond(),
+                "%H:%M:%S%.f",
+            ),
+            (Items::new().year2().month().day().hour12_2().am_pm(), "%y%-m%-d%I%p"),
+            (Items::new().iso_year4().lit("-W").iso_week2(), "%G-W%V"),
+            (Items::new().short_weekday_name().space(" ").long_month_name(), "%a %B"),
+            (Items::new().millis3().micros6().nanos9(), "%.3f%.6f%.9f"),
+            (Items::new().timestamp().offset().timezone_name(), "%s%:z%Z"),
+        ];
+        for (items, fmt) in cases {
+            assert_eq!(items.build(), StrftimeItems::new(fmt).parse().unwrap(), "{}", fmt);
+        }
+    }
+
+    #[test]
+    fn test_items_format() {
+        let tz = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+        let dt = tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap();
+        let items = Items::new()
+            .long_weekday_name()
+            .lit(", ")
+            .day()
+            .space(" ")
+            .short_month_name()
+            .space(" ")
+            .year4()
+            .lit("T")
+            .hour2()
+            .lit(":")
+            .minute2()
+            .offset_z();
+        assert_eq!(
+            dt.format_with_items(items.iter()).to_string(),
+            "Tuesday, 7 May 2024T13:04+09:00"
+        );
+        assert_eq!(
+            dt.format_with_items(items.into_iter()).to_string(),
+            "Tuesday, 7 May 2024T13:04+09:00"
+        );
+
+        let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+        let items = Items::new().ordinal3();
+        assert_eq!(date.format_with_items((&items).into_iter()).to_string(), "128");
+    }
+
+    #[test]
+    fn test_items_parse() {
+        let items = Items::new().year4().lit("-").month2().lit("-").day2();
+        assert_eq!(items.as_slice(), &StrftimeItems::new("%Y-%m-%d").parse().unwrap()[..]);
+
+        let mut parsed = Parsed::new();
+        items.parse(&mut parsed, "2024-05-07").unwrap();
+        assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_ymd_opt(2024, 5, 7).unwrap()));
+
+        let mut parsed = Parsed::new();
+        assert_eq!(items.parse_and_remainder(&mut parsed, "2024-05-07 rest"), Ok(" rest"));
+        assert!(items.parse(&mut Parsed::new(), "2024-05-07 rest").is_err());
+        assert!(items.parse(&mut Parsed::new(), "2024/05/07").is_err());
+    }
+}
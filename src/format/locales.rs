@@ -2,6 +2,8 @@
 mod localized {
     use pure_rust_locales::{Locale, locale_match};
 
+    use crate::{Datelike, NaiveDate, Weekday};
+
     pub(crate) const fn default_locale() -> Locale {
         Locale::POSIX
     }
@@ -45,6 +47,76 @@ mod localized {
     pub(crate) const fn t_fmt_ampm(locale: Locale) -> &'static str {
         locale_match!(locale => LC_TIME::T_FMT_AMPM)
     }
+
+    pub(crate) const fn eras(locale: Locale) -> Option<&'static [&'static str]> {
+        locale_match!(locale => LC_TIME::ERA)
+    }
+
+    /// The first day of the week in the locale.
+    ///
+    /// POSIX locales give a date that falls on the first day of the week in the `week` field, and
+    /// count the days from there in `first_weekday`, starting at 1. Missing fields default to
+    /// 1997-11-30, a Sunday, and to 1, like in glibc.
+    pub(crate) fn first_weekday(locale: Locale) -> Weekday {
+        let base = match locale_match!(locale => LC_TIME::WEEK) {
+            Some(&[_, base, ..]) => base,
+            _ => 19971130,
+        };
+        let first = locale_match!(locale => LC_TIME::FIRST_WEEKDAY).unwrap_or(1);
+        let base = i32::try_from(base).ok().and_then(|base| {
+            NaiveDate::from_ymd_opt(base / 10000, (base / 100 % 100) as u32, (base % 100) as u32)
+        });
+        let base = base.map_or(Weekday::Sun, |date| date.weekday());
+        (1..first.clamp(1, 7)).fold(base, |day, _| day.succ())
+    }
+
+    /// An era of a locale's alternative year representation, as used by `%EC`, `%Ey` and `%EY`.
+    pub(crate) struct Era {
+        /// The name of the era.
+        pub(crate) name: &'static str,
+        /// The year within the era.
+        pub(crate) year: i64,
+        /// The format for the full year, using `%EC` and `%Ey`.
+        pub(crate) format: &'static str,
+    }
+
+    /// Finds the era of the locale that contains the given date.
+    ///
+    /// Each era is described by a POSIX `era` segment of the form
+    /// `direction:offset:start_date:end_date:era_name:era_format`.
+    pub(crate) fn era(locale: Locale, year: i32, month: u32, day: u32) -> Option<Era> {
+        let date = (i64::from(year), month, day);
+        eras(locale)?.iter().find_map(|segment| {
+            let mut fields = segment.splitn(6, ':');
+            let direction = fields.next()?;
+            let offset = fields.next()?.parse::<i64>().ok()?;
+            let start = era_date(fields.next()?)?;
+            let end = era_date(fields.next()?)?;
+            let (name, format) = (fields.next()?, fields.next()?);
+            if date < start.min(end) || date > start.max(end) {
+                return None;
+            }
+            let years = (date.0 - start.0).abs();
+            let year = if direction == "-" { offset - years } else { offset + years };
+            Some(Era { name, year, format })
+        })
+    }
+
+    /// Parses a date of an era segment, `+*` and `-*` are the end and beginning of time.
+    ///
+    /// Years are numbered without a year 0, so `-0001/12/31` is the last day of 1 BCE.
+    fn era_date(s: &str) -> Option<(i64, u32, u32)> {
+        match s {
+            "+*" => return Some((i64::MAX, 12, 31)),
+            "-*" => return Some((i64::MIN, 1, 1)),
+            _ => {}
+        }
+        let mut parts = s.splitn(3, '/');
+        let year = parts.next()?.parse::<i64>().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Some((if year < 0 { year + 1 } else { year }, month, day))
+    }
 }
 
 #[cfg(feature = "unstable-locales")]
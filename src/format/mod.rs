@@ -34,18 +34,28 @@
 #[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
 use alloc::boxed::Box;
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::NonZeroU32;
 use core::str::FromStr;
 #[cfg(feature = "std")]
 use std::error::Error;
 
 use crate::{Month, ParseMonthError, ParseWeekdayError, Weekday};
 
+#[cfg(feature = "unstable-locales")]
+mod duration;
 mod formatting;
+#[cfg(feature = "alloc")]
+mod items;
 mod parsed;
+mod partial;
+#[cfg(feature = "alloc")]
+mod spec;
 
 // due to the size of parsing routines, they are in separate modules.
 mod parse;
 pub(crate) mod scan;
+mod sniff;
 
 pub mod strftime;
 
@@ -54,26 +64,35 @@ pub mod strftime;
 // not require `alloc`.
 pub(crate) mod locales;
 
-pub use formatting::SecondsFormat;
+#[cfg(feature = "unstable-locales")]
+pub use duration::{LocalizedDuration, UnitWidth};
 pub(crate) use formatting::write_hundreds;
-#[cfg(feature = "alloc")]
-pub(crate) use formatting::write_rfc2822;
-#[cfg(any(feature = "alloc", feature = "serde"))]
-pub(crate) use formatting::write_rfc3339;
+pub(crate) use formatting::{BufWriter, write_rfc3339};
 #[cfg(feature = "alloc")]
 #[allow(deprecated)]
 pub use formatting::{DelayedFormat, format, format_item};
+pub use formatting::{Iso8601BasicOptions, Rfc3339Options, SecondsFormat};
+#[cfg(feature = "alloc")]
+pub(crate) use formatting::{write_iso8601_basic, write_rfc2822, write_rfc2822_opts};
+#[cfg(feature = "alloc")]
+pub use items::Items;
 #[cfg(feature = "unstable-locales")]
 pub use locales::Locale;
-pub(crate) use parse::parse_rfc3339;
-pub use parse::{parse, parse_and_remainder};
+pub use parse::{ParseOptions, parse_and_remainder_with_options, parse_with_options};
+pub use parse::{parse, parse_and_remainder, parse_rfc3339_fast, scan_datetime};
+#[cfg(feature = "unstable-locales")]
+pub use parse::{parse_and_remainder_localized, parse_localized};
+pub(crate) use parse::{
+    parse_iso8601_basic, parse_rfc2822, parse_rfc2822_with_comment, parse_rfc3339,
+    parse_rfc3339_relaxed,
+};
 pub use parsed::Parsed;
+pub use partial::PartialDateTime;
+pub use sniff::{DetectedFormat, sniff};
+#[cfg(feature = "alloc")]
+pub use spec::FormatSpec;
 pub use strftime::StrftimeItems;
 
-/// An uninhabited type used for `InternalNumeric` and `InternalFixed` below.
-#[derive(Clone, PartialEq, Eq, Hash)]
-enum Void {}
-
 /// Padding characters for numeric items.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub enum Pad {
@@ -156,10 +175,60 @@ pub enum Numeric {
     Internal(InternalNumeric),
 }
 
+impl Numeric {
+    /// Returns this item padded to an explicit minimal width instead of its formatting width, as
+    /// in `%8Y`. The parsing width is raised to at least `width`.
+    ///
+    /// Returns `None` for an item that already has an explicit width.
+    pub(crate) fn with_width(&self, width: u8) -> Option<Numeric> {
+        let index = Numeric::PLAIN.iter().position(|n| n == self)?;
+        let val = InternalInternalNumeric::Width { index: index as u8, width };
+        Some(Numeric::Internal(InternalNumeric { val }))
+    }
+
+    /// Returns the wrapped item and the width of an item made with [`Numeric::with_width`].
+    pub(crate) fn explicit_width(&self) -> Option<(&'static Numeric, u8)> {
+        match *self {
+            Numeric::Internal(InternalNumeric {
+                val: InternalInternalNumeric::Width { index, width },
+            }) => Some((&Numeric::PLAIN[usize::from(index)], width)),
+            _ => None,
+        }
+    }
+
+    /// All items that can be given an explicit width, indexed by `InternalInternalNumeric::Width`.
+    const PLAIN: [Numeric; 21] = {
+        use self::Numeric::*;
+        [
+            Year,
+            YearDiv100,
+            YearMod100,
+            IsoYear,
+            IsoYearDiv100,
+            IsoYearMod100,
+            Quarter,
+            Month,
+            Day,
+            WeekFromSun,
+            WeekFromMon,
+            IsoWeek,
+            NumDaysFromSun,
+            WeekdayFromMon,
+            Ordinal,
+            Hour,
+            Hour12,
+            Minute,
+            Second,
+            Nanosecond,
+            Timestamp,
+        ]
+    };
+}
+
 /// An opaque type representing numeric item types for internal uses only.
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub struct InternalNumeric {
-    _dummy: Void,
+    val: InternalInternalNumeric,
 }
 
 impl fmt::Debug for InternalNumeric {
@@ -168,6 +237,15 @@ impl fmt::Debug for InternalNumeric {
     }
 }
 
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum InternalInternalNumeric {
+    /// A numeric item padded to an explicit minimal width (`%8Y`, `%_5d`).
+    ///
+    /// The padding character is the `Pad` of the enclosing item. `index` points into
+    /// `Numeric::PLAIN`, which keeps `Item` small.
+    Width { index: u8, width: u8 },
+}
+
 /// Fixed-format item types.
 ///
 /// They have their own rules of formatting and parsing.
@@ -279,6 +357,32 @@ enum InternalInternal {
     Nanosecond6NoDot,
     /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 9 and there is no leading dot.
     Nanosecond9NoDot,
+    /// The UNIX timestamp with a decimal fraction of a second (`%.s`).
+    ///
+    /// The fraction has 0, 3, 6 or 9 digits like [`Nanosecond`](#variant.Nanosecond). Unlike
+    /// `%s%.f` the fraction is correct for timestamps before 1970, which are negative.
+    TimestampFraction,
+    /// The name of the era in the locale's alternative year representation (`%EC`).
+    ///
+    /// # Errors
+    ///
+    /// If you try to use this for parsing.
+    #[cfg(feature = "unstable-locales")]
+    EraName,
+    /// The year within the era in the locale's alternative year representation (`%Ey`).
+    ///
+    /// # Errors
+    ///
+    /// If you try to use this for parsing.
+    #[cfg(feature = "unstable-locales")]
+    EraYear,
+    /// The full alternative year representation of the locale (`%EY`).
+    ///
+    /// # Errors
+    ///
+    /// If you try to use this for parsing.
+    #[cfg(feature = "unstable-locales")]
+    EraFullYear,
 }
 
 /// Type for specifying the format of UTC offsets.
@@ -326,6 +430,40 @@ pub enum Colons {
     Maybe,
 }
 
+/// The kind of zone found in an RFC 2822 date and time.
+///
+/// RFC 2822 gives some offsets a meaning beyond their value: `-0000` says the time is in UTC but
+/// the local time zone is unknown, while `+0000` means the local time zone *is* UTC. The obsolete
+/// zone names are similarly ambiguous. This is returned by
+/// [`DateTime::parse_from_rfc2822_with_zone`](crate::DateTime::parse_from_rfc2822_with_zone) so
+/// that the distinction is not lost.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rfc2822Zone {
+    /// A numeric offset other than `-0000`, such as `+0200` or `+0000`.
+    Offset,
+    /// The numeric offset `-0000`: the time is in UTC, but the local time zone is not known.
+    UnknownLocal,
+    /// The obsolete zone names `UT` and `GMT`: the time is in UTC.
+    Universal,
+    /// One of the obsolete North American zone names `EST`, `EDT`, `CST`, `CDT`, `MST`, `MDT`,
+    /// `PST` or `PDT`.
+    NorthAmerican,
+    /// A single-letter military zone, including `Z`.
+    ///
+    /// These were defined with the wrong sign in RFC 822. RFC 2822 says they should all be treated
+    /// as `-0000`, and so they are parsed as an offset of zero.
+    Military,
+}
+
+impl Rfc2822Zone {
+    /// Returns `true` if the zone does not say anything about the local time zone, which is the
+    /// case for `-0000` and the military zones.
+    pub const fn is_unknown_local(&self) -> bool {
+        matches!(self, Rfc2822Zone::UnknownLocal | Rfc2822Zone::Military)
+    }
+}
+
 /// A single formatting item. This is used for both formatting and parsing.
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum Item<'a> {
@@ -385,13 +523,87 @@ impl Item<'_> {
 }
 
 /// An error from the `parse` function.
-#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
-pub struct ParseError(ParseErrorKind);
+///
+/// Besides the [kind](ParseError::kind) of error, it may record the [byte offset](ParseError::offset)
+/// in the input at which parsing failed. The offset is diagnostic only: two `ParseError`s compare
+/// equal if their kinds are equal.
+#[derive(Clone, Copy)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    // The offset plus one, which keeps `ParseError` at 8 bytes as it is returned on every parsing
+    // path. An offset that doesn't fit in a `u32` is not recorded.
+    offset: Option<NonZeroU32>,
+}
 
 impl ParseError {
+    const fn new(kind: ParseErrorKind) -> ParseError {
+        ParseError { kind, offset: None }
+    }
+
     /// The category of parse error
     pub const fn kind(&self) -> ParseErrorKind {
-        self.0
+        self.kind
+    }
+
+    /// The byte index in the input at which parsing failed, if known.
+    ///
+    /// This is the start of the formatting item that could not be parsed, or for
+    /// [`ParseErrorKind::TooLong`] the start of the trailing input. It is `None` for errors that
+    /// are not tied to a position in the input, such as a [`ParseErrorKind::NotEnough`] error from
+    /// [`Parsed`], or a [`ParseErrorKind::BadFormat`] error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::ParseErrorKind;
+    /// use chrono::NaiveDate;
+    ///
+    /// let err = NaiveDate::parse_from_str("2024-05-x7", "%Y-%m-%d").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::Invalid);
+    /// assert_eq!(err.offset(), Some(8));
+    ///
+    /// let err = NaiveDate::parse_from_str("2024-05-07 ", "%Y-%m-%d").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::TooLong);
+    /// assert_eq!(err.offset(), Some(10));
+    /// ```
+    pub const fn offset(&self) -> Option<usize> {
+        match self.offset {
+            Some(offset) => Some(offset.get() as usize - 1),
+            None => None,
+        }
+    }
+
+    /// Records `offset` as the position of this error, unless it already has one.
+    pub(crate) const fn with_offset(self, offset: usize) -> ParseError {
+        if self.offset.is_some() || offset >= u32::MAX as usize {
+            return self;
+        }
+        ParseError { kind: self.kind, offset: NonZeroU32::new(offset as u32 + 1) }
+    }
+}
+
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParseError")
+            .field("kind", &self.kind)
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
+
+// The offset is left out so that an error still compares equal to the `ParseError` constants and
+// to errors for the same reason at a different position, as it did before the offset was added.
+impl PartialEq for ParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl Eq for ParseError {}
+
+impl Hash for ParseError {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
     }
 }
 
@@ -437,7 +649,7 @@ pub type ParseResult<T> = Result<T, ParseError>;
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.0 {
+        match self.kind {
             ParseErrorKind::OutOfRange => write!(f, "input is out of range"),
             ParseErrorKind::Impossible => write!(f, "no possible date and time matching input"),
             ParseErrorKind::NotEnough => write!(f, "input is not enough for unique date and time"),
@@ -459,13 +671,13 @@ impl Error for ParseError {
 }
 
 // to be used in this module and submodules
-pub(crate) const OUT_OF_RANGE: ParseError = ParseError(ParseErrorKind::OutOfRange);
-const IMPOSSIBLE: ParseError = ParseError(ParseErrorKind::Impossible);
-const NOT_ENOUGH: ParseError = ParseError(ParseErrorKind::NotEnough);
-const INVALID: ParseError = ParseError(ParseErrorKind::Invalid);
-const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
-pub(crate) const TOO_LONG: ParseError = ParseError(ParseErrorKind::TooLong);
-const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
+pub(crate) const OUT_OF_RANGE: ParseError = ParseError::new(ParseErrorKind::OutOfRange);
+pub(crate) const IMPOSSIBLE: ParseError = ParseError::new(ParseErrorKind::Impossible);
+pub(crate) const NOT_ENOUGH: ParseError = ParseError::new(ParseErrorKind::NotEnough);
+pub(crate) const INVALID: ParseError = ParseError::new(ParseErrorKind::Invalid);
+pub(crate) const TOO_SHORT: ParseError = ParseError::new(ParseErrorKind::TooShort);
+pub(crate) const TOO_LONG: ParseError = ParseError::new(ParseErrorKind::TooLong);
+const BAD_FORMAT: ParseError = ParseError::new(ParseErrorKind::BadFormat);
 
 // this implementation is here only because we need some private code from `scan`
 
@@ -5,13 +5,15 @@
 //! Date and time parsing routines.
 
 use core::borrow::Borrow;
+use core::ops::Range;
 use core::str;
 
+use super::locales::{self, Locale};
 use super::scan;
 use super::{BAD_FORMAT, INVALID, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
 use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad, Parsed};
-use super::{ParseError, ParseResult};
-use crate::{DateTime, FixedOffset, Weekday};
+use super::{ParseError, ParseResult, Rfc2822Zone};
+use crate::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone, Weekday};
 
 fn set_weekday_with_num_days_from_sunday(p: &mut Parsed, v: i64) -> ParseResult<()> {
     p.set_weekday(match v {
@@ -39,7 +41,19 @@ fn set_weekday_with_number_from_monday(p: &mut Parsed, v: i64) -> ParseResult<()
     })
 }
 
-fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, ())> {
+pub(crate) fn parse_rfc2822<'a>(
+    parsed: &mut Parsed,
+    s: &'a str,
+) -> ParseResult<(&'a str, Rfc2822Zone)> {
+    parse_rfc2822_with_comment(parsed, s).map(|(s, zone, _)| (s, zone))
+}
+
+/// Same as `parse_rfc2822`, but also returns the contents of the first comment following the
+/// zone, without the enclosing parentheses.
+pub(crate) fn parse_rfc2822_with_comment<'a>(
+    parsed: &mut Parsed,
+    mut s: &'a str,
+) -> ParseResult<(&'a str, Rfc2822Zone, Option<&'a str>)> {
     macro_rules! try_consume {
         ($e:expr) => {{
             let (s_, v) = $e?;
@@ -141,13 +155,55 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     }
 
     s = scan::space(s)?; // mandatory
-    parsed.set_offset(i64::from(try_consume!(scan::timezone_offset_2822(s))))?;
+    let (offset, zone) = try_consume!(scan::timezone_offset_2822(s));
+    parsed.set_offset(i64::from(offset))?;
 
     // optional comments
+    let mut comment = None;
     while let Ok((s_out, ())) = scan::comment_2822(s) {
+        if comment.is_none() {
+            let text = s.trim_start();
+            comment = Some(&text[1..text.len() - s_out.len() - 1]);
+        }
         s = s_out;
     }
 
+    Ok((s, zone, comment))
+}
+
+/// Parses a date and time in the ISO 8601 basic format, such as `20240507T123456.789`, followed
+/// by an offset such as `Z`, `+02` or `+0200` if `with_offset` is true.
+///
+/// The year must have four digits and the seconds can not be left out. Both a full stop and a
+/// comma are accepted as the decimal separator, as the standard allows.
+pub(crate) fn parse_iso8601_basic<'a>(
+    parsed: &mut Parsed,
+    mut s: &'a str,
+    with_offset: bool,
+) -> ParseResult<(&'a str, ())> {
+    macro_rules! try_consume {
+        ($e:expr) => {{
+            let (s_, v) = $e?;
+            s = s_;
+            v
+        }};
+    }
+
+    parsed.set_year(try_consume!(scan::number(s, 4, 4)))?;
+    parsed.set_month(try_consume!(scan::number(s, 2, 2)))?;
+    parsed.set_day(try_consume!(scan::number(s, 2, 2)))?;
+    s = scan::char(s, b'T')?;
+    parsed.set_hour(try_consume!(scan::number(s, 2, 2)))?;
+    parsed.set_minute(try_consume!(scan::number(s, 2, 2)))?;
+    parsed.set_second(try_consume!(scan::number(s, 2, 2)))?;
+    if let Some(b'.' | b',') = s.as_bytes().first() {
+        let nanosecond = try_consume!(scan::nanosecond(&s[1..]));
+        parsed.set_nanosecond(nanosecond)?;
+    }
+    if with_offset {
+        let offset = try_consume!(scan::timezone_offset(s, |s| Ok(s), true, true, false));
+        parsed.set_offset(i64::from(offset))?;
+    }
     Ok((s, ()))
 }
 
@@ -225,6 +281,157 @@ pub(crate) fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseRes
     Ok((s, ()))
 }
 
+/// Parses an RFC 3339 date-and-time string into a `DateTime<FixedOffset>` value.
+///
+/// This accepts exactly the same input as [`DateTime::parse_from_rfc3339`], but is faster for the
+/// common forms such as `1996-12-19T16:39:57-08:00` or `2015-02-18T23:16:09.34Z`: it reads them
+/// with a specialized parser that does not fill in a [`Parsed`]. Other input, such as input that
+/// is invalid or contains a leap second, is handed to the general parser, so the result and the
+/// error are always the same as from [`DateTime::parse_from_rfc3339`].
+///
+/// # Errors
+///
+/// Returns an error if `s` is not a valid RFC 3339 date-and-time string.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::parse_rfc3339_fast;
+/// use chrono::{DateTime, FixedOffset, TimeZone};
+///
+/// let tz = FixedOffset::west_opt(8 * 3600).unwrap();
+/// let dt = tz.with_ymd_and_hms(1996, 12, 19, 16, 39, 57).unwrap();
+/// assert_eq!(parse_rfc3339_fast("1996-12-19T16:39:57-08:00"), Ok(dt));
+///
+/// let s = "2015-02-30T23:16:09Z";
+/// assert_eq!(parse_rfc3339_fast(s), DateTime::parse_from_rfc3339(s));
+/// ```
+pub fn parse_rfc3339_fast(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+    if let Some(dt) = parse_rfc3339_common(s.as_bytes()) {
+        return Ok(dt);
+    }
+    let mut parsed = Parsed::new();
+    let (s, _) = parse_rfc3339(&mut parsed, s)?;
+    if !s.is_empty() {
+        return Err(TOO_LONG);
+    }
+    parsed.to_datetime()
+}
+
+/// Finds the first date and time with an offset in `haystack` and parses it.
+///
+/// This is meant for pulling timestamps out of log lines and other text. Every position where a
+/// word starts is tried with the parser of [`DateTime::parse_from_rfc3339`], the more relaxed
+/// parser of the `FromStr` implementation for `DateTime<FixedOffset>`, and finally the parser of
+/// [`DateTime::parse_from_rfc2822`]. The first value that parses is returned with its byte range
+/// in `haystack`, so the search can continue with `&haystack[range.end..]`.
+///
+/// Returns `None` if there is no date and time in `haystack`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::scan_datetime;
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// let line = "[worker-3] 2024-05-07T13:04:05+02:00 job finished";
+/// let (dt, range) = scan_datetime(line).unwrap();
+/// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+/// assert_eq!(dt, tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap());
+/// assert_eq!(&line[range.end..], " job finished");
+///
+/// let line = "Received: from mx1; Tue, 07 May 2024 13:04:05 +0200";
+/// assert_eq!(scan_datetime(line).unwrap().0, tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap());
+///
+/// assert!(scan_datetime("version 1.2.3, built on a Tuesday").is_none());
+/// ```
+#[must_use]
+pub fn scan_datetime(haystack: &str) -> Option<(DateTime<FixedOffset>, Range<usize>)> {
+    type Parser = for<'a> fn(&mut Parsed, &'a str) -> ParseResult<(&'a str, ())>;
+    fn rfc2822<'a>(parsed: &mut Parsed, s: &'a str) -> ParseResult<(&'a str, ())> {
+        parse_rfc2822(parsed, s).map(|(s, _)| (s, ()))
+    }
+    const PARSERS: [Parser; 3] = [parse_rfc3339, parse_rfc3339_relaxed, rfc2822];
+
+    let bytes = haystack.as_bytes();
+    (0..bytes.len()).find_map(|start| {
+        // Only try at the start of a word, so we don't find a date in the middle of a number.
+        if !bytes[start].is_ascii_alphanumeric()
+            || (start > 0 && bytes[start - 1].is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        // `start` is at an ASCII character, so it is a char boundary.
+        let s = &haystack[start..];
+        PARSERS.iter().find_map(|parser| {
+            let mut parsed = Parsed::new();
+            let (rest, ()) = parser(&mut parsed, s).ok()?;
+            let dt = parsed.to_datetime().ok()?;
+            Some((dt, start..haystack.len() - rest.len()))
+        })
+    })
+}
+
+/// Parses the common forms of RFC 3339 without going through `Parsed`.
+///
+/// Returns `None` for anything else, including all invalid input; the general parser then
+/// decides on the result.
+fn parse_rfc3339_common(s: &[u8]) -> Option<DateTime<FixedOffset>> {
+    fn digits(s: &[u8], at: usize) -> Option<u32> {
+        match (s.get(at)?, s.get(at + 1)?) {
+            (a @ b'0'..=b'9', b @ b'0'..=b'9') => {
+                Some(u32::from(a - b'0') * 10 + u32::from(b - b'0'))
+            }
+            _ => None,
+        }
+    }
+
+    if s.len() < 20
+        || s[4] != b'-'
+        || s[7] != b'-'
+        || !matches!(s[10], b'T' | b't' | b' ')
+        || s[13] != b':'
+        || s[16] != b':'
+    {
+        return None;
+    }
+    let year = digits(s, 0)? * 100 + digits(s, 2)?;
+    let date = NaiveDate::from_ymd_opt(year as i32, digits(s, 5)?, digits(s, 8)?)?;
+    let (hour, minute, second) = (digits(s, 11)?, digits(s, 14)?, digits(s, 17)?);
+    // Leap seconds are left to the general parser.
+    if second > 59 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut nano = 0;
+    if let Some((b'.', fraction)) = rest.split_first() {
+        let len = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        for (i, &c) in fraction[..len.min(9)].iter().enumerate() {
+            nano += u32::from(c - b'0') * 10u32.pow(8 - i as u32);
+        }
+        rest = &fraction[len..];
+    }
+    let time = NaiveTime::from_hms_nano_opt(hour, minute, second, nano)?;
+
+    let offset = match rest {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let (hours, minutes) = (digits(rest, 1)?, digits(rest, 4)?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = (hours * 3600 + minutes * 60) as i32;
+            if *sign == b'-' { -offset } else { offset }
+        }
+        _ => return None,
+    };
+    FixedOffset::east_opt(offset)?.from_local_datetime(&date.and_time(time)).single()
+}
+
 /// Tries to parse given string into `parsed` with given formatting items.
 /// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
 /// There should be no trailing string after parsing;
@@ -246,9 +453,10 @@ where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
-    match parse_internal(parsed, s, items) {
+    match parse_internal(parsed, s, items, None, &DEFAULT_OPTIONS) {
         Ok("") => Ok(()),
-        Ok(_) => Err(TOO_LONG), // if there are trailing chars it is an error
+        // if there are trailing chars it is an error
+        Ok(rest) => Err(TOO_LONG.with_offset(s.len() - rest.len())),
         Err(e) => Err(e),
     }
 }
@@ -276,18 +484,388 @@ where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
-    parse_internal(parsed, s, items)
+    parse_internal(parsed, s, items, None, &DEFAULT_OPTIONS)
+}
+
+/// Tries to parse given string into `parsed` with given formatting items and locale.
+/// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
+///
+/// This is the counterpart of the `format_localized_with_items` methods: month and weekday names,
+/// AM/PM and the decimal point are read in the form of `locale`. Combined with
+/// [`StrftimeItems::new_with_locale`](super::StrftimeItems::new_with_locale) this also makes the
+/// localized `%x`, `%X`, `%c` and `%r` formats parseable.
+///
+/// Names are matched case-insensitively, and long names also accept the abbreviated form, as is
+/// the case with [`parse`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_localized, Locale, Parsed, StrftimeItems};
+/// use chrono::NaiveDate;
+///
+/// let mut parsed = Parsed::new();
+/// let items = StrftimeItems::new_with_locale("%c", Locale::fr_BE);
+/// parse_localized(&mut parsed, "mar 07 mai 2024 13:04:05", items, Locale::fr_BE)?;
+/// assert_eq!(
+///     parsed.to_naive_datetime_with_offset(0)?,
+///     NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(13, 4, 5).unwrap()
+/// );
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[cfg(feature = "unstable-locales")]
+pub fn parse_localized<'a, I, B>(
+    parsed: &mut Parsed,
+    s: &str,
+    items: I,
+    locale: Locale,
+) -> ParseResult<()>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    match parse_internal(parsed, s, items, Some(locale), &DEFAULT_OPTIONS) {
+        Ok("") => Ok(()),
+        // if there are trailing chars it is an error
+        Ok(rest) => Err(TOO_LONG.with_offset(s.len() - rest.len())),
+        Err(e) => Err(e),
+    }
+}
+
+/// Tries to parse given string into `parsed` with given formatting items and locale.
+/// Returns `Ok` with a slice of the unparsed remainder.
+///
+/// See [`parse_localized`] for the differences with [`parse_and_remainder`].
+#[cfg(feature = "unstable-locales")]
+pub fn parse_and_remainder_localized<'a, 'b, I, B>(
+    parsed: &mut Parsed,
+    s: &'b str,
+    items: I,
+    locale: Locale,
+) -> ParseResult<&'b str>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    parse_internal(parsed, s, items, Some(locale), &DEFAULT_OPTIONS)
+}
+
+/// Options for [`parse_with_options`]: limits on the size of the input, and a more lenient
+/// parsing mode.
+///
+/// The parser scans its input in a single pass, but some items are unbounded: any number of
+/// digits following a fractional second are skipped, and a year with an explicit sign may have
+/// any number of digits. Services that parse untrusted input can use these limits to reject
+/// oversized input up front, at a cost that does not depend on the rest of the input.
+///
+/// All limits are disabled by default. Input that exceeds a limit fails with
+/// [`ParseErrorKind::TooLong`](super::ParseErrorKind::TooLong).
+///
+/// Month and weekday names and AM/PM are always matched ignoring case, and numbers never need
+/// leading zeros. For messy input such as log data, [`ParseOptions::lenient`] additionally
+/// matches literals ignoring case and accepts more forms of offsets; see
+/// [`with_ignore_case`](ParseOptions::with_ignore_case) and
+/// [`with_lenient_offsets`](ParseOptions::with_lenient_offsets).
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_with_options, ParseErrorKind, ParseOptions, Parsed, StrftimeItems};
+///
+/// let options = ParseOptions::new().with_max_input_len(64).with_max_fraction_digits(9);
+///
+/// let mut parsed = Parsed::new();
+/// let items = StrftimeItems::new("%H:%M:%S%.f");
+/// parse_with_options(&mut parsed, "12:34:56.789", items.clone(), &options)?;
+/// assert_eq!(parsed.nanosecond(), Some(789_000_000));
+///
+/// let mut parsed = Parsed::new();
+/// let err = parse_with_options(&mut parsed, "12:34:56.1234567890", items, &options).unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::TooLong);
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    max_input_len: Option<usize>,
+    max_year_digits: Option<usize>,
+    max_fraction_digits: Option<usize>,
+    ignore_case: bool,
+    lenient_offsets: bool,
+}
+
+/// The options used by the parsing functions without a `ParseOptions` argument.
+const DEFAULT_OPTIONS: ParseOptions = ParseOptions::new();
+
+impl ParseOptions {
+    /// Makes a new `ParseOptions` without any limits, that parses as strictly as [`parse`].
+    #[must_use]
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            max_input_len: None,
+            max_year_digits: None,
+            max_fraction_digits: None,
+            ignore_case: false,
+            lenient_offsets: false,
+        }
+    }
+
+    /// Makes a new `ParseOptions` without any limits, that matches literals ignoring case and
+    /// accepts more forms of offsets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::ParseOptions;
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let fmt = "%Y-%m-%dT%H:%M:%S%:z";
+    /// let parse = |s| DateTime::parse_from_str_with_options(s, fmt, &options);
+    /// let expected = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2024, 5, 7, 8, 9, 10);
+    /// assert_eq!(parse("2024-05-07t08:09:10Z").ok(), expected.single());
+    /// assert_eq!(parse("2024-05-07T08:09:10 UTC").ok(), expected.single());
+    /// assert!(DateTime::parse_from_str("2024-05-07T08:09:10Z", fmt).is_err());
+    /// ```
+    #[must_use]
+    pub const fn lenient() -> ParseOptions {
+        ParseOptions::new().with_ignore_case(true).with_lenient_offsets(true)
+    }
+
+    /// Matches literals in the format ignoring ASCII case, so for example the `T` in
+    /// `%Y-%m-%dT%H:%M` also matches `t`.
+    #[must_use]
+    pub const fn with_ignore_case(mut self, ignore_case: bool) -> ParseOptions {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Accepts more forms of offsets from UTC.
+    ///
+    /// With this option all offset specifiers (`%z`, `%:z`, `%::z`, `%:::z` and `%#z`) accept:
+    ///
+    /// - `Z` and `UTC` in any case for a zero offset, and `UTC` followed by an offset;
+    /// - an hour with a single digit, as in `+5:30`, `+530` or `-8`;
+    /// - minutes with or without a colon.
+    ///
+    /// A literal `Z` in the format also matches a zero offset such as `+00:00`.
+    #[must_use]
+    pub const fn with_lenient_offsets(mut self, lenient_offsets: bool) -> ParseOptions {
+        self.lenient_offsets = lenient_offsets;
+        self
+    }
+
+    /// Limits the length of the input in bytes.
+    ///
+    /// The length is checked before anything is parsed.
+    #[must_use]
+    pub const fn with_max_input_len(mut self, len: usize) -> ParseOptions {
+        self.max_input_len = Some(len);
+        self
+    }
+
+    /// Limits the number of digits of a year with an explicit `+` or `-` sign.
+    ///
+    /// Years without a sign are always limited to four digits.
+    #[must_use]
+    pub const fn with_max_year_digits(mut self, digits: usize) -> ParseOptions {
+        self.max_year_digits = Some(digits);
+        self
+    }
+
+    /// Limits the number of digits of a fractional second.
+    ///
+    /// Only the first nine digits are significant, further digits are skipped by default.
+    #[must_use]
+    pub const fn with_max_fraction_digits(mut self, digits: usize) -> ParseOptions {
+        self.max_fraction_digits = Some(digits);
+        self
+    }
+
+    /// Returns the maximum length of the input in bytes, if limited.
+    #[must_use]
+    pub const fn max_input_len(&self) -> Option<usize> {
+        self.max_input_len
+    }
+
+    /// Returns the maximum number of digits of a signed year, if limited.
+    #[must_use]
+    pub const fn max_year_digits(&self) -> Option<usize> {
+        self.max_year_digits
+    }
+
+    /// Returns the maximum number of digits of a fractional second, if limited.
+    #[must_use]
+    pub const fn max_fraction_digits(&self) -> Option<usize> {
+        self.max_fraction_digits
+    }
+
+    /// Returns `true` if literals are matched ignoring ASCII case.
+    #[must_use]
+    pub const fn ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    /// Returns `true` if more forms of offsets are accepted.
+    #[must_use]
+    pub const fn lenient_offsets(&self) -> bool {
+        self.lenient_offsets
+    }
+}
+
+/// Tries to parse given string into `parsed` with given formatting items, bounded by `options`.
+/// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
+///
+/// This works like [`parse`], but fails with `TooLong` as soon as the input exceeds one of the
+/// limits in `options`, and is more lenient if enabled in `options`. The limits and leniency
+/// apply to the items of the given format; items parsing a complete date and time such as
+/// [`Fixed::RFC3339`] are only bound by the input length.
+pub fn parse_with_options<'a, I, B>(
+    parsed: &mut Parsed,
+    s: &str,
+    items: I,
+    options: &ParseOptions,
+) -> ParseResult<()>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    match parse_internal(parsed, s, items, None, options) {
+        Ok("") => Ok(()),
+        // if there are trailing chars it is an error
+        Ok(rest) => Err(TOO_LONG.with_offset(s.len() - rest.len())),
+        Err(e) => Err(e),
+    }
+}
+
+/// Tries to parse given string into `parsed` with given formatting items, bounded by `options`.
+/// Returns `Ok` with a slice of the unparsed remainder.
+///
+/// See [`parse_with_options`] for how the limits are applied.
+pub fn parse_and_remainder_with_options<'a, 'b, I, B>(
+    parsed: &mut Parsed,
+    s: &'b str,
+    items: I,
+    options: &ParseOptions,
+) -> ParseResult<&'b str>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    parse_internal(parsed, s, items, None, options)
+}
+
+/// Consumes the literal `prefix` from `s`, as configured by `options`.
+fn literal<'a>(s: &'a str, prefix: &str, options: &ParseOptions) -> ParseResult<&'a str> {
+    if options.lenient_offsets && prefix.eq_ignore_ascii_case("Z") {
+        if let Ok((rest, 0)) = lenient_timezone_offset(s) {
+            return Ok(rest);
+        }
+    }
+    if s.len() < prefix.len() {
+        return Err(TOO_SHORT);
+    }
+    let matches = match options.ignore_case {
+        // Only ASCII bytes can differ, so `prefix.len()` is at a character boundary of `s`.
+        true => s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes()),
+        false => s.starts_with(prefix),
+    };
+    if !matches {
+        return Err(INVALID);
+    }
+    Ok(&s[prefix.len()..])
+}
+
+/// Parses an offset from UTC for [`ParseOptions::with_lenient_offsets`].
+fn lenient_timezone_offset(mut s: &str) -> ParseResult<(&str, i32)> {
+    if s.len() >= 3 && s.as_bytes()[..3].eq_ignore_ascii_case(b"UTC") {
+        s = &s[3..];
+        if !s.starts_with(['+', '-', '−']) {
+            return Ok((s, 0));
+        }
+    } else if let Some(&(b'Z' | b'z')) = s.as_bytes().first() {
+        return Ok((&s[1..], 0));
+    }
+
+    let mut chars = s.chars();
+    let negative = match chars.next() {
+        Some('+') => false,
+        Some('-' | '−') => true,
+        Some(_) => return Err(INVALID),
+        None => return Err(TOO_SHORT),
+    };
+    s = chars.as_str();
+
+    // one or two digits of hours, optionally followed by two digits of minutes
+    let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+    let hour_digits = match digits {
+        0 if s.is_empty() => return Err(TOO_SHORT),
+        1 | 2 => digits,
+        3 | 4 => digits - 2,
+        _ => return Err(INVALID),
+    };
+    let (rest, hours) = scan::number(s, hour_digits, hour_digits)?;
+    s = rest;
+    let minutes = if digits > 2 {
+        let (rest, minutes) = scan::number(s, 2, 2)?;
+        s = rest;
+        minutes
+    } else if let Some(rest) = s.strip_prefix(':') {
+        let (rest, minutes) = scan::number(rest, 2, 2)?;
+        s = rest;
+        minutes
+    } else {
+        0
+    };
+    if minutes > 59 {
+        return Err(OUT_OF_RANGE);
+    }
+    let seconds = (hours * 3600 + minutes * 60) as i32;
+    Ok((s, if negative { -seconds } else { seconds }))
+}
+
+/// Returns `TOO_LONG` if `s` starts with more than `limit` ASCII digits.
+///
+/// At most `limit + 1` bytes are inspected.
+fn check_digits(s: &str, limit: Option<usize>) -> ParseResult<()> {
+    match limit {
+        Some(limit) if s.bytes().take(limit + 1).take_while(u8::is_ascii_digit).count() > limit => {
+            Err(TOO_LONG)
+        }
+        _ => Ok(()),
+    }
 }
 
 fn parse_internal<'a, 'b, I, B>(
     parsed: &mut Parsed,
     mut s: &'b str,
     items: I,
+    locale: Option<Locale>,
+    options: &ParseOptions,
 ) -> Result<&'b str, ParseError>
 where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
+    if options.max_input_len.map_or(false, |max| s.len() > max) {
+        return Err(TOO_LONG);
+    }
+
+    let input = s;
+    for item in items {
+        s = parse_item(parsed, s, item.borrow(), locale, options)
+            .map_err(|e| e.with_offset(input.len() - s.len()))?;
+    }
+    Ok(s)
+}
+
+/// Parses a single formatting item from the start of `s`, returning the remainder.
+fn parse_item<'b>(
+    parsed: &mut Parsed,
+    mut s: &'b str,
+    item: &Item,
+    locale: Option<Locale>,
+    options: &ParseOptions,
+) -> ParseResult<&'b str> {
     macro_rules! try_consume {
         ($e:expr) => {{
             match $e {
@@ -300,210 +878,287 @@ where
         }};
     }
 
-    for item in items {
-        match *item.borrow() {
-            Item::Literal(prefix) => {
-                if s.len() < prefix.len() {
-                    return Err(TOO_SHORT);
-                }
-                if !s.starts_with(prefix) {
-                    return Err(INVALID);
-                }
-                s = &s[prefix.len()..];
-            }
+    match *item {
+        Item::Literal(prefix) => {
+            s = literal(s, prefix, options)?;
+        }
 
-            #[cfg(feature = "alloc")]
-            Item::OwnedLiteral(ref prefix) => {
-                if s.len() < prefix.len() {
-                    return Err(TOO_SHORT);
-                }
-                if !s.starts_with(&prefix[..]) {
-                    return Err(INVALID);
-                }
-                s = &s[prefix.len()..];
-            }
+        #[cfg(feature = "alloc")]
+        Item::OwnedLiteral(ref prefix) => {
+            s = literal(s, prefix, options)?;
+        }
 
-            Item::Space(_) => {
-                s = s.trim_start();
-            }
+        Item::Space(_) => {
+            s = s.trim_start();
+        }
 
-            #[cfg(feature = "alloc")]
-            Item::OwnedSpace(_) => {
-                s = s.trim_start();
-            }
+        #[cfg(feature = "alloc")]
+        Item::OwnedSpace(_) => {
+            s = s.trim_start();
+        }
 
-            Item::Numeric(ref spec, ref _pad) => {
-                use super::Numeric::*;
-                type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
-
-                let (width, signed, set): (usize, bool, Setter) = match *spec {
-                    Year => (4, true, Parsed::set_year),
-                    YearDiv100 => (2, false, Parsed::set_year_div_100),
-                    YearMod100 => (2, false, Parsed::set_year_mod_100),
-                    IsoYear => (4, true, Parsed::set_isoyear),
-                    IsoYearDiv100 => (2, false, Parsed::set_isoyear_div_100),
-                    IsoYearMod100 => (2, false, Parsed::set_isoyear_mod_100),
-                    Quarter => (1, false, Parsed::set_quarter),
-                    Month => (2, false, Parsed::set_month),
-                    Day => (2, false, Parsed::set_day),
-                    WeekFromSun => (2, false, Parsed::set_week_from_sun),
-                    WeekFromMon => (2, false, Parsed::set_week_from_mon),
-                    IsoWeek => (2, false, Parsed::set_isoweek),
-                    NumDaysFromSun => (1, false, set_weekday_with_num_days_from_sunday),
-                    WeekdayFromMon => (1, false, set_weekday_with_number_from_monday),
-                    Ordinal => (3, false, Parsed::set_ordinal),
-                    Hour => (2, false, Parsed::set_hour),
-                    Hour12 => (2, false, Parsed::set_hour12),
-                    Minute => (2, false, Parsed::set_minute),
-                    Second => (2, false, Parsed::set_second),
-                    Nanosecond => (9, false, Parsed::set_nanosecond),
-                    Timestamp => (usize::MAX, false, Parsed::set_timestamp),
-
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
-                };
-
-                s = s.trim_start();
-                let v = if signed {
-                    if s.starts_with('-') {
-                        let v = try_consume!(scan::number(&s[1..], 1, usize::MAX));
-                        0i64.checked_sub(v).ok_or(OUT_OF_RANGE)?
-                    } else if s.starts_with('+') {
-                        try_consume!(scan::number(&s[1..], 1, usize::MAX))
-                    } else {
-                        // if there is no explicit sign, we respect the original `width`
-                        try_consume!(scan::number(s, 1, width))
-                    }
+        Item::Numeric(ref spec, ref _pad) => {
+            use super::Numeric::*;
+            type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
+
+            // an explicit width may allow more digits than the intrinsic parsing width
+            let (spec, explicit_width) = match spec.explicit_width() {
+                Some((numeric, width)) => (numeric, usize::from(width)),
+                None => (spec, 0),
+            };
+            let (width, signed, set): (usize, bool, Setter) = match *spec {
+                Year => (4, true, Parsed::set_year),
+                YearDiv100 => (2, false, Parsed::set_year_div_100),
+                YearMod100 => (2, false, Parsed::set_year_mod_100),
+                IsoYear => (4, true, Parsed::set_isoyear),
+                IsoYearDiv100 => (2, false, Parsed::set_isoyear_div_100),
+                IsoYearMod100 => (2, false, Parsed::set_isoyear_mod_100),
+                Quarter => (1, false, Parsed::set_quarter),
+                Month => (2, false, Parsed::set_month),
+                Day => (2, false, Parsed::set_day),
+                WeekFromSun => (2, false, Parsed::set_week_from_sun),
+                WeekFromMon => (2, false, Parsed::set_week_from_mon),
+                IsoWeek => (2, false, Parsed::set_isoweek),
+                NumDaysFromSun => (1, false, set_weekday_with_num_days_from_sunday),
+                WeekdayFromMon => (1, false, set_weekday_with_number_from_monday),
+                Ordinal => (3, false, Parsed::set_ordinal),
+                Hour => (2, false, Parsed::set_hour),
+                Hour12 => (2, false, Parsed::set_hour12),
+                Minute => (2, false, Parsed::set_minute),
+                Second => (2, false, Parsed::set_second),
+                Nanosecond => (9, false, Parsed::set_nanosecond),
+                Timestamp => (usize::MAX, false, Parsed::set_timestamp),
+
+                // only used for an explicit width, which is handled above
+                Internal(_) => return Err(BAD_FORMAT),
+            };
+            let width = width.max(explicit_width);
+
+            s = s.trim_start();
+            let v = if signed {
+                if s.starts_with('-') || s.starts_with('+') {
+                    check_digits(&s[1..], options.max_year_digits)?;
+                }
+                if s.starts_with('-') {
+                    let v = try_consume!(scan::number(&s[1..], 1, usize::MAX));
+                    0i64.checked_sub(v).ok_or(OUT_OF_RANGE)?
+                } else if s.starts_with('+') {
+                    try_consume!(scan::number(&s[1..], 1, usize::MAX))
                 } else {
+                    // if there is no explicit sign, we respect the original `width`
                     try_consume!(scan::number(s, 1, width))
-                };
-                set(parsed, v)?;
-            }
+                }
+            } else {
+                try_consume!(scan::number(s, 1, width))
+            };
+            set(parsed, v)?;
+        }
 
-            Item::Fixed(ref spec) => {
-                use super::Fixed::*;
+        Item::Fixed(ref spec) => {
+            use super::Fixed::*;
 
-                match spec {
-                    &ShortMonthName => {
-                        let month0 = try_consume!(scan::short_month0(s));
-                        parsed.set_month(i64::from(month0) + 1)?;
-                    }
+            match spec {
+                &ShortMonthName if locale.is_some() => {
+                    let names = [locales::short_months(locale.unwrap())];
+                    let month0 = try_consume!(scan::localized_name(s, &names));
+                    parsed.set_month(month0 as i64 + 1)?;
+                }
 
-                    &LongMonthName => {
-                        let month0 = try_consume!(scan::short_or_long_month0(s));
-                        parsed.set_month(i64::from(month0) + 1)?;
-                    }
+                &ShortMonthName => {
+                    let month0 = try_consume!(scan::short_month0(s));
+                    parsed.set_month(i64::from(month0) + 1)?;
+                }
 
-                    &ShortWeekdayName => {
-                        let weekday = try_consume!(scan::short_weekday(s));
-                        parsed.set_weekday(weekday)?;
-                    }
+                &LongMonthName if locale.is_some() => {
+                    let locale = locale.unwrap();
+                    let names = [locales::long_months(locale), locales::short_months(locale)];
+                    let month0 = try_consume!(scan::localized_name(s, &names));
+                    parsed.set_month(month0 as i64 + 1)?;
+                }
 
-                    &LongWeekdayName => {
-                        let weekday = try_consume!(scan::short_or_long_weekday(s));
-                        parsed.set_weekday(weekday)?;
-                    }
+                &LongMonthName => {
+                    let month0 = try_consume!(scan::short_or_long_month0(s));
+                    parsed.set_month(i64::from(month0) + 1)?;
+                }
 
-                    &LowerAmPm | &UpperAmPm => {
-                        if s.len() < 2 {
-                            return Err(TOO_SHORT);
-                        }
-                        let ampm = match (s.as_bytes()[0] | 32, s.as_bytes()[1] | 32) {
-                            (b'a', b'm') => false,
-                            (b'p', b'm') => true,
-                            _ => return Err(INVALID),
-                        };
-                        parsed.set_ampm(ampm)?;
-                        s = &s[2..];
-                    }
+                &ShortWeekdayName if locale.is_some() => {
+                    let names = [locales::short_weekdays(locale.unwrap())];
+                    let weekday = try_consume!(scan::localized_name(s, &names));
+                    set_weekday_with_num_days_from_sunday(parsed, weekday as i64)?;
+                }
 
-                    &Nanosecond | &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
-                        if s.starts_with('.') {
-                            let nano = try_consume!(scan::nanosecond(&s[1..]));
-                            parsed.set_nanosecond(nano)?;
-                        }
-                    }
+                &ShortWeekdayName => {
+                    let weekday = try_consume!(scan::short_weekday(s));
+                    parsed.set_weekday(weekday)?;
+                }
 
-                    &Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
-                        if s.len() < 3 {
-                            return Err(TOO_SHORT);
-                        }
-                        let nano = try_consume!(scan::nanosecond_fixed(s, 3));
-                        parsed.set_nanosecond(nano)?;
+                &LongWeekdayName if locale.is_some() => {
+                    let locale = locale.unwrap();
+                    let names = [locales::long_weekdays(locale), locales::short_weekdays(locale)];
+                    let weekday = try_consume!(scan::localized_name(s, &names));
+                    set_weekday_with_num_days_from_sunday(parsed, weekday as i64)?;
+                }
+
+                &LongWeekdayName => {
+                    let weekday = try_consume!(scan::short_or_long_weekday(s));
+                    parsed.set_weekday(weekday)?;
+                }
+
+                &LowerAmPm | &UpperAmPm if locale.is_some() => {
+                    let names = [locales::am_pm(locale.unwrap())];
+                    let ampm = try_consume!(scan::localized_name(s, &names));
+                    parsed.set_ampm(ampm == 1)?;
+                }
+
+                &LowerAmPm | &UpperAmPm => {
+                    if s.len() < 2 {
+                        return Err(TOO_SHORT);
                     }
+                    let ampm = match (s.as_bytes()[0] | 32, s.as_bytes()[1] | 32) {
+                        (b'a', b'm') => false,
+                        (b'p', b'm') => true,
+                        _ => return Err(INVALID),
+                    };
+                    parsed.set_ampm(ampm)?;
+                    s = &s[2..];
+                }
 
-                    &Internal(InternalFixed { val: InternalInternal::Nanosecond6NoDot }) => {
-                        if s.len() < 6 {
-                            return Err(TOO_SHORT);
-                        }
-                        let nano = try_consume!(scan::nanosecond_fixed(s, 6));
+                &Nanosecond | &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
+                    let decimal_point = locale.map_or(".", locales::decimal_point);
+                    if let Some(digits) = s.strip_prefix(decimal_point) {
+                        check_digits(digits, options.max_fraction_digits)?;
+                        let nano = try_consume!(scan::nanosecond(digits));
                         parsed.set_nanosecond(nano)?;
                     }
+                }
 
-                    &Internal(InternalFixed { val: InternalInternal::Nanosecond9NoDot }) => {
-                        if s.len() < 9 {
-                            return Err(TOO_SHORT);
-                        }
-                        let nano = try_consume!(scan::nanosecond_fixed(s, 9));
-                        parsed.set_nanosecond(nano)?;
+                &Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
+                    if s.len() < 3 {
+                        return Err(TOO_SHORT);
                     }
+                    let nano = try_consume!(scan::nanosecond_fixed(s, 3));
+                    parsed.set_nanosecond(nano)?;
+                }
 
-                    &TimezoneName => {
-                        try_consume!(Ok((s.trim_start_matches(|c: char| !c.is_whitespace()), ())));
+                &Internal(InternalFixed { val: InternalInternal::Nanosecond6NoDot }) => {
+                    if s.len() < 6 {
+                        return Err(TOO_SHORT);
                     }
+                    let nano = try_consume!(scan::nanosecond_fixed(s, 6));
+                    parsed.set_nanosecond(nano)?;
+                }
 
-                    &TimezoneOffsetColon
-                    | &TimezoneOffsetDoubleColon
-                    | &TimezoneOffsetTripleColon
-                    | &TimezoneOffset => {
-                        let offset = try_consume!(scan::timezone_offset(
-                            s.trim_start(),
-                            scan::colon_or_space,
-                            false,
-                            false,
-                            true,
-                        ));
-                        parsed.set_offset(i64::from(offset))?;
+                &Internal(InternalFixed { val: InternalInternal::Nanosecond9NoDot }) => {
+                    if s.len() < 9 {
+                        return Err(TOO_SHORT);
                     }
+                    let nano = try_consume!(scan::nanosecond_fixed(s, 9));
+                    parsed.set_nanosecond(nano)?;
+                }
 
-                    &TimezoneOffsetColonZ | &TimezoneOffsetZ => {
-                        let offset = try_consume!(scan::timezone_offset(
-                            s.trim_start(),
-                            scan::colon_or_space,
-                            true,
-                            false,
-                            true,
-                        ));
-                        parsed.set_offset(i64::from(offset))?;
+                &Internal(InternalFixed { val: InternalInternal::TimestampFraction }) => {
+                    let negative = s.starts_with('-');
+                    if negative {
+                        s = &s[1..];
                     }
-                    &Internal(InternalFixed {
-                        val: InternalInternal::TimezoneOffsetPermissive,
-                    }) => {
-                        let offset = try_consume!(scan::timezone_offset(
-                            s.trim_start(),
-                            scan::colon_or_space,
-                            true,
-                            true,
-                            true,
-                        ));
-                        parsed.set_offset(i64::from(offset))?;
+                    let secs = try_consume!(scan::number(s, 1, usize::MAX));
+                    let mut nano = 0;
+                    if let Some(digits) = s.strip_prefix('.') {
+                        s = digits;
+                        check_digits(s, options.max_fraction_digits)?;
+                        nano = try_consume!(scan::nanosecond(s));
                     }
+                    let (timestamp, nano) = match (negative, nano) {
+                        (false, _) => (secs, nano),
+                        (true, 0) => (-secs, 0),
+                        (true, _) => (-secs - 1, 1_000_000_000 - nano),
+                    };
+                    parsed.set_timestamp(timestamp)?;
+                    parsed.set_nanosecond(nano)?;
+                }
 
-                    &RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
-                    &RFC3339 => {
-                        // Used for the `%+` specifier, which has the description:
-                        // "Same as `%Y-%m-%dT%H:%M:%S%.f%:z` (...)
-                        // This format also supports having a `Z` or `UTC` in place of `%:z`."
-                        // Use the relaxed parser to match this description.
-                        try_consume!(parse_rfc3339_relaxed(parsed, s))
-                    }
+                &TimezoneName => {
+                    try_consume!(Ok((s.trim_start_matches(|c: char| !c.is_whitespace()), ())));
+                }
+
+                &TimezoneOffsetColon
+                | &TimezoneOffsetDoubleColon
+                | &TimezoneOffsetTripleColon
+                | &TimezoneOffset
+                    if options.lenient_offsets =>
+                {
+                    let offset = try_consume!(lenient_timezone_offset(s.trim_start()));
+                    parsed.set_offset(i64::from(offset))?;
+                }
+
+                &TimezoneOffsetColon
+                | &TimezoneOffsetDoubleColon
+                | &TimezoneOffsetTripleColon
+                | &TimezoneOffset => {
+                    let offset = try_consume!(scan::timezone_offset(
+                        s.trim_start(),
+                        scan::colon_or_space,
+                        false,
+                        false,
+                        true,
+                    ));
+                    parsed.set_offset(i64::from(offset))?;
+                }
+
+                &TimezoneOffsetColonZ
+                | &TimezoneOffsetZ
+                | &Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive })
+                    if options.lenient_offsets =>
+                {
+                    let offset = try_consume!(lenient_timezone_offset(s.trim_start()));
+                    parsed.set_offset(i64::from(offset))?;
                 }
-            }
 
-            Item::Error => {
-                return Err(BAD_FORMAT);
+                &TimezoneOffsetColonZ | &TimezoneOffsetZ => {
+                    let offset = try_consume!(scan::timezone_offset(
+                        s.trim_start(),
+                        scan::colon_or_space,
+                        true,
+                        false,
+                        true,
+                    ));
+                    parsed.set_offset(i64::from(offset))?;
+                }
+                &Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive }) => {
+                    let offset = try_consume!(scan::timezone_offset(
+                        s.trim_start(),
+                        scan::colon_or_space,
+                        true,
+                        true,
+                        true,
+                    ));
+                    parsed.set_offset(i64::from(offset))?;
+                }
+                // Parsing the alternative year representation of a locale is not supported.
+                #[cfg(feature = "unstable-locales")]
+                &Internal(InternalFixed {
+                    val:
+                        InternalInternal::EraName
+                        | InternalInternal::EraYear
+                        | InternalInternal::EraFullYear,
+                }) => return Err(BAD_FORMAT),
+
+                &RFC2822 => {
+                    try_consume!(parse_rfc2822(parsed, s));
+                }
+                &RFC3339 => {
+                    // Used for the `%+` specifier, which has the description:
+                    // "Same as `%Y-%m-%dT%H:%M:%S%.f%:z` (...)
+                    // This format also supports having a `Z` or `UTC` in place of `%:z`."
+                    // Use the relaxed parser to match this description.
+                    try_consume!(parse_rfc3339_relaxed(parsed, s))
+                }
             }
         }
+
+        Item::Error => {
+            return Err(BAD_FORMAT);
+        }
     }
     Ok(s)
 }
@@ -523,11 +1178,11 @@ where
 impl str::FromStr for DateTime<FixedOffset> {
     type Err = ParseError;
 
-    fn from_str(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+    fn from_str(input: &str) -> ParseResult<DateTime<FixedOffset>> {
         let mut parsed = Parsed::new();
-        let (s, _) = parse_rfc3339_relaxed(&mut parsed, s)?;
+        let (s, _) = parse_rfc3339_relaxed(&mut parsed, input)?;
         if !s.trim_start().is_empty() {
-            return Err(TOO_LONG);
+            return Err(TOO_LONG.with_offset(input.len() - s.len()));
         }
         parsed.to_datetime()
     }
@@ -542,7 +1197,10 @@ impl str::FromStr for DateTime<FixedOffset> {
 ///   `DateTime<Utc>`.
 /// - There can be spaces between any of the components.
 /// - The colon in the offset may be missing.
-fn parse_rfc3339_relaxed<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, ())> {
+pub(crate) fn parse_rfc3339_relaxed<'a>(
+    parsed: &mut Parsed,
+    mut s: &'a str,
+) -> ParseResult<(&'a str, ())> {
     const DATE_ITEMS: &[Item<'static>] = &[
         Item::Numeric(Numeric::Year, Pad::Zero),
         Item::Space(""),
@@ -564,7 +1222,7 @@ fn parse_rfc3339_relaxed<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult
         Item::Space(""),
     ];
 
-    s = parse_internal(parsed, s, DATE_ITEMS.iter())?;
+    s = parse_internal(parsed, s, DATE_ITEMS.iter(), None, &DEFAULT_OPTIONS)?;
 
     s = match s.as_bytes().first() {
         Some(&b't' | &b'T' | &b' ') => &s[1..],
@@ -572,7 +1230,7 @@ fn parse_rfc3339_relaxed<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult
         None => return Err(TOO_SHORT),
     };
 
-    s = parse_internal(parsed, s, TIME_ITEMS.iter())?;
+    s = parse_internal(parsed, s, TIME_ITEMS.iter(), None, &DEFAULT_OPTIONS)?;
     s = s.trim_start();
     let (s, offset) = if s.len() >= 3 && "UTC".as_bytes().eq_ignore_ascii_case(&s.as_bytes()[..3]) {
         (&s[3..], 0)
@@ -585,6 +1243,7 @@ fn parse_rfc3339_relaxed<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult
 
 #[cfg(test)]
 mod tests {
+    use super::{parse_rfc3339, parse_rfc3339_common};
     use crate::format::*;
     use crate::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Timelike, Utc};
 
@@ -1843,12 +2502,269 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rfc3339_fast() {
+        fn general(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+            let mut parsed = Parsed::new();
+            let (s, _) = parse_rfc3339(&mut parsed, s)?;
+            if !s.is_empty() {
+                return Err(TOO_LONG);
+            }
+            parsed.to_datetime()
+        }
+
+        let inputs = [
+            "2015-01-20T17:35:20-08:00",
+            "2015-01-20t17:35:20z",
+            "2015-01-20 17:35:20.1+23:59",
+            "2015-01-20T17:35:20.123456789123-08:00",
+            "2015-01-20T17:35:20.-08:00",
+            "2015-01-20T17:35:20−08:00",
+            "0000-01-01T00:00:00Z",
+            "9999-12-31T23:59:59.999999999-23:59",
+            "2015-06-30T23:59:60Z",
+            "2015-06-30T23:59:60.5+01:00",
+            "2015-06-30T12:00:60Z",
+            "2016-02-29T00:00:00Z",
+            "2015-02-29T00:00:00Z",
+            "2015-13-01T00:00:00Z",
+            "2015-00-01T00:00:00Z",
+            "2015-01-20T24:00:00Z",
+            "2015-01-20T17:35:20+24:00",
+            "2015-01-20T17:35:20+00:60",
+            "2015-01-20T17:35:20-08:00 ",
+            "2015-01-20T17:35:20",
+            "2015-01-20T17:35:2",
+            "2015-01-20T17:3é:20Z",
+            "2015-01-20T17:35:20Zé",
+            "",
+        ];
+        for s in inputs {
+            assert_eq!(parse_rfc3339_fast(s), general(s), "{:?}", s);
+        }
+        for s in &inputs[..4] {
+            assert!(parse_rfc3339_common(s.as_bytes()).is_some(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_scan_datetime() {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        let dt = tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap();
+        let cases = [
+            ("2024-05-07T13:04:05+02:00", 0..25),
+            ("at 2024-05-07T13:04:05+02:00.", 3..28),
+            ("ts=2024-05-07 13:04:05 +02:00 level=info", 3..29),
+            ("1 2024-5-7 13:4:5+0200", 2..22),
+            ("date: Tue, 07 May 2024 13:04:05 +0200 (CEST)\n", 6..44),
+            ("<07 May 2024 13:04:05 +0200>", 1..27),
+            ("error 42 2024-05-07T11:04:05Z 2025-01-01T00:00:00Z", 9..29),
+        ];
+        for (s, range) in cases {
+            assert_eq!(scan_datetime(s), Some((dt, range)), "{:?}", s);
+        }
+
+        let (_, range) = scan_datetime("é 2024-05-07T13:04:05+02:00é").unwrap();
+        assert_eq!(range, 3..28);
+        // Inside a number or word.
+        assert_eq!(scan_datetime("12024-05-07T13:04:05+02:00"), None);
+        assert_eq!(scan_datetime("x2024-05-07T13:04:05+02:00"), None);
+        for s in ["", "no dates here", "2024-05-07", "2024-05-07T13:04:05", "2024-02-30T00:00:00Z"]
+        {
+            assert_eq!(scan_datetime(s), None, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_error_offset() {
+        fn offset(s: &str, fmt: &str) -> Option<usize> {
+            let mut parsed = Parsed::new();
+            parse(&mut parsed, s, StrftimeItems::new(fmt)).unwrap_err().offset()
+        }
+
+        assert_eq!(offset("2024-05-07", "%Y/%m/%d"), Some(4));
+        assert_eq!(offset("2024-13-07", "%Y-%m-%d"), Some(5));
+        assert_eq!(offset("2024-05-", "%Y-%m-%d"), Some(8));
+        assert_eq!(offset("2024-05-07 12", "%Y-%m-%d"), Some(10));
+        assert_eq!(offset("Tue, 2024", "%a, %Y %H"), Some(9));
+
+        let mut parsed = Parsed::new();
+        let err = parse(&mut parsed, "2024", [Item::Error].iter()).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::BadFormat);
+        assert_eq!(err.offset(), Some(0));
+
+        let err = crate::NaiveDate::parse_from_str("2024-05", "%Y-%m").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NotEnough);
+        assert_eq!(err.offset(), None);
+
+        let err = "2024-05-07T12:34:56Z x".parse::<DateTime<FixedOffset>>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::TooLong);
+        assert_eq!(err.offset(), Some(20));
+
+        // the position does not take part in comparisons
+        assert_eq!(INVALID.with_offset(3), INVALID);
+
+        // the position is kept small, and left out if it doesn't fit
+        assert_eq!(core::mem::size_of::<ParseError>(), 8);
+        assert_eq!(
+            INVALID.with_offset(u32::MAX as usize - 1).offset(),
+            Some(u32::MAX as usize - 1)
+        );
+        assert_eq!(INVALID.with_offset(u32::MAX as usize).offset(), None);
+    }
+
     #[test]
     fn test_issue_1010() {
         let dt = crate::NaiveDateTime::parse_from_str(
             "\u{c}SUN\u{e}\u{3000}\0m@J\u{3000}\0\u{3000}\0m\u{c}!\u{c}\u{b}\u{c}\u{c}\u{c}\u{c}%A\u{c}\u{b}\0SU\u{c}\u{c}",
             "\u{c}\u{c}%A\u{c}\u{b}\0SUN\u{c}\u{c}\u{c}SUNN\u{c}\u{c}\u{c}SUN\u{c}\u{c}!\u{c}\u{b}\u{c}\u{c}\u{c}\u{c}%A\u{c}\u{b}%a",
         );
-        assert_eq!(dt, Err(ParseError(ParseErrorKind::Invalid)));
+        assert_eq!(dt, Err(INVALID));
+    }
+
+    #[test]
+    fn test_parse_with_options() {
+        fn check_opts(s: &str, fmt: &str, options: ParseOptions, expected: ParseResult<Parsed>) {
+            let mut parsed = Parsed::new();
+            let result = parse_with_options(&mut parsed, s, StrftimeItems::new(fmt), &options);
+            assert_eq!(result.map(|_| parsed), expected, "{:?} {:?}", s, fmt);
+        }
+
+        let no_limits = ParseOptions::new();
+        assert_eq!(no_limits, ParseOptions::default());
+        assert_eq!(no_limits.max_input_len(), None);
+        check_opts("+0000012024", "%Y", no_limits, parsed!(year: 12024));
+        check_opts(
+            "1.1234567890123",
+            "%s%.f",
+            no_limits,
+            parsed!(timestamp: 1, nanosecond: 123_456_789),
+        );
+
+        let len = ParseOptions::new().with_max_input_len(8);
+        assert_eq!(len.max_input_len(), Some(8));
+        check_opts("12:34:56", "%M:%S", len, Err(TOO_LONG));
+        check_opts("12:34", "%M:%S", len, parsed!(minute: 12, second: 34));
+        check_opts("12:34:56 ", "%M:%S:%S ", len, Err(TOO_LONG));
+        check_opts("         1", "%M", len, Err(TOO_LONG));
+
+        let year = ParseOptions::new().with_max_year_digits(6);
+        assert_eq!(year.max_year_digits(), Some(6));
+        check_opts("-123456", "%Y", year, parsed!(year: -123456));
+        check_opts("+1234567", "%Y", year, Err(TOO_LONG));
+        check_opts("-1234567", "%G", year, Err(TOO_LONG));
+        // years without a sign are limited to four digits anyway
+        check_opts("20240", "%Y", year, Err(TOO_LONG));
+
+        let fraction = ParseOptions::new().with_max_fraction_digits(9);
+        assert_eq!(fraction.max_fraction_digits(), Some(9));
+        check_opts(".123456789", "%.f", fraction, parsed!(nanosecond: 123_456_789));
+        check_opts(".1234567890", "%.f", fraction, Err(TOO_LONG));
+        check_opts("1.1234567890", "%.s", fraction, Err(TOO_LONG));
+        check_opts("1.12", "%.s", fraction, parsed!(timestamp: 1, nanosecond: 120_000_000));
+
+        let mut parsed = Parsed::new();
+        let rest = parse_and_remainder_with_options(
+            &mut parsed,
+            "2024-05-07 rest",
+            StrftimeItems::new("%F"),
+            &ParseOptions::new().with_max_input_len(15),
+        );
+        assert_eq!(rest, Ok(" rest"));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        fn check_opts(s: &str, fmt: &str, options: ParseOptions, expected: ParseResult<Parsed>) {
+            let mut parsed = Parsed::new();
+            let result = parse_with_options(&mut parsed, s, StrftimeItems::new(fmt), &options);
+            assert_eq!(result.map(|_| parsed), expected, "{:?} {:?}", s, fmt);
+        }
+
+        let strict = ParseOptions::new();
+        let lenient = ParseOptions::lenient();
+        assert!(!strict.ignore_case() && !strict.lenient_offsets());
+        assert!(lenient.ignore_case() && lenient.lenient_offsets());
+
+        // literals
+        check_opts("12h", "%HH", strict, Err(INVALID));
+        check_opts("12h", "%HH", lenient, parsed!(hour_div_12: 1, hour_mod_12: 0));
+        check_opts("12À", "%Hà", lenient, Err(INVALID));
+        check_opts(
+            "12:00",
+            "%H:%M",
+            ParseOptions::new().with_ignore_case(true),
+            parsed!(hour_div_12: 1, hour_mod_12: 0, minute: 0),
+        );
+        // names and numbers are lenient in any case
+        check_opts("jUL 7", "%b %d", strict, parsed!(month: 7, day: 7));
+
+        // offsets
+        for (s, offset) in [
+            ("Z", 0),
+            ("z", 0),
+            ("UTC", 0),
+            ("utc", 0),
+            ("UTC+01:00", 3600),
+            ("+01:00", 3600),
+            ("+0100", 3600),
+            ("+01", 3600),
+            ("+1", 3600),
+            ("-8", -8 * 3600),
+            ("+5:30", 19_800),
+            ("+530", 19_800),
+            ("−05:30", -19_800),
+        ] {
+            for fmt in ["%z", "%:z", "%::z", "%:::z", "%#z"] {
+                check_opts(s, fmt, lenient, parsed!(offset: offset));
+            }
+        }
+        check_opts("Z", "%:z", strict, Err(INVALID));
+        check_opts("+5:30", "%:z", strict, Err(INVALID));
+        check_opts("+5:60", "%:z", lenient, Err(OUT_OF_RANGE));
+        check_opts("+12345", "%z", lenient, Err(INVALID));
+        check_opts("+", "%z", lenient, Err(TOO_SHORT));
+        check_opts("", "%z", lenient, Err(TOO_SHORT));
+
+        // a literal `Z` in the format
+        check_opts("10+00:00", "%HZ", strict, Err(INVALID));
+        check_opts("10+00:00", "%HZ", lenient, parsed!(hour_div_12: 0, hour_mod_12: 10));
+        check_opts("10z", "%HZ", lenient, parsed!(hour_div_12: 0, hour_mod_12: 10));
+        check_opts("10+01:00", "%HZ", lenient, Err(INVALID));
+    }
+
+    #[test]
+    #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
+    fn test_parse_localized() {
+        use crate::{Datelike, NaiveDate};
+
+        let locales = [
+            Locale::en_US,
+            Locale::fr_FR,
+            Locale::de_DE,
+            Locale::ko_KR,
+            Locale::ja_JP,
+            Locale::ru_RU,
+            Locale::ar_EG,
+            Locale::nl_NL,
+        ];
+        let date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        for locale in locales {
+            for month0 in 0..12 {
+                for hour in [0, 9, 12, 23] {
+                    let dt = date
+                        .with_month0(month0)
+                        .unwrap()
+                        .and_hms_opt(hour, 34, 59)
+                        .unwrap()
+                        .and_utc();
+                    for fmt in ["%x %X", "%c", "%x %r", "%A %e %B %Y %T"] {
+                        let s = dt.format_localized(fmt, locale).to_string();
+                        let parsed = NaiveDateTime::parse_from_str_localized(&s, fmt, locale);
+                        assert_eq!(parsed, Ok(dt.naive_utc()), "{:?} {} {}", locale, fmt, s);
+                    }
+                }
+            }
+        }
     }
 }
@@ -825,8 +825,12 @@ impl Parsed {
             // if date and time is problematic already, there is no point proceeding.
             // we at least try to give a correct error though.
             match (date, time) {
-                (Err(PE(OutOfRange)), _) | (_, Err(PE(OutOfRange))) => return Err(OUT_OF_RANGE),
-                (Err(PE(Impossible)), _) | (_, Err(PE(Impossible))) => return Err(IMPOSSIBLE),
+                (Err(PE { kind: OutOfRange, .. }), _) | (_, Err(PE { kind: OutOfRange, .. })) => {
+                    return Err(OUT_OF_RANGE);
+                }
+                (Err(PE { kind: Impossible, .. }), _) | (_, Err(PE { kind: Impossible, .. })) => {
+                    return Err(IMPOSSIBLE);
+                }
                 (_, _) => {} // one of them is insufficient
             }
 
@@ -1162,6 +1166,219 @@ impl Parsed {
     pub fn offset(&self) -> Option<i32> {
         self.offset
     }
+
+    /// Returns a `Parsed` with the [`year`](Parsed::year), [`month`](Parsed::month) and
+    /// [`day`](Parsed::day) fields set to those of `date`.
+    ///
+    /// This is useful to combine a known date with fields parsed later, such as a time of day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+    /// let mut parsed = Parsed::from_date(&date);
+    /// parse(&mut parsed, "13:04", StrftimeItems::new("%H:%M"))?;
+    /// assert_eq!(parsed.to_naive_datetime_with_offset(0)?, date.and_hms_opt(13, 4, 0).unwrap());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn from_date<D: Datelike>(date: &D) -> Parsed {
+        let mut parsed = Parsed::new();
+        parsed.with_year(date.year()).with_month(date.month()).with_day(date.day());
+        parsed
+    }
+
+    /// Set the [`year`](Parsed::year) field to `value`, and return `self` for chaining.
+    ///
+    /// Unlike [`set_year()`](Parsed::set_year) and the other `set_*` methods, the `with_*`
+    /// methods replace any value that was already set, and don't check the range of `value`.
+    /// Values that are out of range, and fields that are inconsistent, are reported as errors by
+    /// the `to_*` methods.
+    ///
+    /// The fields that are split in two, `year_div_100`, `year_mod_100`, `hour_div_12` and
+    /// `hour_mod_12`, can be set with the `set_*` methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::Parsed;
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let date = Parsed::new().with_year(2024).with_month(5).with_day(7).to_naive_date()?;
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 5, 7).unwrap());
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.with_isoyear(2024).with_isoweek(19).with_weekday(Weekday::Tue);
+    /// assert_eq!(parsed.to_naive_date()?, date);
+    ///
+    /// assert!(Parsed::new().with_year(2024).with_month(13).with_day(1).to_naive_date().is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn with_year(&mut self, value: i32) -> &mut Parsed {
+        self.year = Some(value);
+        self
+    }
+
+    /// Set the [`isoyear`](Parsed::isoyear) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_isoyear()`](Parsed::set_isoyear).
+    #[inline]
+    pub fn with_isoyear(&mut self, value: i32) -> &mut Parsed {
+        self.isoyear = Some(value);
+        self
+    }
+
+    /// Set the [`quarter`](Parsed::quarter) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_quarter()`](Parsed::set_quarter).
+    #[inline]
+    pub fn with_quarter(&mut self, value: u32) -> &mut Parsed {
+        self.quarter = Some(value);
+        self
+    }
+
+    /// Set the [`month`](Parsed::month) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_month()`](Parsed::set_month).
+    #[inline]
+    pub fn with_month(&mut self, value: u32) -> &mut Parsed {
+        self.month = Some(value);
+        self
+    }
+
+    /// Set the [`week_from_sun`](Parsed::week_from_sun) field to `value`, and return `self` for
+    /// chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_week_from_sun()`](Parsed::set_week_from_sun).
+    #[inline]
+    pub fn with_week_from_sun(&mut self, value: u32) -> &mut Parsed {
+        self.week_from_sun = Some(value);
+        self
+    }
+
+    /// Set the [`week_from_mon`](Parsed::week_from_mon) field to `value`, and return `self` for
+    /// chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_week_from_mon()`](Parsed::set_week_from_mon).
+    #[inline]
+    pub fn with_week_from_mon(&mut self, value: u32) -> &mut Parsed {
+        self.week_from_mon = Some(value);
+        self
+    }
+
+    /// Set the [`isoweek`](Parsed::isoweek) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_isoweek()`](Parsed::set_isoweek).
+    #[inline]
+    pub fn with_isoweek(&mut self, value: u32) -> &mut Parsed {
+        self.isoweek = Some(value);
+        self
+    }
+
+    /// Set the [`weekday`](Parsed::weekday) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_weekday()`](Parsed::set_weekday).
+    #[inline]
+    pub fn with_weekday(&mut self, value: Weekday) -> &mut Parsed {
+        self.weekday = Some(value);
+        self
+    }
+
+    /// Set the [`ordinal`](Parsed::ordinal) (day of the year) field to `value`, and return `self`
+    /// for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_ordinal()`](Parsed::set_ordinal).
+    #[inline]
+    pub fn with_ordinal(&mut self, value: u32) -> &mut Parsed {
+        self.ordinal = Some(value);
+        self
+    }
+
+    /// Set the [`day`](Parsed::day) of the month field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_day()`](Parsed::set_day).
+    #[inline]
+    pub fn with_day(&mut self, value: u32) -> &mut Parsed {
+        self.day = Some(value);
+        self
+    }
+
+    /// Set the [`hour_div_12`](Parsed::hour_div_12) and [`hour_mod_12`](Parsed::hour_mod_12)
+    /// fields to `value` for a 24-hour clock, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_hour()`](Parsed::set_hour).
+    #[inline]
+    pub fn with_hour(&mut self, value: u32) -> &mut Parsed {
+        self.hour_div_12 = Some(value / 12);
+        self.hour_mod_12 = Some(value % 12);
+        self
+    }
+
+    /// Set the [`minute`](Parsed::minute) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_minute()`](Parsed::set_minute).
+    #[inline]
+    pub fn with_minute(&mut self, value: u32) -> &mut Parsed {
+        self.minute = Some(value);
+        self
+    }
+
+    /// Set the [`second`](Parsed::second) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_second()`](Parsed::set_second).
+    #[inline]
+    pub fn with_second(&mut self, value: u32) -> &mut Parsed {
+        self.second = Some(value);
+        self
+    }
+
+    /// Set the [`nanosecond`](Parsed::nanosecond) field to `value`, and return `self` for
+    /// chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_nanosecond()`](Parsed::set_nanosecond).
+    #[inline]
+    pub fn with_nanosecond(&mut self, value: u32) -> &mut Parsed {
+        self.nanosecond = Some(value);
+        self
+    }
+
+    /// Set the [`timestamp`](Parsed::timestamp) field to `value`, and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_timestamp()`](Parsed::set_timestamp).
+    #[inline]
+    pub fn with_timestamp(&mut self, value: i64) -> &mut Parsed {
+        self.timestamp = Some(value);
+        self
+    }
+
+    /// Set the [`offset`](Parsed::offset) field to `value`, in seconds from local time to UTC,
+    /// and return `self` for chaining.
+    ///
+    /// See [`with_year()`](Parsed::with_year) for how this differs from
+    /// [`set_offset()`](Parsed::set_offset).
+    #[inline]
+    pub fn with_offset(&mut self, value: i32) -> &mut Parsed {
+        self.offset = Some(value);
+        self
+    }
 }
 
 /// Create a `NaiveDate` when given a year, week, weekday, and the definition at which day of the
@@ -1909,4 +2126,46 @@ mod tests {
         parsed.year = Some(2001);
         assert_eq!(NaiveDate::from_ymd_opt(2001, 5, 28).unwrap(), parsed.to_naive_date().unwrap());
     }
+
+    #[test]
+    fn test_parsed_builder() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+        let time = NaiveTime::from_hms_nano_opt(13, 4, 5, 6).unwrap();
+        let dt = date.and_time(time);
+
+        assert_eq!(
+            Parsed::new().with_year(2024).with_month(5).with_day(7).to_naive_date(),
+            Ok(date)
+        );
+        assert_eq!(Parsed::new().with_year(2024).with_ordinal(128).to_naive_date(), Ok(date));
+        let mut parsed = Parsed::new();
+        parsed.with_year(2024).with_week_from_sun(18).with_weekday(Tue);
+        assert_eq!(parsed.to_naive_date(), Ok(date));
+        parsed.with_week_from_sun(0).with_week_from_mon(19);
+        assert_eq!(parsed.to_naive_date(), Err(IMPOSSIBLE));
+        let mut parsed = Parsed::new();
+        parsed.with_isoyear(2024).with_isoweek(19).with_weekday(Tue).with_quarter(2);
+        assert_eq!(parsed.to_naive_date(), Ok(date));
+
+        let mut parsed = Parsed::from_date(&date);
+        assert_eq!(parsed.to_naive_date(), Ok(date));
+        parsed.with_hour(13).with_minute(4).with_second(5).with_nanosecond(6);
+        assert_eq!(parsed.to_naive_time(), Ok(time));
+        assert_eq!(parsed.to_naive_datetime_with_offset(0), Ok(dt));
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        parsed.with_offset(3600);
+        assert_eq!(parsed.to_datetime(), Ok(offset.from_local_datetime(&dt).unwrap()));
+        let ts = dt.and_utc().timestamp();
+        assert_eq!(parsed.with_timestamp(ts).to_datetime(), Err(IMPOSSIBLE));
+        assert!(parsed.with_timestamp(ts - 3600).to_datetime().is_ok());
+
+        // Values are replaced, and their range is checked when resolving.
+        assert_eq!(parsed.with_hour(7).hour_div_12(), Some(0));
+        assert_eq!(parsed.hour_mod_12(), Some(7));
+        assert_eq!(parsed.with_hour(24).to_naive_time(), Err(OUT_OF_RANGE));
+        assert_eq!(Parsed::from_date(&date).with_day(32).to_naive_date(), Err(OUT_OF_RANGE));
+        assert_eq!(Parsed::new().with_year(2024).to_naive_date(), Err(NOT_ENOUGH));
+        let dt = Utc.with_ymd_and_hms(2024, 5, 7, 0, 0, 0).unwrap();
+        assert_eq!(Parsed::from_date(&dt).day(), Some(7));
+    }
 }
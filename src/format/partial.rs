@@ -0,0 +1,798 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A date and time of which any field may be missing.
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use super::{INVALID, NOT_ENOUGH, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
+use super::{ParseError, ParseErrorKind, ParseResult, Parsed, StrftimeItems, parse, scan};
+use crate::offset::Offset;
+use crate::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+/// A date and time of which any field may be missing, such as "2023-05" or "May 3, 10 AM".
+///
+/// The fields are the year, month, day, hour, minute, second, nanosecond and offset. Each field
+/// is checked to be in its own range, but the fields are not checked against each other: the
+/// 31st of a month is accepted without knowing the month. Missing fields can be filled in from
+/// a reference date and time with [`complete_naive`](PartialDateTime::complete_naive) and
+/// [`complete`](PartialDateTime::complete).
+///
+/// A `PartialDateTime` can be made from a [`Parsed`] value, which collects the fields found by
+/// any format string. Its [`Display`](fmt::Display) form is an extension of ISO 8601 in which
+/// a missing field is written as `X`s, and missing fields after the last present field of the
+/// date or time are left out: `2023-05`, `XXXX-05-03`, `T10:XX:30`, `2023-05-03T10+02:00`.
+/// This form is parsed back by the [`FromStr`] implementation, and used by serde.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::PartialDateTime;
+/// use chrono::NaiveDate;
+///
+/// let partial = PartialDateTime::parse_from_str("May 3, 10 AM", "%B %-d, %-I %p")?;
+/// assert_eq!((partial.month(), partial.hour(), partial.year()), (Some(5), Some(10), None));
+/// assert_eq!(partial.to_string(), "XXXX-05-03T10");
+/// assert_eq!("XXXX-05-03T10".parse(), Ok(partial));
+///
+/// let reference = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap().and_hms_opt(16, 30, 0).unwrap();
+/// assert_eq!(
+///     partial.complete_naive(reference),
+///     NaiveDate::from_ymd_opt(2024, 5, 3).unwrap().and_hms_opt(10, 0, 0)
+/// );
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PartialDateTime {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    nanosecond: Option<u32>,
+    offset: Option<FixedOffset>,
+}
+
+impl PartialDateTime {
+    /// Makes a new `PartialDateTime` without any fields.
+    #[must_use]
+    pub const fn new() -> PartialDateTime {
+        PartialDateTime {
+            year: None,
+            month: None,
+            day: None,
+            hour: None,
+            minute: None,
+            second: None,
+            nanosecond: None,
+            offset: None,
+        }
+    }
+
+    /// Makes a new `PartialDateTime` from the fields of `parsed`.
+    ///
+    /// The year may also be given with the `%C` and `%y` fields, and the hour with the `%I` and
+    /// `%p` fields. If the date can be fully resolved, such as from a year and ordinal, it is
+    /// converted to a year, month and day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotEnough` if the hour is given without AM or PM, or if the century is given
+    /// without the rest of the year. Returns `Impossible` if the fields of the date are
+    /// inconsistent, and `OutOfRange` for a leap second.
+    pub fn from_parsed(parsed: &Parsed) -> ParseResult<PartialDateTime> {
+        let mut partial = PartialDateTime::new();
+        match parsed.to_naive_date() {
+            Ok(date) => {
+                partial.year = Some(date.year());
+                partial.month = Some(date.month());
+                partial.day = Some(date.day());
+            }
+            Err(e) if e.kind() == ParseErrorKind::NotEnough => {
+                partial.year = match (parsed.year(), parsed.year_div_100(), parsed.year_mod_100()) {
+                    (Some(year), _, _) => Some(year),
+                    (None, Some(q), Some(r)) => Some(q * 100 + r),
+                    (None, None, Some(r)) => Some(if r < 70 { 2000 + r } else { 1900 + r }),
+                    (None, Some(_), None) => return Err(NOT_ENOUGH),
+                    (None, None, None) => None,
+                };
+                partial.month = parsed.month();
+                partial.day = parsed.day();
+            }
+            Err(e) => return Err(e),
+        }
+        partial.hour = match (parsed.hour_div_12(), parsed.hour_mod_12()) {
+            (Some(div), Some(rem)) => Some(div * 12 + rem),
+            (None, None) => None,
+            _ => return Err(NOT_ENOUGH),
+        };
+        partial.minute = parsed.minute();
+        partial.second = match parsed.second() {
+            Some(60) => return Err(OUT_OF_RANGE),
+            second => second,
+        };
+        partial.nanosecond = parsed.nanosecond();
+        partial.offset = match parsed.offset() {
+            Some(offset) => Some(FixedOffset::east_opt(offset).ok_or(OUT_OF_RANGE)?),
+            None => None,
+        };
+        if partial.year.map_or(false, |year| partial.with_year(year).is_none()) {
+            return Err(OUT_OF_RANGE);
+        }
+        Ok(partial)
+    }
+
+    /// Parses a string with the specified format string into a new `PartialDateTime`.
+    ///
+    /// See the [`format::strftime` module](crate::format::strftime) on the supported escape
+    /// sequences. Unlike [`NaiveDateTime::parse_from_str`], any field may be missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` does not match `fmt`, or for the reasons given in
+    /// [`from_parsed`](PartialDateTime::from_parsed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::PartialDateTime;
+    ///
+    /// let partial = PartialDateTime::parse_from_str("2023-05", "%Y-%m")?;
+    /// assert_eq!((partial.year(), partial.month(), partial.day()), (Some(2023), Some(5), None));
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_from_str(s: &str, fmt: &str) -> ParseResult<PartialDateTime> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        PartialDateTime::from_parsed(&parsed)
+    }
+
+    /// Returns a [`Parsed`] with the fields of `self`.
+    ///
+    /// The hour is given as the `%I` and `%p` fields, as is done by the parser for `%H`.
+    #[must_use]
+    pub fn to_parsed(&self) -> Parsed {
+        let mut parsed = Parsed::new();
+        // The fields are in range, so setting them on an empty `Parsed` can not fail.
+        let _ = self.year.map(|v| parsed.set_year(i64::from(v)));
+        let _ = self.month.map(|v| parsed.set_month(i64::from(v)));
+        let _ = self.day.map(|v| parsed.set_day(i64::from(v)));
+        let _ = self.hour.map(|v| parsed.set_hour(i64::from(v)));
+        let _ = self.minute.map(|v| parsed.set_minute(i64::from(v)));
+        let _ = self.second.map(|v| parsed.set_second(i64::from(v)));
+        let _ = self.nanosecond.map(|v| parsed.set_nanosecond(i64::from(v)));
+        let _ = self.offset.map(|v| parsed.set_offset(i64::from(v.local_minus_utc())));
+        parsed
+    }
+
+    /// Returns the year, if present.
+    #[must_use]
+    pub const fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// Returns the month, starting from 1, if present.
+    #[must_use]
+    pub const fn month(&self) -> Option<u32> {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from 1, if present.
+    #[must_use]
+    pub const fn day(&self) -> Option<u32> {
+        self.day
+    }
+
+    /// Returns the hour, from 0 to 23, if present.
+    #[must_use]
+    pub const fn hour(&self) -> Option<u32> {
+        self.hour
+    }
+
+    /// Returns the minute, if present.
+    #[must_use]
+    pub const fn minute(&self) -> Option<u32> {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59, if present.
+    #[must_use]
+    pub const fn second(&self) -> Option<u32> {
+        self.second
+    }
+
+    /// Returns the nanoseconds since the whole second, if present.
+    #[must_use]
+    pub const fn nanosecond(&self) -> Option<u32> {
+        self.nanosecond
+    }
+
+    /// Returns the offset from UTC, if present.
+    #[must_use]
+    pub const fn offset(&self) -> Option<FixedOffset> {
+        self.offset
+    }
+
+    /// Makes a new `PartialDateTime` with the year changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the year is out of the range of a [`NaiveDate`].
+    #[must_use]
+    pub fn with_year(mut self, year: i32) -> Option<PartialDateTime> {
+        if year < NaiveDate::MIN.year() || year > NaiveDate::MAX.year() {
+            return None;
+        }
+        self.year = Some(year);
+        Some(self)
+    }
+
+    /// Makes a new `PartialDateTime` with the month changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the month is not in the range 1 to 12.
+    #[must_use]
+    pub fn with_month(mut self, month: u32) -> Option<PartialDateTime> {
+        self.month = Some(month).filter(|m| (1..=12).contains(m));
+        self.month.map(|_| self)
+    }
+
+    /// Makes a new `PartialDateTime` with the day of the month changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the day is not in the range 1 to 31.
+    #[must_use]
+    pub fn with_day(mut self, day: u32) -> Option<PartialDateTime> {
+        self.day = Some(day).filter(|d| (1..=31).contains(d));
+        self.day.map(|_| self)
+    }
+
+    /// Makes a new `PartialDateTime` with the hour changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the hour is greater than 23.
+    #[must_use]
+    pub fn with_hour(mut self, hour: u32) -> Option<PartialDateTime> {
+        self.hour = Some(hour).filter(|h| *h < 24);
+        self.hour.map(|_| self)
+    }
+
+    /// Makes a new `PartialDateTime` with the minute changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the minute is greater than 59.
+    #[must_use]
+    pub fn with_minute(mut self, minute: u32) -> Option<PartialDateTime> {
+        self.minute = Some(minute).filter(|m| *m < 60);
+        self.minute.map(|_| self)
+    }
+
+    /// Makes a new `PartialDateTime` with the second changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the second is greater than 59.
+    #[must_use]
+    pub fn with_second(mut self, second: u32) -> Option<PartialDateTime> {
+        self.second = Some(second).filter(|s| *s < 60);
+        self.second.map(|_| self)
+    }
+
+    /// Makes a new `PartialDateTime` with the nanoseconds since the whole second changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the nanoseconds are 1,000,000,000 or more.
+    #[must_use]
+    pub fn with_nanosecond(mut self, nanosecond: u32) -> Option<PartialDateTime> {
+        self.nanosecond = Some(nanosecond).filter(|n| *n < 1_000_000_000);
+        self.nanosecond.map(|_| self)
+    }
+
+    /// Makes a new `PartialDateTime` with the offset changed.
+    #[must_use]
+    pub const fn with_offset(mut self, offset: FixedOffset) -> PartialDateTime {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Fills in the missing fields from `reference`, ignoring the offset.
+    ///
+    /// Missing fields that are more significant than the least significant present field are
+    /// taken from `reference`, and the other missing fields are set to their minimum. So
+    /// "2023-05" becomes the start of May 2023, and "May 3, 10 AM" becomes 10:00 on the 3rd of
+    /// May in the year of `reference`. Without any fields, `reference` is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date or time does not exist, such as February 30.
+    #[must_use]
+    pub fn complete_naive(&self, reference: NaiveDateTime) -> Option<NaiveDateTime> {
+        let fields = [
+            self.year.map(i64::from),
+            self.month.map(i64::from),
+            self.day.map(i64::from),
+            self.hour.map(i64::from),
+            self.minute.map(i64::from),
+            self.second.map(i64::from),
+            self.nanosecond.map(i64::from),
+        ];
+        let reference = [
+            i64::from(reference.year()),
+            i64::from(reference.month()),
+            i64::from(reference.day()),
+            i64::from(reference.hour()),
+            i64::from(reference.minute()),
+            i64::from(reference.second()),
+            i64::from(reference.nanosecond()),
+        ];
+        let minimum = [0, 1, 1, 0, 0, 0, 0];
+        let last = fields.iter().rposition(Option::is_some).unwrap_or(fields.len());
+        let mut values = [0; 7];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = fields[i].unwrap_or(if i < last { reference[i] } else { minimum[i] });
+        }
+        let [year, month, day, hour, minute, second, nanosecond] = values;
+        NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)?.and_hms_nano_opt(
+            hour as u32,
+            minute as u32,
+            second as u32,
+            nanosecond as u32,
+        )
+    }
+
+    /// Fills in the missing fields from `reference`, in the offset of `self`.
+    ///
+    /// If `self` has no offset, the offset of `reference` is used. Otherwise `reference` is
+    /// first converted to the offset of `self`. The fields are filled in as described in
+    /// [`complete_naive`](PartialDateTime::complete_naive).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date or time does not exist, or is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::PartialDateTime;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let partial: PartialDateTime = "T23+02:00".parse()?;
+    /// let reference = Utc.with_ymd_and_hms(2024, 8, 15, 22, 30, 0).unwrap();
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// // 22:30 UTC is already the 16th in UTC+2.
+    /// let expected = tz.with_ymd_and_hms(2024, 8, 16, 23, 0, 0).unwrap();
+    /// assert_eq!(partial.complete(&reference), Some(expected));
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn complete<Tz: TimeZone>(
+        &self,
+        reference: &DateTime<Tz>,
+    ) -> Option<DateTime<FixedOffset>> {
+        let offset = self.offset.unwrap_or_else(|| reference.offset().fix());
+        let local = reference.naive_utc().checked_add_offset(offset)?;
+        self.complete_naive(local)?.and_fixed_offset(offset)
+    }
+}
+
+impl From<NaiveDateTime> for PartialDateTime {
+    fn from(datetime: NaiveDateTime) -> PartialDateTime {
+        PartialDateTime {
+            year: Some(datetime.year()),
+            month: Some(datetime.month()),
+            day: Some(datetime.day()),
+            hour: Some(datetime.hour()),
+            minute: Some(datetime.minute()),
+            // A leap second can not be represented, and is folded into second 59.
+            second: Some(datetime.second()),
+            nanosecond: Some(datetime.nanosecond() % 1_000_000_000),
+            offset: None,
+        }
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for PartialDateTime {
+    fn from(datetime: DateTime<Tz>) -> PartialDateTime {
+        PartialDateTime::from(datetime.naive_local()).with_offset(datetime.offset().fix())
+    }
+}
+
+/// Writes a two-digit field after `separator`, or `XX` if it is missing.
+fn write_field(f: &mut fmt::Formatter, separator: &str, value: Option<u32>) -> fmt::Result {
+    f.write_str(separator)?;
+    match value {
+        Some(value) => write!(f, "{:02}", value),
+        None => f.write_str("XX"),
+    }
+}
+
+/// Parses a two-digit field, or `XX` for a missing field.
+fn parse_field(s: &str) -> ParseResult<(&str, Option<u32>)> {
+    match s.strip_prefix("XX") {
+        Some(s) => Ok((s, None)),
+        None => scan::number(s, 2, 2).map(|(s, v)| (s, Some(v as u32))),
+    }
+}
+
+impl fmt::Display for PartialDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year.is_some() || self.month.is_some() || self.day.is_some() {
+            match self.year {
+                Some(year) if (0..=9999).contains(&year) => write!(f, "{:04}", year)?,
+                Some(year) => write!(f, "{:+05}", year)?,
+                None => f.write_str("XXXX")?,
+            }
+            if self.month.is_some() || self.day.is_some() {
+                write_field(f, "-", self.month)?;
+            }
+            if self.day.is_some() {
+                write_field(f, "-", self.day)?;
+            }
+        }
+
+        let has_seconds = self.second.is_some() || self.nanosecond.is_some();
+        let has_minutes = has_seconds || self.minute.is_some();
+        let has_hours = has_minutes || self.hour.is_some();
+        if !has_hours && self.offset.is_none() {
+            return Ok(());
+        }
+        f.write_char('T')?;
+        if has_hours {
+            write_field(f, "", self.hour)?;
+        }
+        if has_minutes {
+            write_field(f, ":", self.minute)?;
+        }
+        if has_seconds {
+            write_field(f, ":", self.second)?;
+        }
+        match self.nanosecond {
+            Some(nano) if nano % 1_000_000 == 0 => write!(f, ".{:03}", nano / 1_000_000)?,
+            Some(nano) if nano % 1_000 == 0 => write!(f, ".{:06}", nano / 1_000)?,
+            Some(nano) => write!(f, ".{:09}", nano)?,
+            None => {}
+        }
+        if let Some(offset) = self.offset {
+            // Unlike `%:z`, this keeps the seconds of an offset.
+            let secs = offset.local_minus_utc();
+            let sign = if secs < 0 { '-' } else { '+' };
+            let secs = secs.unsigned_abs();
+            write!(f, "{}{:02}:{:02}", sign, secs / 3600, secs / 60 % 60)?;
+            if secs % 60 != 0 {
+                write!(f, ":{:02}", secs % 60)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parsing a `str` into a `PartialDateTime` uses the format of its [`Display`](fmt::Display)
+/// implementation.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::PartialDateTime;
+///
+/// let partial: PartialDateTime = "XXXX-XX-03T10:XX:30.5".parse()?;
+/// assert_eq!((partial.day(), partial.hour(), partial.minute()), (Some(3), Some(10), None));
+/// assert_eq!(partial.nanosecond(), Some(500_000_000));
+///
+/// assert!("2023-5".parse::<PartialDateTime>().is_err());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+impl FromStr for PartialDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<PartialDateTime> {
+        let mut partial = PartialDateTime::new();
+        let (mut s, time) = match s.find('T') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+
+        if !s.is_empty() {
+            if let Some(rest) = s.strip_prefix("XXXX") {
+                s = rest;
+            } else {
+                let (rest, year) = match s.as_bytes()[0] {
+                    b'+' => scan::number(&s[1..], 4, usize::MAX)?,
+                    b'-' => scan::number(&s[1..], 4, usize::MAX).map(|(s, v)| (s, -v))?,
+                    _ => scan::number(s, 4, 4)?,
+                };
+                let year = i32::try_from(year).map_err(|_| OUT_OF_RANGE)?;
+                partial = partial.with_year(year).ok_or(OUT_OF_RANGE)?;
+                s = rest;
+            }
+            if !s.is_empty() {
+                let (rest, month) = parse_field(scan::char(s, b'-')?)?;
+                match month {
+                    Some(month) => partial = partial.with_month(month).ok_or(OUT_OF_RANGE)?,
+                    None if rest.is_empty() => return Err(INVALID),
+                    None => {}
+                }
+                s = rest;
+            }
+            if !s.is_empty() {
+                let (rest, day) = parse_field(scan::char(s, b'-')?)?;
+                partial.day = match day {
+                    Some(day) => partial.with_day(day).ok_or(OUT_OF_RANGE)?.day,
+                    None => return Err(INVALID), // a missing last field is left out
+                };
+                s = rest;
+            }
+            if !s.is_empty() {
+                return Err(TOO_LONG);
+            }
+            if partial.year.is_none() && partial.month.is_none() && partial.day.is_none() {
+                return Err(INVALID);
+            }
+        }
+
+        let mut s = match time {
+            Some("") => return Err(TOO_SHORT),
+            Some(s) => s,
+            None => return Ok(partial),
+        };
+        let fields: [fn(PartialDateTime, u32) -> Option<PartialDateTime>; 3] = [
+            PartialDateTime::with_hour,
+            PartialDateTime::with_minute,
+            PartialDateTime::with_second,
+        ];
+        let mut last_missing = false;
+        for (i, with_field) in fields.iter().enumerate() {
+            if i == 0 && !s.starts_with(|c: char| c.is_ascii_digit() || c == 'X') {
+                break;
+            }
+            if i > 0 {
+                match s.strip_prefix(':') {
+                    Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit() || c == 'X') => {
+                        s = rest
+                    }
+                    _ => break,
+                }
+            }
+            let (rest, value) = parse_field(s)?;
+            if let Some(value) = value {
+                partial = with_field(partial, value).ok_or(OUT_OF_RANGE)?;
+            }
+            last_missing = value.is_none();
+            s = rest;
+        }
+        // a missing last field is left out, unless it is followed by a fraction
+        if last_missing && !s.starts_with('.') {
+            return Err(INVALID);
+        }
+        if let Some(digits) = s.strip_prefix('.') {
+            let (rest, nano) = scan::nanosecond(digits)?;
+            partial.nanosecond = Some(nano as u32);
+            s = rest;
+        }
+        if !s.is_empty() {
+            let (rest, mut offset) =
+                scan::timezone_offset(s, |s| scan::char(s, b':'), true, false, true)?;
+            s = rest;
+            if let Some(rest) = s.strip_prefix(':') {
+                let (rest, secs) = scan::number(rest, 2, 2)?;
+                if secs >= 60 {
+                    return Err(OUT_OF_RANGE);
+                }
+                offset += if offset < 0 { -(secs as i32) } else { secs as i32 };
+                s = rest;
+            }
+            if !s.is_empty() {
+                return Err(TOO_LONG);
+            }
+            partial.offset = Some(FixedOffset::east_opt(offset).ok_or(OUT_OF_RANGE)?);
+        }
+        Ok(partial)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::PartialDateTime;
+    use core::fmt;
+    use serde::{de, ser};
+
+    impl ser::Serialize for PartialDateTime {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct PartialDateTimeVisitor;
+
+    impl de::Visitor<'_> for PartialDateTimeVisitor {
+        type Value = PartialDateTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a formatted partial date and time string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            value.parse().map_err(E::custom)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for PartialDateTime {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_str(PartialDateTimeVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::PartialDateTime;
+
+        #[test]
+        fn test_serde_partial_datetime() {
+            let partial: PartialDateTime = "XXXX-05-03T10+02:00".parse().unwrap();
+            assert_eq!(
+                serde_json::to_string(&partial).ok(),
+                Some(r#""XXXX-05-03T10+02:00""#.into())
+            );
+            assert_eq!(serde_json::from_str(r#""XXXX-05-03T10+02:00""#).ok(), Some(partial));
+            assert_eq!(
+                serde_json::from_str::<PartialDateTime>(r#""""#).ok(),
+                Some(PartialDateTime::new())
+            );
+            assert!(serde_json::from_str::<PartialDateTime>(r#""2023-13""#).is_err());
+            assert!(serde_json::from_str::<PartialDateTime>("2023").is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PartialDateTime;
+    use crate::format::{ParseErrorKind, Parsed};
+    use crate::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn test_partial_datetime_display_and_parse() {
+        let cases = [
+            ("", PartialDateTime::new()),
+            ("2023", PartialDateTime::new().with_year(2023).unwrap()),
+            ("2023-05", PartialDateTime::new().with_year(2023).unwrap().with_month(5).unwrap()),
+            (
+                "-0044-03-15",
+                PartialDateTime::new()
+                    .with_year(-44)
+                    .unwrap()
+                    .with_month(3)
+                    .unwrap()
+                    .with_day(15)
+                    .unwrap(),
+            ),
+            ("+12345", PartialDateTime::new().with_year(12345).unwrap()),
+            ("XXXX-05-03", PartialDateTime::new().with_month(5).unwrap().with_day(3).unwrap()),
+            ("XXXX-XX-03", PartialDateTime::new().with_day(3).unwrap()),
+            ("T10", PartialDateTime::new().with_hour(10).unwrap()),
+            ("TXX:30", PartialDateTime::new().with_minute(30).unwrap()),
+            (
+                "T10:XX:30.250",
+                PartialDateTime::new()
+                    .with_hour(10)
+                    .unwrap()
+                    .with_second(30)
+                    .unwrap()
+                    .with_nanosecond(250_000_000)
+                    .unwrap(),
+            ),
+            ("TXX:XX:XX.000001", PartialDateTime::new().with_nanosecond(1000).unwrap()),
+            ("TXX:XX:XX.000000001", PartialDateTime::new().with_nanosecond(1).unwrap()),
+            ("T+02:00", PartialDateTime::new().with_offset(FixedOffset::east_opt(7200).unwrap())),
+            (
+                "2023T-00:30:15",
+                PartialDateTime::new()
+                    .with_year(2023)
+                    .unwrap()
+                    .with_offset(FixedOffset::west_opt(1815).unwrap()),
+            ),
+        ];
+        for (s, partial) in cases {
+            assert_eq!(partial.to_string(), s);
+            assert_eq!(s.parse(), Ok(partial), "{}", s);
+        }
+
+        let partial: PartialDateTime = "2023-05-03T10:30:00Z".parse().unwrap();
+        assert_eq!(partial.to_string(), "2023-05-03T10:30:00+00:00");
+        for s in [
+            "XXXX",
+            "2023-XX",
+            "T10:XX",
+            "2023-",
+            "2023-5",
+            "2023-13",
+            "2023-XX",
+            "XX-05",
+            "2023T",
+            "T24",
+            "T10:60",
+            "T10:XX:XX:",
+            "Tfoo",
+            "2023-05-03T10 ",
+        ] {
+            assert!(s.parse::<PartialDateTime>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_partial_datetime_from_parsed() {
+        let parse = PartialDateTime::parse_from_str;
+        let partial = parse("May 3, 10 PM", "%B %-d, %-I %p").unwrap();
+        assert_eq!(partial.to_string(), "XXXX-05-03T22");
+        assert_eq!(parse("23 05", "%y %m").unwrap().to_string(), "2023-05");
+        assert_eq!(parse("2023-123", "%Y-%j").unwrap().to_string(), "2023-05-03");
+        assert_eq!(parse("10:30 +0100", "%H:%M %z").unwrap().to_string(), "T10:30+01:00");
+        assert_eq!(parse("10", "%I").map_err(|e| e.kind()), Err(ParseErrorKind::NotEnough));
+        assert_eq!(parse("20", "%C").map_err(|e| e.kind()), Err(ParseErrorKind::NotEnough));
+        assert_eq!(
+            parse("2023-02-30", "%F").map_err(|e| e.kind()),
+            Err(ParseErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            parse("Tue 2023-05-03", "%a %F").map_err(|e| e.kind()),
+            Err(ParseErrorKind::Impossible)
+        );
+
+        let mut parsed = Parsed::new();
+        parsed.set_month(2).unwrap();
+        parsed.set_day(30).unwrap();
+        let partial = PartialDateTime::from_parsed(&parsed).unwrap();
+        assert_eq!(partial.to_string(), "XXXX-02-30");
+        assert_eq!(PartialDateTime::from_parsed(&partial.to_parsed()), Ok(partial));
+    }
+
+    #[test]
+    fn test_partial_datetime_complete() {
+        let reference =
+            NaiveDate::from_ymd_opt(2024, 8, 15).unwrap().and_hms_milli_opt(16, 30, 12, 5).unwrap();
+        let complete = |s: &str| s.parse::<PartialDateTime>().unwrap().complete_naive(reference);
+        let ymd_hms = |y, m, d, h, mi, s| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, mi, s).unwrap()
+        };
+
+        assert_eq!(complete("2023-05"), Some(ymd_hms(2023, 5, 1, 0, 0, 0)));
+        assert_eq!(complete("XXXX-05-03T10"), Some(ymd_hms(2024, 5, 3, 10, 0, 0)));
+        assert_eq!(complete("2023-XX-03"), Some(ymd_hms(2023, 8, 3, 0, 0, 0)));
+        assert_eq!(complete("TXX:45"), Some(ymd_hms(2024, 8, 15, 16, 45, 0)));
+        assert_eq!(complete("XXXX-02-30"), None);
+        assert_eq!(PartialDateTime::new().complete_naive(reference), Some(reference));
+        assert_eq!(
+            PartialDateTime::from(reference).complete_naive(ymd_hms(2000, 1, 1, 0, 0, 0)),
+            Some(reference)
+        );
+
+        let utc = Utc.from_utc_datetime(&reference);
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let partial = "T08+09:00".parse::<PartialDateTime>().unwrap();
+        assert_eq!(partial.complete(&utc), tz.with_ymd_and_hms(2024, 8, 16, 8, 0, 0).single());
+        let partial = "XXXX-12".parse::<PartialDateTime>().unwrap();
+        assert_eq!(
+            partial.complete(&utc),
+            FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2024, 12, 1, 0, 0, 0).single()
+        );
+        assert_eq!(
+            PartialDateTime::from(utc.with_timezone(&tz)).complete(&DateTime::<Utc>::UNIX_EPOCH),
+            Some(utc.with_timezone(&tz).fixed_offset())
+        );
+    }
+}
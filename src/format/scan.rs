@@ -5,7 +5,7 @@
  * Various scanning routines for the parser.
  */
 
-use super::{INVALID, OUT_OF_RANGE, ParseResult, TOO_SHORT};
+use super::{INVALID, OUT_OF_RANGE, ParseResult, Rfc2822Zone, TOO_SHORT};
 use crate::Weekday;
 
 /// Tries to parse the non-negative number from `min` to `max` digits.
@@ -159,6 +159,44 @@ pub(super) fn short_or_long_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
     Ok((s, weekday))
 }
 
+/// Tries to parse the longest name from any of the lists in `names`, ignoring case.
+/// Returns the index of the name within its list. Empty names never match.
+pub(super) fn localized_name<'a>(s: &'a str, names: &[&[&str]]) -> ParseResult<(&'a str, usize)> {
+    let mut longest: Option<(&'a str, usize)> = None;
+    for list in names {
+        for (i, name) in list.iter().enumerate() {
+            if name.is_empty() {
+                continue;
+            }
+            match strip_prefix_ignore_case(s, name) {
+                Some(rest) if longest.map_or(true, |(r, _)| rest.len() < r.len()) => {
+                    longest = Some((rest, i))
+                }
+                _ => {}
+            }
+        }
+    }
+    match longest {
+        Some(longest) => Ok(longest),
+        None if s.is_empty() => Err(TOO_SHORT),
+        None => Err(INVALID),
+    }
+}
+
+/// Returns the remainder of `s` after `prefix` if `s` starts with it, ignoring case.
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut chars = s.char_indices();
+    let mut end = 0;
+    for p in prefix.chars() {
+        let (i, c) = chars.next()?;
+        if c != p && !c.to_lowercase().eq(p.to_lowercase()) {
+            return None;
+        }
+        end = i + c.len_utf8();
+    }
+    Some(&s[end..])
+}
+
 /// Tries to consume exactly one given character.
 pub(super) fn char(s: &str, c1: u8) -> ParseResult<&str> {
     match s.as_bytes().first() {
@@ -283,20 +321,19 @@ where
 /// See [RFC 2822 Section 4.3].
 ///
 /// [RFC 2822 Section 4.3]: https://tools.ietf.org/html/rfc2822#section-4.3
-pub(super) fn timezone_offset_2822(s: &str) -> ParseResult<(&str, i32)> {
+///
+/// Also returns the kind of zone that was found.
+pub(super) fn timezone_offset_2822(s: &str) -> ParseResult<(&str, (i32, Rfc2822Zone))> {
     // tries to parse legacy time zone names
     let upto = s.as_bytes().iter().position(|&c| !c.is_ascii_alphabetic()).unwrap_or(s.len());
     if upto > 0 {
         let name = &s.as_bytes()[..upto];
         let s = &s[upto..];
-        let offset_hours = |o| Ok((s, o * 3600));
+        let offset_hours = |o| Ok((s, (o * 3600, Rfc2822Zone::NorthAmerican)));
         // RFC 2822 requires support for some named North America timezones, a small subset of all
         // named timezones.
-        if name.eq_ignore_ascii_case(b"gmt")
-            || name.eq_ignore_ascii_case(b"ut")
-            || name.eq_ignore_ascii_case(b"z")
-        {
-            return offset_hours(0);
+        if name.eq_ignore_ascii_case(b"gmt") || name.eq_ignore_ascii_case(b"ut") {
+            return Ok((s, (0, Rfc2822Zone::Universal)));
         } else if name.eq_ignore_ascii_case(b"edt") {
             return offset_hours(-4);
         } else if name.eq_ignore_ascii_case(b"est") || name.eq_ignore_ascii_case(b"cdt") {
@@ -308,14 +345,20 @@ pub(super) fn timezone_offset_2822(s: &str) -> ParseResult<(&str, i32)> {
         } else if name.eq_ignore_ascii_case(b"pst") {
             return offset_hours(-8);
         } else if name.len() == 1 {
-            if let b'a'..=b'i' | b'k'..=b'y' | b'A'..=b'I' | b'K'..=b'Y' = name[0] {
-                // recommended by RFC 2822: consume but treat it as -0000
-                return Ok((s, 0));
+            if let b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z' = name[0] {
+                // recommended by RFC 2822: consume but treat it as -0000. This includes `Z`,
+                // which RFC 2822 lists with the other military zones.
+                return Ok((s, (0, Rfc2822Zone::Military)));
             }
         }
         Err(INVALID)
     } else {
-        timezone_offset(s, |s| Ok(s), false, false, false)
+        let (s_, offset) = timezone_offset(s, |s| Ok(s), false, false, false)?;
+        let zone = match offset == 0 && s.starts_with('-') {
+            true => Rfc2822Zone::UnknownLocal,
+            false => Rfc2822Zone::Offset,
+        };
+        Ok((s_, (offset, zone)))
     }
 }
 
@@ -356,7 +399,7 @@ mod tests {
         timezone_offset_2822,
     };
     use crate::Weekday;
-    use crate::format::{INVALID, TOO_SHORT};
+    use crate::format::{INVALID, Rfc2822Zone, TOO_SHORT};
 
     #[test]
     fn test_rfc2822_comments() {
@@ -394,10 +437,17 @@ mod tests {
 
     #[test]
     fn test_timezone_offset_2822() {
-        assert_eq!(timezone_offset_2822("cSt").unwrap(), ("", -21600));
-        assert_eq!(timezone_offset_2822("pSt").unwrap(), ("", -28800));
-        assert_eq!(timezone_offset_2822("mSt").unwrap(), ("", -25200));
-        assert_eq!(timezone_offset_2822("-1551").unwrap(), ("", -57060));
+        use Rfc2822Zone::*;
+        assert_eq!(timezone_offset_2822("cSt").unwrap(), ("", (-21600, NorthAmerican)));
+        assert_eq!(timezone_offset_2822("pSt").unwrap(), ("", (-28800, NorthAmerican)));
+        assert_eq!(timezone_offset_2822("mSt").unwrap(), ("", (-25200, NorthAmerican)));
+        assert_eq!(timezone_offset_2822("-1551").unwrap(), ("", (-57060, Offset)));
+        assert_eq!(timezone_offset_2822("+0000").unwrap(), ("", (0, Offset)));
+        assert_eq!(timezone_offset_2822("-0000").unwrap(), ("", (0, UnknownLocal)));
+        assert_eq!(timezone_offset_2822("GMT").unwrap(), ("", (0, Universal)));
+        assert_eq!(timezone_offset_2822("ut").unwrap(), ("", (0, Universal)));
+        assert_eq!(timezone_offset_2822("Z").unwrap(), ("", (0, Military)));
+        assert_eq!(timezone_offset_2822("a").unwrap(), ("", (0, Military)));
         assert_eq!(timezone_offset_2822("Gp"), Err(INVALID));
     }
 
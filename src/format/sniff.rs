@@ -0,0 +1,182 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Detecting the format of a date and time string from a sample.
+
+use super::{ParseErrorKind, Parsed, StrftimeItems, parse};
+
+/// The formats tried by [`sniff`], from the most to the least specific.
+const CANDIDATES: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f%:z",
+    "%Y-%m-%dT%H:%M:%S%.fZ",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d",
+    "%Y/%m/%d %H:%M:%S%.f",
+    "%Y/%m/%d",
+    "%Y:%m:%d %H:%M:%S",
+    "%a, %d %b %Y %H:%M:%S %z",
+    "%d %b %Y %H:%M:%S %z",
+    "%d/%b/%Y:%H:%M:%S %z",
+    "%a %b %e %H:%M:%S %Y",
+    "%m/%d/%Y %H:%M:%S",
+    "%m/%d/%Y",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y",
+    "%d.%m.%Y %H:%M:%S",
+    "%d.%m.%Y",
+    "%d %b %Y",
+    "%b %d, %Y",
+    "%Y%m%dT%H%M%S%#z",
+    "%Y%m%dT%H%M%S",
+    "%Y%m%d",
+    "%H:%M:%S%.f",
+    "%H:%M",
+    "%s",
+];
+
+/// A format detected by [`sniff`], together with the value parsed from the sample.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedFormat {
+    fmt: &'static str,
+    parsed: Parsed,
+}
+
+impl DetectedFormat {
+    /// Returns the detected format as a `strftime`-like format string.
+    ///
+    /// This string can be used with the `parse_from_str` methods to parse other values in the same
+    /// format.
+    #[must_use]
+    pub const fn format_str(&self) -> &'static str {
+        self.fmt
+    }
+
+    /// Returns the formatting items of the detected format.
+    #[must_use]
+    pub const fn items(&self) -> StrftimeItems<'static> {
+        StrftimeItems::new(self.fmt)
+    }
+
+    /// Returns the fields parsed from the sample.
+    #[must_use]
+    pub const fn parsed(&self) -> &Parsed {
+        &self.parsed
+    }
+
+    /// Returns the fields parsed from the sample, consuming `self`.
+    #[must_use]
+    pub fn into_parsed(self) -> Parsed {
+        self.parsed
+    }
+}
+
+/// Detects the format of a date and/or time string from a sample.
+///
+/// The sample is tried against a list of common formats, such as RFC 3339, RFC 2822, the Common
+/// Log Format, EXIF, and various numeric date formats. The first format that parses the whole
+/// sample into a valid date, time or timestamp is returned, together with the parsed fields.
+///
+/// This is a heuristic meant for configuring a parser from a preview of the data. Some strings are
+/// ambiguous: `05/07/2024` is detected as `%m/%d/%Y`, and only `13/07/2024` as `%d/%m/%Y`. So
+/// when the values of a column differ in their detected format, picking the most specific one,
+/// or asking the user, is up to the caller.
+///
+/// Returns `None` if no format matches.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::sniff;
+/// use chrono::NaiveDate;
+///
+/// let detected = sniff("2024-05-07 13:04:05").unwrap();
+/// assert_eq!(detected.format_str(), "%Y-%m-%d %H:%M:%S%.f");
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+/// assert_eq!(detected.parsed().to_naive_date(), Ok(date));
+///
+/// // The format can be used to parse the rest of the column.
+/// let fmt = sniff("07/May/2024:13:04:05 +0200").unwrap().format_str();
+/// assert!(chrono::DateTime::parse_from_str("08/May/2024:09:00:00 +0200", fmt).is_ok());
+///
+/// assert!(sniff("not a date").is_none());
+/// ```
+#[must_use]
+pub fn sniff(s: &str) -> Option<DetectedFormat> {
+    let s = s.trim();
+    CANDIDATES.iter().find_map(|&fmt| {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt)).ok()?;
+        // A sample without date fields must be a valid time or timestamp, otherwise the date must
+        // be valid and consistent.
+        let valid = match parsed.to_naive_date() {
+            Ok(_) => true,
+            Err(e) if e.kind() == ParseErrorKind::NotEnough => {
+                parsed.to_naive_time().is_ok() || parsed.to_naive_datetime_with_offset(0).is_ok()
+            }
+            Err(_) => false,
+        };
+        if valid { Some(DetectedFormat { fmt, parsed }) } else { None }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sniff;
+    use crate::{FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+    #[test]
+    fn test_sniff() {
+        let cases = [
+            ("2024-05-07T13:04:05+02:00", "%Y-%m-%dT%H:%M:%S%.f%:z"),
+            ("2024-05-07T13:04:05.123Z", "%Y-%m-%dT%H:%M:%S%.fZ"),
+            ("2024-05-07T13:04:05", "%Y-%m-%dT%H:%M:%S%.f"),
+            ("2024-05-07T13:04", "%Y-%m-%dT%H:%M"),
+            ("2024-05-07 13:04:05 +0200", "%Y-%m-%d %H:%M:%S%.f%:z"),
+            ("2024-05-07", "%Y-%m-%d"),
+            ("2024/05/07", "%Y/%m/%d"),
+            ("2024:05:07 13:04:05", "%Y:%m:%d %H:%M:%S"),
+            ("Tue, 07 May 2024 13:04:05 +0200", "%a, %d %b %Y %H:%M:%S %z"),
+            ("07/May/2024:13:04:05 +0200", "%d/%b/%Y:%H:%M:%S %z"),
+            ("Tue May  7 13:04:05 2024", "%a %b %e %H:%M:%S %Y"),
+            ("05/07/2024", "%m/%d/%Y"),
+            ("13/07/2024", "%d/%m/%Y"),
+            ("07.05.2024", "%d.%m.%Y"),
+            ("May 7, 2024", "%b %d, %Y"),
+            ("20240507T130405Z", "%Y%m%dT%H%M%S%#z"),
+            ("20240507", "%Y%m%d"),
+            ("13:04:05.5", "%H:%M:%S%.f"),
+            ("1715087045", "%s"),
+            ("  2024-05-07  ", "%Y-%m-%d"),
+        ];
+        for (s, fmt) in cases {
+            assert_eq!(sniff(s).map(|d| d.format_str()), Some(fmt), "{}", s);
+        }
+
+        for s in ["", "hello", "2024-13-07", "2024-02-30", "Mon, 07 May 2024 13:04:05 +0200"] {
+            assert!(sniff(s).is_none(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_sniff_parsed() {
+        let detected = sniff("Tue, 07 May 2024 13:04:05 +0200").unwrap();
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!(
+            detected.parsed().to_datetime(),
+            Ok(tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap())
+        );
+        let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+        assert_eq!(
+            NaiveDate::parse_from_str("2024-05-08", sniff("2024-05-07").unwrap().format_str()),
+            Ok(date.succ_opt().unwrap())
+        );
+        let detected = sniff("13:04").unwrap();
+        assert_eq!(detected.items().count(), 3);
+        let time = NaiveTime::from_hms_opt(13, 4, 0).unwrap();
+        assert_eq!(detected.into_parsed().to_naive_time(), Ok(time));
+    }
+}
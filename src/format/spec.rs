@@ -0,0 +1,177 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A format string that is parsed once and can be reused.
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use super::{Item, ParseError, ParseResult, Parsed, StrftimeItems, parse, parse_and_remainder};
+
+/// A `strftime`-like format string that has been parsed into owned formatting [`Item`]s.
+///
+/// Methods such as `format` and `parse_from_str` parse their format string again on every call.
+/// A `FormatSpec` does this once, and can then be used for any number of values with the
+/// `format_with_items` methods and with [`format::parse()`]. Because it does not borrow the format
+/// string it can be stored in a struct, or in a static with a crate like `once_cell`.
+///
+/// [`format::parse()`]: crate::format::parse()
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{FormatSpec, Parsed};
+/// use chrono::NaiveDate;
+///
+/// let spec = FormatSpec::new("%Y-%m-%d")?;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+/// assert_eq!(date.format_with_items(spec.iter()).to_string(), "2024-05-07");
+///
+/// let mut parsed = Parsed::new();
+/// spec.parse(&mut parsed, "2024-05-07")?;
+/// assert_eq!(parsed.to_naive_date(), Ok(date));
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FormatSpec {
+    items: Vec<Item<'static>>,
+}
+
+impl FormatSpec {
+    /// Parses a `strftime`-like format string.
+    ///
+    /// See the [`format::strftime` module](crate::format::strftime) for the supported format
+    /// sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format string contains an invalid or unrecognized formatting
+    /// specifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::FormatSpec;
+    ///
+    /// assert!(FormatSpec::new("%H:%M:%S").is_ok());
+    /// assert!(FormatSpec::new("%H:%M:%").is_err());
+    /// ```
+    pub fn new(fmt: &str) -> Result<FormatSpec, ParseError> {
+        StrftimeItems::new(fmt).parse_to_owned().map(|items| FormatSpec { items })
+    }
+
+    /// Returns the formatting items as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Item<'static>] {
+        &self.items
+    }
+
+    /// Returns an iterator over the formatting items.
+    pub fn iter(&self) -> core::slice::Iter<'_, Item<'static>> {
+        self.items.iter()
+    }
+
+    /// Parses `s` with this format and updates `parsed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` does not match the format, or if a parsed field is inconsistent with
+    /// a value already present in `parsed`.
+    pub fn parse(&self, parsed: &mut Parsed, s: &str) -> ParseResult<()> {
+        parse(parsed, s, self.items.iter())
+    }
+
+    /// Parses the start of `s` with this format, updates `parsed` and returns the remaining input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start of `s` does not match the format, or if a parsed field is
+    /// inconsistent with a value already present in `parsed`.
+    pub fn parse_and_remainder<'a>(&self, parsed: &mut Parsed, s: &'a str) -> ParseResult<&'a str> {
+        parse_and_remainder(parsed, s, self.items.iter())
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<FormatSpec, ParseError> {
+        FormatSpec::new(s)
+    }
+}
+
+impl From<Vec<Item<'static>>> for FormatSpec {
+    fn from(items: Vec<Item<'static>>) -> FormatSpec {
+        FormatSpec { items }
+    }
+}
+
+impl From<FormatSpec> for Vec<Item<'static>> {
+    fn from(spec: FormatSpec) -> Vec<Item<'static>> {
+        spec.items
+    }
+}
+
+impl<'a> IntoIterator for &'a FormatSpec {
+    type Item = &'a Item<'static>;
+    type IntoIter = core::slice::Iter<'a, Item<'static>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormatSpec;
+    use crate::format::{Item, Parsed, StrftimeItems};
+    use crate::{FixedOffset, NaiveDateTime, TimeZone};
+
+    #[test]
+    fn test_format_spec() {
+        let spec = {
+            let fmt = String::from("%Y-%m-%dT%H:%M:%S%.3f%:z");
+            FormatSpec::new(&fmt).unwrap()
+        };
+        assert_eq!(
+            spec.as_slice(),
+            &StrftimeItems::new("%Y-%m-%dT%H:%M:%S%.3f%:z").parse_to_owned().unwrap()[..]
+        );
+        assert_eq!("%Y-%m-%dT%H:%M:%S%.3f%:z".parse(), Ok(spec.clone()));
+
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let dt = tz.with_ymd_and_hms(2024, 5, 7, 13, 4, 5).unwrap();
+        for _ in 0..2 {
+            assert_eq!(
+                dt.format_with_items(spec.iter()).to_string(),
+                "2024-05-07T13:04:05.000+01:00"
+            );
+        }
+        let date_spec = FormatSpec::new("%d %b %Y").unwrap();
+        assert_eq!(
+            dt.naive_local().format_with_items((&date_spec).into_iter()).to_string(),
+            "07 May 2024"
+        );
+
+        let mut parsed = Parsed::new();
+        spec.parse(&mut parsed, "2024-05-07T13:04:05.000+01:00").unwrap();
+        assert_eq!(parsed.to_datetime(), Ok(dt));
+        let mut parsed = Parsed::new();
+        let rest = spec.parse_and_remainder(&mut parsed, "2024-05-07T13:04:05.000+01:00 rest");
+        assert_eq!(rest, Ok(" rest"));
+        assert!(spec.parse(&mut Parsed::new(), "2024-05-07").is_err());
+
+        assert!(FormatSpec::new("%Y-%").is_err());
+        assert_eq!(FormatSpec::default().as_slice(), &[]);
+    }
+
+    #[test]
+    fn test_format_spec_from_items() {
+        let spec = FormatSpec::from(vec![Item::Literal("at "), Item::OwnedLiteral("noon".into())]);
+        let dt = NaiveDateTime::default();
+        assert_eq!(dt.format_with_items(spec.iter()).to_string(), "at noon");
+        assert_eq!(Vec::from(spec).len(), 2);
+    }
+}
@@ -14,8 +14,12 @@ The following specifiers are available both to formatting and parsing.
 | `%Y`  | `2001`   | The full proleptic Gregorian year, zero-padded to 4 digits. chrono supports years from -262144 to 262143. Note: years before 1 BCE or after 9999 CE, require an initial sign (+/-).|
 | `%C`  | `20`     | The proleptic Gregorian year divided by 100, zero-padded to 2 digits. [^1] |
 | `%y`  | `01`     | The proleptic Gregorian year modulo 100, zero-padded to 2 digits. [^1]     |
+| `%EC` | `令和`   | The name of the era in the locale's alternative year representation. [^9]  |
+| `%Ey` | `6`      | The year within the era of the locale. [^9]                                |
+| `%EY` | `令和6年`| The locale's full alternative year representation. [^9]                    |
 |       |          |                                                                            |
 | `%q`  | `1`      | Quarter of year (1-4)                                                      |
+| `%Q`  | `Q1`     | Quarter of year with a `Q` prefix. Same as `Q%q`.                          |
 | `%m`  | `07`     | Month number (01--12), zero-padded to 2 digits.                            |
 | `%b`  | `Jul`    | Abbreviated month name. Always 3 letters.                                  |
 | `%B`  | `July`   | Full month name. Also accepts corresponding abbreviation in parsing.       |
@@ -54,6 +58,7 @@ The following specifiers are available both to formatting and parsing.
 |       |          |                                                                            |
 | `%M`  | `34`     | Minute number (00--59), zero-padded to 2 digits.                           |
 | `%S`  | `60`     | Second number (00--60), zero-padded to 2 digits. [^4]                      |
+| `%#S` | `60.026490`| Second number with a decimal fraction. Same as `%S%.f`.                  |
 | `%f`  | `26490000`    | Number of nanoseconds since last whole second. [^7]                   |
 | `%.f` | `.026490`| Decimal fraction of a second. Consumes the leading dot. [^7]               |
 | `%.3f`| `.026`        | Decimal fraction of a second with a fixed length of 3.                |
@@ -62,6 +67,7 @@ The following specifiers are available both to formatting and parsing.
 | `%3f` | `026`         | Decimal fraction of a second like `%.3f` but without the leading dot. |
 | `%6f` | `026490`      | Decimal fraction of a second like `%.6f` but without the leading dot. |
 | `%9f` | `026490000`   | Decimal fraction of a second like `%.9f` but without the leading dot. |
+| `%N`  | `026490000`   | Same as `%9f`, like in GNU `date`. `%3N` and `%6N` are the same as `%3f` and `%6f`. |
 |       |               |                                                                       |
 | `%R`  | `00:34`       | Hour-minute format. Same as `%H:%M`.                                  |
 | `%T`  | `00:34:60`    | Hour-minute-second format. Same as `%H:%M:%S`.                        |
@@ -81,6 +87,7 @@ The following specifiers are available both to formatting and parsing.
 | `%+`  | `2001-07-08T00:34:60.026490+09:30` | ISO 8601 / RFC 3339 date & time format. [^5]     |
 |       |               |                                                                       |
 | `%s`  | `994518299`   | UNIX timestamp, the number of seconds since 1970-01-01 00:00 UTC. [^6]|
+| `%.s` | `994518299.026490` | UNIX timestamp with a decimal fraction of a second. [^10]        |
 |       |          |                                                                            |
 |       |          | **SPECIAL SPECIFIERS:**                                                    |
 | `%t`  |          | Literal tab (`\t`).                                                        |
@@ -95,6 +102,12 @@ Modifier | Description
 `%-?`    | Suppresses any padding including spaces and zeroes. (e.g. `%j` = `012`, `%-j` = `12`)
 `%_?`    | Uses spaces as a padding. (e.g. `%j` = `012`, `%_j` = ` 12`)
 `%0?`    | Uses zeroes as a padding. (e.g. `%e` = ` 9`, `%0e` = `09`)
+`%<width>?`  | Pads to the given width, with the default padding or zeroes. (e.g. `%6Y` = `002001`, `%3e` = `  9`)
+`%_<width>?` | Pads to the given width with spaces. (e.g. `%_8Y` = `    2001`)
+`%0<width>?` | Pads to the given width with zeroes. (e.g. `%05d` = `00008`)
+
+The width is at most 255, and can not be combined with `%-?`.
+A width smaller than that of the value does not truncate it. (e.g. `%1Y` = `2001`)
 
 Notes:
 
@@ -157,6 +170,20 @@ Notes:
    It is not possible to reliably convert from an abbreviation to an offset,
    for example CDT can mean either Central Daylight Time (North America) or
    China Daylight Time.
+
+[^9]: `%EC`, `%Ey`, `%EY`:
+   These use the eras of the locale, such as the Japanese eras for `ja_JP` or the Buddhist era for
+   `th_TH`, when formatting with the `unstable-locales` feature. Without a locale, for locales
+   without eras, or for dates outside them, they are the same as `%C`, `%y` and `%Y`.
+   <br>
+   <br>
+   Parsing is only supported when they are the same as `%C`, `%y` and `%Y`. See also the
+   [`calendars`](crate::calendars) module.
+
+[^10]: `%.s`:
+   The fraction has 0, 3, 6 or 9 digits like `%.f`, and a dot is always used as the decimal
+   point. Unlike `%s%.f`, this is also correct for times before 1970: half a second before
+   1970-01-01 00:00 UTC is formatted as `-0.500`, where `%s%.f` gives `-1.500`.
 */
 
 #[cfg(feature = "alloc")]
@@ -243,6 +270,7 @@ impl<'a> StrftimeItems<'a> {
     /// Note: `StrftimeItems::new_with_locale` only localizes the *format*. You usually want to
     /// combine it with other locale-aware methods such as
     /// [`DateTime::format_localized_with_items`] to get things like localized month or day names.
+    /// For parsing, the counterpart is [`format::parse_localized`](crate::format::parse_localized).
     ///
     /// The `%x` formatting specifier will use the local date format, `%X` the local time format,
     ///  and `%c` the local format for date and time.
@@ -386,7 +414,7 @@ impl<'a> StrftimeItems<'a> {
     }
 }
 
-const HAVE_ALTERNATES: &str = "z";
+const HAVE_ALTERNATES: &str = "Sz";
 
 impl<'a> Iterator for StrftimeItems<'a> {
     type Item = Item<'a>;
@@ -476,11 +504,32 @@ impl<'a> StrftimeItems<'a> {
                     _ => None,
                 };
                 let is_alternate = spec == '#';
-                let spec = if pad_override.is_some() || is_alternate { next!() } else { spec };
+                let mut spec = if pad_override.is_some() || is_alternate { next!() } else { spec };
                 if is_alternate && !HAVE_ALTERNATES.contains(spec) {
                     return Some((remainder, Item::Error));
                 }
 
+                // An explicit width, as in `%8Y` or `%_5d`.
+                // `%3f`, `%6f`, `%9f` and their `N` counterparts are specifiers of their own.
+                let is_fraction = pad_override.is_none()
+                    && matches!(spec, '3' | '6' | '9')
+                    && remainder.starts_with(['f', 'N']);
+                let mut width = None;
+                if spec.is_ascii_digit() && !is_alternate && !is_fraction {
+                    let mut w = u32::from(spec as u8 - b'0');
+                    loop {
+                        spec = next!();
+                        match spec.to_digit(10) {
+                            Some(digit) if w <= u32::from(u8::MAX) => w = w * 10 + digit,
+                            _ => break,
+                        }
+                    }
+                    match u8::try_from(w) {
+                        Ok(w) => width = Some(w),
+                        Err(_) => return Some((remainder, Item::Error)),
+                    }
+                }
+
                 macro_rules! queue {
                     [$head:expr, $($tail:expr),+ $(,)*] => ({
                         const QUEUE: &'static [Item<'static>] = &[$($tail),+];
@@ -503,14 +552,36 @@ impl<'a> StrftimeItems<'a> {
                     'D' => {
                         queue![num0(Month), Literal("/"), num0(Day), Literal("/"), num0(YearMod100)]
                     }
+                    #[cfg(not(feature = "unstable-locales"))]
+                    'E' => match next!() {
+                        'C' => num0(YearDiv100),
+                        'y' => num0(YearMod100),
+                        'Y' => num0(Year),
+                        _ => Item::Error,
+                    },
+                    #[cfg(feature = "unstable-locales")]
+                    'E' => match next!() {
+                        'C' => self.era_item(EraName, num0(YearDiv100)),
+                        'y' => self.era_item(EraYear, num0(YearMod100)),
+                        'Y' => self.era_item(EraFullYear, num0(Year)),
+                        _ => Item::Error,
+                    },
                     'F' => queue![num0(Year), Literal("-"), num0(Month), Literal("-"), num0(Day)],
                     'G' => num0(IsoYear),
                     'H' => num0(Hour),
                     'I' => num0(Hour12),
                     'M' => num0(Minute),
+                    'N' => internal_fixed(Nanosecond9NoDot),
                     'P' => fixed(Fixed::LowerAmPm),
+                    'Q' => queue![Literal("Q"), num(Quarter)],
                     'R' => queue![num0(Hour), Literal(":"), num0(Minute)],
-                    'S' => num0(Second),
+                    'S' => {
+                        if is_alternate {
+                            queue![num0(Second), fixed(Fixed::Nanosecond)]
+                        } else {
+                            num0(Second)
+                        }
+                    }
                     'T' => {
                         queue![num0(Hour), Literal(":"), num0(Minute), Literal(":"), num0(Second)]
                     }
@@ -607,18 +678,19 @@ impl<'a> StrftimeItems<'a> {
                             _ => Item::Error,
                         },
                         'f' => fixed(Fixed::Nanosecond),
+                        's' => internal_fixed(TimestampFraction),
                         _ => Item::Error,
                     },
                     '3' => match next!() {
-                        'f' => internal_fixed(Nanosecond3NoDot),
+                        'f' | 'N' => internal_fixed(Nanosecond3NoDot),
                         _ => Item::Error,
                     },
                     '6' => match next!() {
-                        'f' => internal_fixed(Nanosecond6NoDot),
+                        'f' | 'N' => internal_fixed(Nanosecond6NoDot),
                         _ => Item::Error,
                     },
                     '9' => match next!() {
-                        'f' => internal_fixed(Nanosecond9NoDot),
+                        'f' | 'N' => internal_fixed(Nanosecond9NoDot),
                         _ => Item::Error,
                     },
                     '%' => Literal("%"),
@@ -628,7 +700,22 @@ impl<'a> StrftimeItems<'a> {
                 // Adjust `item` if we have any padding modifier.
                 // Not allowed on non-numeric items or on specifiers composed out of multiple
                 // formatting items.
-                if let Some(new_pad) = pad_override {
+                if let Some(width) = width {
+                    match item {
+                        Item::Numeric(ref kind, pad) if self.queue.is_empty() => {
+                            let new_pad = match (pad_override, pad) {
+                                (Some(Pad::None), _) => return Some((remainder, Item::Error)),
+                                (Some(Pad::Space), _) | (None, Pad::Space) => Pad::Space,
+                                _ => Pad::Zero,
+                            };
+                            match kind.with_width(width) {
+                                Some(kind) => Some((remainder, Item::Numeric(kind, new_pad))),
+                                None => Some((remainder, Item::Error)),
+                            }
+                        }
+                        _ => Some((remainder, Item::Error)),
+                    }
+                } else if let Some(new_pad) = pad_override {
                     match item {
                         Item::Numeric(ref kind, _pad) if self.queue.is_empty() => {
                             Some((remainder, Item::Numeric(kind.clone(), new_pad)))
@@ -664,6 +751,15 @@ impl<'a> StrftimeItems<'a> {
         }
     }
 
+    /// Returns `era` if the locale has an alternative year representation, `fallback` otherwise.
+    #[cfg(feature = "unstable-locales")]
+    fn era_item(&self, era: InternalInternal, fallback: Item<'a>) -> Item<'a> {
+        match self.locale.and_then(locales::eras) {
+            Some(_) => internal_fixed(era),
+            None => fallback,
+        }
+    }
+
     #[cfg(feature = "unstable-locales")]
     fn switch_to_locale_str(
         &mut self,
@@ -704,6 +800,9 @@ mod tests {
         }
 
         assert_eq!(parse_and_collect(""), []);
+        assert_eq!(parse_and_collect("%EC%Ey"), [num0(YearDiv100), num0(YearMod100)]);
+        assert_eq!(parse_and_collect("%EY"), [num0(Year)]);
+        assert_eq!(parse_and_collect("%E"), [Item::Error]);
         assert_eq!(parse_and_collect(" "), [Space(" ")]);
         assert_eq!(parse_and_collect("  "), [Space("  ")]);
         // ne!
@@ -848,6 +947,7 @@ mod tests {
             parse_and_collect("%#z"),
             [internal_fixed(InternalInternal::TimezoneOffsetPermissive)]
         );
+        assert_eq!(parse_and_collect("%#S"), [num0(Second), fixed(Fixed::Nanosecond)]);
         assert_eq!(parse_and_collect("%#m"), [Item::Error]);
     }
 
@@ -869,6 +969,7 @@ mod tests {
         assert_eq!(dt.format("%C").to_string(), "20");
         assert_eq!(dt.format("%y").to_string(), "01");
         assert_eq!(dt.format("%q").to_string(), "3");
+        assert_eq!(dt.format("%Q").to_string(), "Q3");
         assert_eq!(dt.format("%m").to_string(), "07");
         assert_eq!(dt.format("%b").to_string(), "Jul");
         assert_eq!(dt.format("%B").to_string(), "July");
@@ -902,6 +1003,7 @@ mod tests {
         assert_eq!(dt.format("%p").to_string(), "AM");
         assert_eq!(dt.format("%M").to_string(), "34");
         assert_eq!(dt.format("%S").to_string(), "60");
+        assert_eq!(dt.format("%#S").to_string(), "60.026490708");
         assert_eq!(dt.format("%f").to_string(), "026490708");
         assert_eq!(dt.format("%.f").to_string(), ".026490708");
         assert_eq!(dt.with_nanosecond(1_026_490_000).unwrap().format("%.f").to_string(), ".026490");
@@ -911,6 +1013,10 @@ mod tests {
         assert_eq!(dt.format("%3f").to_string(), "026");
         assert_eq!(dt.format("%6f").to_string(), "026490");
         assert_eq!(dt.format("%9f").to_string(), "026490708");
+        assert_eq!(dt.format("%N").to_string(), "026490708");
+        assert_eq!(dt.format("%3N").to_string(), "026");
+        assert_eq!(dt.format("%6N").to_string(), "026490");
+        assert_eq!(dt.format("%9N").to_string(), "026490708");
         assert_eq!(dt.format("%R").to_string(), "00:34");
         assert_eq!(dt.format("%T").to_string(), "00:34:60");
         assert_eq!(dt.format("%X").to_string(), "00:34:60");
@@ -949,6 +1055,7 @@ mod tests {
             "2001-07-08T00:34:60.026490+09:30"
         );
         assert_eq!(dt.format("%s").to_string(), "994518299");
+        assert_eq!(dt.format("%.s").to_string(), "994518299.026490708");
 
         // special specifiers
         assert_eq!(dt.format("%t").to_string(), "\t");
@@ -1095,6 +1202,47 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
+    fn test_strftime_localized_era() {
+        use crate::calendars::{Calendar, Japanese};
+        use crate::format::{BAD_FORMAT, Parsed, parse};
+
+        let era = |y, m, d, fmt, locale| {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            date.format_localized(fmt, locale).to_string()
+        };
+        let ja = Locale::ja_JP;
+        assert_eq!(era(2024, 5, 7, "%EC|%Ey|%EY", ja), "令和|6|令和6年");
+        assert_eq!(era(2019, 5, 1, "%EC|%Ey|%EY", ja), "令和|1|令和元年");
+        assert_eq!(era(2019, 4, 30, "%EY", ja), "平成31年");
+        assert_eq!(era(1989, 1, 7, "%EY", ja), "昭和64年");
+        assert_eq!(era(1900, 1, 1, "%EY", ja), "明治33年");
+        assert_eq!(era(1850, 1, 1, "%EY", ja), "西暦1850年");
+        assert_eq!(era(0, 12, 31, "%EY", ja), "紀元前1年");
+        assert_eq!(era(-9, 1, 1, "%EY", ja), "紀元前10年");
+        assert_eq!(era(2024, 5, 7, "%EY", Locale::th_TH), "พ.ศ. 2567");
+        assert_eq!(era(2024, 5, 7, "%EY", Locale::zh_TW), "民國113年");
+        assert_eq!(era(1900, 5, 7, "%EY", Locale::zh_TW), "民前12年");
+
+        // locales without eras, and no locale
+        assert_eq!(era(2024, 5, 7, "%EC|%Ey|%EY", Locale::en_US), "20|24|2024");
+        let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+        assert_eq!(date.format("%EC|%Ey|%EY").to_string(), "20|24|2024");
+
+        // the Japanese eras match those of the `Japanese` calendar
+        let japanese = Japanese.to_calendar_date(date).unwrap();
+        assert_eq!(era(2024, 5, 7, "%EC", ja), japanese.era().kanji());
+
+        // parsing is not supported with eras
+        let items = StrftimeItems::new_with_locale("%EY", ja);
+        let mut parsed = Parsed::new();
+        assert_eq!(parse(&mut parsed, "令和6年", items), Err(BAD_FORMAT));
+        let items = StrftimeItems::new_with_locale("%EY", Locale::en_US);
+        assert_eq!(parse(&mut parsed, "2024", items), Ok(()));
+        assert_eq!(StrftimeItems::new("%EX").collect::<Vec<_>>(), [Item::Error]);
+    }
+
     #[test]
     #[cfg(all(feature = "unstable-locales", feature = "alloc"))]
     fn test_strftime_localized_time() {
@@ -1129,6 +1277,113 @@ mod tests {
         assert_eq!(size_of::<Locale>(), 2);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strftime_timestamp_fraction() {
+        let at = |secs, nanos| DateTime::from_timestamp(secs, nanos).unwrap();
+        let cases = [
+            (at(0, 0), "0"),
+            (at(1_715_083_200, 0), "1715083200"),
+            (at(1_715_083_200, 250_000_000), "1715083200.250"),
+            (at(1_715_083_200, 1_000), "1715083200.000001"),
+            (at(-1, 500_000_000), "-0.500"),
+            (at(-2, 999_999_999), "-1.000000001"),
+            (at(-2, 0), "-2"),
+            (at(-1_000_000_000, 1_000_000), "-999999999.999"),
+        ];
+        for (dt, s) in cases {
+            assert_eq!(dt.format("%.s").to_string(), s);
+            assert_eq!(DateTime::parse_from_str(s, "%.s"), Ok(dt.fixed_offset()), "{}", s);
+        }
+
+        // the offset does not change the timestamp
+        let dt = at(-1, 500_000_000).with_timezone(&FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(dt.format("%.s").to_string(), "-0.500");
+
+        // parsing accepts any number of fractional digits
+        let parsed = DateTime::parse_from_str("-0.5", "%.s");
+        assert_eq!(parsed, Ok(at(-1, 500_000_000).fixed_offset()));
+        assert!(DateTime::parse_from_str("12.", "%.s").is_err());
+        assert!(DateTime::parse_from_str("-", "%.s").is_err());
+        assert!(DateTime::parse_from_str(".5", "%.s").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strftime_quarter_and_nanoseconds() {
+        use crate::NaiveTime;
+        use crate::format::{Parsed, parse};
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, "2024 Q3", StrftimeItems::new("%Y %Q")).unwrap();
+        assert_eq!((parsed.year(), parsed.quarter()), (Some(2024), Some(3)));
+        assert!(parse(&mut Parsed::new(), "2024 3", StrftimeItems::new("%Y %Q")).is_err());
+
+        let time = NaiveTime::from_hms_nano_opt(1, 2, 3, 4).unwrap();
+        for fmt in ["%T.%N", "%T.%9N"] {
+            assert_eq!(time.format(fmt).to_string(), "01:02:03.000000004");
+            assert_eq!(NaiveTime::parse_from_str("01:02:03.000000004", fmt), Ok(time));
+        }
+        let time = NaiveTime::from_hms_milli_opt(1, 2, 3, 250).unwrap();
+        assert_eq!(time.format("%T.%3N").to_string(), "01:02:03.250");
+        assert_eq!(NaiveTime::parse_from_str("01:02:03.250", "%T.%3N"), Ok(time));
+        assert_eq!(NaiveTime::parse_from_str("01:02:03.250000", "%T.%6N"), Ok(time));
+
+        assert_eq!(time.format("%H:%M:%#S").to_string(), "01:02:03.250");
+        assert_eq!(NaiveTime::parse_from_str("01:02:03.250", "%H:%M:%#S"), Ok(time));
+        let time = NaiveTime::from_hms_opt(1, 2, 3).unwrap();
+        assert_eq!(time.format("%H:%M:%#S").to_string(), "01:02:03");
+        assert_eq!(NaiveTime::parse_from_str("01:02:03", "%H:%M:%#S"), Ok(time));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strftime_padding_width() {
+        use crate::format::Pad;
+
+        assert_eq!(
+            StrftimeItems::new("%8Y%_5d%03e%12s").collect::<Vec<_>>(),
+            [
+                Item::Numeric(Year.with_width(8).unwrap(), Pad::Zero),
+                Item::Numeric(Day.with_width(5).unwrap(), Pad::Space),
+                Item::Numeric(Day.with_width(3).unwrap(), Pad::Zero),
+                Item::Numeric(Timestamp.with_width(12).unwrap(), Pad::Zero),
+            ]
+        );
+        assert_eq!(
+            StrftimeItems::new("%255Y").next(),
+            Some(Item::Numeric(Year.with_width(255).unwrap(), Pad::Zero))
+        );
+        assert_eq!(StrftimeItems::new("%256Y").next(), Some(Item::Error));
+        assert_eq!(StrftimeItems::new("%-5d").next(), Some(Item::Error));
+        assert_eq!(StrftimeItems::new("%8F").next(), Some(Item::Error));
+        assert_eq!(StrftimeItems::new("%8a").next(), Some(Item::Error));
+        assert_eq!(StrftimeItems::new("%8").next(), Some(Item::Error));
+        assert_eq!(
+            StrftimeItems::new("%3f").next(),
+            Some(internal_fixed(InternalInternal::Nanosecond3NoDot))
+        );
+
+        let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap();
+        assert_eq!(dt.format("%6Y").to_string(), "002001");
+        assert_eq!(dt.format("%_8Y|").to_string(), "    2001|");
+        assert_eq!(dt.format("%1Y").to_string(), "2001");
+        assert_eq!(dt.format("%05d").to_string(), "00008");
+        assert_eq!(dt.format("%3e").to_string(), "  8");
+        assert_eq!(dt.format("%_4m %3H %4j %3u").to_string(), "   7 000 0189 007");
+        assert_eq!(dt.format("%12s").to_string(), "000994552499");
+
+        let dt = NaiveDate::from_ymd_opt(-12, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(dt.format("%Y").to_string(), "-0012");
+        assert_eq!(dt.format("%7Y").to_string(), "-000012");
+        assert_eq!(dt.format("%_7Y").to_string(), "    -12");
+
+        // an explicit width also allows parsing that many digits
+        let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+        assert_eq!(NaiveDate::parse_from_str("00002001-07-08", "%8Y-%m-%d"), Ok(date));
+        assert_eq!(NaiveDate::parse_from_str("    2001-07-008", "%_8Y-%m-%3d"), Ok(date));
+    }
+
     #[test]
     #[cfg(any(feature = "alloc", feature = "std"))]
     fn test_strftime_parse() {
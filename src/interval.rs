@@ -0,0 +1,649 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A span of time between two `DateTime`s.
+
+use core::fmt;
+use core::hash;
+use core::iter::FusedIterator;
+
+use crate::offset::{MappedLocalTime, TimeZone};
+use crate::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+
+/// A half-open span of time from a start (inclusive) to an end (exclusive) [`DateTime`].
+///
+/// Intervals are compared by the instants of their start and end. All operations that split an
+/// interval or return a new one keep the time zone of `self`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Interval, TimeDelta, TimeZone, Utc};
+///
+/// let morning = Interval::new(
+///     Utc.with_ymd_and_hms(2024, 5, 7, 8, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap(),
+/// )
+/// .unwrap();
+/// let meeting = Interval::new(
+///     Utc.with_ymd_and_hms(2024, 5, 7, 11, 30, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 5, 7, 13, 0, 0).unwrap(),
+/// )
+/// .unwrap();
+///
+/// assert!(morning.overlaps(&meeting));
+/// assert_eq!(morning.intersection(&meeting).unwrap().duration(), TimeDelta::minutes(30));
+/// assert!(morning.contains(&Utc.with_ymd_and_hms(2024, 5, 7, 8, 0, 0).unwrap()));
+/// assert!(!morning.contains(&Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap()));
+/// ```
+#[derive(Clone)]
+pub struct Interval<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> Interval<Tz> {
+    /// Makes a new `Interval` from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// An interval with `start == end` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `end` is before `start`.
+    #[must_use]
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Option<Interval<Tz>> {
+        if end < start {
+            return None;
+        }
+        Some(Interval { start, end })
+    }
+
+    /// Makes a new `Interval` that starts at `start` and lasts for `duration`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `duration` is negative, or if the end would be out of range.
+    #[must_use]
+    pub fn from_start(start: DateTime<Tz>, duration: TimeDelta) -> Option<Interval<Tz>> {
+        let end = start.clone().checked_add_signed(duration)?;
+        Interval::new(start, end)
+    }
+
+    /// Returns the start of the interval, which is included in the interval.
+    #[must_use]
+    pub const fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the interval, which is not included in the interval.
+    #[must_use]
+    pub const fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns the amount of time between the start and end of the interval.
+    #[must_use]
+    pub fn duration(&self) -> TimeDelta {
+        self.end.clone().signed_duration_since(&self.start)
+    }
+
+    /// Returns `true` if the start and end of the interval are equal.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if `dt` lies within the interval.
+    #[must_use]
+    pub fn contains<Tz2: TimeZone>(&self, dt: &DateTime<Tz2>) -> bool {
+        self.start <= *dt && *dt < self.end
+    }
+
+    /// Returns `true` if `other` lies completely within this interval.
+    #[must_use]
+    pub fn contains_interval<Tz2: TimeZone>(&self, other: &Interval<Tz2>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true` if this interval and `other` have any instant in common.
+    ///
+    /// Intervals that only touch, where the end of one is the start of the other, do not overlap.
+    /// An empty interval overlaps nothing.
+    #[must_use]
+    pub fn overlaps<Tz2: TimeZone>(&self, other: &Interval<Tz2>) -> bool {
+        self.start < other.end && other.start < self.end && !self.is_empty() && !other.is_empty()
+    }
+
+    /// Returns the interval of time this interval and `other` have in common.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the intervals do not [overlap](Interval::overlaps).
+    #[must_use]
+    pub fn intersection<Tz2: TimeZone>(&self, other: &Interval<Tz2>) -> Option<Interval<Tz>> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let tz = self.start.timezone();
+        let start = if self.start < other.start {
+            other.start.with_timezone(&tz)
+        } else {
+            self.start.clone()
+        };
+        let end =
+            if self.end > other.end { other.end.with_timezone(&tz) } else { self.end.clone() };
+        Some(Interval { start, end })
+    }
+
+    /// Returns the smallest interval covering both this interval and `other`, if there is no
+    /// gap between them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the intervals neither overlap nor touch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeDelta, TimeZone, Utc};
+    ///
+    /// let hour = TimeDelta::hours(1);
+    /// let start = Utc.with_ymd_and_hms(2024, 5, 7, 8, 0, 0).unwrap();
+    /// let first = Interval::from_start(start, hour).unwrap();
+    /// let second = Interval::from_start(*first.end(), hour).unwrap();
+    /// let third = Interval::from_start(*second.end() + hour, hour).unwrap();
+    ///
+    /// assert_eq!(first.union_if_adjacent(&second), Interval::from_start(start, hour * 2));
+    /// assert_eq!(first.union_if_adjacent(&third), None);
+    /// ```
+    #[must_use]
+    pub fn union_if_adjacent<Tz2: TimeZone>(&self, other: &Interval<Tz2>) -> Option<Interval<Tz>> {
+        if self.end < other.start || other.end < self.start {
+            return None;
+        }
+        let tz = self.start.timezone();
+        let start = if self.start > other.start {
+            other.start.with_timezone(&tz)
+        } else {
+            self.start.clone()
+        };
+        let end =
+            if self.end < other.end { other.end.with_timezone(&tz) } else { self.end.clone() };
+        Some(Interval { start, end })
+    }
+
+    /// Returns an iterator over the instants in the interval, starting at `start` and advancing by
+    /// `step`.
+    ///
+    /// The iterator is empty if `step` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeDelta, TimeZone, Timelike, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 5, 7, 8, 0, 0).unwrap();
+    /// let interval = Interval::from_start(start, TimeDelta::minutes(50)).unwrap();
+    /// let minutes = interval.step_by(TimeDelta::minutes(20)).map(|t| t.minute());
+    /// assert_eq!(minutes.collect::<Vec<_>>(), [0, 20, 40]);
+    /// ```
+    pub fn step_by(&self, step: TimeDelta) -> Steps<Tz> {
+        let next = if step > TimeDelta::zero() { Some(self.start.clone()) } else { None };
+        Steps { next, end: self.end.clone(), step }
+    }
+
+    /// Returns an iterator over the windows of length `width` inside the interval, starting every
+    /// `stride` from the start.
+    ///
+    /// Only windows that fit in the interval entirely are returned, like [`slice::windows`]. If
+    /// `stride` is less than `width` the windows overlap, which is what a rolling aggregate
+    /// needs; if it is equal they are adjacent. The windows are computed on the absolute time
+    /// line, so every window has the same duration even across daylight saving time transitions.
+    ///
+    /// The iterator is empty if `width` or `stride` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeDelta, TimeZone, Timelike, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 5, 7, 8, 0, 0).unwrap();
+    /// let interval = Interval::from_start(start, TimeDelta::hours(4)).unwrap();
+    /// let windows: Vec<_> = interval
+    ///     .windows(TimeDelta::hours(2), TimeDelta::hours(1))
+    ///     .map(|w| (w.start().hour(), w.end().hour()))
+    ///     .collect();
+    /// assert_eq!(windows, [(8, 10), (9, 11), (10, 12)]);
+    /// ```
+    pub fn windows(&self, width: TimeDelta, stride: TimeDelta) -> Windows<Tz> {
+        let next = if width > TimeDelta::zero() && stride > TimeDelta::zero() {
+            Some(self.start.clone())
+        } else {
+            None
+        };
+        Windows { next, end: self.end.clone(), width, stride }
+    }
+
+    /// Returns an iterator that splits the interval at every local midnight.
+    ///
+    /// If midnight does not exist on some day because of a daylight saving time transition, the
+    /// day starts at the first local time that does exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeZone, Utc};
+    ///
+    /// let interval = Interval::new(
+    ///     Utc.with_ymd_and_hms(2024, 5, 7, 20, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2024, 5, 9, 6, 0, 0).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let hours: Vec<_> = interval.split_days().map(|day| day.duration().num_hours()).collect();
+    /// assert_eq!(hours, [4, 24, 6]);
+    /// ```
+    pub fn split_days(&self) -> Split<Tz> {
+        self.split(SplitUnit::Day)
+    }
+
+    /// Returns an iterator that splits the interval at the start of every month in local time.
+    ///
+    /// If midnight does not exist on the first day of a month because of a daylight saving time
+    /// transition, the month starts at the first local time that does exist.
+    pub fn split_months(&self) -> Split<Tz> {
+        self.split(SplitUnit::Month)
+    }
+
+    fn split(&self, unit: SplitUnit) -> Split<Tz> {
+        let next = if self.is_empty() { None } else { Some(self.start.clone()) };
+        Split { next, end: self.end.clone(), unit }
+    }
+}
+
+impl<Tz: TimeZone> Copy for Interval<Tz> where <Tz as TimeZone>::Offset: Copy {}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<Interval<Tz2>> for Interval<Tz> {
+    fn eq(&self, other: &Interval<Tz2>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Tz: TimeZone> Eq for Interval<Tz> {}
+
+impl<Tz: TimeZone> hash::Hash for Interval<Tz> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<Tz: TimeZone> fmt::Debug for Interval<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}/{:?}", self.start, self.end)
+    }
+}
+
+/// Iterator over the instants in an [`Interval`], created by [`Interval::step_by`].
+#[derive(Clone, Debug)]
+pub struct Steps<Tz: TimeZone> {
+    next: Option<DateTime<Tz>>,
+    end: DateTime<Tz>,
+    step: TimeDelta,
+}
+
+impl<Tz: TimeZone> Iterator for Steps<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        let current = self.next.take().filter(|dt| *dt < self.end)?;
+        self.next = current.clone().checked_add_signed(self.step);
+        Some(current)
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Steps<Tz> {}
+
+/// Iterator over windows of an [`Interval`], created by [`Interval::windows`].
+#[derive(Clone, Debug)]
+pub struct Windows<Tz: TimeZone> {
+    next: Option<DateTime<Tz>>,
+    end: DateTime<Tz>,
+    width: TimeDelta,
+    stride: TimeDelta,
+}
+
+impl<Tz: TimeZone> Iterator for Windows<Tz> {
+    type Item = Interval<Tz>;
+
+    fn next(&mut self) -> Option<Interval<Tz>> {
+        let start = self.next.take()?;
+        let end = start.clone().checked_add_signed(self.width).filter(|end| *end <= self.end)?;
+        self.next = start.clone().checked_add_signed(self.stride);
+        Some(Interval { start, end })
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Windows<Tz> {}
+
+#[derive(Clone, Copy, Debug)]
+enum SplitUnit {
+    Day,
+    Month,
+}
+
+/// Iterator over the parts of an [`Interval`] split at calendar boundaries, created by
+/// [`Interval::split_days`] and [`Interval::split_months`].
+#[derive(Clone, Debug)]
+pub struct Split<Tz: TimeZone> {
+    next: Option<DateTime<Tz>>,
+    end: DateTime<Tz>,
+    unit: SplitUnit,
+}
+
+impl<Tz: TimeZone> Iterator for Split<Tz> {
+    type Item = Interval<Tz>;
+
+    fn next(&mut self) -> Option<Interval<Tz>> {
+        let start = self.next.take()?;
+        let date = start.overflowing_naive_local().date();
+        let next_date = match self.unit {
+            SplitUnit::Day => date.succ_opt(),
+            SplitUnit::Month => match date.month() {
+                12 => NaiveDate::from_ymd_opt(date.year() + 1, 1, 1),
+                month => NaiveDate::from_ymd_opt(date.year(), month + 1, 1),
+            },
+        };
+        let boundary = next_date
+            .and_then(|date| {
+                first_instant_from(&start.timezone(), date.and_time(NaiveTime::MIN), &start)
+            })
+            .filter(|boundary| *boundary < self.end);
+        let end = match boundary {
+            Some(boundary) => {
+                self.next = Some(boundary.clone());
+                boundary
+            }
+            None => self.end.clone(),
+        };
+        Some(Interval { start, end })
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Split<Tz> {}
+
+/// Returns the earliest instant after `after` of the local time `local`, or of the first local
+/// time after it that exists if it falls in a gap.
+///
+/// If `local` occurs twice because of a transition that turns the clocks back, its first
+/// occurrence can lie before `after`; the second one is used then, so that the returned boundary
+/// never goes backward.
+fn first_instant_from<Tz: TimeZone>(
+    tz: &Tz,
+    mut local: NaiveDateTime,
+    after: &DateTime<Tz>,
+) -> Option<DateTime<Tz>> {
+    // Gaps caused by transitions are never longer than a day, and they start and end at whole
+    // quarters of an hour.
+    for _ in 0..96 {
+        match tz.from_local_datetime(&local) {
+            MappedLocalTime::Single(dt) => return Some(dt).filter(|dt| dt > after),
+            MappedLocalTime::Ambiguous(earliest, _) if earliest > *after => return Some(earliest),
+            MappedLocalTime::Ambiguous(_, latest) => return Some(latest).filter(|dt| dt > after),
+            MappedLocalTime::None => local = local.checked_add_signed(TimeDelta::minutes(15))?,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval;
+    use crate::offset::{FixedOffset, MappedLocalTime, TimeZone, Utc};
+    use crate::{DateTime, NaiveDate, NaiveDateTime, TimeDelta};
+
+    fn utc(month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap()
+    }
+
+    fn interval(start: DateTime<Utc>, end: DateTime<Utc>) -> Interval<Utc> {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Interval::new(utc(5, 7, 1), utc(5, 7, 0)).is_none());
+        assert!(interval(utc(5, 7, 0), utc(5, 7, 0)).is_empty());
+        assert_eq!(interval(utc(5, 7, 0), utc(5, 8, 0)).duration(), TimeDelta::days(1));
+        assert_eq!(
+            Interval::from_start(utc(5, 7, 0), TimeDelta::hours(2)),
+            Some(interval(utc(5, 7, 0), utc(5, 7, 2)))
+        );
+        assert!(Interval::from_start(utc(5, 7, 0), TimeDelta::hours(-2)).is_none());
+        assert!(Interval::from_start(DateTime::<Utc>::MAX_UTC, TimeDelta::hours(1)).is_none());
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let a = interval(utc(5, 7, 8), utc(5, 7, 12));
+        assert!(a.contains(&utc(5, 7, 8)));
+        assert!(a.contains(&utc(5, 7, 11)));
+        assert!(!a.contains(&utc(5, 7, 12)));
+        assert!(!a.contains(&utc(5, 7, 7)));
+
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        assert!(a.contains(&utc(5, 7, 8).with_timezone(&tz)));
+
+        assert!(a.contains_interval(&a));
+        assert!(a.contains_interval(&interval(utc(5, 7, 9), utc(5, 7, 10))));
+        assert!(a.contains_interval(&interval(utc(5, 7, 12), utc(5, 7, 12))));
+        assert!(!a.contains_interval(&interval(utc(5, 7, 9), utc(5, 7, 13))));
+
+        assert!(a.overlaps(&interval(utc(5, 7, 11), utc(5, 7, 13))));
+        assert!(a.overlaps(&interval(utc(5, 7, 7), utc(5, 7, 9))));
+        assert!(a.overlaps(&interval(utc(5, 7, 9), utc(5, 7, 10))));
+        assert!(!a.overlaps(&interval(utc(5, 7, 12), utc(5, 7, 13))));
+        assert!(!a.overlaps(&interval(utc(5, 7, 6), utc(5, 7, 8))));
+        assert!(!a.overlaps(&interval(utc(5, 7, 9), utc(5, 7, 9))));
+    }
+
+    #[test]
+    fn test_intersection_and_union() {
+        let a = interval(utc(5, 7, 8), utc(5, 7, 12));
+        let b = interval(utc(5, 7, 10), utc(5, 7, 14));
+        assert_eq!(a.intersection(&b), Some(interval(utc(5, 7, 10), utc(5, 7, 12))));
+        assert_eq!(b.intersection(&a), Some(interval(utc(5, 7, 10), utc(5, 7, 12))));
+        assert_eq!(a.union_if_adjacent(&b), Some(interval(utc(5, 7, 8), utc(5, 7, 14))));
+        assert_eq!(b.union_if_adjacent(&a), Some(interval(utc(5, 7, 8), utc(5, 7, 14))));
+
+        let c = interval(utc(5, 7, 12), utc(5, 7, 13));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union_if_adjacent(&c), Some(interval(utc(5, 7, 8), utc(5, 7, 13))));
+
+        let d = interval(utc(5, 7, 13), utc(5, 7, 14));
+        assert_eq!(a.intersection(&d), None);
+        assert_eq!(a.union_if_adjacent(&d), None);
+
+        // The result keeps the time zone of `self`.
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let b_fixed =
+            Interval::new(b.start().with_timezone(&tz), b.end().with_timezone(&tz)).unwrap();
+        let union = a.union_if_adjacent(&b_fixed).unwrap();
+        assert_eq!(union.end().timezone(), Utc);
+        let union = b_fixed.union_if_adjacent(&a).unwrap();
+        assert_eq!(union.start().offset(), &tz);
+    }
+
+    #[test]
+    fn test_step_by() {
+        let a = interval(utc(5, 7, 8), utc(5, 7, 11));
+        let steps: Vec<_> = a.step_by(TimeDelta::hours(1)).collect();
+        assert_eq!(steps, [utc(5, 7, 8), utc(5, 7, 9), utc(5, 7, 10)]);
+        assert_eq!(a.step_by(TimeDelta::hours(5)).count(), 1);
+        assert_eq!(a.step_by(TimeDelta::zero()).count(), 0);
+        assert_eq!(a.step_by(TimeDelta::hours(-1)).count(), 0);
+        assert_eq!(interval(utc(5, 7, 8), utc(5, 7, 8)).step_by(TimeDelta::hours(1)).count(), 0);
+
+        let end = Interval::new(DateTime::<Utc>::MAX_UTC, DateTime::<Utc>::MAX_UTC).unwrap();
+        assert_eq!(end.step_by(TimeDelta::hours(1)).count(), 0);
+    }
+
+    #[test]
+    fn test_windows() {
+        let a = interval(utc(5, 7, 8), utc(5, 7, 12));
+        let windows: Vec<_> = a.windows(TimeDelta::hours(2), TimeDelta::hours(1)).collect();
+        let expected =
+            [(8, 10), (9, 11), (10, 12)].map(|(s, e)| interval(utc(5, 7, s), utc(5, 7, e)));
+        assert_eq!(windows, expected);
+
+        let adjacent: Vec<_> = a.windows(TimeDelta::hours(2), TimeDelta::hours(2)).collect();
+        assert_eq!(
+            adjacent,
+            [interval(utc(5, 7, 8), utc(5, 7, 10)), interval(utc(5, 7, 10), a.end)]
+        );
+        assert_eq!(a.windows(TimeDelta::hours(3), TimeDelta::hours(2)).count(), 1);
+        assert_eq!(a.windows(TimeDelta::hours(1), TimeDelta::hours(3)).count(), 2);
+        assert_eq!(a.windows(TimeDelta::hours(5), TimeDelta::hours(1)).count(), 0);
+        assert_eq!(a.windows(TimeDelta::zero(), TimeDelta::hours(1)).count(), 0);
+        assert_eq!(a.windows(TimeDelta::hours(1), TimeDelta::hours(-1)).count(), 0);
+
+        // Every window lasts the same across a DST transition.
+        let start = MidnightGap.from_utc_datetime(&utc(3, 31, 22).naive_utc());
+        let end = MidnightGap.from_utc_datetime(&utc(4, 1, 2).naive_utc());
+        let hours: Vec<_> = Interval::new(start, end)
+            .unwrap()
+            .windows(TimeDelta::hours(1), TimeDelta::hours(1))
+            .map(|w| w.duration())
+            .collect();
+        assert_eq!(hours, [TimeDelta::hours(1); 4]);
+
+        let end = Interval::new(DateTime::<Utc>::MAX_UTC, DateTime::<Utc>::MAX_UTC).unwrap();
+        assert_eq!(end.windows(TimeDelta::hours(1), TimeDelta::hours(1)).count(), 0);
+    }
+
+    #[test]
+    fn test_split() {
+        let a = interval(utc(1, 30, 12), utc(3, 2, 0));
+        let days: Vec<_> = a.split_days().collect();
+        assert_eq!(days.len(), 32);
+        assert_eq!(days[0], interval(utc(1, 30, 12), utc(1, 31, 0)));
+        assert_eq!(days[31], interval(utc(3, 1, 0), utc(3, 2, 0)));
+        let months: Vec<_> = a.split_months().collect();
+        assert_eq!(
+            months,
+            [
+                interval(utc(1, 30, 12), utc(2, 1, 0)),
+                interval(utc(2, 1, 0), utc(3, 1, 0)),
+                interval(utc(3, 1, 0), utc(3, 2, 0)),
+            ]
+        );
+        assert_eq!(interval(utc(1, 1, 0), utc(1, 1, 0)).split_days().count(), 0);
+        assert_eq!(interval(utc(1, 1, 1), utc(1, 1, 2)).split_months().count(), 1);
+
+        // Boundaries are in local time.
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let b = Interval::new(utc(5, 7, 0).with_timezone(&tz), utc(5, 8, 0).with_timezone(&tz));
+        let days: Vec<_> = b.unwrap().split_days().map(|day| day.duration().num_hours()).collect();
+        assert_eq!(days, [5, 19]);
+    }
+
+    /// Maps `local` in a time zone whose offset changes from zero to `offset` seconds at the UTC
+    /// time `at`.
+    fn map_local(
+        local: &NaiveDateTime,
+        at: NaiveDateTime,
+        offset: i32,
+    ) -> MappedLocalTime<FixedOffset> {
+        let before = FixedOffset::east_opt(0).unwrap();
+        let after = FixedOffset::east_opt(offset).unwrap();
+        match (*local - before < at, *local - after >= at) {
+            (true, true) => MappedLocalTime::Ambiguous(before, after),
+            (true, false) => MappedLocalTime::Single(before),
+            (false, true) => MappedLocalTime::Single(after),
+            (false, false) => MappedLocalTime::None,
+        }
+    }
+
+    macro_rules! transition_zone {
+        ($(#[$attr:meta])* $name:ident, $at:expr, $offset:expr) => {
+            $(#[$attr])*
+            #[derive(Clone)]
+            struct $name;
+
+            impl TimeZone for $name {
+                type Offset = FixedOffset;
+
+                fn from_offset(_: &FixedOffset) -> Self {
+                    $name
+                }
+
+                fn offset_from_local_date(&self, _: &NaiveDate) -> MappedLocalTime<FixedOffset> {
+                    unimplemented!()
+                }
+
+                fn offset_from_local_datetime(
+                    &self,
+                    local: &NaiveDateTime,
+                ) -> MappedLocalTime<FixedOffset> {
+                    map_local(local, $at, $offset)
+                }
+
+                fn offset_from_utc_date(&self, _: &NaiveDate) -> FixedOffset {
+                    unimplemented!()
+                }
+
+                fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+                    let offset = if *utc < $at { 0 } else { $offset };
+                    FixedOffset::east_opt(offset).unwrap()
+                }
+            }
+        };
+    }
+
+    transition_zone!(
+        /// Time zone where local midnight of April 1 is skipped: clocks jump from 00:00 to 01:00.
+        MidnightGap,
+        utc(4, 1, 0).naive_utc(),
+        3600
+    );
+
+    transition_zone!(
+        /// Time zone where local midnight of April 1 occurs twice: clocks go back from 00:30 to
+        /// 23:30 of March 31.
+        MidnightFold,
+        utc(4, 1, 0).naive_utc() + TimeDelta::minutes(30),
+        -3600
+    );
+
+    #[test]
+    fn test_split_dst_gap() {
+        let start = MidnightGap.from_utc_datetime(&utc(3, 31, 12).naive_utc());
+        let end = MidnightGap.from_utc_datetime(&utc(4, 1, 12).naive_utc());
+        let days: Vec<_> = Interval::new(start, end).unwrap().split_days().collect();
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].end().naive_local().to_string(), "2024-04-01 01:00:00");
+        assert_eq!(days[0].end().naive_utc(), utc(4, 1, 0).naive_utc());
+        assert_eq!(days[0].duration(), TimeDelta::hours(12));
+    }
+
+    #[test]
+    fn test_split_dst_fold() {
+        // Starts at the second occurrence of 23:40 on March 31, after the first midnight.
+        let start = utc(4, 1, 0) + TimeDelta::minutes(40);
+        let start = MidnightFold.from_utc_datetime(&start.naive_utc());
+        let end = MidnightFold.from_utc_datetime(&utc(4, 2, 12).naive_utc());
+        assert_eq!(start.naive_local().to_string(), "2024-03-31 23:40:00");
+        let days: Vec<_> = Interval::new(start, end).unwrap().split_days().collect();
+        assert_eq!(days.len(), 3);
+        assert!(days.iter().all(|day| day.start() < day.end()));
+        // The first day ends at the second occurrence of midnight.
+        assert_eq!(days[0].end().naive_utc(), utc(4, 1, 1).naive_utc());
+        assert_eq!(days[0].duration(), TimeDelta::minutes(20));
+        assert_eq!(days[1].duration(), TimeDelta::hours(24));
+
+        // Starting before the fold, the first occurrence of midnight is the boundary.
+        let start = MidnightFold.from_utc_datetime(&utc(3, 31, 12).naive_utc());
+        let days: Vec<_> = Interval::new(start, end).unwrap().split_days().collect();
+        assert_eq!(days[0].end().naive_utc(), utc(4, 1, 0).naive_utc());
+        assert_eq!(days[1].duration(), TimeDelta::hours(25));
+    }
+}
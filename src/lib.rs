@@ -37,15 +37,22 @@
 //! - `serde`: Enable serialization/deserialization via [serde].
 //! - `rkyv`: Deprecated, use the `rkyv-*` features.
 //! - `rkyv-16`: Enable serialization/deserialization via [rkyv],
-//!    using 16-bit integers for integral `*size` types.
+//!   using 16-bit integers for integral `*size` types.
 //! - `rkyv-32`: Enable serialization/deserialization via [rkyv],
-//!    using 32-bit integers for integral `*size` types.
+//!   using 32-bit integers for integral `*size` types.
 //! - `rkyv-64`: Enable serialization/deserialization via [rkyv],
-//!    using 64-bit integers for integral `*size` types.
+//!   using 64-bit integers for integral `*size` types.
 //! - `rkyv-validation`: Enable rkyv validation support using `bytecheck`.
 //! - `arbitrary`: Construct arbitrary instances of a type with the Arbitrary crate.
 //! - `unstable-locales`: Enable localization. This adds various methods with a `_localized` suffix.
 //!   The implementation and API may change or even be removed in a patch release. Feedback welcome.
+//! - `wasm-perf-clock`: Read the current time on the `wasm32` target from
+//!   `performance.timeOrigin + performance.now()` instead of allocating a JS `Date`. This is a
+//!   superset of `wasmbind`.
+//! - `stats`: Summary statistics such as the mean and median over iterators of `DateTime`s, see
+//!   `TimestampStats`.
+//! - `arrow`: Conversions to and from the date, time and timestamp types of Apache Arrow and
+//!   Parquet, see the `arrow` module.
 //! - `oldtime`: This feature no longer has any effect; it used to offer compatibility with the
 //!   `time` 0.1 crate.
 //!
@@ -546,21 +553,49 @@ pub use date::Date;
 pub use date::{MAX_DATE, MIN_DATE};
 
 mod datetime;
-pub use datetime::DateTime;
+pub use datetime::{DateTime, ElapsedError, TimestampUnit};
 #[allow(deprecated)]
 #[doc(no_inline)]
 pub use datetime::{MAX_DATETIME, MIN_DATETIME};
 
+mod fixed_precision;
+pub use fixed_precision::FixedPrecision;
+
+pub mod calendar_math;
+
+pub mod calendars;
+
+mod compact;
+pub use compact::CompactDateTime;
+
+pub mod cmp;
+
+pub mod cron;
+
+mod expiry;
+pub use expiry::Expiry;
+
 pub mod format;
+
+pub mod interval;
 /// L10n locales.
 #[cfg(feature = "unstable-locales")]
 pub use format::Locale;
 pub use format::{ParseError, ParseResult, SecondsFormat};
+pub use interval::Interval;
+
+mod macros;
+
+mod monotonic;
+#[cfg(feature = "std")]
+pub use monotonic::HybridTimestamp;
+pub use monotonic::MonotonicTimestampGen;
 
 pub mod naive;
 #[doc(inline)]
 pub use naive::{Days, NaiveDate, NaiveDateTime, NaiveTime};
-pub use naive::{IsoWeek, NaiveWeek};
+pub use naive::{IsoWeek, IsoWeekDate, IsoWeekDateError, NaiveWeek, WeekNumbering};
+pub use naive::{Ordinal, WeekOfYear};
 
 pub mod offset;
 #[cfg(feature = "clock")]
@@ -575,6 +610,16 @@ pub use offset::{FixedOffset, Offset, TimeZone, Utc};
 pub mod round;
 pub use round::{DurationRound, RoundingError, SubsecRound};
 
+pub mod rtc;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "stats")]
+pub use stats::TimestampStats;
+
 mod weekday;
 #[doc(no_inline)]
 pub use weekday::ParseWeekdayError;
@@ -583,10 +628,10 @@ pub use weekday::Weekday;
 mod month;
 #[doc(no_inline)]
 pub use month::ParseMonthError;
-pub use month::{Month, Months};
+pub use month::{Month, Months, Quarter, YearQuarter};
 
 mod traits;
-pub use traits::{Datelike, Timelike};
+pub use traits::{CalendarFields, Datelike, Timelike};
 
 #[cfg(feature = "__internal_bench")]
 #[doc(hidden)]
@@ -613,6 +658,54 @@ pub mod serde {
     use serde::de;
 
     pub use super::datetime::serde::*;
+    pub use super::time_delta::serde::{duration_iso8601, duration_milliseconds, duration_seconds};
+
+    /// Ser/de `Weekday` to/from numbers instead of names
+    ///
+    /// The modules in here are intended to be used with serde's [`with` annotation].
+    ///
+    /// [`with` annotation]: https://serde.rs/field-attrs.html#with
+    pub mod weekday {
+        pub use crate::weekday::weekday_serde::{as_number_monday0, as_number_sunday1};
+    }
+
+    #[doc(hidden)]
+    pub use ::serde as __serde;
+
+    /// Deserialize a string with `parse` and the given format, for use by [`serde_format!`].
+    ///
+    /// [`serde_format!`]: crate::serde_format
+    #[doc(hidden)]
+    pub fn __deserialize_formatted<'de, D, T>(
+        deserializer: D,
+        format: &'static str,
+        parse: fn(&str, &str) -> crate::ParseResult<T>,
+    ) -> Result<T, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct FormattedVisitor<T> {
+            format: &'static str,
+            parse: fn(&str, &str) -> crate::ParseResult<T>,
+        }
+
+        impl<T> de::Visitor<'_> for FormattedVisitor<T> {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a string in the format `{}`", self.format)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<T, E>
+            where
+                E: de::Error,
+            {
+                (self.parse)(value, self.format).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(FormattedVisitor { format, parse })
+    }
 
     /// Create a custom `de::Error` with `SerdeError::InvalidTimestamp`.
     pub(crate) fn invalid_ts<E, T>(value: T) -> E
@@ -623,6 +716,39 @@ pub mod serde {
         E::custom(SerdeError::InvalidTimestamp(value))
     }
 
+    /// Deserializes an optional value with the wrapped timestamp visitor.
+    pub(crate) struct OptionVisitor<V>(pub(crate) V);
+
+    impl<'de, V: de::Visitor<'de>> de::Visitor<'de> for OptionVisitor<V> {
+        type Value = Option<V::Value>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            self.0.expecting(formatter)?;
+            formatter.write_str(" or none")
+        }
+
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(self.0).map(Some)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+
     enum SerdeError<T: fmt::Display> {
         InvalidTimestamp(T),
     }
@@ -638,16 +764,85 @@ pub mod serde {
     }
 }
 
+/// Generates a module to ser/de a value as a string in a custom format.
+///
+/// The generated module is intended for use with serde's [`with` annotation], and uses the
+/// [`strftime`](crate::format::strftime) syntax. It works with every type that has a `format` and
+/// a `parse_from_str` method: [`NaiveDate`], [`NaiveTime`], [`NaiveDateTime`] and
+/// [`DateTime<FixedOffset>`].
+///
+/// The type and the format are resolved in the enclosing module, so the macro must be used at
+/// module level and not inside a function.
+///
+/// *Available on crate features 'serde' and 'alloc' only.*
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use serde_derive::{Deserialize, Serialize};
+///
+/// chrono::serde_format!(mod dmy, NaiveDate, "%d/%m/%Y");
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "dmy")]
+///     date: NaiveDate,
+/// }
+///
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let my_s: S = serde_json::from_str(r#"{"date":"07/05/2024"}"#)?;
+/// assert_eq!(my_s.date, NaiveDate::from_ymd_opt(2024, 5, 7).unwrap());
+/// assert_eq!(serde_json::to_string(&my_s)?, r#"{"date":"07/05/2024"}"#);
+///
+/// let err = serde_json::from_str::<S>(r#"{"date":"2024-05-07"}"#);
+/// assert!(err.is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`with` annotation]: https://serde.rs/field-attrs.html#with
+#[cfg(all(feature = "serde", feature = "alloc"))]
+#[macro_export]
+macro_rules! serde_format {
+    ($vis:vis mod $name:ident, $ty:ty, $format:expr) => {
+        #[allow(unreachable_pub)]
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            const FORMAT: &str = $format;
+
+            /// Serialize the value as a string in the format of this module.
+            pub fn serialize<S>(value: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::__serde::Serializer,
+            {
+                serializer.collect_str(&value.format(FORMAT))
+            }
+
+            /// Deserialize the value from a string in the format of this module.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: $crate::serde::__serde::Deserializer<'de>,
+            {
+                $crate::serde::__deserialize_formatted(deserializer, FORMAT, <$ty>::parse_from_str)
+            }
+        }
+    };
+}
+
 /// Zero-copy serialization/deserialization with rkyv.
 ///
 /// This module re-exports the `Archived*` versions of chrono's types.
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
 pub mod rkyv {
     pub use crate::datetime::ArchivedDateTime;
-    pub use crate::month::ArchivedMonth;
+    pub use crate::month::{ArchivedMonth, ArchivedMonths, ArchivedQuarter, ArchivedYearQuarter};
+    pub use crate::naive::ArchivedDays;
     pub use crate::naive::date::ArchivedNaiveDate;
     pub use crate::naive::datetime::ArchivedNaiveDateTime;
-    pub use crate::naive::isoweek::ArchivedIsoWeek;
+    pub use crate::naive::isoweek::{ArchivedIsoWeek, ArchivedIsoWeekDate};
     pub use crate::naive::time::ArchivedNaiveTime;
     pub use crate::offset::fixed::ArchivedFixedOffset;
     #[cfg(feature = "clock")]
@@ -707,6 +902,25 @@ pub(crate) const fn expect<T: Copy>(opt: Option<T>, msg: &str) -> T {
     }
 }
 
+/// The 64-bit FNV-1a hash of the little-endian bytes of `values`.
+///
+/// This is the algorithm documented for the `stable_hash` methods, its output must never change.
+pub(crate) const fn stable_hash(values: &[i64]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_le_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            hash ^= bytes[j] as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+            j += 1;
+        }
+        i += 1;
+    }
+    hash
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "clock")]
@@ -729,4 +943,22 @@ mod tests {
         assert_eq!(size_of::<DateTime<Local>>(), 16);
         assert_eq!(size_of::<Option<DateTime<FixedOffset>>>(), 16);
     }
+
+    #[test]
+    fn test_stable_hash() {
+        use super::stable_hash;
+        use crate::{NaiveDate, NaiveTime, TimeDelta};
+
+        assert_eq!(stable_hash(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_hash(&[0]), 0xa8c7_f832_281a_39c5);
+        assert_eq!(stable_hash(&[1, 2]), 0x7717_9803_63c8_e066);
+
+        let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+        assert_ne!(date.stable_hash(), date.succ_opt().unwrap().stable_hash());
+        let time = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+        let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_000).unwrap();
+        assert_ne!(time.stable_hash(), leap.stable_hash());
+        assert_eq!(date.and_time(time).stable_hash(), stable_hash(&[739_013, 86_399, 0]));
+        assert_eq!(TimeDelta::zero().stable_hash(), stable_hash(&[0, 0]));
+    }
 }
@@ -0,0 +1,145 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Macros for constructing values at compile time.
+
+/// Makes a new [`TimeDelta`](crate::TimeDelta) constant from a list of amounts and units, or from
+/// a time in `hours:minutes:seconds` form.
+///
+/// The value is computed by the compiler, so an out-of-range value or an unknown unit is an
+/// error at compile time instead of a panic at runtime. Amounts must be integer literals.
+///
+/// The supported units are:
+///
+/// | Unit         | Spelling                                  |
+/// |--------------|-------------------------------------------|
+/// | weeks        | `weeks`, `week`, `w`                      |
+/// | days         | `days`, `day`, `d`                        |
+/// | hours        | `hours`, `hour`, `h`                      |
+/// | minutes      | `minutes`, `minute`, `min`                |
+/// | seconds      | `seconds`, `second`, `s`                  |
+/// | milliseconds | `milliseconds`, `millisecond`, `ms`       |
+/// | microseconds | `microseconds`, `microsecond`, `us`       |
+/// | nanoseconds  | `nanoseconds`, `nanosecond`, `ns`         |
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeDelta, timedelta};
+///
+/// const TIMEOUT: TimeDelta = timedelta!(2 days 3 hours 4 ms);
+/// assert_eq!(TIMEOUT, TimeDelta::days(2) + TimeDelta::hours(3) + TimeDelta::milliseconds(4));
+///
+/// assert_eq!(timedelta!(02:30:00), TimeDelta::minutes(150));
+/// assert_eq!(timedelta!(100:00:01), TimeDelta::hours(100) + TimeDelta::seconds(1));
+/// ```
+///
+/// Values that don't fit in a `TimeDelta`, and minutes or seconds of 60 or more in the
+/// `hours:minutes:seconds` form, fail to compile:
+///
+/// ```compile_fail
+/// # use chrono::timedelta;
+/// let _ = timedelta!(1000000000000000 weeks);
+/// ```
+///
+/// ```compile_fail
+/// # use chrono::timedelta;
+/// let _ = timedelta!(01:60:00);
+/// ```
+///
+/// ```compile_fail
+/// # use chrono::timedelta;
+/// let _ = timedelta!(2 fortnights);
+/// ```
+#[macro_export]
+macro_rules! timedelta {
+    (@in_range $e:expr) => {
+        match $e {
+            ::core::option::Option::Some(delta) => delta,
+            ::core::option::Option::None => ::core::panic!("`timedelta!` value out of range"),
+        }
+    };
+    (@unit $n:literal weeks) => { $crate::timedelta!(@in_range $crate::TimeDelta::try_weeks($n)) };
+    (@unit $n:literal week) => { $crate::timedelta!(@unit $n weeks) };
+    (@unit $n:literal w) => { $crate::timedelta!(@unit $n weeks) };
+    (@unit $n:literal days) => { $crate::timedelta!(@in_range $crate::TimeDelta::try_days($n)) };
+    (@unit $n:literal day) => { $crate::timedelta!(@unit $n days) };
+    (@unit $n:literal d) => { $crate::timedelta!(@unit $n days) };
+    (@unit $n:literal hours) => { $crate::timedelta!(@in_range $crate::TimeDelta::try_hours($n)) };
+    (@unit $n:literal hour) => { $crate::timedelta!(@unit $n hours) };
+    (@unit $n:literal h) => { $crate::timedelta!(@unit $n hours) };
+    (@unit $n:literal minutes) => {
+        $crate::timedelta!(@in_range $crate::TimeDelta::try_minutes($n))
+    };
+    (@unit $n:literal minute) => { $crate::timedelta!(@unit $n minutes) };
+    (@unit $n:literal min) => { $crate::timedelta!(@unit $n minutes) };
+    (@unit $n:literal seconds) => {
+        $crate::timedelta!(@in_range $crate::TimeDelta::try_seconds($n))
+    };
+    (@unit $n:literal second) => { $crate::timedelta!(@unit $n seconds) };
+    (@unit $n:literal s) => { $crate::timedelta!(@unit $n seconds) };
+    (@unit $n:literal milliseconds) => {
+        $crate::timedelta!(@in_range $crate::TimeDelta::try_milliseconds($n))
+    };
+    (@unit $n:literal millisecond) => { $crate::timedelta!(@unit $n milliseconds) };
+    (@unit $n:literal ms) => { $crate::timedelta!(@unit $n milliseconds) };
+    (@unit $n:literal microseconds) => { $crate::TimeDelta::microseconds($n) };
+    (@unit $n:literal microsecond) => { $crate::timedelta!(@unit $n microseconds) };
+    (@unit $n:literal us) => { $crate::timedelta!(@unit $n microseconds) };
+    (@unit $n:literal nanoseconds) => { $crate::TimeDelta::nanoseconds($n) };
+    (@unit $n:literal nanosecond) => { $crate::timedelta!(@unit $n nanoseconds) };
+    (@unit $n:literal ns) => { $crate::timedelta!(@unit $n nanoseconds) };
+    (@unit $n:literal $unit:ident) => {
+        ::core::compile_error!(concat!("unknown unit `", stringify!($unit), "` in `timedelta!`"))
+    };
+    ($hours:literal : $minutes:literal : $seconds:literal) => {{
+        const TIMEDELTA: $crate::TimeDelta = {
+            let (hours, minutes, seconds): (i64, i64, i64) = ($hours, $minutes, $seconds);
+            if minutes >= 60 || seconds >= 60 {
+                ::core::panic!("`timedelta!` minutes and seconds must be less than 60");
+            }
+            let seconds = hours * 3600 + minutes * 60 + seconds;
+            $crate::timedelta!(@in_range $crate::TimeDelta::try_seconds(seconds))
+        };
+        TIMEDELTA
+    }};
+    ($($n:literal $unit:ident)+) => {{
+        const TIMEDELTA: $crate::TimeDelta = {
+            let mut total = $crate::TimeDelta::zero();
+            $(
+                let delta = $crate::timedelta!(@unit $n $unit);
+                total = $crate::timedelta!(@in_range total.checked_add(&delta));
+            )+
+            total
+        };
+        TIMEDELTA
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TimeDelta;
+
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn test_timedelta_macro() {
+        assert_eq!(timedelta!(1 w), TimeDelta::weeks(1));
+        assert_eq!(
+            timedelta!(2 days 3 hours 4 ms),
+            TimeDelta::new(2 * 86_400 + 3 * 3600, 4_000_000).unwrap()
+        );
+        assert_eq!(timedelta!(1 day 1 hour 1 minute 1 second), TimeDelta::seconds(90_061));
+        assert_eq!(timedelta!(5 min 30 s), TimeDelta::seconds(330));
+        assert_eq!(timedelta!(1 ms 2 us 3 ns), TimeDelta::nanoseconds(1_002_003));
+        assert_eq!(timedelta!(3 hours 3 hours), TimeDelta::hours(6));
+        assert_eq!(timedelta!(0 seconds), TimeDelta::zero());
+
+        assert_eq!(timedelta!(00:00:00), TimeDelta::zero());
+        assert_eq!(timedelta!(02:30:00), TimeDelta::minutes(150));
+        assert_eq!(timedelta!(23:59:59), TimeDelta::seconds(86_399));
+        assert_eq!(timedelta!(48:00:00), TimeDelta::days(2));
+
+        const MAX_WEEKS: TimeDelta = timedelta!(15_250_284_420 weeks);
+        assert_eq!(MAX_WEEKS, TimeDelta::weeks(15_250_284_420));
+    }
+}
@@ -0,0 +1,275 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A generator of strictly increasing timestamps, and timestamps anchored to a monotonic clock.
+
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::{DateTime, TimeDelta, Utc};
+
+/// A generator of strictly increasing `DateTime<Utc>` values.
+///
+/// The system clock can repeat a value when it is read twice in quick succession, and it can go
+/// backwards when it is adjusted. Event logs and similar systems often need every timestamp to be
+/// unique and ordered instead. This generator returns the wall clock time when it is later than
+/// the previous timestamp, and otherwise the previous timestamp plus one nanosecond.
+///
+/// The generator takes `&mut self`. To share it between threads, wrap it in a `Mutex`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{MonotonicTimestampGen, TimeZone, Utc};
+///
+/// let mut timestamps = MonotonicTimestampGen::new();
+/// let wall_clock = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+///
+/// let first = timestamps.advance(wall_clock);
+/// let second = timestamps.advance(wall_clock); // the clock did not move
+/// let third = timestamps.advance(wall_clock - chrono::TimeDelta::seconds(1)); // or went back
+/// assert_eq!(first, wall_clock);
+/// assert_eq!(second, wall_clock + chrono::TimeDelta::nanoseconds(1));
+/// assert_eq!(third, wall_clock + chrono::TimeDelta::nanoseconds(2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MonotonicTimestampGen {
+    last: Option<DateTime<Utc>>,
+}
+
+impl MonotonicTimestampGen {
+    /// Makes a new generator that has not returned any timestamp yet.
+    #[must_use]
+    pub const fn new() -> MonotonicTimestampGen {
+        MonotonicTimestampGen { last: None }
+    }
+
+    /// Makes a new generator whose timestamps all come after `last`.
+    ///
+    /// This is useful to continue a sequence after a restart, for example from the last timestamp
+    /// written to a log.
+    #[must_use]
+    pub const fn starting_after(last: DateTime<Utc>) -> MonotonicTimestampGen {
+        MonotonicTimestampGen { last: Some(last) }
+    }
+
+    /// Returns the last timestamp returned by this generator, if any.
+    #[must_use]
+    pub const fn last(&self) -> Option<DateTime<Utc>> {
+        self.last
+    }
+
+    /// Returns a timestamp based on the current time of the system clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp would go beyond the range of `DateTime<Utc>`, see [`advance`].
+    ///
+    /// [`advance`]: MonotonicTimestampGen::advance
+    #[cfg(feature = "now")]
+    #[must_use]
+    pub fn now(&mut self) -> DateTime<Utc> {
+        self.advance(Utc::now())
+    }
+
+    /// Returns `wall_clock` if it is later than the previous timestamp, or else the previous
+    /// timestamp plus one nanosecond.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the previous timestamp is [`DateTime::<Utc>::MAX_UTC`], as no later timestamp
+    /// exists.
+    pub fn advance(&mut self, wall_clock: DateTime<Utc>) -> DateTime<Utc> {
+        let next = match self.last {
+            Some(last) if wall_clock <= last => last
+                .checked_add_signed(TimeDelta::nanoseconds(1))
+                .expect("timestamp out of range for `DateTime<Utc>`"),
+            _ => wall_clock,
+        };
+        self.last = Some(next);
+        next
+    }
+}
+
+/// A wall clock time paired with a reading of the monotonic clock taken at the same moment.
+///
+/// The system clock can jump when it is adjusted, so the difference between two readings of
+/// [`Utc::now`] is not a reliable measure of elapsed time, and a time read after a jump may not
+/// line up with the ones before. A `HybridTimestamp` records the wall clock once, and derives
+/// later wall clock times from the elapsed time of the monotonic [`Instant`] instead.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{HybridTimestamp, TimeDelta};
+///
+/// let start = HybridTimestamp::now();
+/// let elapsed = start.elapsed();
+/// assert!(elapsed >= TimeDelta::zero());
+///
+/// // Later wall clock times are derived from the monotonic clock.
+/// assert!(start.derived_now() >= start.wall_clock() + elapsed);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HybridTimestamp {
+    wall_clock: DateTime<Utc>,
+    instant: Instant,
+}
+
+#[cfg(feature = "std")]
+impl HybridTimestamp {
+    /// Reads the system clock and the monotonic clock.
+    #[cfg(feature = "now")]
+    #[must_use]
+    pub fn now() -> HybridTimestamp {
+        HybridTimestamp { wall_clock: Utc::now(), instant: Instant::now() }
+    }
+
+    /// Makes a new `HybridTimestamp` from a wall clock time and the `Instant` it was read at.
+    #[must_use]
+    pub const fn from_parts(wall_clock: DateTime<Utc>, instant: Instant) -> HybridTimestamp {
+        HybridTimestamp { wall_clock, instant }
+    }
+
+    /// Returns the recorded wall clock time.
+    #[must_use]
+    pub const fn wall_clock(&self) -> DateTime<Utc> {
+        self.wall_clock
+    }
+
+    /// Returns the recorded reading of the monotonic clock.
+    #[must_use]
+    pub const fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    /// Returns the time elapsed on the monotonic clock since this timestamp was taken.
+    ///
+    /// This saturates at [`TimeDelta::MAX`].
+    #[must_use]
+    pub fn elapsed(&self) -> TimeDelta {
+        TimeDelta::from_std(self.instant.elapsed()).unwrap_or(TimeDelta::MAX)
+    }
+
+    /// Returns the wall clock time at `instant`, derived from the recorded wall clock time and
+    /// the difference between the monotonic clock readings.
+    ///
+    /// `instant` may be earlier than the recorded `Instant`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the result would be out of range for a `DateTime<Utc>`.
+    #[must_use]
+    pub fn wall_clock_at(&self, instant: Instant) -> Option<DateTime<Utc>> {
+        match instant.checked_duration_since(self.instant) {
+            Some(after) => self.wall_clock.checked_add_signed(TimeDelta::from_std(after).ok()?),
+            None => {
+                let before = TimeDelta::from_std(self.instant.duration_since(instant)).ok()?;
+                self.wall_clock.checked_sub_signed(before)
+            }
+        }
+    }
+
+    /// Returns the current wall clock time, derived from the recorded wall clock time and the
+    /// time elapsed on the monotonic clock.
+    ///
+    /// Unlike [`Utc::now`] this is not affected by adjustments of the system clock since this
+    /// timestamp was taken.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be out of range for a `DateTime<Utc>`.
+    #[must_use]
+    pub fn derived_now(&self) -> DateTime<Utc> {
+        self.wall_clock_at(Instant::now()).expect("timestamp out of range for `DateTime<Utc>`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MonotonicTimestampGen;
+    use crate::{DateTime, NaiveDate, TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn test_monotonic_advance() {
+        let t0 = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+        let ns = TimeDelta::nanoseconds;
+        let mut timestamps = MonotonicTimestampGen::new();
+        assert_eq!(timestamps.last(), None);
+        assert_eq!(timestamps.advance(t0), t0);
+        assert_eq!(timestamps.advance(t0), t0 + ns(1));
+        assert_eq!(timestamps.advance(t0 - TimeDelta::hours(1)), t0 + ns(2));
+        assert_eq!(timestamps.advance(t0 + ns(2)), t0 + ns(3));
+        assert_eq!(timestamps.advance(t0 + ns(10)), t0 + ns(10));
+        assert_eq!(timestamps.last(), Some(t0 + ns(10)));
+
+        let mut timestamps = MonotonicTimestampGen::starting_after(t0);
+        assert_eq!(timestamps.advance(t0 - ns(1)), t0 + ns(1));
+
+        // leap second
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_999_999_999)
+            .unwrap()
+            .and_utc();
+        let mut timestamps = MonotonicTimestampGen::starting_after(leap);
+        assert!(timestamps.advance(leap) > leap);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_monotonic_overflow() {
+        let mut timestamps = MonotonicTimestampGen::starting_after(DateTime::<Utc>::MAX_UTC);
+        let _ = timestamps.advance(DateTime::<Utc>::MAX_UTC);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hybrid_timestamp() {
+        use super::HybridTimestamp;
+        use std::time::{Duration, Instant};
+
+        let wall_clock = Utc.with_ymd_and_hms(2024, 5, 7, 12, 0, 0).unwrap();
+        let instant = Instant::now();
+        let ts = HybridTimestamp::from_parts(wall_clock, instant);
+        assert_eq!(ts.wall_clock(), wall_clock);
+        assert_eq!(ts.instant(), instant);
+        assert_eq!(ts.wall_clock_at(instant), Some(wall_clock));
+
+        let later = instant + Duration::from_millis(1500);
+        assert_eq!(ts.wall_clock_at(later), Some(wall_clock + TimeDelta::milliseconds(1500)));
+        if let Some(earlier) = instant.checked_sub(Duration::from_secs(2)) {
+            assert_eq!(ts.wall_clock_at(earlier), Some(wall_clock - TimeDelta::seconds(2)));
+        }
+
+        let ts = HybridTimestamp::from_parts(DateTime::<Utc>::MAX_UTC, instant);
+        assert_eq!(ts.wall_clock_at(later), None);
+
+        let elapsed = ts.elapsed();
+        assert!(elapsed >= TimeDelta::zero() && elapsed < TimeDelta::minutes(5));
+    }
+
+    #[test]
+    #[cfg(feature = "now")]
+    fn test_hybrid_timestamp_now() {
+        use super::HybridTimestamp;
+
+        let ts = HybridTimestamp::now();
+        let derived = ts.derived_now();
+        assert!(derived >= ts.wall_clock());
+        assert!(derived - Utc::now() < TimeDelta::minutes(5));
+    }
+
+    #[test]
+    #[cfg(feature = "now")]
+    fn test_monotonic_now() {
+        let mut timestamps = MonotonicTimestampGen::new();
+        let mut last = timestamps.now();
+        for _ in 0..1000 {
+            let next = timestamps.now();
+            assert!(next > last);
+            last = next;
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use core::fmt;
+use core::{fmt, str};
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
 use rkyv::{Archive, Deserialize, Serialize};
 
-use crate::OutOfRange;
+use crate::format::{
+    Item, NOT_ENOUGH, Numeric, OUT_OF_RANGE, Pad, ParseError, ParseResult, Parsed, parse,
+};
 use crate::naive::NaiveDate;
+use crate::{Datelike, OutOfRange, expect};
 
 /// The month of the year.
 ///
@@ -163,6 +166,23 @@ impl Month {
         }
     }
 
+    /// Returns the quarter this month is in.
+    ///
+    /// ```
+    /// use chrono::{Month, Quarter};
+    ///
+    /// assert_eq!(Month::May.quarter(), Quarter::Q2)
+    /// ```
+    #[must_use]
+    pub const fn quarter(&self) -> Quarter {
+        match *self {
+            Month::January | Month::February | Month::March => Quarter::Q1,
+            Month::April | Month::May | Month::June => Quarter::Q2,
+            Month::July | Month::August | Month::September => Quarter::Q3,
+            Month::October | Month::November | Month::December => Quarter::Q4,
+        }
+    }
+
     /// Get the length in days of the month
     ///
     /// Yields `None` if `year` is out of range for `NaiveDate`.
@@ -245,8 +265,290 @@ impl num_traits::FromPrimitive for Month {
     }
 }
 
+/// The quarter of the year.
+///
+/// Like [`Month`] this enum is just a convenience implementation: [`Datelike::quarter`] returns
+/// the quarter as a number from 1 to 4, which can be converted with [`Quarter::try_from`].
+///
+/// ```
+/// use chrono::prelude::*;
+/// use chrono::Quarter;
+///
+/// let date = Utc.with_ymd_and_hms(2019, 10, 28, 9, 10, 11).unwrap();
+/// let quarter = Quarter::try_from(date.quarter() as u8).unwrap();
+/// assert_eq!(quarter, Quarter::Q4);
+/// assert_eq!(quarter.months(), [Month::October, Month::November, Month::December]);
+/// ```
+///
+/// [`Datelike::quarter`]: crate::Datelike::quarter
+// Actual implementation is zero-indexed, API intended as 1-indexed for more intuitive behavior.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
+    derive(Archive, Deserialize, Serialize),
+    archive(compare(PartialEq, PartialOrd)),
+    archive_attr(derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash))
+)]
+#[cfg_attr(feature = "rkyv-validation", archive(check_bytes))]
+#[cfg_attr(all(feature = "arbitrary", feature = "std"), derive(arbitrary::Arbitrary))]
+pub enum Quarter {
+    /// January to March
+    Q1 = 0,
+    /// April to June
+    Q2 = 1,
+    /// July to September
+    Q3 = 2,
+    /// October to December
+    Q4 = 3,
+}
+
+impl Quarter {
+    /// The next quarter.
+    ///
+    /// `q`:        | `Q1` | `Q2` | `Q3` | `Q4`
+    /// ----------- | ---- | ---- | ---- | ----
+    /// `q.succ()`: | `Q2` | `Q3` | `Q4` | `Q1`
+    #[inline]
+    #[must_use]
+    pub const fn succ(&self) -> Quarter {
+        match *self {
+            Quarter::Q1 => Quarter::Q2,
+            Quarter::Q2 => Quarter::Q3,
+            Quarter::Q3 => Quarter::Q4,
+            Quarter::Q4 => Quarter::Q1,
+        }
+    }
+
+    /// The previous quarter.
+    ///
+    /// `q`:        | `Q1` | `Q2` | `Q3` | `Q4`
+    /// ----------- | ---- | ---- | ---- | ----
+    /// `q.pred()`: | `Q4` | `Q1` | `Q2` | `Q3`
+    #[inline]
+    #[must_use]
+    pub const fn pred(&self) -> Quarter {
+        match *self {
+            Quarter::Q1 => Quarter::Q4,
+            Quarter::Q2 => Quarter::Q1,
+            Quarter::Q3 => Quarter::Q2,
+            Quarter::Q4 => Quarter::Q3,
+        }
+    }
+
+    /// Returns a quarter-of-year number starting from `Q1` = 1.
+    ///
+    /// `q`:          | `Q1` | `Q2` | `Q3` | `Q4`
+    /// ------------- | ---- | ---- | ---- | ----
+    /// `q.number()`: | 1    | 2    | 3    | 4
+    #[inline]
+    #[must_use]
+    pub const fn number(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// Returns the three months in this quarter.
+    #[must_use]
+    pub const fn months(&self) -> [Month; 3] {
+        match *self {
+            Quarter::Q1 => [Month::January, Month::February, Month::March],
+            Quarter::Q2 => [Month::April, Month::May, Month::June],
+            Quarter::Q3 => [Month::July, Month::August, Month::September],
+            Quarter::Q4 => [Month::October, Month::November, Month::December],
+        }
+    }
+
+    /// Returns the first month of this quarter.
+    #[inline]
+    #[must_use]
+    pub const fn first_month(&self) -> Month {
+        self.months()[0]
+    }
+
+    /// Get the length in days of the quarter
+    ///
+    /// Yields `None` if `year` is out of range for `NaiveDate`.
+    pub fn num_days(&self, year: i32) -> Option<u8> {
+        let [a, b, c] = self.months();
+        Some(a.num_days(year)? + b.num_days(year)? + c.num_days(year)?)
+    }
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = OutOfRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Quarter::Q1),
+            2 => Ok(Quarter::Q2),
+            3 => Ok(Quarter::Q3),
+            4 => Ok(Quarter::Q4),
+            _ => Err(OutOfRange::new()),
+        }
+    }
+}
+
+impl num_traits::FromPrimitive for Quarter {
+    /// Returns an `Option<Quarter>` from a i64, assuming a 1-index, `Q1` = 1.
+    #[inline]
+    fn from_u64(n: u64) -> Option<Quarter> {
+        Self::from_u32(u32::try_from(n).ok()?)
+    }
+
+    #[inline]
+    fn from_i64(n: i64) -> Option<Quarter> {
+        Self::from_u32(u32::try_from(n).ok()?)
+    }
+
+    #[inline]
+    fn from_u32(n: u32) -> Option<Quarter> {
+        match n {
+            1 => Some(Quarter::Q1),
+            2 => Some(Quarter::Q2),
+            3 => Some(Quarter::Q3),
+            4 => Some(Quarter::Q4),
+            _ => None,
+        }
+    }
+}
+
+/// A quarter of a specific year, such as `2024-Q3`.
+///
+/// Its `Display` and `FromStr` implementations use the format `%Y-Q%q`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Quarter, YearQuarter};
+///
+/// let quarter: YearQuarter = "2024-Q3".parse().unwrap();
+/// assert_eq!(quarter, YearQuarter::new(2024, Quarter::Q3).unwrap());
+/// assert_eq!(quarter.first_day(), NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+/// assert_eq!(quarter.last_day(), NaiveDate::from_ymd_opt(2024, 9, 30).unwrap());
+/// assert_eq!(quarter.to_string(), "2024-Q3");
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 11, 5).unwrap();
+/// assert_eq!(YearQuarter::from(date).to_string(), "2024-Q4");
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
+    derive(Archive, Deserialize, Serialize),
+    archive(compare(PartialEq, PartialOrd)),
+    archive_attr(derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash))
+)]
+#[cfg_attr(feature = "rkyv-validation", archive(check_bytes))]
+pub struct YearQuarter {
+    year: i32,
+    quarter: Quarter,
+}
+
+impl YearQuarter {
+    /// Makes a new `YearQuarter` from a year and a quarter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `year` is out of range for `NaiveDate`.
+    #[must_use]
+    pub const fn new(year: i32, quarter: Quarter) -> Option<YearQuarter> {
+        match NaiveDate::from_ymd_opt(year, 1, 1) {
+            Some(_) => Some(YearQuarter { year, quarter }),
+            None => None,
+        }
+    }
+
+    /// Returns the year.
+    #[inline]
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the quarter of the year.
+    #[inline]
+    #[must_use]
+    pub const fn quarter(&self) -> Quarter {
+        self.quarter
+    }
+
+    /// Returns the first day of the quarter.
+    #[must_use]
+    pub const fn first_day(&self) -> NaiveDate {
+        let month = self.quarter.first_month().number_from_month();
+        expect(NaiveDate::from_ymd_opt(self.year, month, 1), "year is in range for `NaiveDate`")
+    }
+
+    /// Returns the last day of the quarter.
+    #[must_use]
+    pub const fn last_day(&self) -> NaiveDate {
+        let (month, day) = match self.quarter {
+            Quarter::Q1 => (3, 31),
+            Quarter::Q2 => (6, 30),
+            Quarter::Q3 => (9, 30),
+            Quarter::Q4 => (12, 31),
+        };
+        expect(NaiveDate::from_ymd_opt(self.year, month, day), "year is in range for `NaiveDate`")
+    }
+}
+
+impl From<NaiveDate> for YearQuarter {
+    fn from(date: NaiveDate) -> YearQuarter {
+        let quarter = match date.month() {
+            1..=3 => Quarter::Q1,
+            4..=6 => Quarter::Q2,
+            7..=9 => Quarter::Q3,
+            _ => Quarter::Q4,
+        };
+        YearQuarter { year: date.year(), quarter }
+    }
+}
+
+/// The `Display` output uses the format `%Y-Q%q`, with an explicit sign for years before 1 BCE
+/// or after 9999 CE.
+impl fmt::Display for YearQuarter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}-Q{}", self.year, self.quarter.number())
+        } else {
+            // ISO 8601 requires the explicit sign for out-of-range years
+            write!(f, "{:+05}-Q{}", self.year, self.quarter.number())
+        }
+    }
+}
+
+/// Parsing a `str` into a `YearQuarter` uses the format `%Y-Q%q`, the inverse of its `Display`
+/// output.
+impl str::FromStr for YearQuarter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<YearQuarter> {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Numeric(Numeric::Year, Pad::Zero),
+            Item::Literal("-Q"),
+            Item::Numeric(Numeric::Quarter, Pad::Zero),
+        ];
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        let year = parsed.year().ok_or(NOT_ENOUGH)?;
+        let quarter = match parsed.quarter().ok_or(NOT_ENOUGH)? {
+            1 => Quarter::Q1,
+            2 => Quarter::Q2,
+            3 => Quarter::Q3,
+            _ => Quarter::Q4,
+        };
+        YearQuarter::new(year, quarter).ok_or(OUT_OF_RANGE)
+    }
+}
+
 /// A duration in calendar months
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(
+    any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
+    derive(Archive, Deserialize, Serialize),
+    archive(compare(PartialEq, PartialOrd)),
+    archive_attr(derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash))
+)]
+#[cfg_attr(feature = "rkyv-validation", archive(check_bytes))]
 #[cfg_attr(all(feature = "arbitrary", feature = "std"), derive(arbitrary::Arbitrary))]
 pub struct Months(pub(crate) u32);
 
@@ -286,7 +588,7 @@ impl fmt::Debug for ParseMonthError {
 
 #[cfg(feature = "serde")]
 mod month_serde {
-    use super::Month;
+    use super::{Month, Months};
     use serde::{de, ser};
 
     use core::fmt;
@@ -325,12 +627,31 @@ mod month_serde {
             deserializer.deserialize_str(MonthVisitor)
         }
     }
+
+    /// Serialize `Months` as a plain number of months
+    impl ser::Serialize for Months {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_u32(self.0)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Months {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            <u32 as de::Deserialize>::deserialize(deserializer).map(Months)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Month;
-    use crate::{Datelike, Months, OutOfRange, TimeZone, Utc};
+    use super::{Month, Quarter, YearQuarter};
+    use crate::{Datelike, Months, NaiveDate, OutOfRange, TimeZone, Utc};
 
     #[test]
     fn test_month_enum_try_from() {
@@ -455,12 +776,33 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_months() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(to_string(&Months::new(0)).unwrap(), "0");
+        assert_eq!(to_string(&Months::new(u32::MAX)).unwrap(), "4294967295");
+        assert_eq!(from_str::<Months>("14").unwrap(), Months::new(14));
+        assert!(from_str::<Months>("-1").is_err());
+        assert!(from_str::<Months>("4294967296").is_err());
+        assert!(from_str::<Months>(r#""14""#).is_err());
+    }
+
     #[test]
     #[cfg(feature = "rkyv-validation")]
     fn test_rkyv_validation() {
         let month = Month::January;
         let bytes = rkyv::to_bytes::<_, 1>(&month).unwrap();
         assert_eq!(rkyv::from_bytes::<Month>(&bytes).unwrap(), month);
+
+        let months = Months::new(u32::MAX);
+        let bytes = rkyv::to_bytes::<_, 4>(&months).unwrap();
+        assert_eq!(rkyv::from_bytes::<Months>(&bytes).unwrap(), months);
+
+        let quarter = YearQuarter::new(-2024, Quarter::Q3).unwrap();
+        let bytes = rkyv::to_bytes::<_, 8>(&quarter).unwrap();
+        assert_eq!(rkyv::from_bytes::<YearQuarter>(&bytes).unwrap(), quarter);
     }
 
     #[test]
@@ -469,4 +811,82 @@ mod tests {
         assert_eq!(Month::February.num_days(2020), Some(29));
         assert_eq!(Month::February.num_days(2019), Some(28));
     }
+
+    #[test]
+    fn test_quarter_enum() {
+        use num_traits::FromPrimitive;
+
+        assert_eq!(Quarter::try_from(1), Ok(Quarter::Q1));
+        assert_eq!(Quarter::try_from(4), Ok(Quarter::Q4));
+        assert_eq!(Quarter::try_from(0), Err(OutOfRange::new()));
+        assert_eq!(Quarter::try_from(5), Err(OutOfRange::new()));
+        assert_eq!(Quarter::from_u32(3), Some(Quarter::Q3));
+        assert_eq!(Quarter::from_i64(-1), None);
+        // values that wrap around to a quarter when truncated to a `u32`
+        assert_eq!(Quarter::from_i64((1 << 32) + 1), None);
+        assert_eq!(Quarter::from_u64((1 << 32) + 2), None);
+
+        assert_eq!(Quarter::Q1.succ(), Quarter::Q2);
+        assert_eq!(Quarter::Q4.succ(), Quarter::Q1);
+        assert_eq!(Quarter::Q1.pred(), Quarter::Q4);
+        assert_eq!(Quarter::Q3.pred(), Quarter::Q2);
+        assert!(Quarter::Q1 < Quarter::Q4);
+
+        let mut month = Month::January;
+        for quarter in [Quarter::Q1, Quarter::Q2, Quarter::Q3, Quarter::Q4] {
+            assert_eq!(quarter.first_month(), month);
+            for m in quarter.months() {
+                assert_eq!(m, month);
+                assert_eq!(m.quarter(), quarter);
+                assert_eq!(quarter.number(), (m.number_from_month() + 2) / 3);
+                month = month.succ();
+            }
+        }
+
+        assert_eq!(Quarter::Q1.num_days(2019), Some(90));
+        assert_eq!(Quarter::Q1.num_days(2020), Some(91));
+        assert_eq!(Quarter::Q4.num_days(2020), Some(92));
+        assert_eq!(Quarter::Q1.num_days(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_year_quarter() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let q = YearQuarter::new(2024, Quarter::Q1).unwrap();
+        assert_eq!((q.year(), q.quarter()), (2024, Quarter::Q1));
+        assert_eq!((q.first_day(), q.last_day()), (ymd(2024, 1, 1), ymd(2024, 3, 31)));
+        let q = YearQuarter::new(2023, Quarter::Q2).unwrap();
+        assert_eq!((q.first_day(), q.last_day()), (ymd(2023, 4, 1), ymd(2023, 6, 30)));
+        let q = YearQuarter::new(-5, Quarter::Q4).unwrap();
+        assert_eq!((q.first_day(), q.last_day()), (ymd(-5, 10, 1), ymd(-5, 12, 31)));
+        assert_eq!(YearQuarter::new(i32::MAX, Quarter::Q1), None);
+
+        let max = YearQuarter::from(NaiveDate::MAX);
+        assert_eq!(max.last_day(), NaiveDate::MAX);
+        assert_eq!(YearQuarter::from(NaiveDate::MIN).first_day(), NaiveDate::MIN);
+        assert_eq!(
+            YearQuarter::from(ymd(2024, 9, 30)),
+            YearQuarter::new(2024, Quarter::Q3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_year_quarter_from_str() {
+        let q = |y, q| YearQuarter::new(y, q).unwrap();
+        assert_eq!("2024-Q3".parse(), Ok(q(2024, Quarter::Q3)));
+        assert_eq!("0000-Q1".parse(), Ok(q(0, Quarter::Q1)));
+        assert_eq!("-0001-Q4".parse(), Ok(q(-1, Quarter::Q4)));
+        assert_eq!("+10000-Q2".parse(), Ok(q(10000, Quarter::Q2)));
+        assert!("2024-Q0".parse::<YearQuarter>().is_err());
+        assert!("2024-Q5".parse::<YearQuarter>().is_err());
+        assert!("2024-3".parse::<YearQuarter>().is_err());
+        assert!("2024-Q3 ".parse::<YearQuarter>().is_err());
+        assert!("+999999-Q1".parse::<YearQuarter>().is_err());
+
+        #[cfg(feature = "alloc")]
+        for s in ["2024-Q3", "0000-Q1", "-0001-Q4", "+10000-Q2"] {
+            assert_eq!(s.parse::<YearQuarter>().unwrap().to_string(), s);
+        }
+    }
 }
@@ -13,6 +13,8 @@
 //! `W`: weekday before the first day of the year
 //! `LWWW`: will also be referred to as the year flags (`F`)
 
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::string::{String, ToString};
 #[cfg(feature = "alloc")]
 use core::borrow::Borrow;
 use core::iter::FusedIterator;
@@ -24,18 +26,25 @@ use core::{fmt, str};
 use rkyv::{Archive, Deserialize, Serialize};
 
 /// L10n locales.
-#[cfg(all(feature = "unstable-locales", feature = "alloc"))]
+#[cfg(feature = "unstable-locales")]
 use pure_rust_locales::Locale;
 
+use crate::calendar_math::DAYS_IN_400_YEAR_CYCLE;
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{
-    Item, Numeric, Pad, ParseError, ParseResult, Parsed, StrftimeItems, parse, parse_and_remainder,
+    INVALID, Item, Numeric, OUT_OF_RANGE, Pad, ParseError, ParseOptions, ParseResult, Parsed,
+    StrftimeItems, TOO_LONG, TOO_SHORT, parse, parse_and_remainder, parse_with_options,
     write_hundreds,
 };
+#[cfg(feature = "unstable-locales")]
+use crate::format::{parse_and_remainder_localized, parse_localized};
 use crate::month::Months;
-use crate::naive::{Days, IsoWeek, NaiveDateTime, NaiveTime, NaiveWeek};
-use crate::{Datelike, TimeDelta, Weekday};
+use crate::naive::{
+    DateFields, Days, Field, FieldError, IsoWeek, NaiveDateTime, NaiveTime, NaiveWeek, Ordinal,
+    TimeFields, WeekOfYear,
+};
+use crate::{CalendarFields, Datelike, FixedOffset, OutOfRange, TimeDelta, Weekday};
 use crate::{expect, try_opt};
 
 use super::internals::{Mdf, YearFlags};
@@ -203,6 +212,84 @@ impl NaiveDate {
         }
     }
 
+    /// Makes a new `NaiveDate` from plain [`DateFields`], reporting which field is invalid.
+    ///
+    /// This is meant for validating user input. See [`from_ymd_opt`](#method.from_ymd_opt) for a
+    /// constructor that returns an `Option`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FieldError`] with:
+    /// - [`Field::Year`] if the year is out of range for `NaiveDate`.
+    /// - [`Field::Month`] if the month is not between 1 and 12.
+    /// - [`Field::Day`] if the day does not exist in that month.
+    ///
+    /// [`Field::Year`]: crate::naive::Field::Year
+    /// [`Field::Month`]: crate::naive::Field::Month
+    /// [`Field::Day`]: crate::naive::Field::Day
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::naive::{DateFields, Field};
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::try_from_fields(DateFields { year: 2015, month: 3, day: 14 });
+    /// assert_eq!(date, Ok(NaiveDate::from_ymd_opt(2015, 3, 14).unwrap()));
+    ///
+    /// let date = NaiveDate::try_from_fields(DateFields { year: 2015, month: 13, day: 14 });
+    /// assert_eq!(date.unwrap_err().field(), Field::Month);
+    /// ```
+    pub const fn try_from_fields(fields: DateFields) -> Result<NaiveDate, FieldError> {
+        let DateFields { year, month, day } = fields;
+        if year < MIN_YEAR || year > MAX_YEAR {
+            return Err(FieldError::new(Field::Year));
+        }
+        if month < 1 || month > 12 {
+            return Err(FieldError::new(Field::Month));
+        }
+        match NaiveDate::from_ymd_opt(year, month, day) {
+            Some(date) => Ok(date),
+            None => Err(FieldError::new(Field::Day)),
+        }
+    }
+
+    /// Asserts that `year`, `month` and `day` form a valid `NaiveDate`.
+    ///
+    /// This is meant for checking dates at compile time in a `const` item, for library authors
+    /// that keep plain date fields in constants. An invalid date is a compile error with a
+    /// message naming the invalid field, instead of a `None` found at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year` is out of range for `NaiveDate`, if `month` is not between 1 and 12, or
+    /// if the day does not exist in that month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// const LAUNCH: (i32, u32, u32) = (2024, 2, 29);
+    /// const _: () = NaiveDate::assert_ymd_valid(LAUNCH.0, LAUNCH.1, LAUNCH.2);
+    /// ```
+    ///
+    /// An invalid date fails to compile:
+    ///
+    /// ```compile_fail
+    /// # use chrono::NaiveDate;
+    /// const _: () = NaiveDate::assert_ymd_valid(2023, 2, 29);
+    /// ```
+    pub const fn assert_ymd_valid(year: i32, month: u32, day: u32) {
+        if let Err(e) = NaiveDate::try_from_fields(DateFields { year, month, day }) {
+            match e.field() {
+                Field::Year => panic!("year is out of range for `NaiveDate`"),
+                Field::Month => panic!("month is not between 1 and 12"),
+                _ => panic!("day does not exist in that month"),
+            }
+        }
+    }
+
     /// Makes a new `NaiveDate` from the [ordinal date](#ordinal-date)
     /// (year and day of the year).
     ///
@@ -247,6 +334,32 @@ impl NaiveDate {
         NaiveDate::from_ordinal_and_flags(year, ordinal, flags)
     }
 
+    /// Makes a new `NaiveDate` from the year and a typed day of the year.
+    ///
+    /// This is the same as [`from_yo_opt`](NaiveDate::from_yo_opt), but the [`Ordinal`] type
+    /// makes sure the day of the year can not be mixed up with another number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The specified ordinal day does not exist (for example day 366 in a non-leap year).
+    /// - The value for `year` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Ordinal};
+    ///
+    /// let day_60 = Ordinal::new(60).unwrap();
+    /// let from_year_ordinal = NaiveDate::from_year_ordinal;
+    /// assert_eq!(from_year_ordinal(2024, day_60), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// assert_eq!(from_year_ordinal(2023, day_60), NaiveDate::from_ymd_opt(2023, 3, 1));
+    /// ```
+    #[must_use]
+    pub const fn from_year_ordinal(year: i32, ordinal: Ordinal) -> Option<NaiveDate> {
+        NaiveDate::from_yo_opt(year, ordinal.get())
+    }
+
     /// Makes a new `NaiveDate` from the [ISO week date](#week-date)
     /// (year, week number and day of the week).
     /// The resulting `NaiveDate` may have a different year from the input year.
@@ -272,6 +385,9 @@ impl NaiveDate {
     /// - The value for `week` is invalid (for example: `0`, `60`).
     /// - If the resulting date is out of range for `NaiveDate`.
     ///
+    /// [`IsoWeekDate::new`](crate::IsoWeekDate::new) can be used instead to tell these cases
+    /// apart.
+    ///
     /// # Example
     ///
     /// ```
@@ -339,6 +455,35 @@ impl NaiveDate {
         NaiveDate::from_ordinal_and_flags(year, ordinal, flags)
     }
 
+    /// Makes a new `NaiveDate` from the [ISO week date](#week-date) with a typed week number.
+    ///
+    /// This is the same as [`from_isoywd_opt`](NaiveDate::from_isoywd_opt), but the
+    /// [`WeekOfYear`] type makes sure the week can not be mixed up with another number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The specified week does not exist in that year (for example 2023 W53).
+    /// - The value for `year` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, WeekOfYear, Weekday};
+    ///
+    /// let week = WeekOfYear::new(10).unwrap();
+    /// let date = NaiveDate::from_iso_year_week(2024, week, Weekday::Wed);
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 6));
+    /// ```
+    #[must_use]
+    pub const fn from_iso_year_week(
+        year: i32,
+        week: WeekOfYear,
+        weekday: Weekday,
+    ) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(year, week.get(), weekday)
+    }
+
     /// Makes a new `NaiveDate` from a day's number in the proleptic Gregorian calendar, with
     /// January 1, 1 being day 1.
     ///
@@ -377,13 +522,167 @@ impl NaiveDate {
     #[must_use]
     pub const fn from_num_days_from_ce_opt(days: i32) -> Option<NaiveDate> {
         let days = try_opt!(days.checked_add(365)); // make December 31, 1 BCE equal to day 0
-        let year_div_400 = days.div_euclid(146_097);
-        let cycle = days.rem_euclid(146_097);
+        let year_div_400 = days.div_euclid(DAYS_IN_400_YEAR_CYCLE);
+        let cycle = days.rem_euclid(DAYS_IN_400_YEAR_CYCLE);
         let (year_mod_400, ordinal) = cycle_to_yo(cycle as u32);
         let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
         NaiveDate::from_ordinal_and_flags(year_div_400 * 400 + year_mod_400 as i32, ordinal, flags)
     }
 
+    /// Makes a new `NaiveDate` from a date in the proleptic Julian calendar.
+    ///
+    /// Like the Gregorian calendar used by `NaiveDate`, years are numbered astronomically: the
+    /// year 1 BCE is year 0, 2 BCE is year -1, and so on. Every year divisible by 4 is a leap year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The specified day does not exist in the Julian calendar (for example 1901-02-29).
+    /// - The value for `month` or `day` is invalid.
+    /// - The date is out of range for `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// // The day the Gregorian calendar was introduced.
+    /// let gregorian = NaiveDate::from_ymd_opt(1582, 10, 15).unwrap();
+    /// assert_eq!(NaiveDate::from_julian_calendar(1582, 10, 5), Some(gregorian));
+    ///
+    /// // A leap day that does not exist in the Gregorian calendar.
+    /// let leap_day = NaiveDate::from_julian_calendar(1900, 2, 29).unwrap();
+    /// assert_eq!(leap_day, NaiveDate::from_ymd_opt(1900, 3, 13).unwrap());
+    ///
+    /// assert_eq!(NaiveDate::from_julian_calendar(1901, 2, 29), None);
+    /// assert_eq!(NaiveDate::from_julian_calendar(300_000, 1, 1), None);
+    /// ```
+    #[must_use]
+    pub const fn from_julian_calendar(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.rem_euclid(4) == 0 => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day < 1 || day > days_in_month {
+            return None;
+        }
+        // Shift the start of the year to March 1, so the leap day is the last day of the year.
+        let (year, month) = match month {
+            1 | 2 => (year as i64 - 1, month as i64 + 9),
+            _ => (year as i64, month as i64 - 3),
+        };
+        let days = year * 365 + year.div_euclid(4) + (153 * month + 2) / 5 + day as i64 - 1;
+        let days = days + JULIAN_MARCH_1_OF_YEAR_0;
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        NaiveDate::from_num_days_from_ce_opt(days as i32)
+    }
+
+    /// Makes a new `NaiveDate` from a Julian Day Number.
+    ///
+    /// The Julian Day Number counts the days since January 1, 4713 BCE in the proleptic Julian
+    /// calendar, which is day 0. It is the integer part of the astronomical Julian Date at noon
+    /// UTC of the given day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_jdn(2_451_545), NaiveDate::from_ymd_opt(2000, 1, 1));
+    /// assert_eq!(NaiveDate::from_jdn(0), NaiveDate::from_julian_calendar(-4712, 1, 1));
+    /// assert_eq!(NaiveDate::from_jdn(i32::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn from_jdn(jdn: i32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(jdn.checked_sub(JDN_OF_DAY_0)))
+    }
+
+    /// Makes a new `NaiveDate` from a Modified Julian Day Number.
+    ///
+    /// The Modified Julian Day counts the days since November 17, 1858, which is day 0. It equals
+    /// the Julian Day Number minus 2,400,001.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_mjd(0), NaiveDate::from_ymd_opt(1858, 11, 17));
+    /// assert_eq!(NaiveDate::from_mjd(51_544), NaiveDate::from_ymd_opt(2000, 1, 1));
+    /// assert_eq!(NaiveDate::from_mjd(i32::MIN), None);
+    /// ```
+    #[must_use]
+    pub const fn from_mjd(mjd: i32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(mjd.checked_add(MJD_OF_DAY_0)))
+    }
+
+    /// Makes a new `NaiveDate` from the number of days since January 1, 1970.
+    ///
+    /// This is how columnar formats such as Apache Arrow and Parquet store dates (`date32`).
+    /// Negative values are dates before 1970.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_days_since_epoch(0), NaiveDate::from_ymd_opt(1970, 1, 1));
+    /// assert_eq!(NaiveDate::from_days_since_epoch(19_850), NaiveDate::from_ymd_opt(2024, 5, 7));
+    /// assert_eq!(NaiveDate::from_days_since_epoch(-1), NaiveDate::from_ymd_opt(1969, 12, 31));
+    /// assert_eq!(NaiveDate::from_days_since_epoch(i32::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn from_days_since_epoch(days: i32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(days.checked_add(UNIX_EPOCH_DAY as i32)))
+    }
+
+    /// Makes a new `NaiveDate` for the UTC date of a timestamp in milliseconds since
+    /// January 1, 1970 0:00:00.000 UTC (aka "UNIX timestamp").
+    ///
+    /// This is the same as `DateTime::from_timestamp_millis(millis)?.date_naive()`, and is useful
+    /// for grouping event times by day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let ymd = NaiveDate::from_ymd_opt;
+    /// assert_eq!(NaiveDate::from_epoch_millis_utc(1_715_083_200_000), ymd(2024, 5, 7));
+    /// assert_eq!(NaiveDate::from_epoch_millis_utc(0), ymd(1970, 1, 1));
+    /// assert_eq!(NaiveDate::from_epoch_millis_utc(-1), ymd(1969, 12, 31));
+    /// assert_eq!(NaiveDate::from_epoch_millis_utc(i64::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn from_epoch_millis_utc(millis: i64) -> Option<NaiveDate> {
+        let days = millis.div_euclid(86_400_000) + UNIX_EPOCH_DAY;
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        NaiveDate::from_num_days_from_ce_opt(days as i32)
+    }
+
     /// Makes a new `NaiveDate` by counting the number of occurrences of a particular day-of-week
     /// since the beginning of the given month. For instance, if you want the 2nd Friday of March
     /// 2017, you would use `NaiveDate::from_weekday_of_month(2017, 3, Weekday::Fri, 2)`.
@@ -497,6 +796,33 @@ impl NaiveDate {
         parsed.to_naive_date()
     }
 
+    /// Parses a string with the specified format string and parsing options into a new
+    /// `NaiveDate` value.
+    ///
+    /// This can be used to parse more leniently, or to limit the size of the input. See
+    /// [`ParseOptions`] for the available options, and [`parse_from_str`](#method.parse_from_str)
+    /// for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::ParseOptions;
+    /// use chrono::NaiveDate;
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let parse = |s| NaiveDate::parse_from_str_with_options(s, "%d %b Y%Y", &options);
+    /// assert_eq!(parse("08 JUL y2015"), Ok(NaiveDate::from_ymd_opt(2015, 7, 8).unwrap()));
+    /// ```
+    pub fn parse_from_str_with_options(
+        s: &str,
+        fmt: &str,
+        options: &ParseOptions,
+    ) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse_with_options(&mut parsed, s, StrftimeItems::new(fmt), options)?;
+        parsed.to_naive_date()
+    }
+
     /// Parses a string from a user-specified format into a new `NaiveDate` value, and a slice with
     /// the remaining portion of the string.
     /// See the [`format::strftime` module](crate::format::strftime)
@@ -519,6 +845,107 @@ impl NaiveDate {
         parsed.to_naive_date().map(|d| (d, remainder))
     }
 
+    /// Parses an iCalendar `DATE` value, such as `20240315`.
+    ///
+    /// The format is defined in [RFC 5545, section 3.3.4], and is the basic ISO 8601 date with
+    /// exactly four digits for the year.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not in exactly this format, or if the date does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    /// assert_eq!(NaiveDate::parse_ical("20240315"), Ok(date));
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(date.to_ical_string(), "20240315");
+    ///
+    /// assert!(NaiveDate::parse_ical("2024-03-15").is_err());
+    /// assert!(NaiveDate::parse_ical("20230229").is_err());
+    /// ```
+    ///
+    /// [RFC 5545, section 3.3.4]: https://www.rfc-editor.org/rfc/rfc5545#section-3.3.4
+    pub fn parse_ical(s: &str) -> ParseResult<NaiveDate> {
+        let bytes = s.as_bytes();
+        match bytes.len() {
+            0..=7 => return Err(TOO_SHORT),
+            8 => {}
+            _ => return Err(TOO_LONG),
+        }
+        if !bytes.iter().all(u8::is_ascii_digit) {
+            return Err(INVALID);
+        }
+        let num = |from: usize, to: usize| {
+            bytes[from..to].iter().fold(0, |n, c| n * 10 + u32::from(c - b'0'))
+        };
+        NaiveDate::from_ymd_opt(num(0, 4) as i32, num(4, 6), num(6, 8)).ok_or(OUT_OF_RANGE)
+    }
+
+    /// Returns the date as an iCalendar `DATE` value, such as `20240315`.
+    ///
+    /// Years before 0 or after 9999 can not be represented in iCalendar, and are written with a
+    /// sign as in [`format`](NaiveDate::format).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_ical_string(&self) -> String {
+        self.format("%Y%m%d").to_string()
+    }
+
+    /// Parses a string with the specified format string and locale into a new `NaiveDate` value,
+    /// and a slice with the remaining portion of the string.
+    ///
+    /// Similar to [`parse_from_str_localized`](#method.parse_from_str_localized).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::NaiveDate;
+    ///
+    /// let parse = NaiveDate::parse_and_remainder_localized;
+    /// let (date, remainder) = parse("11 août 2023, 14h", "%d %B %Y", Locale::fr_FR).unwrap();
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2023, 8, 11).unwrap());
+    /// assert_eq!(remainder, ", 14h");
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_and_remainder_localized<'a>(
+        s: &'a str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<(NaiveDate, &'a str)> {
+        let mut parsed = Parsed::new();
+        let items = StrftimeItems::new_with_locale(fmt, locale);
+        let remainder = parse_and_remainder_localized(&mut parsed, s, items, locale)?;
+        parsed.to_naive_date().map(|d| (d, remainder))
+    }
+
+    /// Parses a string with the specified format string and locale into a new `NaiveDate` value.
+    ///
+    /// Month and weekday names and the localized `%x` format are read in the form of `locale`.
+    /// See [`parse_from_str`](#method.parse_from_str) for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::NaiveDate;
+    ///
+    /// let parse = NaiveDate::parse_from_str_localized;
+    /// let date = NaiveDate::from_ymd_opt(2023, 7, 11).unwrap();
+    /// assert_eq!(parse("2023년 07월 11일", "%x", Locale::ko_KR), Ok(date));
+    /// assert_eq!(parse("11. Juli 2023", "%d. %B %Y", Locale::de_DE), Ok(date));
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(s: &str, fmt: &str, locale: Locale) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_naive_date()
+    }
+
     /// Add a duration in [`Months`] to the date
     ///
     /// Uses the last day of the month if the day does not exist in the resulting month.
@@ -683,7 +1110,7 @@ impl NaiveDate {
         let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
         let cycle = yo_to_cycle(year_mod_400 as u32, self.ordinal());
         let cycle = try_opt!((cycle as i32).checked_add(days));
-        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
+        let (cycle_div_400y, cycle) = div_mod_floor(cycle, DAYS_IN_400_YEAR_CYCLE);
         year_div_400 += cycle_div_400y;
 
         let (year_mod_400, ordinal) = cycle_to_yo(cycle as u32);
@@ -1126,7 +1553,8 @@ impl NaiveDate {
         let (year2_div_400, year2_mod_400) = div_mod_floor(year2, 400);
         let cycle1 = yo_to_cycle(year1_mod_400 as u32, self.ordinal()) as i64;
         let cycle2 = yo_to_cycle(year2_mod_400 as u32, rhs.ordinal()) as i64;
-        let days = (year1_div_400 as i64 - year2_div_400 as i64) * 146_097 + (cycle1 - cycle2);
+        let days = (year1_div_400 as i64 - year2_div_400 as i64) * DAYS_IN_400_YEAR_CYCLE as i64
+            + (cycle1 - cycle2);
         // The range of `TimeDelta` is ca. 585 million years, the range of `NaiveDate` ca. 525.000
         // years.
         expect(TimeDelta::try_days(days), "always in range")
@@ -1331,6 +1759,32 @@ impl NaiveDate {
         NaiveWeek::new(*self, start)
     }
 
+    /// Returns the [`NaiveWeek`] that the date belongs to, starting with the first day of the week
+    /// in `locale`.
+    ///
+    /// Weeks start on Monday in most of Europe, on Sunday in the United States, and on Saturday in
+    /// some Middle Eastern locales. Locales that don't specify it start the week on Sunday.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::Locale;
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 8).unwrap();
+    /// let week = date.week_for_locale(Locale::de_DE);
+    /// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2024, 5, 6).unwrap());
+    /// assert_eq!(week.first_day().weekday(), Weekday::Mon);
+    /// let week = date.week_for_locale(Locale::en_US);
+    /// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2024, 5, 5).unwrap());
+    /// assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2024, 5, 11).unwrap());
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    #[inline]
+    pub fn week_for_locale(&self, locale: Locale) -> NaiveWeek {
+        NaiveWeek::new(*self, crate::format::locales::first_weekday(locale))
+    }
+
     /// Returns `true` if this is a leap year.
     ///
     /// ```
@@ -1346,6 +1800,102 @@ impl NaiveDate {
         self.yof() & (0b1000) == 0
     }
 
+    /// Returns the day of the year as a typed [`Ordinal`].
+    ///
+    /// This is the same as [`Datelike::ordinal`], but the `Ordinal` type makes sure the day of
+    /// the year can not be mixed up with another number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// assert_eq!(date.day_of_year().get(), 366);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn day_of_year(&self) -> Ordinal {
+        expect(Ordinal::new(self.ordinal()), "ordinal is always in range")
+    }
+
+    /// Returns the year, month and day of this date in the proleptic Julian calendar.
+    ///
+    /// See [`NaiveDate::from_julian_calendar`] for how years are numbered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    /// assert_eq!(date.to_julian_calendar(), (1999, 12, 19));
+    /// ```
+    #[must_use]
+    pub const fn to_julian_calendar(&self) -> (i32, u32, u32) {
+        // Days since March 1 of the year 0 in the Julian calendar, see `from_julian_calendar`.
+        let days = self.num_days_from_ce() as i64 - JULIAN_MARCH_1_OF_YEAR_0;
+        let year = (4 * days + 3).div_euclid(1461);
+        let day_of_year = days - (365 * year + year.div_euclid(4));
+        let month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month + 2) / 5 + 1) as u32;
+        match month {
+            10 | 11 => (year as i32 + 1, month as u32 - 9, day),
+            _ => (year as i32, month as u32 + 3, day),
+        }
+    }
+
+    /// Returns the Julian Day Number of this date.
+    ///
+    /// See [`NaiveDate::from_jdn`] for its definition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().to_jdn(), 2_451_545);
+    /// ```
+    #[must_use]
+    pub const fn to_jdn(&self) -> i32 {
+        // The range of `NaiveDate` is small enough for this not to overflow.
+        self.num_days_from_ce() + JDN_OF_DAY_0
+    }
+
+    /// Returns the Modified Julian Day Number of this date.
+    ///
+    /// See [`NaiveDate::from_mjd`] for its definition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().to_mjd(), 51_544);
+    /// ```
+    #[must_use]
+    pub const fn to_mjd(&self) -> i32 {
+        self.num_days_from_ce() - MJD_OF_DAY_0
+    }
+
+    /// Returns the number of days since January 1, 1970.
+    ///
+    /// See [`NaiveDate::from_days_since_epoch`] for its definition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().days_since_epoch(), 19_850);
+    /// assert_eq!(NaiveDate::from_ymd_opt(1969, 12, 31).unwrap().days_since_epoch(), -1);
+    /// ```
+    #[must_use]
+    pub const fn days_since_epoch(&self) -> i32 {
+        // The range of `NaiveDate` is small enough for this not to overflow.
+        self.num_days_from_ce() - UNIX_EPOCH_DAY as i32
+    }
+
     // This duplicates `Datelike::year()`, because trait methods can't be const yet.
     #[inline]
     const fn year(&self) -> i32 {
@@ -1400,7 +1950,7 @@ impl NaiveDate {
         if year < 0 {
             let excess = 1 + (-year) / 400;
             year += excess * 400;
-            ndays -= excess * 146_097;
+            ndays -= excess * DAYS_IN_400_YEAR_CYCLE;
         }
         let div_100 = year / 100;
         ndays += ((year * 1461) >> 2) - div_100 + (div_100 >> 2);
@@ -1439,6 +1989,43 @@ impl NaiveDate {
     /// One day after the maximum possible `NaiveDate` (January 1, 262143 CE).
     pub(crate) const AFTER_MAX: NaiveDate =
         NaiveDate::from_yof(((MAX_YEAR + 1) << 13) | (1 << 4) | 0o17 /* F */);
+
+    /// Returns a hash of the date that is the same on every platform and in every version of
+    /// chrono.
+    ///
+    /// The output of the [`Hash`](core::hash::Hash) implementation depends on the `Hasher` and on
+    /// the internal representation, which may change between versions. This hash does not, so it
+    /// can be used for consistent hashing or partitioning across processes.
+    ///
+    /// It is the 64-bit FNV-1a hash of the number of days since January 1 of year 1 (see
+    /// [`Datelike::num_days_from_ce`]) as a little-endian `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+    /// assert_eq!(date.stable_hash(), 0x46a1_e104_066d_7351);
+    /// ```
+    #[must_use]
+    pub const fn stable_hash(&self) -> u64 {
+        crate::stable_hash(&[self.num_days_from_ce() as i64])
+    }
+}
+
+impl CalendarFields for NaiveDate {
+    fn date_fields(&self) -> Option<DateFields> {
+        Some(DateFields::from(*self))
+    }
+
+    fn time_fields(&self) -> Option<TimeFields> {
+        None
+    }
+
+    fn utc_offset(&self) -> Option<FixedOffset> {
+        None
+    }
 }
 
 impl Datelike for NaiveDate {
@@ -2126,6 +2713,25 @@ impl From<NaiveDateTime> for NaiveDate {
     }
 }
 
+impl TryFrom<(i32, u32, u32)> for NaiveDate {
+    type Error = OutOfRange;
+
+    /// Makes a `NaiveDate` from a `(year, month, day)` tuple, see [`NaiveDate::from_ymd_opt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::try_from((2024, 2, 29));
+    /// assert_eq!(date, Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+    /// assert!(NaiveDate::try_from((2023, 2, 29)).is_err());
+    /// ```
+    fn try_from((year, month, day): (i32, u32, u32)) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(year, month, day).ok_or(OutOfRange::new())
+    }
+}
+
 /// Iterator over `NaiveDate` with a step size of one day.
 #[derive(Debug, Copy, Clone, Hash, PartialEq, PartialOrd, Eq, Ord)]
 pub struct NaiveDateDaysIterator {
@@ -2147,6 +2753,20 @@ impl Iterator for NaiveDateDaysIterator {
         let exact_size = NaiveDate::MAX.signed_duration_since(self.value).num_days();
         (exact_size as usize, Some(exact_size as usize))
     }
+
+    // Jumps directly to the `n`th day instead of stepping through all of them.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match i32::try_from(n).ok().and_then(|n| self.value.add_days(n)) {
+            Some(date) if date < NaiveDate::MAX => {
+                self.value = date.add_days(1)?;
+                Some(date)
+            }
+            _ => {
+                self.value = NaiveDate::MAX;
+                None
+            }
+        }
+    }
 }
 
 impl ExactSizeIterator for NaiveDateDaysIterator {}
@@ -2158,6 +2778,19 @@ impl DoubleEndedIterator for NaiveDateDaysIterator {
         self.value = current.pred_opt()?;
         Some(current)
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match i32::try_from(n).ok().and_then(|n| self.value.add_days(-n)) {
+            Some(date) if date > NaiveDate::MIN => {
+                self.value = date.add_days(-1)?;
+                Some(date)
+            }
+            _ => {
+                self.value = NaiveDate::MIN;
+                None
+            }
+        }
+    }
 }
 
 impl FusedIterator for NaiveDateDaysIterator {}
@@ -2181,6 +2814,19 @@ impl Iterator for NaiveDateWeeksIterator {
         let exact_size = NaiveDate::MAX.signed_duration_since(self.value).num_weeks();
         (exact_size as usize, Some(exact_size as usize))
     }
+
+    // Jumps directly to the `n`th week instead of stepping through all of them.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let remaining = self.size_hint().0;
+        if n >= remaining {
+            // Stop at the last date `next` would have reached.
+            self.value = self.value.add_days(remaining as i32 * 7)?;
+            return None;
+        }
+        let date = self.value.add_days(n as i32 * 7)?;
+        self.value = date.add_days(7)?;
+        Some(date)
+    }
 }
 
 impl ExactSizeIterator for NaiveDateWeeksIterator {}
@@ -2191,6 +2837,18 @@ impl DoubleEndedIterator for NaiveDateWeeksIterator {
         self.value = current.checked_sub_days(Days::new(7))?;
         Some(current)
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let remaining = self.value.signed_duration_since(NaiveDate::MIN).num_weeks() as usize;
+        if n >= remaining {
+            // Stop at the last date `next_back` would have reached.
+            self.value = self.value.add_days(-(remaining as i32) * 7)?;
+            return None;
+        }
+        let date = self.value.add_days(-(n as i32) * 7)?;
+        self.value = date.add_days(-7)?;
+        Some(date)
+    }
 }
 
 impl FusedIterator for NaiveDateWeeksIterator {}
@@ -2319,7 +2977,7 @@ impl Default for NaiveDate {
     }
 }
 
-const fn cycle_to_yo(cycle: u32) -> (u32, u32) {
+pub(crate) const fn cycle_to_yo(cycle: u32) -> (u32, u32) {
     let mut year_mod_400 = cycle / 365;
     let mut ordinal0 = cycle % 365;
     let delta = YEAR_DELTAS[year_mod_400 as usize] as u32;
@@ -2332,7 +2990,7 @@ const fn cycle_to_yo(cycle: u32) -> (u32, u32) {
     (year_mod_400, ordinal0 + 1)
 }
 
-const fn yo_to_cycle(year_mod_400: u32, ordinal: u32) -> u32 {
+pub(crate) const fn yo_to_cycle(year_mod_400: u32, ordinal: u32) -> u32 {
     year_mod_400 * 365 + YEAR_DELTAS[year_mod_400 as usize] as u32 + ordinal - 1
 }
 
@@ -2350,6 +3008,26 @@ pub(super) const MAX_YEAR: i32 = (i32::MAX >> 13) - 1;
 /// `NaiveDate::MIN` pushes it beyond the valid, representable range.
 pub(super) const MIN_YEAR: i32 = (i32::MIN >> 13) + 1;
 
+// The day number (as in `num_days_from_ce`) of March 1, 0000 in the proleptic Julian calendar,
+// which is February 28, 0000 in the proleptic Gregorian calendar.
+const JULIAN_MARCH_1_OF_YEAR_0: i64 = -307;
+
+// The Julian Day Number of December 31, 1 BCE, day 0 of `num_days_from_ce`.
+const JDN_OF_DAY_0: i32 = 1_721_425;
+
+// Day 0 of `num_days_from_ce` as a Modified Julian Day Number, negated.
+const MJD_OF_DAY_0: i32 = 678_576;
+
+/// Number of days between Januari 1, 1970 and December 31, 1 BCE which we define to be day 0.
+/// 4 full leap year cycles until December 31, 1600     4 * 146097 = 584388
+/// 1 day until January 1, 1601                                           1
+/// 369 years until Januari 1, 1970                      369 * 365 = 134685
+/// of which floor(369 / 4) are leap years          floor(369 / 4) =     92
+/// except for 1700, 1800 and 1900                                       -3 +
+///                                                                  --------
+///                                                                  719163
+pub(crate) const UNIX_EPOCH_DAY: i64 = 719_163;
+
 const ORDINAL_MASK: i32 = 0b1_1111_1111_0000;
 
 const LEAP_YEAR_MASK: i32 = 0b1000;
@@ -2390,7 +3068,7 @@ const YEAR_DELTAS: &[u8; 401] = &[
 ];
 
 #[cfg(feature = "serde")]
-mod serde {
+pub(super) mod serde {
     use super::NaiveDate;
     use core::fmt;
     use serde::{de, ser};
@@ -2442,6 +3120,88 @@ mod serde {
         }
     }
 
+    /// Ser/de a `NaiveDate` to/from the number of days since January 1, 1970.
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::naive::serde::ts_days;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "ts_days")]
+    ///     date: NaiveDate,
+    /// }
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+    /// let my_s = S { date };
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"date":19850}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.date, date);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod ts_days {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use super::super::UNIX_EPOCH_DAY;
+        use crate::NaiveDate;
+        use crate::serde::invalid_ts;
+
+        /// Serialize a `NaiveDate` into the number of days since January 1, 1970.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(date.num_days_from_ce() as i64 - UNIX_EPOCH_DAY)
+        }
+
+        /// Deserialize a `NaiveDate` from the number of days since January 1, 1970.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveDate, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(DaysVisitor)
+        }
+
+        struct DaysVisitor;
+
+        impl de::Visitor<'_> for DaysVisitor {
+            type Value = NaiveDate;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a number of days since the unix epoch")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                value
+                    .checked_add(UNIX_EPOCH_DAY)
+                    .and_then(|days| i32::try_from(days).ok())
+                    .and_then(NaiveDate::from_num_days_from_ce_opt)
+                    .ok_or_else(|| invalid_ts(value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value).map_err(|_| invalid_ts(value)).and_then(|v| self.visit_i64(v))
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use crate::NaiveDate;
@@ -2530,5 +3290,35 @@ mod serde {
             let decoded: NaiveDate = deserialize(&encoded).unwrap();
             assert_eq!(d, decoded);
         }
+
+        #[test]
+        fn test_serde_ts_days() {
+            #[derive(Debug, PartialEq, serde_derive::Deserialize, serde_derive::Serialize)]
+            struct S {
+                #[serde(with = "super::ts_days")]
+                date: NaiveDate,
+            }
+
+            let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            for (date, days) in [
+                (ymd(1970, 1, 1), 0),
+                (ymd(1969, 12, 31), -1),
+                (ymd(2000, 3, 1), 11_017),
+                (NaiveDate::MIN, -96_465_292),
+                (NaiveDate::MAX, 95_026_236),
+            ] {
+                let json = format!(r#"{{"date":{}}}"#, days);
+                assert_eq!(serde_json::to_string(&S { date }).unwrap(), json);
+                assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { date });
+
+                let encoded = bincode::serialize(&S { date }).unwrap();
+                assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), S { date });
+            }
+
+            assert!(serde_json::from_str::<S>(r#"{"date":-96465293}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":95026237}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":18446744073709551615}"#).is_err());
+            assert!(serde_json::from_str::<S>(r#"{"date":"2024-05-07"}"#).is_err());
+        }
     }
 }
@@ -165,6 +165,18 @@ fn test_date_from_ymd() {
     assert!(from_ymd(2014, 13, 1).is_none());
 }
 
+#[test]
+fn test_date_try_from_tuple() {
+    assert_eq!(
+        NaiveDate::try_from((2012, 2, 29)),
+        Ok(NaiveDate::from_ymd_opt(2012, 2, 29).unwrap())
+    );
+    assert_eq!(NaiveDate::try_from((-400, 1, 1)), Ok(NaiveDate::from_ymd_opt(-400, 1, 1).unwrap()));
+    assert!(NaiveDate::try_from((2014, 2, 29)).is_err());
+    assert!(NaiveDate::try_from((2014, 13, 1)).is_err());
+    assert!(NaiveDate::try_from((MAX_YEAR + 1, 1, 1)).is_err());
+}
+
 #[test]
 fn test_date_from_yo() {
     let from_yo = NaiveDate::from_yo_opt;
@@ -302,6 +314,109 @@ fn test_date_from_num_days_from_ce() {
     assert_eq!(from_ndays_from_ce(i32::MAX), None);
 }
 
+#[test]
+fn test_date_julian_calendar() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let from_julian = NaiveDate::from_julian_calendar;
+    assert_eq!(from_julian(1582, 10, 5), Some(ymd(1582, 10, 15)));
+    assert_eq!(from_julian(1582, 10, 4), Some(ymd(1582, 10, 14)));
+    assert_eq!(from_julian(1900, 2, 29), Some(ymd(1900, 3, 13)));
+    assert_eq!(from_julian(2100, 2, 29), Some(ymd(2100, 3, 14)));
+    assert_eq!(from_julian(200, 3, 1), Some(ymd(200, 3, 1)));
+    assert_eq!(from_julian(0, 3, 1), Some(ymd(0, 2, 28)));
+    assert_eq!(from_julian(-4712, 1, 1), Some(ymd(-4713, 11, 24)));
+    assert_eq!(from_julian(-1, 2, 29), None);
+    assert_eq!(from_julian(-4, 2, 29), Some(ymd(-4, 2, 27)));
+    assert_eq!(from_julian(2024, 0, 1), None);
+    assert_eq!(from_julian(2024, 13, 1), None);
+    assert_eq!(from_julian(2024, 4, 31), None);
+    assert_eq!(from_julian(2024, 4, 0), None);
+    assert_eq!(from_julian(i32::MAX, 12, 31), None);
+    assert_eq!(from_julian(i32::MIN, 1, 1), None);
+
+    for date in [NaiveDate::MIN, NaiveDate::MAX] {
+        let (y, m, d) = date.to_julian_calendar();
+        assert_eq!(from_julian(y, m, d), Some(date));
+    }
+    let (y, m, d) = NaiveDate::MIN.to_julian_calendar();
+    assert_eq!(NaiveDate::from_julian_calendar(y, m, d - 1), None);
+    let (y, m, d) = NaiveDate::MAX.to_julian_calendar();
+    assert_eq!(NaiveDate::from_julian_calendar(y, m, d + 1), None);
+
+    let mut date = ymd(-1000, 1, 1);
+    let mut julian = date.to_julian_calendar();
+    while date.year() < 3000 {
+        assert_eq!(from_julian(julian.0, julian.1, julian.2), Some(date));
+        date = date.succ_opt().unwrap();
+        let next = match from_julian(julian.0, julian.1, julian.2 + 1) {
+            Some(_) => (julian.0, julian.1, julian.2 + 1),
+            None if julian.1 == 12 => (julian.0 + 1, 1, 1),
+            None => (julian.0, julian.1 + 1, 1),
+        };
+        assert_eq!(date.to_julian_calendar(), next);
+        julian = next;
+    }
+}
+
+#[test]
+fn test_date_jdn_mjd() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(ymd(2000, 1, 1).to_jdn(), 2_451_545);
+    assert_eq!(ymd(1970, 1, 1).to_jdn(), 2_440_588);
+    assert_eq!(ymd(1858, 11, 17).to_jdn(), 2_400_001);
+    assert_eq!(ymd(1858, 11, 17).to_mjd(), 0);
+    assert_eq!(ymd(1970, 1, 1).to_mjd(), 40_587);
+    assert_eq!(NaiveDate::from_jdn(1_721_426), Some(ymd(1, 1, 1)));
+    assert_eq!(NaiveDate::from_mjd(-1), Some(ymd(1858, 11, 16)));
+
+    for date in [NaiveDate::MIN, NaiveDate::MAX, ymd(-4713, 11, 24)] {
+        assert_eq!(NaiveDate::from_jdn(date.to_jdn()), Some(date));
+        assert_eq!(NaiveDate::from_mjd(date.to_mjd()), Some(date));
+        assert_eq!(date.to_jdn() - date.to_mjd(), 2_400_001);
+    }
+    assert_eq!(NaiveDate::from_jdn(NaiveDate::MIN.to_jdn() - 1), None);
+    assert_eq!(NaiveDate::from_jdn(NaiveDate::MAX.to_jdn() + 1), None);
+    assert_eq!(NaiveDate::from_mjd(NaiveDate::MAX.to_mjd() + 1), None);
+    assert_eq!(NaiveDate::from_jdn(i32::MIN), None);
+    assert_eq!(NaiveDate::from_mjd(i32::MAX), None);
+}
+
+#[test]
+fn test_date_days_since_epoch() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(ymd(1970, 1, 1).days_since_epoch(), 0);
+    assert_eq!(ymd(1970, 1, 2).days_since_epoch(), 1);
+    assert_eq!(ymd(2000, 1, 1).days_since_epoch(), 10_957);
+    assert_eq!(ymd(1, 1, 1).days_since_epoch(), -719_162);
+    assert_eq!(NaiveDate::from_days_since_epoch(-719_162), Some(ymd(1, 1, 1)));
+
+    for date in [NaiveDate::MIN, NaiveDate::MAX, ymd(1969, 12, 31), ymd(2024, 2, 29)] {
+        let days = date.days_since_epoch();
+        assert_eq!(NaiveDate::from_days_since_epoch(days), Some(date));
+        assert_eq!(NaiveDate::from_epoch_millis_utc(days as i64 * 86_400_000), Some(date));
+    }
+    assert_eq!(NaiveDate::from_days_since_epoch(NaiveDate::MIN.days_since_epoch() - 1), None);
+    assert_eq!(NaiveDate::from_days_since_epoch(NaiveDate::MAX.days_since_epoch() + 1), None);
+    assert_eq!(NaiveDate::from_days_since_epoch(i32::MIN), None);
+}
+
+#[test]
+fn test_date_from_epoch_millis_utc() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(NaiveDate::from_epoch_millis_utc(86_399_999), Some(ymd(1970, 1, 1)));
+    assert_eq!(NaiveDate::from_epoch_millis_utc(86_400_000), Some(ymd(1970, 1, 2)));
+    assert_eq!(NaiveDate::from_epoch_millis_utc(-86_400_000), Some(ymd(1969, 12, 31)));
+    assert_eq!(NaiveDate::from_epoch_millis_utc(-86_400_001), Some(ymd(1969, 12, 30)));
+    assert_eq!(NaiveDate::from_epoch_millis_utc(i64::MIN), None);
+
+    for dt in [crate::DateTime::<crate::Utc>::MIN_UTC, crate::DateTime::<crate::Utc>::MAX_UTC] {
+        let millis = dt.timestamp_millis();
+        assert_eq!(NaiveDate::from_epoch_millis_utc(millis), Some(dt.date_naive()));
+    }
+    let max = crate::DateTime::<crate::Utc>::MAX_UTC.timestamp_millis();
+    assert_eq!(NaiveDate::from_epoch_millis_utc(max + 86_400_000), None);
+}
+
 #[test]
 fn test_date_from_weekday_of_month_opt() {
     let ymwd = NaiveDate::from_weekday_of_month_opt;
@@ -359,6 +474,31 @@ fn test_date_fields() {
     check(2014, 12, 31, 365);
 }
 
+#[test]
+fn test_date_assert_ymd_valid() {
+    const _: () = NaiveDate::assert_ymd_valid(2012, 2, 29);
+    const _: () = NaiveDate::assert_ymd_valid(-4, 2, 29);
+    NaiveDate::assert_ymd_valid(MAX_YEAR, 12, 31);
+}
+
+#[test]
+#[should_panic(expected = "year is out of range")]
+fn test_date_assert_ymd_valid_year() {
+    NaiveDate::assert_ymd_valid(MAX_YEAR + 1, 1, 1);
+}
+
+#[test]
+#[should_panic(expected = "month is not between 1 and 12")]
+fn test_date_assert_ymd_valid_month() {
+    NaiveDate::assert_ymd_valid(2024, 13, 1);
+}
+
+#[test]
+#[should_panic(expected = "day does not exist")]
+fn test_date_assert_ymd_valid_day() {
+    NaiveDate::assert_ymd_valid(2023, 2, 29);
+}
+
 #[test]
 fn test_date_weekday() {
     assert_eq!(NaiveDate::from_ymd_opt(1582, 10, 15).unwrap().weekday(), Weekday::Fri);
@@ -705,6 +845,73 @@ fn test_week_iterator_limit() {
     );
 }
 
+#[test]
+fn test_iterator_nth() {
+    // `nth` and `nth_back` must end up in the same state as calling `next` repeatedly.
+    fn check<I: DoubleEndedIterator<Item = NaiveDate> + Clone + PartialEq + core::fmt::Debug>(
+        iter: I,
+    ) {
+        for n in [0, 1, 2, 3, 10] {
+            let (mut fast, mut slow) = (iter.clone(), iter.clone());
+            for _ in 0..n {
+                slow.next();
+            }
+            assert_eq!(fast.nth(n), slow.next());
+            assert_eq!(fast, slow);
+            let (mut fast, mut slow) = (iter.clone(), iter.clone());
+            for _ in 0..n {
+                slow.next_back();
+            }
+            assert_eq!(fast.nth_back(n), slow.next_back());
+            assert_eq!(fast, slow);
+        }
+    }
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    for date in [ymd(2024, 2, 27), ymd(MAX_YEAR, 12, 27), ymd(MIN_YEAR, 1, 3), NaiveDate::MAX] {
+        check(date.iter_days());
+        check(date.iter_weeks());
+    }
+    check(NaiveDate::MIN.iter_days());
+    check(NaiveDate::MIN.iter_weeks());
+
+    let date = ymd(2000, 1, 1);
+    assert_eq!(date.iter_days().nth(100_000), Some(ymd(2273, 10, 16)));
+    assert_eq!(date.iter_days().nth_back(100_000), Some(ymd(1726, 3, 18)));
+    assert_eq!(date.iter_weeks().nth(52), Some(ymd(2000, 12, 30)));
+    assert_eq!(date.iter_weeks().nth_back(52), Some(ymd(1999, 1, 2)));
+
+    let mut iter = date.iter_days();
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+    let mut iter = date.iter_weeks();
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[cfg(feature = "unstable-locales")]
+fn test_week_for_locale() {
+    use crate::format::Locale;
+
+    // A Wednesday.
+    let date = NaiveDate::from_ymd_opt(2024, 5, 8).unwrap();
+    let cases = [
+        (Locale::de_DE, Weekday::Mon),
+        (Locale::fr_FR, Weekday::Mon),
+        (Locale::en_GB, Weekday::Mon),
+        (Locale::en_US, Weekday::Sun),
+        (Locale::ja_JP, Weekday::Sun),
+        (Locale::ar_EG, Weekday::Sat),
+        (Locale::POSIX, Weekday::Sun),
+    ];
+    for (locale, start) in cases {
+        let week = date.week_for_locale(locale);
+        assert_eq!(week, date.week(start), "{}", locale);
+        assert_eq!(week.first_day().weekday(), start, "{}", locale);
+        assert!(week.days().contains(&date));
+    }
+}
+
 #[test]
 fn test_weeks_from() {
     // tests per: https://github.com/chronotope/chrono/issues/961
@@ -864,6 +1071,24 @@ fn test_rkyv_validation() {
     assert_eq!(rkyv::from_bytes::<NaiveDate>(&bytes).unwrap(), date_max);
 }
 
+#[test]
+fn test_parse_ical() {
+    use crate::format::ParseErrorKind;
+
+    let parse = |s| NaiveDate::parse_ical(s).map_err(|e| e.kind());
+    assert_eq!(parse("20240315"), Ok(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()));
+    assert_eq!(parse("00000101"), Ok(NaiveDate::from_ymd_opt(0, 1, 1).unwrap()));
+    assert_eq!(parse("20240229"), Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+
+    assert_eq!(parse("2024031"), Err(ParseErrorKind::TooShort));
+    assert_eq!(parse("202403150"), Err(ParseErrorKind::TooLong));
+    assert_eq!(parse("2024-315"), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("+0240315"), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("20230229"), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("20241301"), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("00000000"), Err(ParseErrorKind::OutOfRange));
+}
+
 //   MAX_YEAR-12-31 minus 0000-01-01
 // = (MAX_YEAR-12-31 minus 0000-12-31) + (0000-12-31 - 0000-01-01)
 // = MAX_YEAR * 365 + (# of leap years from 0001 to MAX_YEAR) + 365
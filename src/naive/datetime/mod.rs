@@ -3,6 +3,8 @@
 
 //! ISO 8601 date and time without timezone.
 
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::string::String;
 #[cfg(feature = "alloc")]
 use core::borrow::Borrow;
 use core::fmt::Write;
@@ -14,15 +16,19 @@ use core::{fmt, str};
 use rkyv::{Archive, Deserialize, Serialize};
 
 #[cfg(feature = "alloc")]
-use crate::format::DelayedFormat;
+use crate::format::{DelayedFormat, Iso8601BasicOptions, write_iso8601_basic};
 use crate::format::{Fixed, Item, Numeric, Pad};
-use crate::format::{ParseError, ParseResult, Parsed, StrftimeItems, parse, parse_and_remainder};
-use crate::naive::{Days, IsoWeek, NaiveDate, NaiveTime};
+use crate::format::{INVALID, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
+#[cfg(feature = "unstable-locales")]
+use crate::format::{Locale, parse_and_remainder_localized, parse_localized};
+use crate::format::{ParseError, ParseOptions, ParseResult, Parsed, StrftimeItems};
+use crate::format::{parse, parse_and_remainder, parse_iso8601_basic, parse_with_options};
+use crate::naive::{DateFields, Days, IsoWeek, NaiveDate, NaiveTime, TimeFields};
 use crate::offset::Utc;
 use crate::time_delta::NANOS_PER_SEC;
 use crate::{
-    DateTime, Datelike, FixedOffset, MappedLocalTime, Months, TimeDelta, TimeZone, Timelike,
-    Weekday, expect, try_opt,
+    CalendarFields, DateTime, Datelike, FixedOffset, MappedLocalTime, Months, OutOfRange,
+    TimeDelta, TimeZone, Timelike, Weekday, expect, try_opt,
 };
 
 /// Tools to help serializing/deserializing `NaiveDateTime`s
@@ -64,6 +70,9 @@ pub const MAX_DATETIME: NaiveDateTime = NaiveDateTime::MAX;
 /// assert_eq!(dt.weekday(), Weekday::Fri);
 /// assert_eq!(dt.num_seconds_from_midnight(), 33011);
 /// ```
+///
+/// An `Option<NaiveDateTime>` takes no more memory than a `NaiveDateTime`, and the same holds for
+/// `Option<DateTime<Utc>>`.
 #[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
 #[cfg_attr(
     any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
@@ -288,6 +297,198 @@ impl NaiveDateTime {
         parsed.to_naive_datetime_with_offset(0) // no offset adjustment
     }
 
+    /// Parses a string with the specified format string and parsing options into a new
+    /// `NaiveDateTime` value.
+    ///
+    /// This can be used to parse more leniently, or to limit the size of the input. See
+    /// [`ParseOptions`] for the available options, and [`parse_from_str`](#method.parse_from_str)
+    /// for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::ParseOptions;
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let parse = |s| NaiveDateTime::parse_from_str_with_options(s, "%Y-%m-%dT%H:%M", &options);
+    /// let dt = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 0).unwrap();
+    /// assert_eq!(parse("2015-09-05t23:56"), Ok(dt));
+    /// ```
+    pub fn parse_from_str_with_options(
+        s: &str,
+        fmt: &str,
+        options: &ParseOptions,
+    ) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse_with_options(&mut parsed, s, StrftimeItems::new(fmt), options)?;
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+
+    /// Parses an EXIF date and time, such as the `DateTimeOriginal` tag of a photo.
+    ///
+    /// EXIF writes a date and time as `YYYY:MM:DD HH:MM:SS`, with colons in the date and without
+    /// an offset. Trailing NUL characters, which terminate EXIF strings, are ignored. See
+    /// [`DateTime::parse_exif`] to combine this with an `OffsetTime` tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not in exactly this format, including the blank or zeroed
+    /// values that EXIF uses for an unknown date and time, or if the date and time do not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2023, 9, 8).unwrap().and_hms_opt(7, 3, 25).unwrap();
+    /// assert_eq!(NaiveDateTime::parse_exif("2023:09:08 07:03:25"), Ok(dt));
+    /// assert_eq!(NaiveDateTime::parse_exif("2023:09:08 07:03:25\0"), Ok(dt));
+    /// assert!(NaiveDateTime::parse_exif("2023-09-08 07:03:25").is_err());
+    /// assert!(NaiveDateTime::parse_exif("0000:00:00 00:00:00").is_err());
+    /// assert!(NaiveDateTime::parse_exif("    :  :     :  :  ").is_err());
+    /// ```
+    pub fn parse_exif(s: &str) -> ParseResult<NaiveDateTime> {
+        let bytes = s.trim_end_matches('\0').as_bytes();
+        match bytes.len() {
+            0..=18 => return Err(TOO_SHORT),
+            19 => {}
+            _ => return Err(TOO_LONG),
+        }
+        for (i, &c) in bytes.iter().enumerate() {
+            let valid = match i {
+                4 | 7 | 13 | 16 => c == b':',
+                10 => c == b' ',
+                _ => c.is_ascii_digit(),
+            };
+            if !valid {
+                return Err(INVALID);
+            }
+        }
+        let num = |from: usize, to: usize| {
+            bytes[from..to].iter().fold(0, |n, c| n * 10 + u32::from(c - b'0'))
+        };
+        NaiveDate::from_ymd_opt(num(0, 4) as i32, num(5, 7), num(8, 10))
+            .and_then(|d| d.and_hms_opt(num(11, 13), num(14, 16), num(17, 19)))
+            .ok_or(OUT_OF_RANGE)
+    }
+
+    /// Returns the date and time in the EXIF format, such as `2023:09:08 07:03:25`.
+    ///
+    /// This is the format of the `DateTime`, `DateTimeOriginal` and `DateTimeDigitized` tags.
+    /// Years before 0 or after 9999 can not be represented in EXIF, and are written with a sign
+    /// as in [`format`](NaiveDateTime::format).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 9, 8).unwrap();
+    /// let dt = date.and_hms_milli_opt(7, 3, 25, 500).unwrap();
+    /// assert_eq!(dt.to_exif_string(), "2023:09:08 07:03:25");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_exif_string(&self) -> String {
+        let mut result = String::with_capacity(19);
+        write!(result, "{}", self.format("%Y:%m:%d %H:%M:%S"))
+            .expect("writing an EXIF datetime to a string should never fail");
+        result
+    }
+
+    /// Parses an iCalendar `DATE-TIME` value in the floating form, such as `20240315T093000`.
+    ///
+    /// The format is defined in [RFC 5545, section 3.3.5]. A floating time is not bound to any
+    /// time zone. Values in UTC end with a `Z`, and are parsed with [`DateTime::parse_ical`];
+    /// values that reference a `TZID` parameter are parsed with [`DateTime::parse_ical_in`].
+    /// A second of `60` is a leap second.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not in exactly this format, including values with a `Z` suffix,
+    /// or if the date and time do not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    /// assert_eq!(NaiveDateTime::parse_ical("20240315T093000"), Ok(dt));
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(dt.to_ical_string(), "20240315T093000");
+    ///
+    /// assert!(NaiveDateTime::parse_ical("20240315T093000Z").is_err());
+    /// assert!(NaiveDateTime::parse_ical("20240315").is_err());
+    /// ```
+    ///
+    /// [RFC 5545, section 3.3.5]: https://www.rfc-editor.org/rfc/rfc5545#section-3.3.5
+    pub fn parse_ical(s: &str) -> ParseResult<NaiveDateTime> {
+        let bytes = s.as_bytes();
+        match bytes.len() {
+            0..=14 => return Err(TOO_SHORT),
+            15 => {}
+            _ => return Err(TOO_LONG),
+        }
+        let date = NaiveDate::parse_ical(s.get(..8).ok_or(INVALID)?);
+        if bytes[8] != b'T' || !bytes[9..].iter().all(u8::is_ascii_digit) {
+            return Err(INVALID);
+        }
+        let date = date?;
+        let num =
+            |from: usize| u32::from(bytes[from] - b'0') * 10 + u32::from(bytes[from + 1] - b'0');
+        let time = match num(13) {
+            60 => NaiveTime::from_hms_milli_opt(num(9), num(11), 59, 1_000),
+            sec => NaiveTime::from_hms_opt(num(9), num(11), sec),
+        };
+        time.map(|t| date.and_time(t)).ok_or(OUT_OF_RANGE)
+    }
+
+    /// Returns the date and time as a floating iCalendar `DATE-TIME` value, such as
+    /// `20240315T093000`.
+    ///
+    /// iCalendar has no fractional seconds, so they are dropped. A leap second is written as
+    /// second `60`. Years before 0 or after 9999 can not be represented in iCalendar, and are
+    /// written with a sign as in [`format`](NaiveDateTime::format).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_ical_string(&self) -> String {
+        let mut result = String::with_capacity(15);
+        write!(result, "{}", self.format("%Y%m%dT%H%M%S"))
+            .expect("writing an iCalendar datetime to a string should never fail");
+        result
+    }
+
+    /// Parses a string with the specified format string and locale into a new `NaiveDateTime`
+    /// value.
+    ///
+    /// The localized `%x`, `%X`, `%c` and `%r` formats, month and weekday names and AM/PM are read
+    /// in the form of `locale`. See [`parse_from_str`](#method.parse_from_str) for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::NaiveDate;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2023, 7, 11).unwrap().and_hms_opt(0, 34, 59).unwrap();
+    /// let parse = NaiveDateTime::parse_from_str_localized;
+    /// assert_eq!(parse("2023年07月11日 00時34分59秒", "%x %X", Locale::ja_JP), Ok(dt));
+    /// assert_eq!(parse("Tue 11 Jul 2023 12:34:59 AM", "%c", Locale::en_US), Ok(dt));
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(
+        s: &str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDateTime`, and a
     /// slice with the remaining portion of the string.
     /// See the [`format::strftime` module](crate::format::strftime)
@@ -316,6 +517,116 @@ impl NaiveDateTime {
         parsed.to_naive_datetime_with_offset(0).map(|d| (d, remainder)) // no offset adjustment
     }
 
+    /// Parses a string with the specified format string and locale into a new `NaiveDateTime`
+    /// value, and a slice with the remaining portion of the string.
+    ///
+    /// Similar to [`parse_from_str_localized`](#method.parse_from_str_localized).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let (datetime, remainder) = NaiveDateTime::parse_and_remainder_localized(
+    ///     "Dienstag, 11. Juli 2023 00:34 Uhr",
+    ///     "%A, %d. %B %Y %H:%M",
+    ///     Locale::de_DE,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     datetime,
+    ///     NaiveDate::from_ymd_opt(2023, 7, 11).unwrap().and_hms_opt(0, 34, 0).unwrap()
+    /// );
+    /// assert_eq!(remainder, " Uhr");
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_and_remainder_localized<'a>(
+        s: &'a str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<(NaiveDateTime, &'a str)> {
+        let mut parsed = Parsed::new();
+        let items = StrftimeItems::new_with_locale(fmt, locale);
+        let remainder = parse_and_remainder_localized(&mut parsed, s, items, locale)?;
+        parsed.to_naive_datetime_with_offset(0).map(|d| (d, remainder)) // no offset adjustment
+    }
+
+    /// Parses a date and time in the ISO 8601 basic format, such as `20240507T123456`.
+    ///
+    /// The basic format is the compact form of ISO 8601 without separators between the fields,
+    /// which is common in industrial protocols and file names. The year must have four digits and
+    /// the seconds can not be left out. A fraction of a second may follow, with either a full
+    /// stop or a comma as the decimal separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a date and time in the ISO 8601 basic format, or if it has
+    /// an offset. Use [`DateTime::parse_from_iso8601_basic`] to parse a value with an offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_milli_opt(12, 34, 56, 700).unwrap();
+    /// assert_eq!(NaiveDateTime::parse_iso8601_basic("20240507T123456.7"), Ok(dt));
+    /// assert_eq!(NaiveDateTime::parse_iso8601_basic("20240507T123456,7"), Ok(dt));
+    /// assert!(NaiveDateTime::parse_iso8601_basic("2024-05-07T12:34:56").is_err());
+    /// assert!(NaiveDateTime::parse_iso8601_basic("20240507T1234").is_err());
+    /// ```
+    pub fn parse_iso8601_basic(s: &str) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        let (s, _) = parse_iso8601_basic(&mut parsed, s, false)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        parsed.to_naive_datetime_with_offset(0)
+    }
+
+    /// Returns a date and time string in the ISO 8601 basic format, such as `20240507T123456`.
+    ///
+    /// All non-zero sub-second digits are written, with a full stop as the decimal separator.
+    /// A year outside the range 0 to 9999 is written with a sign and may have more digits, which
+    /// is an expanded representation of ISO 8601 that [`NaiveDateTime::parse_iso8601_basic`]
+    /// does not accept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(dt.to_iso8601_basic(), "20240507T123456");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_iso8601_basic(&self) -> String {
+        self.to_iso8601_basic_opts(Iso8601BasicOptions::new())
+    }
+
+    /// Returns a date and time string in the ISO 8601 basic format, written with the given
+    /// options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::Iso8601BasicOptions;
+    /// use chrono::{NaiveDate, SecondsFormat};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_nano_opt(12, 34, 56, 1).unwrap();
+    /// let opts = Iso8601BasicOptions::new().with_seconds_format(SecondsFormat::Millis);
+    /// assert_eq!(dt.to_iso8601_basic_opts(opts), "20240507T123456.000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_iso8601_basic_opts(&self, opts: Iso8601BasicOptions) -> String {
+        let mut result = String::with_capacity(25);
+        write_iso8601_basic(&mut result, *self, None, opts)
+            .expect("writing an iso 8601 datetime to string should never fail");
+        result
+    }
+
     /// Retrieves a date component.
     ///
     /// # Example
@@ -938,6 +1249,188 @@ impl NaiveDateTime {
         DateTime::from_naive_utc_and_offset(*self, Utc)
     }
 
+    /// Converts the `NaiveDateTime`, a local time at the given offset from UTC, into a
+    /// `DateTime<FixedOffset>`.
+    ///
+    /// Unlike [`and_local_timezone`](NaiveDateTime::and_local_timezone) this method is a
+    /// `const fn`, so it can be used to build constant `DateTime`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the datetime converted to UTC would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+    ///
+    /// const LAUNCH: Option<DateTime<FixedOffset>> = {
+    ///     let offset = match FixedOffset::east_opt(2 * 3600) {
+    ///         Some(offset) => offset,
+    ///         None => panic!("invalid offset"),
+    ///     };
+    ///     match NaiveDate::from_ymd_opt(2015, 9, 5) {
+    ///         Some(date) => match date.and_hms_opt(23, 56, 4) {
+    ///             Some(dt) => dt.and_fixed_offset(offset),
+    ///             None => None,
+    ///         },
+    ///         None => None,
+    ///     }
+    /// };
+    /// let launch = LAUNCH.unwrap();
+    /// let local = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 4).unwrap();
+    /// assert_eq!(launch.naive_local(), local);
+    /// assert_eq!(launch.offset().local_minus_utc(), 2 * 3600);
+    /// assert_eq!(launch.timestamp(), 1441490164);
+    ///
+    /// let dt = NaiveDateTime::MAX.and_fixed_offset(FixedOffset::west_opt(3600).unwrap());
+    /// assert_eq!(dt, None);
+    /// ```
+    #[must_use]
+    pub const fn and_fixed_offset(&self, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        let utc = try_opt!(self.checked_sub_offset(offset));
+        Some(DateTime::from_naive_utc_and_offset(utc, offset))
+    }
+
+    /// Returns the number of seconds since the UNIX epoch as an `f64`, for interoperability with
+    /// plotting and charting libraries that need a floating point axis.
+    ///
+    /// This is the same as [`to_ordered_f64_seconds_since`] with
+    /// [`NaiveDateTime::UNIX_EPOCH`] as the epoch.
+    ///
+    /// [`to_ordered_f64_seconds_since`]: NaiveDateTime::to_ordered_f64_seconds_since
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(1970, 1, 2).unwrap().and_hms_milli_opt(0, 0, 1, 500).unwrap();
+    /// assert_eq!(dt.to_ordered_f64_seconds(), 86_401.5);
+    /// ```
+    #[must_use]
+    pub fn to_ordered_f64_seconds(&self) -> f64 {
+        self.to_ordered_f64_seconds_since(&NaiveDateTime::UNIX_EPOCH)
+    }
+
+    /// Returns the number of seconds since `epoch` as an `f64`.
+    ///
+    /// The conversion is consistent with the ordering of `NaiveDateTime`: if `a < b`, then
+    /// `a.to_ordered_f64_seconds_since(&epoch) <= b.to_ordered_f64_seconds_since(&epoch)`. Values
+    /// can only compare equal after rounding to the precision of an `f64`, or for a
+    /// [leap second](NaiveTime#leap-second-handling), which is clamped to the last nanosecond of
+    /// the preceding second.
+    ///
+    /// An `f64` has a 53-bit mantissa, so the result is exact to the nanosecond for about 104
+    /// days around `epoch`, and to the microsecond for about 285 years. Use an epoch within the
+    /// range of values you are converting to keep their precision, such as the one returned by
+    /// [`NaiveDateTime::f64_epoch_for`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let epoch = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_nano_opt(0, 0, 1, 1).unwrap();
+    /// assert_eq!(dt.to_ordered_f64_seconds_since(&epoch), 1.000_000_001);
+    /// assert_eq!(epoch.to_ordered_f64_seconds_since(&dt), -1.000_000_001);
+    /// ```
+    #[must_use]
+    pub fn to_ordered_f64_seconds_since(&self, epoch: &NaiveDateTime) -> f64 {
+        (self.ordered_nanos() - epoch.ordered_nanos()) as f64 / 1e9
+    }
+
+    /// Makes a new `NaiveDateTime` from a number of seconds since the UNIX epoch as an `f64`.
+    ///
+    /// This is the inverse of [`NaiveDateTime::to_ordered_f64_seconds`], see
+    /// [`NaiveDateTime::from_f64_seconds_since`] for the loss of precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `secs` is not finite or the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(1970, 1, 2).unwrap().and_hms_milli_opt(0, 0, 1, 500).unwrap();
+    /// assert_eq!(NaiveDateTime::from_f64_seconds(86_401.5), Some(dt));
+    /// assert_eq!(NaiveDateTime::from_f64_seconds(f64::NAN), None);
+    /// ```
+    #[must_use]
+    pub fn from_f64_seconds(secs: f64) -> Option<NaiveDateTime> {
+        NaiveDateTime::from_f64_seconds_since(&NaiveDateTime::UNIX_EPOCH, secs)
+    }
+
+    /// Makes a new `NaiveDateTime` from a number of seconds since `epoch` as an `f64`.
+    ///
+    /// This is the inverse of [`NaiveDateTime::to_ordered_f64_seconds_since`], but it is lossy:
+    /// the value is rounded to the nearest nanosecond, and an `f64` far from `epoch` may not be
+    /// precise enough to round-trip a `NaiveDateTime`. A leap second never round-trips.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `secs` is not finite or the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let epoch = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap().and_hms_opt(23, 59, 58).unwrap();
+    /// assert_eq!(NaiveDateTime::from_f64_seconds_since(&epoch, -2.0), Some(dt));
+    /// ```
+    #[must_use]
+    pub fn from_f64_seconds_since(epoch: &NaiveDateTime, secs: f64) -> Option<NaiveDateTime> {
+        if !secs.is_finite() {
+            return None;
+        }
+        let nanos = secs * 1e9;
+        // `as` saturates, values that large are out of range anyway
+        let nanos = if nanos < 0.0 { (nanos - 0.5) as i128 } else { (nanos + 0.5) as i128 };
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        let delta = TimeDelta::new(secs, nanos.rem_euclid(1_000_000_000) as u32)?;
+        epoch.checked_add_signed(delta)
+    }
+
+    /// Returns an epoch for [`NaiveDateTime::to_ordered_f64_seconds_since`] that preserves the
+    /// most precision for values between `start` and `end`.
+    ///
+    /// This is the middle of the window, truncated to a whole second so the epoch itself is easy
+    /// to present, for example as the origin of a chart axis. All values within about 104 days
+    /// of it convert exactly to the nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 5, 8).unwrap().and_hms_opt(0, 0, 1).unwrap();
+    /// let epoch = NaiveDateTime::f64_epoch_for(&start, &end);
+    /// assert_eq!(epoch, NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(12, 0, 0).unwrap());
+    /// assert_eq!(end.to_ordered_f64_seconds_since(&epoch), 43_201.0);
+    /// ```
+    #[must_use]
+    pub fn f64_epoch_for(start: &NaiveDateTime, end: &NaiveDateTime) -> NaiveDateTime {
+        let middle = (start.ordered_nanos() + end.ordered_nanos()).div_euclid(2);
+        let secs = middle.div_euclid(1_000_000_000) as i64;
+        DateTime::from_timestamp(secs, 0).expect("between two valid values").naive_utc()
+    }
+
+    /// Returns the number of nanoseconds since the UNIX epoch, with a leap second clamped to the
+    /// last nanosecond of the preceding second so the result is ordered like `self`.
+    const fn ordered_nanos(&self) -> i128 {
+        let nanos = match self.time.nanosecond() {
+            nanos if nanos >= 1_000_000_000 => 999_999_999,
+            nanos => nanos,
+        };
+        self.and_utc().timestamp() as i128 * 1_000_000_000 + nanos as i128
+    }
+
     /// The minimum possible `NaiveDateTime`.
     pub const MIN: Self = Self { date: NaiveDate::MIN, time: NaiveTime::MIN };
 
@@ -947,6 +1440,29 @@ impl NaiveDateTime {
     /// The Unix Epoch, 1970-01-01 00:00:00.
     pub const UNIX_EPOCH: Self =
         expect(NaiveDate::from_ymd_opt(1970, 1, 1), "").and_time(NaiveTime::MIN);
+
+    /// Returns a hash of the date and time that is the same on every platform and in every
+    /// version of chrono, see [`NaiveDate::stable_hash`].
+    ///
+    /// It is the 64-bit FNV-1a hash of the days since January 1 of year 1, the seconds since
+    /// midnight and the nanoseconds, each as a little-endian `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(13, 4, 5).unwrap();
+    /// assert_eq!(dt.stable_hash(), 0x1893_8cc0_1514_8ec9);
+    /// ```
+    #[must_use]
+    pub const fn stable_hash(&self) -> u64 {
+        crate::stable_hash(&[
+            self.date.num_days_from_ce() as i64,
+            self.time.num_seconds_from_midnight() as i64,
+            self.time.nanosecond() as i64,
+        ])
+    }
 }
 
 impl From<NaiveDate> for NaiveDateTime {
@@ -965,6 +1481,51 @@ impl From<NaiveDate> for NaiveDateTime {
     }
 }
 
+impl From<(NaiveDate, NaiveTime)> for NaiveDateTime {
+    fn from((date, time): (NaiveDate, NaiveTime)) -> Self {
+        NaiveDateTime::new(date, time)
+    }
+}
+
+impl TryFrom<(i32, u32, u32, u32, u32, u32)> for NaiveDateTime {
+    type Error = OutOfRange;
+
+    /// Makes a `NaiveDateTime` from a `(year, month, day, hour, minute, second)` tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// assert_eq!(
+    ///     NaiveDateTime::try_from((2024, 5, 7, 13, 4, 5)),
+    ///     Ok(NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(13, 4, 5).unwrap())
+    /// );
+    /// assert!(NaiveDateTime::try_from((2024, 5, 7, 13, 60, 0)).is_err());
+    /// ```
+    fn try_from(
+        (year, month, day, hour, min, sec): (i32, u32, u32, u32, u32, u32),
+    ) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, min, sec))
+            .ok_or(OutOfRange::new())
+    }
+}
+
+impl CalendarFields for NaiveDateTime {
+    fn date_fields(&self) -> Option<DateFields> {
+        Some(DateFields::from(self.date))
+    }
+
+    fn time_fields(&self) -> Option<TimeFields> {
+        Some(TimeFields::from(self.time))
+    }
+
+    fn utc_offset(&self) -> Option<FixedOffset> {
+        None
+    }
+}
+
 impl Datelike for NaiveDateTime {
     /// Returns the year number in the [calendar date](./struct.NaiveDate.html#calendar-date).
     ///
@@ -1,5 +1,18 @@
 use super::NaiveDateTime;
-use crate::{Datelike, FixedOffset, MappedLocalTime, NaiveDate, TimeDelta, Utc};
+use crate::format::ParseErrorKind;
+use crate::{
+    DateTime, Datelike, FixedOffset, MappedLocalTime, NaiveDate, NaiveTime, TimeDelta, Utc,
+};
+
+#[test]
+fn test_datetime_from_tuple() {
+    let date = NaiveDate::from_ymd_opt(2014, 5, 6).unwrap();
+    let time = NaiveTime::from_hms_opt(7, 8, 9).unwrap();
+    assert_eq!(NaiveDateTime::from((date, time)), date.and_time(time));
+    assert_eq!(NaiveDateTime::try_from((2014, 5, 6, 7, 8, 9)), Ok(date.and_time(time)));
+    assert!(NaiveDateTime::try_from((2014, 5, 32, 7, 8, 9)).is_err());
+    assert!(NaiveDateTime::try_from((2014, 5, 6, 7, 8, 60)).is_err());
+}
 
 #[test]
 fn test_datetime_add() {
@@ -291,6 +304,24 @@ fn test_and_utc() {
     assert_eq!(dt_utc.timezone(), Utc);
 }
 
+#[test]
+fn test_and_fixed_offset() {
+    const OFFSET: FixedOffset = match FixedOffset::east_opt(-5 * 3600 - 1800) {
+        Some(offset) => offset,
+        None => panic!(),
+    };
+    const DT: Option<DateTime<FixedOffset>> = NaiveDateTime::UNIX_EPOCH.and_fixed_offset(OFFSET);
+    let dt = DT.unwrap();
+    assert_eq!(dt.naive_local(), NaiveDateTime::UNIX_EPOCH);
+    assert_eq!(dt.naive_utc().to_string(), "1970-01-01 05:30:00");
+    assert_eq!(dt, NaiveDateTime::UNIX_EPOCH.and_local_timezone(OFFSET).unwrap());
+
+    let east = FixedOffset::east_opt(3600).unwrap();
+    assert_eq!(NaiveDateTime::MIN.and_fixed_offset(east), None);
+    assert_eq!(NaiveDateTime::MAX.and_fixed_offset(OFFSET), None);
+    assert!(NaiveDateTime::MIN.and_fixed_offset(OFFSET).is_some());
+}
+
 #[test]
 fn test_checked_add_offset() {
     let ymdhmsm = |y, m, d, h, mn, s, mi| {
@@ -406,3 +437,178 @@ fn test_rkyv_validation() {
     let bytes = rkyv::to_bytes::<_, 12>(&dt_max).unwrap();
     assert_eq!(rkyv::from_bytes::<NaiveDateTime>(&bytes).unwrap(), dt_max);
 }
+
+#[test]
+fn test_parse_exif() {
+    let parse = NaiveDateTime::parse_exif;
+    let dt = NaiveDate::from_ymd_opt(2023, 9, 8).unwrap().and_hms_opt(7, 3, 25).unwrap();
+    assert_eq!(parse("2023:09:08 07:03:25"), Ok(dt));
+    assert_eq!(parse("2023:09:08 07:03:25\0\0"), Ok(dt));
+    assert_eq!(
+        parse("0000:01:01 00:00:00"),
+        Ok(NaiveDate::from_ymd_opt(0, 1, 1).unwrap().and_time(NaiveTime::MIN))
+    );
+
+    assert_eq!(parse("2023:09:08 07:03").map_err(|e| e.kind()), Err(ParseErrorKind::TooShort));
+    assert_eq!(parse("2023:09:08 07:03:25 ").map_err(|e| e.kind()), Err(ParseErrorKind::TooLong));
+    assert_eq!(parse("2023:09:08T07:03:25").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("2023:9:08  07:03:25").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("+023:09:08 07:03:25").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("2023:02:29 07:03:25").map_err(|e| e.kind()), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("2023:09:08 24:00:00").map_err(|e| e.kind()), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("0000:00:00 00:00:00").map_err(|e| e.kind()), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("    :  :     :  :  ").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_exif_string() {
+    use crate::Timelike;
+
+    let dt = NaiveDate::from_ymd_opt(987, 6, 5).unwrap().and_hms_nano_opt(4, 3, 2, 1).unwrap();
+    assert_eq!(dt.to_exif_string(), "0987:06:05 04:03:02");
+    assert_eq!(NaiveDateTime::parse_exif(&dt.to_exif_string()), Ok(dt.with_nanosecond(0).unwrap()));
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_000)
+        .unwrap();
+    assert_eq!(leap.to_exif_string(), "2016:12:31 23:59:60");
+}
+
+#[test]
+fn test_parse_ical() {
+    let parse = NaiveDateTime::parse_ical;
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(parse("20230908T070325"), Ok(ymd(2023, 9, 8).and_hms_opt(7, 3, 25).unwrap()));
+    assert_eq!(parse("00000101T000000"), Ok(ymd(0, 1, 1).and_time(NaiveTime::MIN)));
+    assert_eq!(
+        parse("20161231T235960"),
+        Ok(ymd(2016, 12, 31).and_hms_milli_opt(23, 59, 59, 1_000).unwrap())
+    );
+
+    assert_eq!(parse("20230908T0703").map_err(|e| e.kind()), Err(ParseErrorKind::TooShort));
+    assert_eq!(parse("20230908T070325Z").map_err(|e| e.kind()), Err(ParseErrorKind::TooLong));
+    assert_eq!(parse("20230908 070325").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("2023-9-8T070325").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("20230908T07:325").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+    assert_eq!(parse("20230229T070325").map_err(|e| e.kind()), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("20230908T240000").map_err(|e| e.kind()), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("20230908T070361").map_err(|e| e.kind()), Err(ParseErrorKind::OutOfRange));
+    assert_eq!(parse("2023090€T0703").map_err(|e| e.kind()), Err(ParseErrorKind::Invalid));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_ical_string() {
+    use crate::Timelike;
+
+    let dt = NaiveDate::from_ymd_opt(987, 6, 5).unwrap().and_hms_nano_opt(4, 3, 2, 1).unwrap();
+    assert_eq!(dt.to_ical_string(), "09870605T040302");
+    assert_eq!(dt.date().to_ical_string(), "09870605");
+    assert_eq!(NaiveDateTime::parse_ical(&dt.to_ical_string()), Ok(dt.with_nanosecond(0).unwrap()));
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap();
+    assert_eq!(leap.to_ical_string(), "20161231T235960");
+    assert_eq!(
+        NaiveDateTime::parse_ical(&leap.to_ical_string()),
+        Ok(leap.with_nanosecond(1_000_000_000).unwrap())
+    );
+}
+
+#[test]
+fn test_datetime_f64_seconds() {
+    use crate::Timelike;
+
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let epoch = NaiveDateTime::UNIX_EPOCH;
+    assert_eq!(epoch.to_ordered_f64_seconds(), 0.0);
+    let dt = ymd(1969, 12, 31).and_hms_milli_opt(23, 59, 59, 250).unwrap();
+    assert_eq!(dt.to_ordered_f64_seconds(), -0.75);
+    assert_eq!(NaiveDateTime::from_f64_seconds(-0.75), Some(dt));
+
+    // ordered, with a leap second clamped to the end of the preceding second
+    let values = [
+        ymd(2016, 12, 31).and_hms_nano_opt(23, 59, 59, 999_999_998).unwrap(),
+        ymd(2016, 12, 31).and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+        ymd(2016, 12, 31).and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap(),
+        ymd(2017, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+        ymd(2017, 1, 1).and_hms_nano_opt(0, 0, 0, 1).unwrap(),
+    ];
+    let epoch = NaiveDateTime::f64_epoch_for(&values[0], &values[4]);
+    assert_eq!(epoch, ymd(2016, 12, 31).and_hms_opt(23, 59, 59).unwrap());
+    let secs = values.map(|v| v.to_ordered_f64_seconds_since(&epoch));
+    assert_eq!(secs, [0.999_999_998, 0.999_999_999, 0.999_999_999, 1.0, 1.000_000_001]);
+    for (value, secs) in values.iter().zip(secs).filter(|(v, _)| v.nanosecond() < 1_000_000_000) {
+        assert_eq!(NaiveDateTime::from_f64_seconds_since(&epoch, secs), Some(*value));
+    }
+    for pair in values.windows(2) {
+        assert!(pair[0].to_ordered_f64_seconds() <= pair[1].to_ordered_f64_seconds());
+    }
+
+    assert_eq!(NaiveDateTime::from_f64_seconds(f64::INFINITY), None);
+    assert_eq!(NaiveDateTime::from_f64_seconds(f64::NAN), None);
+    assert_eq!(NaiveDateTime::from_f64_seconds(1e20), None);
+    assert_eq!(NaiveDateTime::from_f64_seconds(-1e300), None);
+    assert_eq!(NaiveDateTime::from_f64_seconds_since(&NaiveDateTime::MAX, 1.0), None);
+    let (min, max) = (NaiveDateTime::MIN, NaiveDateTime::MAX);
+    assert_eq!(NaiveDateTime::f64_epoch_for(&min, &min), min);
+    assert_eq!(NaiveDateTime::f64_epoch_for(&max, &max), max.with_nanosecond(0).unwrap());
+}
+
+#[test]
+fn test_datetime_iso8601_basic() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let parse = NaiveDateTime::parse_iso8601_basic;
+
+    let dt = ymd(2024, 5, 7).and_hms_opt(12, 34, 56).unwrap();
+    assert_eq!(parse("20240507T123456"), Ok(dt));
+    assert_eq!(parse("20240507T123456.000"), Ok(dt));
+    let dt = ymd(2024, 5, 7).and_hms_nano_opt(12, 34, 56, 123_456_789).unwrap();
+    assert_eq!(parse("20240507T123456.123456789"), Ok(dt));
+    assert_eq!(parse("20240507T123456,1234567891"), Ok(dt));
+    let leap = ymd(2016, 12, 31).and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    assert_eq!(parse("20161231T235960.5"), Ok(leap));
+
+    for s in [
+        "",
+        "20240507",
+        "20240507T",
+        "20240507T1234",
+        "20240507t123456",
+        "20240507 123456",
+        "2024-05-07T12:34:56",
+        "+20240507T123456",
+        "2024057T123456",
+        "20240507T123456.",
+        "20240507T123456Z",
+        "20240507T123456 ",
+        "20241307T123456",
+        "20240507T243456",
+    ] {
+        assert!(parse(s).is_err(), "{:?}", s);
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        use crate::SecondsFormat;
+        use crate::format::Iso8601BasicOptions;
+
+        assert_eq!(dt.to_iso8601_basic(), "20240507T123456.123456789");
+        let opts = Iso8601BasicOptions::new().with_seconds_format(SecondsFormat::Millis);
+        assert_eq!(dt.to_iso8601_basic_opts(opts), "20240507T123456.123");
+        let opts = opts.with_decimal_comma(true);
+        assert_eq!(dt.to_iso8601_basic_opts(opts), "20240507T123456,123");
+        assert_eq!(leap.to_iso8601_basic(), "20161231T235960.500");
+        assert_eq!(parse(&leap.to_iso8601_basic()), Ok(leap));
+        assert_eq!(
+            ymd(-1, 1, 1).and_hms_opt(0, 0, 0).unwrap().to_iso8601_basic(),
+            "-00010101T000000"
+        );
+        assert_eq!(
+            ymd(10000, 1, 1).and_hms_opt(0, 0, 0).unwrap().to_iso8601_basic(),
+            "+100000101T000000"
+        );
+    }
+}
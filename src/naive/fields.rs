@@ -0,0 +1,564 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Plain date and time fields, for validating user input.
+
+use core::fmt;
+
+use super::{NaiveDate, NaiveTime};
+use crate::{Datelike, Timelike};
+
+/// The year, month and day of a calendar date, as plain fields.
+///
+/// This is meant for validating user input, such as a date submitted as three separate form fields.
+/// Converting to a [`NaiveDate`] with [`NaiveDate::try_from_fields`] reports which field is
+/// invalid. With the `serde` feature this type can be deserialized from a map or sequence.
+///
+/// # Example
+///
+/// ```
+/// use chrono::naive::{DateFields, Field};
+/// use chrono::NaiveDate;
+///
+/// let fields = DateFields { year: 2023, month: 2, day: 29 };
+/// let err = NaiveDate::try_from_fields(fields).unwrap_err();
+/// assert_eq!(err.field(), Field::Day);
+/// assert_eq!(err.to_string(), "invalid day");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateFields {
+    /// The year, in the proleptic Gregorian calendar.
+    pub year: i32,
+    /// The month, starting from 1.
+    pub month: u32,
+    /// The day of the month, starting from 1.
+    pub day: u32,
+}
+
+/// The hour, minute, second and nanosecond of a time of day, as plain fields.
+///
+/// See [`DateFields`] for the intended use. A leap second is represented by a nanosecond value of
+/// 1,000,000,000 or more when the second is 59, as with [`NaiveTime::from_hms_nano_opt`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::naive::{Field, TimeFields};
+/// use chrono::NaiveTime;
+///
+/// let fields = TimeFields { hour: 12, minute: 60, second: 0, nanosecond: 0 };
+/// assert_eq!(NaiveTime::try_from_fields(fields).unwrap_err().field(), Field::Minute);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeFields {
+    /// The hour, from 0 to 23.
+    pub hour: u32,
+    /// The minute, from 0 to 59.
+    pub minute: u32,
+    /// The second, from 0 to 59.
+    pub second: u32,
+    /// The nanosecond, from 0 to 999,999,999 (or up to 1,999,999,999 for a leap second).
+    ///
+    /// This field is optional when deserializing and defaults to 0.
+    pub nanosecond: u32,
+}
+
+/// A field of a date or time.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// The year.
+    Year,
+    /// The month.
+    Month,
+    /// The day of the month.
+    Day,
+    /// The hour.
+    Hour,
+    /// The minute.
+    Minute,
+    /// The second.
+    Second,
+    /// The nanosecond.
+    Nanosecond,
+}
+
+impl Field {
+    /// Returns the name of the field, the same as used by the `serde` implementations.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Field::Year => "year",
+            Field::Month => "month",
+            Field::Day => "day",
+            Field::Hour => "hour",
+            Field::Minute => "minute",
+            Field::Second => "second",
+            Field::Nanosecond => "nanosecond",
+        }
+    }
+}
+
+/// An error from converting [`DateFields`] or [`TimeFields`], labeled with the invalid field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldError {
+    field: Field,
+}
+
+impl FieldError {
+    pub(super) const fn new(field: Field) -> FieldError {
+        FieldError { field }
+    }
+
+    /// Returns the field that is invalid or out of range.
+    pub const fn field(&self) -> Field {
+        self.field
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid {}", self.field.name())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
+impl From<NaiveDate> for DateFields {
+    fn from(date: NaiveDate) -> DateFields {
+        DateFields { year: date.year(), month: date.month(), day: date.day() }
+    }
+}
+
+impl From<NaiveTime> for TimeFields {
+    fn from(time: NaiveTime) -> TimeFields {
+        TimeFields {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            nanosecond: time.nanosecond(),
+        }
+    }
+}
+
+impl TryFrom<DateFields> for NaiveDate {
+    type Error = FieldError;
+
+    fn try_from(fields: DateFields) -> Result<NaiveDate, FieldError> {
+        NaiveDate::try_from_fields(fields)
+    }
+}
+
+impl TryFrom<TimeFields> for NaiveTime {
+    type Error = FieldError;
+
+    fn try_from(fields: TimeFields) -> Result<NaiveTime, FieldError> {
+        NaiveTime::try_from_fields(fields)
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(super) mod serde {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::{DateFields, Field, TimeFields};
+
+    const DATE_FIELDS: &[&str] = &["year", "month", "day"];
+    const TIME_FIELDS: &[&str] = &["hour", "minute", "second", "nanosecond"];
+
+    impl ser::Serialize for DateFields {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            use ser::SerializeStruct;
+            let mut state = serializer.serialize_struct("DateFields", 3)?;
+            state.serialize_field("year", &self.year)?;
+            state.serialize_field("month", &self.month)?;
+            state.serialize_field("day", &self.day)?;
+            state.end()
+        }
+    }
+
+    impl ser::Serialize for TimeFields {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            use ser::SerializeStruct;
+            let mut state = serializer.serialize_struct("TimeFields", 4)?;
+            state.serialize_field("hour", &self.hour)?;
+            state.serialize_field("minute", &self.minute)?;
+            state.serialize_field("second", &self.second)?;
+            state.serialize_field("nanosecond", &self.nanosecond)?;
+            state.end()
+        }
+    }
+
+    struct FieldVisitor(&'static [&'static str]);
+
+    impl de::Visitor<'_> for FieldVisitor {
+        type Value = Field;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a field name")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Field, E>
+        where
+            E: de::Error,
+        {
+            let field = match value {
+                "year" => Field::Year,
+                "month" => Field::Month,
+                "day" => Field::Day,
+                "hour" => Field::Hour,
+                "minute" => Field::Minute,
+                "second" => Field::Second,
+                "nanosecond" => Field::Nanosecond,
+                _ => return Err(E::unknown_field(value, self.0)),
+            };
+            match self.0.contains(&field.name()) {
+                true => Ok(field),
+                false => Err(E::unknown_field(value, self.0)),
+            }
+        }
+    }
+
+    struct FieldSeed(&'static [&'static str]);
+
+    impl<'de> de::DeserializeSeed<'de> for FieldSeed {
+        type Value = Field;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Field, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(FieldVisitor(self.0))
+        }
+    }
+
+    // Stores a field value, rejecting duplicates.
+    fn set<T, E: de::Error>(slot: &mut Option<T>, field: Field, value: T) -> Result<(), E> {
+        match slot.replace(value) {
+            Some(_) => Err(E::duplicate_field(field.name())),
+            None => Ok(()),
+        }
+    }
+
+    // Reads the next element of a sequence, which must be present.
+    fn element<'de, A, T>(seq: &mut A, i: usize, exp: &dyn de::Expected) -> Result<T, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+        T: de::Deserialize<'de>,
+    {
+        seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, exp))
+    }
+
+    struct DateFieldsVisitor;
+
+    impl<'de> de::Visitor<'de> for DateFieldsVisitor {
+        type Value = DateFields;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a year, month and day")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<DateFields, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            Ok(DateFields {
+                year: element(&mut seq, 0, &self)?,
+                month: element(&mut seq, 1, &self)?,
+                day: element(&mut seq, 2, &self)?,
+            })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<DateFields, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let (mut year, mut month, mut day) = (None, None, None);
+            while let Some(field) = map.next_key_seed(FieldSeed(DATE_FIELDS))? {
+                match field {
+                    Field::Year => set(&mut year, field, map.next_value()?)?,
+                    Field::Month => set(&mut month, field, map.next_value()?)?,
+                    _ => set(&mut day, field, map.next_value()?)?,
+                }
+            }
+            Ok(DateFields {
+                year: year.ok_or_else(|| de::Error::missing_field("year"))?,
+                month: month.ok_or_else(|| de::Error::missing_field("month"))?,
+                day: day.ok_or_else(|| de::Error::missing_field("day"))?,
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for DateFields {
+        fn deserialize<D>(deserializer: D) -> Result<DateFields, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("DateFields", DATE_FIELDS, DateFieldsVisitor)
+        }
+    }
+
+    struct TimeFieldsVisitor;
+
+    impl<'de> de::Visitor<'de> for TimeFieldsVisitor {
+        type Value = TimeFields;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an hour, minute, second and optional nanosecond")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<TimeFields, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            Ok(TimeFields {
+                hour: element(&mut seq, 0, &self)?,
+                minute: element(&mut seq, 1, &self)?,
+                second: element(&mut seq, 2, &self)?,
+                nanosecond: seq.next_element()?.unwrap_or(0),
+            })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<TimeFields, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let (mut hour, mut minute, mut second, mut nanosecond) = (None, None, None, None);
+            while let Some(field) = map.next_key_seed(FieldSeed(TIME_FIELDS))? {
+                match field {
+                    Field::Hour => set(&mut hour, field, map.next_value()?)?,
+                    Field::Minute => set(&mut minute, field, map.next_value()?)?,
+                    Field::Second => set(&mut second, field, map.next_value()?)?,
+                    _ => set(&mut nanosecond, field, map.next_value()?)?,
+                }
+            }
+            Ok(TimeFields {
+                hour: hour.ok_or_else(|| de::Error::missing_field("hour"))?,
+                minute: minute.ok_or_else(|| de::Error::missing_field("minute"))?,
+                second: second.ok_or_else(|| de::Error::missing_field("second"))?,
+                nanosecond: nanosecond.unwrap_or(0),
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for TimeFields {
+        fn deserialize<D>(deserializer: D) -> Result<TimeFields, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("TimeFields", TIME_FIELDS, TimeFieldsVisitor)
+        }
+    }
+
+    /// Ser/de a `NaiveDate` as separate year, month and day fields.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Deserialization fails with a message
+    /// naming the invalid field, such as `invalid day`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::naive::serde::date_fields;
+    /// #[derive(Debug, Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "date_fields")]
+    ///     date: NaiveDate,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{"date":{"year":2024,"month":2,"day":29}}"#)?;
+    /// assert_eq!(my_s.date, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    /// assert_eq!(serde_json::to_string(&my_s)?, r#"{"date":{"year":2024,"month":2,"day":29}}"#);
+    ///
+    /// let err = serde_json::from_str::<S>(r#"{"date":{"year":2023,"month":2,"day":29}}"#);
+    /// assert!(err.unwrap_err().to_string().starts_with("invalid day"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod date_fields {
+        use serde::{Deserialize, Serialize, de, ser};
+
+        use super::DateFields;
+        use crate::NaiveDate;
+
+        /// Serialize a `NaiveDate` as separate year, month and day fields.
+        pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            DateFields::from(*date).serialize(serializer)
+        }
+
+        /// Deserialize a `NaiveDate` from separate year, month and day fields.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            NaiveDate::try_from_fields(DateFields::deserialize(deserializer)?)
+                .map_err(de::Error::custom)
+        }
+    }
+
+    /// Ser/de a `NaiveTime` as separate hour, minute, second and nanosecond fields.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. The nanosecond field is optional when
+    /// deserializing. Deserialization fails with a message naming the invalid field, such as
+    /// `invalid minute`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::NaiveTime;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::naive::serde::time_fields;
+    /// #[derive(Debug, Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "time_fields")]
+    ///     time: NaiveTime,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{"time":{"hour":13,"minute":4,"second":5}}"#)?;
+    /// assert_eq!(my_s.time, NaiveTime::from_hms_opt(13, 4, 5).unwrap());
+    ///
+    /// let err = serde_json::from_str::<S>(r#"{"time":{"hour":13,"minute":60,"second":5}}"#);
+    /// assert!(err.unwrap_err().to_string().starts_with("invalid minute"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod time_fields {
+        use serde::{Deserialize, Serialize, de, ser};
+
+        use super::TimeFields;
+        use crate::NaiveTime;
+
+        /// Serialize a `NaiveTime` as separate hour, minute, second and nanosecond fields.
+        pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            TimeFields::from(*time).serialize(serializer)
+        }
+
+        /// Deserialize a `NaiveTime` from separate hour, minute, second and nanosecond fields.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            NaiveTime::try_from_fields(TimeFields::deserialize(deserializer)?)
+                .map_err(de::Error::custom)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{DateFields, TimeFields};
+        use crate::naive::{Field, FieldError};
+        use crate::{NaiveDate, NaiveTime};
+
+        #[test]
+        fn test_serde_date_fields() {
+            let fields = DateFields { year: 2024, month: 5, day: 7 };
+            let json = r#"{"year":2024,"month":5,"day":7}"#;
+            assert_eq!(serde_json::to_string(&fields).unwrap(), json);
+            assert_eq!(serde_json::from_str::<DateFields>(json).unwrap(), fields);
+            assert_eq!(serde_json::from_str::<DateFields>("[2024,5,7]").unwrap(), fields);
+            let date: NaiveDate =
+                serde_json::from_str::<DateFields>(json).unwrap().try_into().unwrap();
+            assert_eq!(date, NaiveDate::from_ymd_opt(2024, 5, 7).unwrap());
+
+            for s in [
+                r#"{"year":2024,"month":5}"#,
+                r#"{"year":2024,"month":5,"day":7,"day":8}"#,
+                r#"{"year":2024,"month":5,"day":7,"hour":8}"#,
+                r#"{"year":2024,"month":-5,"day":7}"#,
+                "[2024,5]",
+            ] {
+                assert!(serde_json::from_str::<DateFields>(s).is_err(), "{}", s);
+            }
+
+            let fields = serde_json::from_str::<DateFields>(r#"{"day":31,"month":4,"year":2024}"#);
+            assert_eq!(NaiveDate::try_from(fields.unwrap()), Err(FieldError::new(Field::Day)));
+        }
+
+        #[test]
+        fn test_serde_time_fields() {
+            let fields = TimeFields { hour: 13, minute: 4, second: 5, nanosecond: 6 };
+            let json = r#"{"hour":13,"minute":4,"second":5,"nanosecond":6}"#;
+            assert_eq!(serde_json::to_string(&fields).unwrap(), json);
+            assert_eq!(serde_json::from_str::<TimeFields>(json).unwrap(), fields);
+            assert_eq!(serde_json::from_str::<TimeFields>("[13,4,5,6]").unwrap(), fields);
+
+            let fields = TimeFields { nanosecond: 0, ..fields };
+            let json = r#"{"hour":13,"minute":4,"second":5}"#;
+            assert_eq!(serde_json::from_str::<TimeFields>(json).unwrap(), fields);
+            assert_eq!(serde_json::from_str::<TimeFields>("[13,4,5]").unwrap(), fields);
+            let time: NaiveTime =
+                serde_json::from_str::<TimeFields>(json).unwrap().try_into().unwrap();
+            assert_eq!(time, NaiveTime::from_hms_opt(13, 4, 5).unwrap());
+
+            assert!(serde_json::from_str::<TimeFields>(r#"{"hour":13,"minute":4}"#).is_err());
+            assert!(
+                serde_json::from_str::<TimeFields>(r#"{"hour":13,"minute":4,"day":1}"#).is_err()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateFields, Field, FieldError, TimeFields};
+    use crate::{NaiveDate, NaiveTime};
+
+    #[test]
+    fn test_date_fields() {
+        let ymd = |year, month, day| NaiveDate::try_from_fields(DateFields { year, month, day });
+        let err = |field| Err(FieldError::new(field));
+
+        assert_eq!(ymd(2024, 2, 29), Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+        assert_eq!(ymd(2023, 2, 29), err(Field::Day));
+        assert_eq!(ymd(2023, 4, 31), err(Field::Day));
+        assert_eq!(ymd(2023, 1, 0), err(Field::Day));
+        assert_eq!(ymd(2023, 0, 1), err(Field::Month));
+        assert_eq!(ymd(2023, 13, 1), err(Field::Month));
+        assert_eq!(ymd(2023, 13, 32), err(Field::Month));
+        assert_eq!(ymd(400_000, 1, 1), err(Field::Year));
+        assert_eq!(ymd(-400_000, 13, 1), err(Field::Year));
+
+        let date = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert_eq!(NaiveDate::try_from(DateFields::from(date)), Ok(date));
+        assert_eq!(DateFields::from(date), DateFields { year: -44, month: 3, day: 15 });
+    }
+
+    #[test]
+    fn test_time_fields() {
+        let hmsn = |hour, minute, second, nanosecond| {
+            NaiveTime::try_from_fields(TimeFields { hour, minute, second, nanosecond })
+        };
+        let err = |field| Err(FieldError::new(field));
+
+        let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(hmsn(23, 59, 59, 999_999_999), Ok(time));
+        assert_eq!(hmsn(23, 59, 59, 1_500_000_000).map(|t| t.nanosecond()), Ok(1_500_000_000));
+        assert_eq!(hmsn(24, 0, 0, 0), err(Field::Hour));
+        assert_eq!(hmsn(0, 60, 0, 0), err(Field::Minute));
+        assert_eq!(hmsn(0, 0, 60, 0), err(Field::Second));
+        assert_eq!(hmsn(0, 0, 58, 1_000_000_000), err(Field::Nanosecond));
+        assert_eq!(hmsn(0, 0, 59, 2_000_000_000), err(Field::Nanosecond));
+
+        let time = NaiveTime::from_hms_nano_opt(13, 4, 5, 6).unwrap();
+        assert_eq!(NaiveTime::try_from(TimeFields::from(time)), Ok(time));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_field_error_display() {
+        assert_eq!(FieldError::new(Field::Month).to_string(), "invalid month");
+        assert_eq!(FieldError::new(Field::Nanosecond).to_string(), "invalid nanosecond");
+    }
+}
@@ -3,9 +3,15 @@
 
 //! ISO 8601 week.
 
-use core::fmt;
+use core::{fmt, str};
 
 use super::internals::YearFlags;
+use super::{Days, NaiveDate};
+use crate::format::{
+    IMPOSSIBLE, Item, NOT_ENOUGH, Numeric, OUT_OF_RANGE, Pad, ParseError, ParseResult, Parsed,
+    parse,
+};
+use crate::{Datelike, Weekday, expect};
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -115,6 +121,56 @@ impl IsoWeek {
     pub const fn week0(&self) -> u32 {
         ((self.ywf >> 4) & 0x3f) as u32 - 1
     }
+
+    /// Returns the Monday that starts this ISO week.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first day of the week falls just out of range of `NaiveDate`, which can only
+    /// happen for the first week supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{IsoWeek, NaiveDate};
+    ///
+    /// let week: IsoWeek = "2024-W07".parse().unwrap();
+    /// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2024, 2, 12).unwrap());
+    /// assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2024, 2, 18).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn first_day(&self) -> NaiveDate {
+        expect(self.checked_first_day(), "first weekday out of range for `NaiveDate`")
+    }
+
+    /// Returns the Monday that starts this ISO week, or `None` if it is out of range of
+    /// `NaiveDate`.
+    #[inline]
+    #[must_use]
+    pub const fn checked_first_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.year(), self.week(), Weekday::Mon)
+    }
+
+    /// Returns the Sunday that ends this ISO week.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last day of the week falls just out of range of `NaiveDate`, which can only
+    /// happen for the last week supported.
+    #[inline]
+    #[must_use]
+    pub const fn last_day(&self) -> NaiveDate {
+        expect(self.checked_last_day(), "last weekday out of range for `NaiveDate`")
+    }
+
+    /// Returns the Sunday that ends this ISO week, or `None` if it is out of range of
+    /// `NaiveDate`.
+    #[inline]
+    #[must_use]
+    pub const fn checked_last_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.year(), self.week(), Weekday::Sun)
+    }
 }
 
 /// The `Debug` output of the ISO week `w` is the same as
@@ -160,6 +216,323 @@ impl fmt::Debug for IsoWeek {
     }
 }
 
+/// The `Display` output of an ISO week is the same as its `Debug` output, such as `2024-W07`.
+impl fmt::Display for IsoWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parsing a `str` into an `IsoWeek` uses the format `%G-W%V`, the inverse of its `Display`
+/// output.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Datelike, IsoWeek, NaiveDate};
+///
+/// let week = NaiveDate::from_ymd_opt(2015, 12, 31).unwrap().iso_week();
+/// assert_eq!("2015-W53".parse::<IsoWeek>(), Ok(week));
+/// assert_eq!(week.to_string().parse::<IsoWeek>(), Ok(week));
+///
+/// assert!("2015-W54".parse::<IsoWeek>().is_err());
+/// assert!("2015-W53-1".parse::<IsoWeek>().is_err());
+/// ```
+impl str::FromStr for IsoWeek {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<IsoWeek> {
+        parse_iso_week(s).map(|date| date.iso_week())
+    }
+}
+
+/// Parses an ISO week in the format `%G-W%V`, and returns a day in that week that is in range of
+/// `NaiveDate`.
+pub(super) fn parse_iso_week(s: &str) -> ParseResult<NaiveDate> {
+    const ITEMS: &[Item<'static>] = &[
+        Item::Numeric(Numeric::IsoYear, Pad::Zero),
+        Item::Literal("-W"),
+        Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+    ];
+
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, s, ITEMS.iter())?;
+    let year = parsed.isoyear().ok_or(NOT_ENOUGH)?;
+    let week = parsed.isoweek().ok_or(NOT_ENOUGH)?;
+    if week > YearFlags::from_year(year).nisoweeks() {
+        return Err(IMPOSSIBLE);
+    }
+    // the first and last week supported are only partially in range
+    let mut weekday = Weekday::Mon;
+    loop {
+        if let Some(date) = NaiveDate::from_isoywd_opt(year, week, weekday) {
+            return Ok(date);
+        }
+        weekday = weekday.succ();
+        if weekday == Weekday::Mon {
+            return Err(OUT_OF_RANGE);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod iso_week_serde {
+    use super::IsoWeek;
+    use core::fmt;
+    use serde::{de, ser};
+
+    /// Serialize an `IsoWeek` as an ISO 8601 string, such as `2024-W07`
+    impl ser::Serialize for IsoWeek {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct IsoWeekVisitor;
+
+    impl de::Visitor<'_> for IsoWeekVisitor {
+        type Value = IsoWeek;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a formatted ISO week string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            value.parse().map_err(E::custom)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for IsoWeek {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_str(IsoWeekVisitor)
+        }
+    }
+}
+
+/// ISO 8601 week date: a year, week number and day of the week.
+///
+/// Unlike [`IsoWeek`] this type can be constructed directly, and converts to and from
+/// [`NaiveDate`]. Its `Display` and `FromStr` implementations use the extended ISO 8601 format,
+/// such as `2024-W15-3` for the Wednesday of week 15 in 2024.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{IsoWeekDate, NaiveDate, Weekday};
+///
+/// let week_date = IsoWeekDate::new(2024, 15, Weekday::Wed).unwrap();
+/// assert_eq!(week_date.to_naive_date(), NaiveDate::from_ymd_opt(2024, 4, 10).unwrap());
+/// assert_eq!(week_date.to_string(), "2024-W15-3");
+/// assert_eq!("2024-W15-3".parse(), Ok(week_date));
+///
+/// let next = week_date.succ_opt().unwrap();
+/// assert_eq!((next.year(), next.week(), next.weekday()), (2024, 16, Weekday::Wed));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+#[cfg_attr(
+    any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
+    derive(Archive, Deserialize, Serialize),
+    archive(compare(PartialEq, PartialOrd)),
+    archive_attr(derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash))
+)]
+#[cfg_attr(feature = "rkyv-validation", archive(check_bytes))]
+pub struct IsoWeekDate {
+    // Storing the calendar date keeps every value in the range supported by `NaiveDate`.
+    date: NaiveDate,
+}
+
+impl IsoWeekDate {
+    /// Makes a new `IsoWeekDate` from the year, week number and day of the week.
+    ///
+    /// This is the same as [`NaiveDate::from_isoywd_opt`], but says what is wrong on failure.
+    ///
+    /// # Errors
+    ///
+    /// - [`IsoWeekDateError::InvalidWeek`] if the week does not exist in that year, for example
+    ///   week `0`, or week `53` of 2023.
+    /// - [`IsoWeekDateError::OutOfRange`] if the resulting date is out of range for `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{IsoWeekDate, IsoWeekDateError, Weekday};
+    ///
+    /// assert!(IsoWeekDate::new(2020, 53, Weekday::Mon).is_ok());
+    /// assert_eq!(IsoWeekDate::new(2023, 53, Weekday::Mon), Err(IsoWeekDateError::InvalidWeek));
+    /// assert_eq!(IsoWeekDate::new(400000, 1, Weekday::Mon), Err(IsoWeekDateError::OutOfRange));
+    /// ```
+    pub const fn new(
+        year: i32,
+        week: u32,
+        weekday: Weekday,
+    ) -> Result<IsoWeekDate, IsoWeekDateError> {
+        if week == 0 || week > YearFlags::from_year(year).nisoweeks() {
+            return Err(IsoWeekDateError::InvalidWeek);
+        }
+        match NaiveDate::from_isoywd_opt(year, week, weekday) {
+            Some(date) => Ok(IsoWeekDate { date }),
+            None => Err(IsoWeekDateError::OutOfRange),
+        }
+    }
+
+    /// Returns the year number of the week date.
+    ///
+    /// This might not match the calendar year of the date.
+    #[inline]
+    pub fn year(&self) -> i32 {
+        self.iso_week().year()
+    }
+
+    /// Returns the ISO week number starting from 1.
+    ///
+    /// The return value ranges from 1 to 53. (The last week of year differs by years.)
+    #[inline]
+    pub fn week(&self) -> u32 {
+        self.iso_week().week()
+    }
+
+    /// Returns the day of the week.
+    #[inline]
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    /// Returns the ISO week without the day of the week.
+    #[inline]
+    pub fn iso_week(&self) -> IsoWeek {
+        self.date.iso_week()
+    }
+
+    /// Returns the calendar date for this week date.
+    #[inline]
+    pub const fn to_naive_date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Makes a new `IsoWeekDate` for the same day of the week in the next week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range for `NaiveDate`.
+    #[must_use]
+    pub fn succ_opt(&self) -> Option<IsoWeekDate> {
+        Some(IsoWeekDate { date: self.date.checked_add_days(Days::new(7))? })
+    }
+
+    /// Makes a new `IsoWeekDate` for the same day of the week in the previous week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range for `NaiveDate`.
+    #[must_use]
+    pub fn pred_opt(&self) -> Option<IsoWeekDate> {
+        Some(IsoWeekDate { date: self.date.checked_sub_days(Days::new(7))? })
+    }
+}
+
+impl From<NaiveDate> for IsoWeekDate {
+    fn from(date: NaiveDate) -> IsoWeekDate {
+        IsoWeekDate { date }
+    }
+}
+
+impl From<IsoWeekDate> for NaiveDate {
+    fn from(week_date: IsoWeekDate) -> NaiveDate {
+        week_date.date
+    }
+}
+
+/// The `Debug` output of the ISO week date `d` is the same as
+/// [`d.to_naive_date().format("%G-W%V-%u")`](crate::format::strftime).
+///
+/// ISO 8601 requires an explicit sign for years before 1 BCE or after 9999 CE.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{IsoWeekDate, Weekday};
+///
+/// let d = IsoWeekDate::new(2015, 1, Weekday::Mon).unwrap();
+/// assert_eq!(format!("{:?}", d), "2015-W01-1");
+/// let d = IsoWeekDate::new(-1, 52, Weekday::Sun).unwrap();
+/// assert_eq!(format!("{:?}", d), "-0001-W52-7");
+/// ```
+impl fmt::Debug for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}-{}", self.iso_week(), self.weekday().number_from_monday())
+    }
+}
+
+/// The `Display` output of the ISO week date is the same as its `Debug` output.
+impl fmt::Display for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parsing a `str` into an `IsoWeekDate` uses the same format,
+/// [`%G-W%V-%u`](crate::format::strftime), as in `Debug` and `Display`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{IsoWeekDate, Weekday};
+///
+/// let d = IsoWeekDate::new(2015, 53, Weekday::Sun).unwrap();
+/// assert_eq!("2015-W53-7".parse::<IsoWeekDate>(), Ok(d));
+///
+/// assert!("2015-W54-1".parse::<IsoWeekDate>().is_err());
+/// assert!("2015-W01".parse::<IsoWeekDate>().is_err());
+/// ```
+impl str::FromStr for IsoWeekDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<IsoWeekDate> {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Numeric(Numeric::IsoYear, Pad::Zero),
+            Item::Literal("-W"),
+            Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+            Item::Literal("-"),
+            Item::Numeric(Numeric::WeekdayFromMon, Pad::Zero),
+        ];
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        parsed.to_naive_date().map(IsoWeekDate::from)
+    }
+}
+
+/// An error from constructing an [`IsoWeekDate`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IsoWeekDateError {
+    /// The week number does not exist in the given year.
+    InvalidWeek,
+    /// The date is out of range for `NaiveDate`.
+    OutOfRange,
+}
+
+impl fmt::Display for IsoWeekDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IsoWeekDateError::InvalidWeek => write!(f, "week does not exist in that year"),
+            IsoWeekDateError::OutOfRange => write!(f, "date out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IsoWeekDateError {}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "rkyv-validation")]
@@ -230,4 +603,101 @@ mod tests {
         let bytes = rkyv::to_bytes::<_, 4>(&maxweek).unwrap();
         assert_eq!(rkyv::from_bytes::<IsoWeek>(&bytes).unwrap(), maxweek);
     }
+
+    #[test]
+    fn test_iso_week_date() {
+        use super::{IsoWeekDate, IsoWeekDateError};
+        use crate::Weekday;
+
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let cases = [
+            ((2014, 52, Weekday::Sun), ymd(2014, 12, 28)),
+            ((2015, 1, Weekday::Mon), ymd(2014, 12, 29)),
+            ((2015, 53, Weekday::Sun), ymd(2016, 1, 3)),
+            ((2024, 15, Weekday::Wed), ymd(2024, 4, 10)),
+        ];
+        for ((year, week, weekday), date) in cases {
+            let week_date = IsoWeekDate::new(year, week, weekday).unwrap();
+            assert_eq!(week_date.to_naive_date(), date);
+            assert_eq!(NaiveDate::from(week_date), date);
+            assert_eq!(IsoWeekDate::from(date), week_date);
+            assert_eq!(
+                (week_date.year(), week_date.week(), week_date.weekday()),
+                (year, week, weekday)
+            );
+            assert_eq!(week_date.iso_week(), date.iso_week());
+        }
+
+        assert_eq!(IsoWeekDate::new(2015, 0, Weekday::Mon), Err(IsoWeekDateError::InvalidWeek));
+        assert_eq!(IsoWeekDate::new(2014, 53, Weekday::Mon), Err(IsoWeekDateError::InvalidWeek));
+        assert_eq!(IsoWeekDate::new(2015, 60, Weekday::Mon), Err(IsoWeekDateError::InvalidWeek));
+        assert_eq!(
+            IsoWeekDate::new(date::MAX_YEAR + 1, 2, Weekday::Mon),
+            Err(IsoWeekDateError::OutOfRange)
+        );
+        assert_eq!(
+            IsoWeekDate::new(date::MIN_YEAR - 1, 1, Weekday::Mon),
+            Err(IsoWeekDateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_iso_week_date_succ_pred() {
+        use super::IsoWeekDate;
+
+        let week_date = IsoWeekDate::from(NaiveDate::from_ymd_opt(2015, 12, 31).unwrap());
+        let next = week_date.succ_opt().unwrap();
+        assert_eq!((next.year(), next.week(), next.weekday()), (2016, 1, week_date.weekday()));
+        assert_eq!(next.pred_opt(), Some(week_date));
+
+        assert_eq!(IsoWeekDate::from(NaiveDate::MAX).succ_opt(), None);
+        assert_eq!(IsoWeekDate::from(NaiveDate::MIN).pred_opt(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_iso_week_date_format_parse() {
+        use super::IsoWeekDate;
+        use crate::Weekday;
+
+        let cases = [
+            ((2024, 15, Weekday::Wed), "2024-W15-3"),
+            ((2015, 1, Weekday::Mon), "2015-W01-1"),
+            ((0, 1, Weekday::Sun), "0000-W01-7"),
+            ((-1, 52, Weekday::Sat), "-0001-W52-6"),
+            ((10000, 52, Weekday::Fri), "+10000-W52-5"),
+        ];
+        for ((year, week, weekday), s) in cases {
+            let week_date = IsoWeekDate::new(year, week, weekday).unwrap();
+            assert_eq!(week_date.to_string(), s);
+            assert_eq!(format!("{:?}", week_date), s);
+            assert_eq!(
+                week_date.to_string(),
+                week_date.to_naive_date().format("%G-W%V-%u").to_string()
+            );
+            assert_eq!(s.parse::<IsoWeekDate>(), Ok(week_date));
+        }
+
+        for s in ["", "2015", "2015-W01", "2015-W01-0", "2015-W01-8", "2014-W53-1", "2015W011"] {
+            assert!(s.parse::<IsoWeekDate>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_iso_week() {
+        use super::IsoWeek;
+
+        let week = NaiveDate::from_ymd_opt(2015, 12, 31).unwrap().iso_week();
+        assert_eq!(serde_json::to_string(&week).unwrap(), r#""2015-W53""#);
+        assert_eq!(serde_json::from_str::<IsoWeek>(r#""2015-W53""#).unwrap(), week);
+
+        let week = NaiveDate::from_ymd_opt(-1, 12, 31).unwrap().iso_week();
+        assert_eq!(serde_json::to_string(&week).unwrap(), r#""-0001-W52""#);
+        assert_eq!(serde_json::from_str::<IsoWeek>(r#""-0001-W52""#).unwrap(), week);
+
+        assert!(serde_json::from_str::<IsoWeek>(r#""2015-W54""#).is_err());
+        assert!(serde_json::from_str::<IsoWeek>(r#""2015-W53-1""#).is_err());
+        assert!(serde_json::from_str::<IsoWeek>("201553").is_err());
+    }
 }
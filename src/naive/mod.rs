@@ -4,15 +4,23 @@
 //! (e.g. [`TimeZone`](../offset/trait.TimeZone.html)),
 //! but can be also used for the simpler date and time handling.
 
+use core::iter::Take;
 use core::ops::RangeInclusive;
+use core::str;
 
-use crate::Weekday;
 use crate::expect;
+use crate::format::{ParseError, ParseResult};
+use crate::{Datelike, Weekday};
+
+#[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
+use rkyv::{Archive, Deserialize, Serialize};
 
 pub(crate) mod date;
 pub(crate) mod datetime;
+mod fields;
 mod internals;
 pub(crate) mod isoweek;
+mod ordinal;
 pub(crate) mod time;
 
 #[allow(deprecated)]
@@ -20,7 +28,9 @@ pub use self::date::{MAX_DATE, MIN_DATE};
 pub use self::date::{NaiveDate, NaiveDateDaysIterator, NaiveDateWeeksIterator};
 #[allow(deprecated)]
 pub use self::datetime::{MAX_DATETIME, MIN_DATETIME, NaiveDateTime};
-pub use self::isoweek::IsoWeek;
+pub use self::fields::{DateFields, Field, FieldError, TimeFields};
+pub use self::isoweek::{IsoWeek, IsoWeekDate, IsoWeekDateError};
+pub use self::ordinal::{Ordinal, WeekOfYear};
 pub use self::time::NaiveTime;
 
 #[cfg(feature = "__internal_bench")]
@@ -204,6 +214,152 @@ impl NaiveWeek {
             (_, _) => None,
         }
     }
+
+    /// Returns an iterator over the seven days of the week, starting with the first day.
+    ///
+    /// The iterator stops early if the last days of the week are out of range of `NaiveDate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first day of the week happens to fall just out of range of `NaiveDate`
+    /// (more than ca. 262,000 years away from common era).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap();
+    /// let week = date.week(Weekday::Sun);
+    /// let days: Vec<_> = week.iter_days().map(|d| d.day()).collect();
+    /// assert_eq!(days, [17, 18, 19, 20, 21, 22, 23]);
+    /// assert_eq!(week.iter_days().next().map(|d| d.weekday()), Some(Weekday::Sun));
+    /// ```
+    #[inline]
+    pub fn iter_days(&self) -> Take<NaiveDateDaysIterator> {
+        self.first_day().iter_days().take(7)
+    }
+
+    /// Returns `true` if `date` falls within this week.
+    ///
+    /// Unlike [`days`](NaiveWeek::days) this never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap().week(Weekday::Mon);
+    /// assert!(week.contains(NaiveDate::from_ymd_opt(2022, 4, 24).unwrap()));
+    /// assert!(!week.contains(NaiveDate::from_ymd_opt(2022, 4, 25).unwrap()));
+    /// ```
+    #[must_use]
+    pub const fn contains(&self, date: NaiveDate) -> bool {
+        // The number of days from the first day of the week to `self.date`.
+        let offset = self.date.weekday().days_since(self.start) as i64;
+        let days = date.signed_duration_since(self.date).num_days() + offset;
+        days >= 0 && days < 7
+    }
+
+    /// Returns the number of this week within its year, according to the given numbering system.
+    ///
+    /// The number is that of the first day of this week. If the week starts on the same day as
+    /// the numbering system, all days of the week share the same number, as they do for
+    /// `date.week(Weekday::Mon)` with [`WeekNumbering::Iso`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first day of the week happens to fall just out of range of `NaiveDate`
+    /// (more than ca. 262,000 years away from common era).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::naive::WeekNumbering;
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // Sunday, January 1st, 2023
+    /// let week = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap().week(Weekday::Sun);
+    /// assert_eq!(week.week_of_year(WeekNumbering::Iso), 52);
+    /// assert_eq!(week.week_of_year(WeekNumbering::Sunday), 1);
+    /// assert_eq!(week.week_of_year(WeekNumbering::Monday), 0);
+    /// assert_eq!(week.week_of_year(WeekNumbering::Epiphany), 1);
+    /// ```
+    #[must_use]
+    pub fn week_of_year(&self, numbering: WeekNumbering) -> u32 {
+        let first = self.first_day();
+        match numbering {
+            WeekNumbering::Iso => first.iso_week().week(),
+            WeekNumbering::Sunday => first.weeks_from(Weekday::Sun) as u32,
+            WeekNumbering::Monday => first.weeks_from(Weekday::Mon) as u32,
+            WeekNumbering::Epiphany => {
+                // The first day of the first week of `year`.
+                let first_week = |year| {
+                    let epiphany = NaiveDate::from_ymd_opt(year, 1, 6);
+                    expect(epiphany, "year out of range for `NaiveDate`")
+                        .week(self.start)
+                        .first_day()
+                };
+                let mut start = first_week(first.year());
+                if first < start {
+                    start = first_week(first.year() - 1);
+                } else if first.month() == 12 && first.day() >= 31 - 6 {
+                    let next = first_week(first.year() + 1);
+                    if first >= next {
+                        start = next;
+                    }
+                }
+                (first.signed_duration_since(start).num_days() / 7) as u32 + 1
+            }
+        }
+    }
+}
+
+/// Parsing a `str` into a `NaiveWeek` accepts an ISO week in the format `%G-W%V`, such as
+/// `2024-W07`. The resulting week starts on Monday.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveWeek};
+///
+/// let week: NaiveWeek = "2024-W07".parse().unwrap();
+/// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2024, 2, 12).unwrap());
+/// assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2024, 2, 18).unwrap());
+///
+/// assert!("2024-W00".parse::<NaiveWeek>().is_err());
+/// assert!("2024-07".parse::<NaiveWeek>().is_err());
+/// ```
+impl str::FromStr for NaiveWeek {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<NaiveWeek> {
+        isoweek::parse_iso_week(s).map(|date| NaiveWeek::new(date, Weekday::Mon))
+    }
+}
+
+/// A system for numbering the weeks of a year, for use with [`NaiveWeek::week_of_year`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WeekNumbering {
+    /// The [ISO 8601 week number](./struct.NaiveDate.html#week-date), from 1 to 53, as with `%V`.
+    ///
+    /// Weeks start on Monday, and the first week of the year contains its first Thursday.
+    Iso,
+    /// The week number starting with Sunday, from 0 to 53, as with `%U`.
+    ///
+    /// The first Sunday of the year starts week 1. Days before it are in week 0.
+    Sunday,
+    /// The week number starting with Monday, from 0 to 53, as with `%W`.
+    ///
+    /// The first Monday of the year starts week 1. Days before it are in week 0.
+    Monday,
+    /// The week number in which the first week of the year contains the 6th of January
+    /// (Epiphany), from 1 to 53.
+    ///
+    /// Weeks start on the first day of the [`NaiveWeek`]. Days in January before the first week
+    /// belong to the last week of the previous year.
+    Epiphany,
 }
 
 /// A duration in calendar days.
@@ -213,6 +369,13 @@ impl NaiveWeek {
 /// difference applies only when dealing with `DateTime<TimeZone>` data types and in other cases
 /// `TimeDelta::days(n)` and `Days::new(n)` are equivalent.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(
+    any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"),
+    derive(Archive, Deserialize, Serialize),
+    archive(compare(PartialEq, PartialOrd)),
+    archive_attr(derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash))
+)]
+#[cfg_attr(feature = "rkyv-validation", archive(check_bytes))]
 pub struct Days(pub(crate) u64);
 
 impl Days {
@@ -222,7 +385,32 @@ impl Days {
     }
 }
 
-/// Serialization/Deserialization of `NaiveDateTime` in alternate formats
+#[cfg(feature = "serde")]
+mod days_serde {
+    use super::Days;
+    use serde::{de, ser};
+
+    /// Serialize `Days` as a plain number of days
+    impl ser::Serialize for Days {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_u64(self.0)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Days {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            <u64 as de::Deserialize>::deserialize(deserializer).map(Days)
+        }
+    }
+}
+
+/// Serialization/Deserialization of naive types in alternate formats
 ///
 /// The various modules in here are intended to be used with serde's [`with` annotation] to
 /// serialize as something other than the default ISO 8601 format.
@@ -230,12 +418,16 @@ impl Days {
 /// [`with` annotation]: https://serde.rs/field-attrs.html#with
 #[cfg(feature = "serde")]
 pub mod serde {
+    pub use super::date::serde::ts_days;
     pub use super::datetime::serde::*;
+    pub use super::fields::serde::{date_fields, time_fields};
+    pub use super::time::serde::{nanoseconds_from_midnight, seconds_from_midnight};
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{NaiveDate, Weekday};
+    use super::{NaiveWeek, WeekNumbering};
+    use crate::{Datelike, IsoWeek, NaiveDate, Weekday};
     #[test]
     fn test_naiveweek() {
         let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
@@ -278,4 +470,106 @@ mod test {
         let _ = date_min.week(Weekday::Mon).checked_days();
         let _ = date_max.week(Weekday::Mon).checked_days();
     }
+
+    #[test]
+    fn test_naiveweek_iter_days_contains() {
+        let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
+        for start in [Weekday::Mon, Weekday::Wed, Weekday::Sun] {
+            let week = date.week(start);
+            let days: Vec<_> = week.iter_days().collect();
+            assert_eq!(days.len(), 7);
+            assert_eq!(days[0], week.first_day());
+            assert_eq!(days[6], week.last_day());
+            for day in week.first_day().pred_opt().unwrap().iter_days().take(9) {
+                assert_eq!(week.contains(day), week.days().contains(&day));
+            }
+        }
+
+        let week = NaiveDate::MAX.week(Weekday::Mon);
+        assert!(week.iter_days().all(|day| week.contains(day)));
+        assert!(week.contains(NaiveDate::MAX));
+        assert!(!week.contains(NaiveDate::MIN));
+        assert!(NaiveDate::MIN.week(Weekday::Sun).contains(NaiveDate::MIN));
+    }
+
+    #[test]
+    fn test_naiveweek_week_of_year() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // (date, start, iso, sunday, monday, epiphany)
+        let asserts = [
+            (ymd(2023, 1, 1), Weekday::Sun, 52, 1, 0, 1),
+            (ymd(2023, 1, 1), Weekday::Mon, 52, 52, 52, 52),
+            (ymd(2023, 1, 2), Weekday::Mon, 1, 1, 1, 1),
+            (ymd(2021, 1, 6), Weekday::Thu, 53, 52, 52, 1),
+            (ymd(2021, 1, 7), Weekday::Thu, 1, 1, 1, 2),
+            (ymd(2024, 12, 30), Weekday::Mon, 1, 52, 53, 53),
+            (ymd(2019, 12, 31), Weekday::Tue, 1, 52, 52, 1),
+        ];
+        for (date, start, iso, sunday, monday, epiphany) in asserts {
+            let week = date.week(start);
+            assert_eq!(week.week_of_year(WeekNumbering::Iso), iso, "{:?}", date);
+            assert_eq!(week.week_of_year(WeekNumbering::Sunday), sunday, "{:?}", date);
+            assert_eq!(week.week_of_year(WeekNumbering::Monday), monday, "{:?}", date);
+            assert_eq!(week.week_of_year(WeekNumbering::Epiphany), epiphany, "{:?}", date);
+        }
+    }
+
+    #[test]
+    fn test_naiveweek_from_str() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let cases = [
+            ("2024-W07", ymd(2024, 2, 12), ymd(2024, 2, 18)),
+            ("2015-W53", ymd(2015, 12, 28), ymd(2016, 1, 3)),
+            ("2025-W01", ymd(2024, 12, 30), ymd(2025, 1, 5)),
+            ("-0001-W52", ymd(-1, 12, 27), ymd(0, 1, 2)),
+            ("+10000-W52", ymd(10000, 12, 25), ymd(10000, 12, 31)),
+        ];
+        for (s, first, last) in cases {
+            let week = s.parse::<NaiveWeek>().unwrap();
+            assert_eq!((week.first_day(), week.last_day()), (first, last), "{}", s);
+            let iso_week = s.parse::<IsoWeek>().unwrap();
+            assert_eq!(iso_week, first.iso_week());
+            assert_eq!((iso_week.first_day(), iso_week.last_day()), (first, last), "{}", s);
+            #[cfg(feature = "alloc")]
+            assert_eq!(iso_week.to_string(), s);
+        }
+
+        for s in ["2024-W00", "2024-W53", "2024-W7x", "2024-7", "2024-W07-1"] {
+            assert!(s.parse::<NaiveWeek>().is_err(), "{}", s);
+            assert!(s.parse::<IsoWeek>().is_err(), "{}", s);
+        }
+
+        // the first and last supported weeks are only partially in range
+        let min = NaiveDate::MIN.iso_week();
+        assert_eq!(format!("{:?}", min).parse(), Ok(min));
+        assert_eq!(min.checked_first_day(), None);
+        let max = NaiveDate::MAX.iso_week();
+        assert_eq!(format!("{:?}", max).parse(), Ok(max));
+        assert_eq!(max.checked_last_day(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_days() {
+        use crate::Days;
+
+        assert_eq!(serde_json::to_string(&Days::new(7)).unwrap(), "7");
+        assert_eq!(serde_json::from_str::<Days>("7").unwrap(), Days::new(7));
+        assert_eq!(
+            serde_json::from_str::<Days>("18446744073709551615").unwrap(),
+            Days::new(u64::MAX)
+        );
+        assert!(serde_json::from_str::<Days>("-7").is_err());
+        assert!(serde_json::from_str::<Days>("1.5").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "rkyv-validation")]
+    fn test_rkyv_validation() {
+        use crate::Days;
+
+        let days = Days::new(u64::MAX);
+        let bytes = rkyv::to_bytes::<_, 8>(&days).unwrap();
+        assert_eq!(rkyv::from_bytes::<Days>(&bytes).unwrap(), days);
+    }
 }
@@ -0,0 +1,191 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Validated day-of-year and week-of-year numbers.
+
+use core::fmt;
+
+use super::IsoWeek;
+use crate::OutOfRange;
+
+/// A day of the year, from 1 to 366.
+///
+/// Most methods of chrono take and return the day of the year as a plain `u32`, which is easy
+/// to mix up with other numbers such as a week or a day of the month. This type can be used
+/// instead with [`NaiveDate::from_year_ordinal`] and [`NaiveDate::day_of_year`].
+///
+/// An `Ordinal` is only checked to be in the range 1 to 366. Whether day 366 exists depends on
+/// the year, and is checked when making a date.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Ordinal};
+///
+/// let ordinal = Ordinal::new(256).unwrap();
+/// let date = NaiveDate::from_year_ordinal(2024, ordinal).unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 9, 12).unwrap());
+/// assert_eq!(date.day_of_year(), ordinal);
+///
+/// assert_eq!(Ordinal::new(0), None);
+/// assert_eq!(Ordinal::try_from(367u16).ok(), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ordinal(u16);
+
+impl Ordinal {
+    /// Makes a new `Ordinal` from a day of the year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `ordinal` is not in the range 1 to 366.
+    #[must_use]
+    pub const fn new(ordinal: u32) -> Option<Ordinal> {
+        match ordinal {
+            1..=366 => Some(Ordinal(ordinal as u16)),
+            _ => None,
+        }
+    }
+
+    /// Returns the day of the year, starting from 1.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl TryFrom<u16> for Ordinal {
+    type Error = OutOfRange;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ordinal::new(u32::from(value)).ok_or(OutOfRange::new())
+    }
+}
+
+impl From<Ordinal> for u16 {
+    fn from(ordinal: Ordinal) -> u16 {
+        ordinal.0
+    }
+}
+
+impl fmt::Display for Ordinal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A week of the year, from 1 to 53.
+///
+/// This is the week number of an [ISO 8601 week](IsoWeek), as a type that can not be mixed up
+/// with other numbers such as the day of the year. It can be used with
+/// [`NaiveDate::from_iso_year_week`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, WeekOfYear, Weekday};
+///
+/// let week = WeekOfYear::new(53).unwrap();
+/// let date = NaiveDate::from_iso_year_week(2020, week, Weekday::Fri).unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2021, 1, 1).unwrap());
+/// assert_eq!(WeekOfYear::from(date.iso_week()), week);
+///
+/// // 2021 has only 52 ISO weeks.
+/// assert_eq!(NaiveDate::from_iso_year_week(2021, week, Weekday::Fri), None);
+/// assert_eq!(WeekOfYear::new(54), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WeekOfYear(u8);
+
+impl WeekOfYear {
+    /// Makes a new `WeekOfYear` from a week number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `week` is not in the range 1 to 53.
+    #[must_use]
+    pub const fn new(week: u32) -> Option<WeekOfYear> {
+        match week {
+            1..=53 => Some(WeekOfYear(week as u8)),
+            _ => None,
+        }
+    }
+
+    /// Returns the week number, starting from 1.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl TryFrom<u8> for WeekOfYear {
+    type Error = OutOfRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        WeekOfYear::new(u32::from(value)).ok_or(OutOfRange::new())
+    }
+}
+
+impl From<WeekOfYear> for u8 {
+    fn from(week: WeekOfYear) -> u8 {
+        week.0
+    }
+}
+
+impl From<IsoWeek> for WeekOfYear {
+    fn from(week: IsoWeek) -> WeekOfYear {
+        WeekOfYear(week.week() as u8)
+    }
+}
+
+impl fmt::Display for WeekOfYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ordinal, WeekOfYear};
+    use crate::{Datelike, NaiveDate, OutOfRange, Weekday};
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(Ordinal::new(1).map(|o| o.get()), Some(1));
+        assert_eq!(Ordinal::new(366).map(u16::from), Some(366));
+        assert_eq!(Ordinal::new(0), None);
+        assert_eq!(Ordinal::new(367), None);
+        assert_eq!(Ordinal::try_from(0u16), Err(OutOfRange::new()));
+        assert_eq!(Ordinal::try_from(42u16).unwrap().to_string(), "42");
+
+        let day_366 = Ordinal::new(366).unwrap();
+        assert_eq!(
+            NaiveDate::from_year_ordinal(2024, day_366),
+            NaiveDate::from_ymd_opt(2024, 12, 31)
+        );
+        assert_eq!(NaiveDate::from_year_ordinal(2023, day_366), None);
+        assert_eq!(NaiveDate::MAX.day_of_year().get(), NaiveDate::MAX.ordinal());
+        assert_eq!(NaiveDate::MIN.day_of_year(), Ordinal::new(1).unwrap());
+    }
+
+    #[test]
+    fn test_week_of_year() {
+        assert_eq!(WeekOfYear::new(1).map(u8::from), Some(1));
+        assert_eq!(WeekOfYear::new(53).map(|w| w.get()), Some(53));
+        assert_eq!(WeekOfYear::new(0), None);
+        assert_eq!(WeekOfYear::new(54), None);
+        assert_eq!(WeekOfYear::try_from(54u8), Err(OutOfRange::new()));
+        assert_eq!(WeekOfYear::try_from(7u8).unwrap().to_string(), "7");
+
+        let week = WeekOfYear::new(1).unwrap();
+        let date = NaiveDate::from_iso_year_week(2025, week, Weekday::Mon).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+        assert_eq!(WeekOfYear::from(date.iso_week()), week);
+        assert_eq!(
+            WeekOfYear::from(NaiveDate::MAX.iso_week()).get(),
+            NaiveDate::MAX.iso_week().week()
+        );
+    }
+}
@@ -15,14 +15,17 @@ use rkyv::{Archive, Deserialize, Serialize};
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{
-    Fixed, Item, Numeric, Pad, ParseError, ParseResult, Parsed, StrftimeItems, parse,
-    parse_and_remainder, write_hundreds,
+    Fixed, Item, Numeric, Pad, ParseError, ParseOptions, ParseResult, Parsed, StrftimeItems, parse,
+    parse_and_remainder, parse_with_options, write_hundreds,
 };
-use crate::{FixedOffset, TimeDelta, Timelike};
+#[cfg(feature = "unstable-locales")]
+use crate::format::{Locale, parse_and_remainder_localized, parse_localized};
+use crate::naive::{DateFields, Field, FieldError, TimeFields};
+use crate::{CalendarFields, FixedOffset, OutOfRange, TimeDelta, Timelike};
 use crate::{expect, try_opt};
 
 #[cfg(feature = "serde")]
-mod serde;
+pub(super) mod serde;
 
 #[cfg(test)]
 mod tests;
@@ -436,6 +439,110 @@ impl NaiveTime {
         Some(NaiveTime { secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from plain [`TimeFields`], reporting which field is invalid.
+    ///
+    /// This is meant for validating user input. See
+    /// [`from_hms_nano_opt`](#method.from_hms_nano_opt) for a constructor that returns an
+    /// `Option`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FieldError`] labeled with the first field that is out of range. The nanosecond
+    /// field may exceed 1,000,000,000 for a [leap second](#leap-second-handling), but only when
+    /// the second is 59.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::naive::{Field, TimeFields};
+    /// use chrono::NaiveTime;
+    ///
+    /// let time = NaiveTime::try_from_fields(TimeFields {
+    ///     hour: 23,
+    ///     minute: 56,
+    ///     second: 4,
+    ///     nanosecond: 12_345_678,
+    /// });
+    /// assert_eq!(time, Ok(NaiveTime::from_hms_nano_opt(23, 56, 4, 12_345_678).unwrap()));
+    ///
+    /// let fields = TimeFields { hour: 24, minute: 0, second: 0, nanosecond: 0 };
+    /// assert_eq!(NaiveTime::try_from_fields(fields).unwrap_err().field(), Field::Hour);
+    /// ```
+    pub const fn try_from_fields(fields: TimeFields) -> Result<NaiveTime, FieldError> {
+        let TimeFields { hour, minute, second, nanosecond } = fields;
+        let field = if hour >= 24 {
+            Field::Hour
+        } else if minute >= 60 {
+            Field::Minute
+        } else if second >= 60 {
+            Field::Second
+        } else {
+            match NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond) {
+                Some(time) => return Ok(time),
+                None => Field::Nanosecond,
+            }
+        };
+        Err(FieldError::new(field))
+    }
+
+    /// Asserts that `hour`, `min` and `sec` form a valid `NaiveTime`.
+    ///
+    /// This is meant for checking times at compile time in a `const` item, see
+    /// [`NaiveDate::assert_ymd_valid`](crate::NaiveDate::assert_ymd_valid).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hour` is 24 or more, or `min` or `sec` is 60 or more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// const _: () = NaiveTime::assert_hms_valid(23, 59, 59);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use chrono::NaiveTime;
+    /// const _: () = NaiveTime::assert_hms_valid(24, 0, 0);
+    /// ```
+    pub const fn assert_hms_valid(hour: u32, min: u32, sec: u32) {
+        NaiveTime::assert_hms_nano_valid(hour, min, sec, 0)
+    }
+
+    /// Asserts that `hour`, `min`, `sec` and `nano` form a valid `NaiveTime`.
+    ///
+    /// The nanosecond part may exceed 1,000,000,000 for a [leap second](#leap-second-handling),
+    /// but only when `sec == 59`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hour` is 24 or more, `min` or `sec` is 60 or more, or `nano` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// const _: () = NaiveTime::assert_hms_nano_valid(23, 59, 59, 1_999_999_999);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use chrono::NaiveTime;
+    /// const _: () = NaiveTime::assert_hms_nano_valid(23, 59, 58, 1_000_000_000);
+    /// ```
+    pub const fn assert_hms_nano_valid(hour: u32, min: u32, sec: u32, nano: u32) {
+        let fields = TimeFields { hour, minute: min, second: sec, nanosecond: nano };
+        if let Err(e) = NaiveTime::try_from_fields(fields) {
+            match e.field() {
+                Field::Hour => panic!("hour is not between 0 and 23"),
+                Field::Minute => panic!("minute is not between 0 and 59"),
+                Field::Second => panic!("second is not between 0 and 59"),
+                _ => panic!("nanosecond is out of range"),
+            }
+        }
+    }
+
     /// Makes a new `NaiveTime` from the number of seconds since midnight and nanosecond.
     ///
     /// The nanosecond part is allowed to exceed 1,000,000,000 in order to represent a
@@ -556,6 +663,56 @@ impl NaiveTime {
         parsed.to_naive_time()
     }
 
+    /// Parses a string with the specified format string and parsing options into a new
+    /// `NaiveTime` value.
+    ///
+    /// This can be used to parse more leniently, or to limit the size of the input. See
+    /// [`ParseOptions`] for the available options, and [`parse_from_str`](#method.parse_from_str)
+    /// for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::ParseOptions;
+    /// use chrono::NaiveTime;
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let parse = |s| NaiveTime::parse_from_str_with_options(s, "%Hh%Mm", &options);
+    /// assert_eq!(parse("23H56M"), Ok(NaiveTime::from_hms_opt(23, 56, 0).unwrap()));
+    /// ```
+    pub fn parse_from_str_with_options(
+        s: &str,
+        fmt: &str,
+        options: &ParseOptions,
+    ) -> ParseResult<NaiveTime> {
+        let mut parsed = Parsed::new();
+        parse_with_options(&mut parsed, s, StrftimeItems::new(fmt), options)?;
+        parsed.to_naive_time()
+    }
+
+    /// Parses a string with the specified format string and locale into a new `NaiveTime` value.
+    ///
+    /// AM/PM and the localized `%X` and `%r` formats are read in the form of `locale`.
+    /// See [`parse_from_str`](#method.parse_from_str) for other details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::NaiveTime;
+    ///
+    /// let parse = NaiveTime::parse_from_str_localized;
+    /// let time = NaiveTime::from_hms_opt(13, 4, 5).unwrap();
+    /// assert_eq!(parse("오후 01시 04분 05초", "%r", Locale::ko_KR), Ok(time));
+    /// assert_eq!(parse("13:04:05", "%X", Locale::de_DE), Ok(time));
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(s: &str, fmt: &str, locale: Locale) -> ParseResult<NaiveTime> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_naive_time()
+    }
+
     /// Parses a string from a user-specified format into a new `NaiveTime` value, and a slice with
     /// the remaining portion of the string.
     /// See the [`format::strftime` module](crate::format::strftime)
@@ -578,6 +735,35 @@ impl NaiveTime {
         parsed.to_naive_time().map(|t| (t, remainder))
     }
 
+    /// Parses a string with the specified format string and locale into a new `NaiveTime` value,
+    /// and a slice with the remaining portion of the string.
+    ///
+    /// Similar to [`parse_from_str_localized`](#method.parse_from_str_localized).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::format::Locale;
+    /// use chrono::NaiveTime;
+    ///
+    /// let (time, remainder) =
+    ///     NaiveTime::parse_and_remainder_localized("01:04 PM ET", "%I:%M %p", Locale::en_US)
+    ///         .unwrap();
+    /// assert_eq!(time, NaiveTime::from_hms_opt(13, 4, 0).unwrap());
+    /// assert_eq!(remainder, " ET");
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_and_remainder_localized<'a>(
+        s: &'a str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<(NaiveTime, &'a str)> {
+        let mut parsed = Parsed::new();
+        let items = StrftimeItems::new_with_locale(fmt, locale);
+        let remainder = parse_and_remainder_localized(&mut parsed, s, items, locale)?;
+        parsed.to_naive_time().map(|t| (t, remainder))
+    }
+
     /// Adds given `TimeDelta` to the current time, and also returns the number of *seconds*
     /// in the integral number of days ignored from the addition.
     ///
@@ -897,6 +1083,39 @@ impl NaiveTime {
     /// The earliest possible `NaiveTime`
     pub const MIN: Self = Self { secs: 0, frac: 0 };
     pub(super) const MAX: Self = Self { secs: 23 * 3600 + 59 * 60 + 59, frac: 999_999_999 };
+
+    /// Returns a hash of the time that is the same on every platform and in every version of
+    /// chrono, see [`NaiveDate::stable_hash`].
+    ///
+    /// It is the 64-bit FNV-1a hash of the number of seconds since midnight and the nanoseconds
+    /// (including the leap second representation), each as a little-endian `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let time = NaiveTime::from_hms_milli_opt(13, 4, 5, 678).unwrap();
+    /// assert_eq!(time.stable_hash(), 0x7988_6001_79b8_34df);
+    /// ```
+    #[must_use]
+    pub const fn stable_hash(&self) -> u64 {
+        crate::stable_hash(&[self.num_seconds_from_midnight() as i64, self.frac as i64])
+    }
+}
+
+impl CalendarFields for NaiveTime {
+    fn date_fields(&self) -> Option<DateFields> {
+        None
+    }
+
+    fn time_fields(&self) -> Option<TimeFields> {
+        Some(TimeFields::from(*self))
+    }
+
+    fn utc_offset(&self) -> Option<FixedOffset> {
+        None
+    }
 }
 
 impl Timelike for NaiveTime {
@@ -1636,6 +1855,28 @@ impl str::FromStr for NaiveTime {
 /// let default_time = NaiveTime::default();
 /// assert_eq!(default_time, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
 /// ```
+impl TryFrom<(u32, u32, u32, u32)> for NaiveTime {
+    type Error = OutOfRange;
+
+    /// Makes a `NaiveTime` from an `(hour, minute, second, nanosecond)` tuple, see
+    /// [`NaiveTime::from_hms_nano_opt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(
+    ///     NaiveTime::try_from((23, 56, 4, 12_345_678)),
+    ///     Ok(NaiveTime::from_hms_nano_opt(23, 56, 4, 12_345_678).unwrap())
+    /// );
+    /// assert!(NaiveTime::try_from((24, 0, 0, 0)).is_err());
+    /// ```
+    fn try_from((hour, min, sec, nano): (u32, u32, u32, u32)) -> Result<Self, Self::Error> {
+        NaiveTime::from_hms_nano_opt(hour, min, sec, nano).ok_or(OutOfRange::new())
+    }
+}
+
 impl Default for NaiveTime {
     fn default() -> Self {
         NaiveTime::from_hms_opt(0, 0, 0).unwrap()
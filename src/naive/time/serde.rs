@@ -40,6 +40,178 @@ impl<'de> de::Deserialize<'de> for NaiveTime {
     }
 }
 
+/// Ser/de a `NaiveTime` to/from the number of seconds since midnight.
+///
+/// Intended for use with `serde`'s `with` attribute. Serialization drops any fractional seconds.
+///
+/// # Example
+///
+/// ```rust
+/// # use chrono::NaiveTime;
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::naive::serde::seconds_from_midnight;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "seconds_from_midnight")]
+///     time: NaiveTime,
+/// }
+///
+/// let time = NaiveTime::from_hms_opt(13, 4, 5).unwrap();
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":47045}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod seconds_from_midnight {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use crate::NaiveTime;
+    use crate::serde::invalid_ts;
+
+    /// Serialize a `NaiveTime` into the number of whole seconds since midnight.
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_u32(time.num_seconds_from_midnight())
+    }
+
+    /// Deserialize a `NaiveTime` from the number of seconds since midnight.
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<NaiveTime, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_u32(SecondsVisitor)
+    }
+
+    struct SecondsVisitor;
+
+    impl de::Visitor<'_> for SecondsVisitor {
+        type Value = NaiveTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a number of seconds since midnight")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u32::try_from(value).map_err(|_| invalid_ts(value)).and_then(|v| self.visit_u32(v))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u32::try_from(value).map_err(|_| invalid_ts(value)).and_then(|v| self.visit_u32(v))
+        }
+
+        fn visit_u32<E>(self, value: u32) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            NaiveTime::from_num_seconds_from_midnight_opt(value, 0).ok_or_else(|| invalid_ts(value))
+        }
+    }
+}
+
+/// Ser/de a `NaiveTime` to/from the number of nanoseconds since midnight.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// A leap second at the end of the day is represented by values from 86,400,000,000,000 up to
+/// 86,400,999,999,999. Other leap seconds serialize to the same value as the second that follows
+/// them.
+///
+/// # Example
+///
+/// ```rust
+/// # use chrono::NaiveTime;
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::naive::serde::nanoseconds_from_midnight;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "nanoseconds_from_midnight")]
+///     time: NaiveTime,
+/// }
+///
+/// let time = NaiveTime::from_hms_nano_opt(13, 4, 5, 678).unwrap();
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":47045000000678}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod nanoseconds_from_midnight {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use crate::NaiveTime;
+    use crate::serde::invalid_ts;
+
+    /// Serialize a `NaiveTime` into the number of nanoseconds since midnight.
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let secs = u64::from(time.num_seconds_from_midnight());
+        serializer.serialize_u64(secs * 1_000_000_000 + u64::from(time.nanosecond()))
+    }
+
+    /// Deserialize a `NaiveTime` from the number of nanoseconds since midnight.
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D>(d: D) -> Result<NaiveTime, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_u64(NanoSecondsVisitor)
+    }
+
+    struct NanoSecondsVisitor;
+
+    impl de::Visitor<'_> for NanoSecondsVisitor {
+        type Value = NaiveTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a number of nanoseconds since midnight")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u64::try_from(value).map_err(|_| invalid_ts(value)).and_then(|v| self.visit_u64(v))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // values past the end of the day are a leap second at 23:59:59
+            let secs = (value / 1_000_000_000).min(86_399);
+            let nanos = value - secs * 1_000_000_000;
+            u32::try_from(nanos)
+                .ok()
+                .and_then(|nanos| NaiveTime::from_num_seconds_from_midnight_opt(secs as u32, nanos))
+                .ok_or_else(|| invalid_ts(value))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::NaiveTime;
@@ -140,4 +312,46 @@ mod tests {
         let decoded: NaiveTime = deserialize(&encoded).unwrap();
         assert_eq!(t, decoded);
     }
+
+    #[test]
+    fn test_serde_time_from_midnight() {
+        #[derive(Debug, PartialEq, serde_derive::Deserialize, serde_derive::Serialize)]
+        struct S {
+            #[serde(with = "super::seconds_from_midnight")]
+            secs: NaiveTime,
+            #[serde(with = "super::nanoseconds_from_midnight")]
+            nanos: NaiveTime,
+        }
+
+        let hms = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+        let cases = [
+            (hms(0, 0, 0, 0), 0i64, 0u64),
+            (hms(13, 4, 5, 0), 47_045, 47_045_000_000_000),
+            (hms(23, 59, 59, 999_999_999), 86_399, 86_399_999_999_999),
+            (hms(23, 59, 59, 1_250_000_000), 86_399, 86_400_250_000_000),
+        ];
+        for (time, secs, nanos) in cases {
+            let json = format!(r#"{{"secs":{},"nanos":{}}}"#, secs, nanos);
+            assert_eq!(serde_json::to_string(&S { secs: time, nanos: time }).unwrap(), json);
+            let s = serde_json::from_str::<S>(&json).unwrap();
+            assert_eq!(s.secs, hms(0, 0, 0, 0) + crate::TimeDelta::seconds(secs));
+            assert_eq!(s.nanos, time);
+
+            let encoded = bincode::serialize(&S { secs: time, nanos: time }).unwrap();
+            assert_eq!(bincode::deserialize::<S>(&encoded).unwrap().nanos, time);
+        }
+
+        // fractional seconds are dropped
+        let s = serde_json::to_string(&S { secs: hms(1, 0, 0, 5), nanos: hms(1, 0, 0, 5) });
+        assert_eq!(s.unwrap(), r#"{"secs":3600,"nanos":3600000000005}"#);
+
+        for json in [
+            r#"{"secs":86400,"nanos":0}"#,
+            r#"{"secs":-1,"nanos":0}"#,
+            r#"{"secs":0,"nanos":86401000000000}"#,
+            r#"{"secs":0,"nanos":-1}"#,
+        ] {
+            assert!(serde_json::from_str::<S>(json).is_err(), "{}", json);
+        }
+    }
 }
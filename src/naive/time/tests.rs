@@ -20,6 +20,22 @@ fn test_time_from_hms_milli() {
     assert_eq!(NaiveTime::from_hms_milli_opt(3, 5, 59, u32::MAX), None);
 }
 
+#[test]
+fn test_time_try_from_tuple() {
+    assert_eq!(
+        NaiveTime::try_from((3, 5, 7, 9)),
+        Ok(NaiveTime::from_hms_nano_opt(3, 5, 7, 9).unwrap())
+    );
+    // leap second
+    assert_eq!(
+        NaiveTime::try_from((23, 59, 59, 1_999_999_999)),
+        Ok(NaiveTime::from_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap())
+    );
+    assert!(NaiveTime::try_from((3, 5, 7, 2_000_000_000)).is_err());
+    assert!(NaiveTime::try_from((3, 5, 60, 0)).is_err());
+    assert!(NaiveTime::try_from((24, 0, 0, 0)).is_err());
+}
+
 #[test]
 fn test_time_from_hms_micro() {
     assert_eq!(
@@ -391,3 +407,28 @@ fn test_rkyv_validation() {
     let bytes = rkyv::to_bytes::<_, 8>(&t_max).unwrap();
     assert_eq!(rkyv::from_bytes::<NaiveTime>(&bytes).unwrap(), t_max);
 }
+
+#[test]
+fn test_time_assert_hms_valid() {
+    const _: () = NaiveTime::assert_hms_valid(0, 0, 0);
+    const _: () = NaiveTime::assert_hms_nano_valid(23, 59, 59, 1_999_999_999);
+    NaiveTime::assert_hms_valid(23, 59, 59);
+}
+
+#[test]
+#[should_panic(expected = "hour is not between 0 and 23")]
+fn test_time_assert_hms_valid_hour() {
+    NaiveTime::assert_hms_valid(24, 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "second is not between 0 and 59")]
+fn test_time_assert_hms_valid_second() {
+    NaiveTime::assert_hms_valid(12, 0, 60);
+}
+
+#[test]
+#[should_panic(expected = "nanosecond is out of range")]
+fn test_time_assert_hms_nano_valid_leap() {
+    NaiveTime::assert_hms_nano_valid(12, 0, 58, 1_000_000_000);
+}
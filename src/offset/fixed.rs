@@ -113,6 +113,9 @@ impl FixedOffset {
     pub const fn utc_minus_local(&self) -> i32 {
         -self.local_minus_utc
     }
+
+    /// The offset of UTC itself, `+00:00`.
+    pub(crate) const UTC: FixedOffset = FixedOffset { local_minus_utc: 0 };
 }
 
 /// Parsing a `str` into a `FixedOffset` uses the format [`%z`](crate::format::strftime).
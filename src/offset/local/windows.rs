@@ -228,7 +228,7 @@ mod tests {
         //
         // This uses parts of a previous Windows `Local` implementation in chrono.
         fn from_local_time(dt: &NaiveDateTime) -> DateTime<Local> {
-            let st = system_time_from_naive_date_time(dt);
+            let st = system_time_to_calendar_date_time(dt);
             let utc_time = local_to_utc_time(&st);
             let utc_secs = system_time_as_unix_seconds(&utc_time);
             let local_secs = system_time_as_unix_seconds(&st);
@@ -236,7 +236,7 @@ mod tests {
             let offset = FixedOffset::east_opt(offset).unwrap();
             DateTime::from_naive_utc_and_offset(*dt - offset, offset)
         }
-        fn system_time_from_naive_date_time(dt: &NaiveDateTime) -> SYSTEMTIME {
+        fn system_time_to_calendar_date_time(dt: &NaiveDateTime) -> SYSTEMTIME {
             SYSTEMTIME {
                 // Valid values: 1601-30827
                 wYear: dt.year() as u16,
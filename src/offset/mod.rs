@@ -18,7 +18,10 @@
 //! and provides implementations for 1 and 3.
 //! An `TimeZone` instance can be reconstructed from the corresponding `Offset` instance.
 
-use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::iter::FusedIterator;
+use core::{cmp, fmt};
 
 use crate::Weekday;
 use crate::format::{ParseResult, Parsed, StrftimeItems, parse};
@@ -37,6 +40,9 @@ pub use self::local::Local;
 pub(crate) mod utc;
 pub use self::utc::Utc;
 
+#[cfg(feature = "alloc")]
+mod vtimezone;
+
 /// The result of mapping a local time to a concrete instant in a given time zone.
 ///
 /// The calculation to go from a local time (wall clock time) to an instant in UTC can end up in
@@ -280,6 +286,12 @@ pub trait TimeZone: Sized + Clone {
     /// An associated offset type.
     /// This type is used to store the actual offset in date and time types.
     /// The original `TimeZone` value can be recovered via `TimeZone::from_offset`.
+    ///
+    /// Every [`DateTime`] stores one `Offset` next to its [`NaiveDateTime`], so its size adds
+    /// directly to the size of each value. For a time zone with many rules, the offset does not
+    /// need to hold the rules themselves: it can be a small handle, such as an index into a
+    /// static table of zones or an `Arc` to shared zone data, from which both [`Offset::fix`]
+    /// and `from_offset` look up what they need.
     type Offset: Offset;
 
     /// Make a new `DateTime` from year, month, day, time components and current time zone.
@@ -303,6 +315,148 @@ pub trait TimeZone: Sized + Clone {
         }
     }
 
+    /// Make a new `DateTime` from year, month, day, time components including milliseconds and
+    /// current time zone.
+    ///
+    /// The millisecond part is allowed to exceed 1,000 in order to represent a [leap second](
+    /// crate::NaiveTime#leap-second-handling), but only when `sec == 59`.
+    ///
+    /// Returns `MappedLocalTime::None` on invalid input data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms_milli(2015, 6, 3, 12, 34, 56, 789).unwrap();
+    /// assert_eq!(dt.to_string(), "2015-06-03 12:34:56.789 UTC");
+    /// assert!(Utc.with_ymd_and_hms_milli(2015, 6, 3, 12, 34, 56, 1_789).single().is_none());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn with_ymd_and_hms_milli(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        milli: u32,
+    ) -> MappedLocalTime<DateTime<Self>> {
+        match NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|d| d.and_hms_milli_opt(hour, min, sec, milli))
+        {
+            Some(dt) => self.from_local_datetime(&dt),
+            None => MappedLocalTime::None,
+        }
+    }
+
+    /// Make a new `DateTime` from year, month, day, time components including microseconds and
+    /// current time zone.
+    ///
+    /// The microsecond part is allowed to exceed 1,000,000 in order to represent a [leap second](
+    /// crate::NaiveTime#leap-second-handling), but only when `sec == 59`.
+    ///
+    /// Returns `MappedLocalTime::None` on invalid input data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms_micro(2015, 6, 3, 12, 34, 56, 789_012).unwrap();
+    /// assert_eq!(dt.to_string(), "2015-06-03 12:34:56.789012 UTC");
+    /// assert!(Utc.with_ymd_and_hms_micro(2015, 6, 3, 12, 34, 56, 1_789_012).single().is_none());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn with_ymd_and_hms_micro(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        micro: u32,
+    ) -> MappedLocalTime<DateTime<Self>> {
+        match NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|d| d.and_hms_micro_opt(hour, min, sec, micro))
+        {
+            Some(dt) => self.from_local_datetime(&dt),
+            None => MappedLocalTime::None,
+        }
+    }
+
+    /// Make a new `DateTime` from year, month, day, time components including nanoseconds and
+    /// current time zone.
+    ///
+    /// The nanosecond part is allowed to exceed 1,000,000,000 in order to represent a [leap
+    /// second](crate::NaiveTime#leap-second-handling), but only when `sec == 59`.
+    ///
+    /// Returns `MappedLocalTime::None` on invalid input data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms_nano(2015, 6, 3, 12, 34, 56, 789_012_345).unwrap();
+    /// assert_eq!(dt.to_string(), "2015-06-03 12:34:56.789012345 UTC");
+    /// assert!(Utc.with_ymd_and_hms_nano(2015, 6, 3, 12, 34, 56, 1_789_012_345).single().is_none());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn with_ymd_and_hms_nano(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        nano: u32,
+    ) -> MappedLocalTime<DateTime<Self>> {
+        match NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|d| d.and_hms_nano_opt(hour, min, sec, nano))
+        {
+            Some(dt) => self.from_local_datetime(&dt),
+            None => MappedLocalTime::None,
+        }
+    }
+
+    /// Make a new `DateTime` from ISO week date (year, week number and day of the week), time
+    /// components and current time zone.
+    ///
+    /// This assumes the proleptic Gregorian calendar, with the year 0 being 1 BCE.
+    /// The resulting `DateTime` may have a different year from the input year.
+    ///
+    /// Returns `MappedLocalTime::None` on invalid input data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc, Weekday};
+    ///
+    /// let dt = Utc.isoywd_and_hms(2015, 1, Weekday::Mon, 12, 0, 0).unwrap();
+    /// assert_eq!(dt, Utc.with_ymd_and_hms(2014, 12, 29, 12, 0, 0).unwrap());
+    /// assert!(Utc.isoywd_and_hms(2015, 54, Weekday::Mon, 12, 0, 0).single().is_none());
+    /// ```
+    fn isoywd_and_hms(
+        &self,
+        year: i32,
+        week: u32,
+        weekday: Weekday,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> MappedLocalTime<DateTime<Self>> {
+        match NaiveDate::from_isoywd_opt(year, week, weekday)
+            .and_then(|d| d.and_hms_opt(hour, min, sec))
+        {
+            Some(dt) => self.from_local_datetime(&dt),
+            None => MappedLocalTime::None,
+        }
+    }
+
     /// Makes a new `Date` from year, month, day and the current time zone.
     /// This assumes the proleptic Gregorian calendar, with the year 0 being 1 BCE.
     ///
@@ -593,11 +747,138 @@ pub trait TimeZone: Sized + Clone {
     fn from_utc_datetime(&self, utc: &NaiveDateTime) -> DateTime<Self> {
         DateTime::from_naive_utc_and_offset(*utc, self.offset_from_utc_datetime(utc))
     }
+
+    /// Returns an iterator over the changes in UTC offset of this time zone during the given
+    /// year, such as the start and end of daylight saving time.
+    ///
+    /// Each item is the instant of the transition, and the offsets in effect just before and at
+    /// that instant. The year is taken in UTC, and transitions are found by sampling the offset
+    /// once a day, so two transitions within the same day that cancel each other out are missed.
+    ///
+    /// The iterator is empty if `year` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// assert_eq!(tz.transitions_in_year(2024).count(), 0);
+    /// ```
+    fn transitions_in_year(&self, year: i32) -> Transitions<'_, Self> {
+        Transitions::new(self, year)
+    }
+
+    /// Returns the rules of this time zone for the given range of years as an iCalendar
+    /// `VTIMEZONE` component, as defined in [RFC 5545](https://www.rfc-editor.org/rfc/rfc5545).
+    ///
+    /// Each transition found by [`transitions_in_year`](TimeZone::transitions_in_year) becomes an
+    /// observance with its own `DTSTART`, instead of being encoded as a recurrence rule. A
+    /// transition to a larger offset is written as a `DAYLIGHT` observance, any other transition as
+    /// a `STANDARD` observance. Time zones without transitions get a single `STANDARD` observance.
+    ///
+    /// `tzid` is written as the `TZID` property. Lines are terminated with CRLF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    /// assert_eq!(
+    ///     tz.to_vtimezone("Asia/Kolkata", 2024..=2025),
+    ///     "BEGIN:VTIMEZONE\r\n\
+    ///      TZID:Asia/Kolkata\r\n\
+    ///      BEGIN:STANDARD\r\n\
+    ///      DTSTART:19700101T000000\r\n\
+    ///      TZOFFSETFROM:+0530\r\n\
+    ///      TZOFFSETTO:+0530\r\n\
+    ///      END:STANDARD\r\n\
+    ///      END:VTIMEZONE\r\n"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_vtimezone(&self, tzid: &str, years: core::ops::RangeInclusive<i32>) -> String {
+        vtimezone::to_vtimezone(self, tzid, years)
+    }
 }
 
+/// Iterator over the changes in UTC offset of a time zone during a year.
+///
+/// This `struct` is created by [`TimeZone::transitions_in_year`]. Each item is the instant of the
+/// transition, and the offsets in effect just before and at that instant.
+#[derive(Clone, Debug)]
+pub struct Transitions<'a, Tz: TimeZone> {
+    tz: &'a Tz,
+    // timestamp of the last sample, which is before any transition not yet returned
+    cursor: i64,
+    // timestamp of the last second to sample
+    last: i64,
+}
+
+impl<'a, Tz: TimeZone> Transitions<'a, Tz> {
+    // Interval between samples of the offset.
+    const STEP: i64 = 24 * 60 * 60;
+
+    fn new(tz: &'a Tz, year: i32) -> Self {
+        let timestamp = |date: NaiveDate| date.and_time(NaiveTime::MIN).and_utc().timestamp();
+        match NaiveDate::from_ymd_opt(year, 1, 1) {
+            Some(start) => {
+                let end = NaiveDate::from_ymd_opt(year + 1, 1, 1)
+                    .map_or(NaiveDateTime::MAX.and_utc().timestamp() + 1, timestamp);
+                // start one second early, so a transition at the start of the year is included
+                let cursor = NaiveDate::MIN.and_time(NaiveTime::MIN).and_utc().timestamp();
+                Transitions { tz, cursor: cmp::max(timestamp(start) - 1, cursor), last: end - 1 }
+            }
+            None => Transitions { tz, cursor: 0, last: 0 },
+        }
+    }
+
+    fn offset_at(&self, timestamp: i64) -> Option<Tz::Offset> {
+        let utc = DateTime::from_timestamp(timestamp, 0)?.naive_utc();
+        Some(self.tz.offset_from_utc_datetime(&utc))
+    }
+}
+
+impl<Tz: TimeZone> Iterator for Transitions<'_, Tz> {
+    type Item = (DateTime<Utc>, Tz::Offset, Tz::Offset);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut before = self.offset_at(self.cursor)?;
+        while self.cursor < self.last {
+            let next = cmp::min(self.cursor.saturating_add(Self::STEP), self.last);
+            let mut after = self.offset_at(next)?;
+            if after.fix() == before.fix() {
+                self.cursor = next;
+                before = after;
+                continue;
+            }
+            // The offset changes somewhere in `(self.cursor, next]`, find the exact second.
+            let (mut lo, mut hi) = (self.cursor, next);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                let offset = self.offset_at(mid)?;
+                if offset.fix() == before.fix() {
+                    lo = mid;
+                    before = offset;
+                } else {
+                    hi = mid;
+                    after = offset;
+                }
+            }
+            self.cursor = hi;
+            return Some((DateTime::from_timestamp(hi, 0)?, before, after));
+        }
+        None
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Transitions<'_, Tz> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Datelike, Timelike};
 
     #[test]
     fn test_fixed_offset_min_max_dates() {
@@ -625,6 +906,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_ymd_and_hms_subsec() {
+        let base = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        let nanos = |n| base.with_nanosecond(n).unwrap();
+
+        assert_eq!(
+            Utc.with_ymd_and_hms_milli(2016, 12, 31, 23, 59, 59, 5).unwrap(),
+            nanos(5_000_000)
+        );
+        assert_eq!(Utc.with_ymd_and_hms_micro(2016, 12, 31, 23, 59, 59, 5).unwrap(), nanos(5_000));
+        assert_eq!(Utc.with_ymd_and_hms_nano(2016, 12, 31, 23, 59, 59, 5).unwrap(), nanos(5));
+
+        // leap second
+        assert_eq!(
+            Utc.with_ymd_and_hms_milli(2016, 12, 31, 23, 59, 59, 1_500).unwrap(),
+            nanos(1_500_000_000)
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms_micro(2016, 12, 31, 23, 59, 59, 1_500_000).unwrap(),
+            nanos(1_500_000_000)
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms_nano(2016, 12, 31, 23, 59, 59, 1_500_000_000).unwrap(),
+            nanos(1_500_000_000)
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms_milli(2016, 12, 31, 23, 59, 58, 1_500),
+            MappedLocalTime::None
+        );
+
+        let offset = FixedOffset::west_opt(3 * 3600).unwrap();
+        let dt = offset.with_ymd_and_hms_micro(2024, 2, 29, 8, 0, 0, 250_000).unwrap();
+        assert_eq!(dt.to_string(), "2024-02-29 08:00:00.250 -03:00");
+
+        assert_eq!(
+            Utc.with_ymd_and_hms_milli(2016, 12, 31, 23, 59, 59, 2_000),
+            MappedLocalTime::None
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms_micro(2016, 12, 31, 23, 59, 59, 2_000_000),
+            MappedLocalTime::None
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms_nano(2016, 12, 31, 23, 59, 59, 2_000_000_000),
+            MappedLocalTime::None
+        );
+        assert_eq!(Utc.with_ymd_and_hms_nano(2023, 2, 29, 0, 0, 0, 0), MappedLocalTime::None);
+    }
+
+    #[test]
+    fn test_isoywd_and_hms() {
+        let dt = Utc.isoywd_and_hms(2020, 53, Weekday::Sun, 23, 59, 59).unwrap();
+        assert_eq!(dt, Utc.with_ymd_and_hms(2021, 1, 3, 23, 59, 59).unwrap());
+
+        let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+        let dt = offset.isoywd_and_hms(2024, 10, Weekday::Wed, 1, 30, 0).unwrap();
+        assert_eq!(dt.to_string(), "2024-03-06 01:30:00 +05:00");
+        assert_eq!(dt.iso_week().week(), 10);
+
+        assert_eq!(Utc.isoywd_and_hms(2021, 53, Weekday::Mon, 0, 0, 0), MappedLocalTime::None);
+        assert_eq!(Utc.isoywd_and_hms(2024, 0, Weekday::Mon, 0, 0, 0), MappedLocalTime::None);
+        assert_eq!(Utc.isoywd_and_hms(2024, 1, Weekday::Mon, 24, 0, 0), MappedLocalTime::None);
+    }
+
     #[test]
     fn test_negative_millis() {
         let dt = Utc.timestamp_millis_opt(-1000).unwrap();
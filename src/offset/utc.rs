@@ -18,10 +18,12 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use rkyv::{Archive, Deserialize, Serialize};
 
 use super::{FixedOffset, MappedLocalTime, Offset, TimeZone};
-use crate::naive::{NaiveDate, NaiveDateTime};
 #[cfg(feature = "now")]
 #[allow(deprecated)]
-use crate::{Date, DateTime};
+use crate::Date;
+use crate::DateTime;
+use crate::naive::{NaiveDate, NaiveDateTime};
+use crate::rtc::RtcSource;
 
 /// The UTC time zone. This is the most efficient time zone when you don't need the local time.
 /// It is also used as an offset (which is also a dummy type).
@@ -102,6 +104,7 @@ impl Utc {
     #[cfg(all(
         target_arch = "wasm32",
         feature = "wasmbind",
+        not(feature = "wasm-perf-clock"),
         not(any(target_os = "emscripten", target_os = "wasi"))
     ))]
     #[must_use]
@@ -109,6 +112,126 @@ impl Utc {
         let now = js_sys::Date::new_0();
         DateTime::<Utc>::from(now)
     }
+
+    /// Returns a `DateTime` which corresponds to the current date and time.
+    ///
+    /// This reads `performance.timeOrigin + performance.now()`, which avoids allocating a JS
+    /// `Date` on every call.
+    #[cfg(all(
+        target_arch = "wasm32",
+        feature = "wasm-perf-clock",
+        not(any(target_os = "emscripten", target_os = "wasi"))
+    ))]
+    #[must_use]
+    pub fn now() -> DateTime<Utc> {
+        let millis = performance::Performance::time_origin() + performance::Performance::now();
+        let secs = (millis / 1000.0).floor();
+        // `millis` has sub-microsecond precision at best, clamp any rounding error.
+        let nanos = (((millis - secs * 1000.0) * 1_000_000.0) as u32).min(999_999_999);
+        DateTime::from_timestamp(secs as i64, nanos).expect("timestamp out of range")
+    }
+}
+
+#[cfg(all(
+    feature = "now",
+    target_arch = "wasm32",
+    feature = "wasm-perf-clock",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+))]
+mod performance {
+    use wasm_bindgen::prelude::wasm_bindgen;
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// The global `performance` object of the Web Performance API.
+        #[wasm_bindgen(js_name = performance)]
+        pub(super) type Performance;
+
+        /// The number of milliseconds since the Unix epoch at which the current context started.
+        #[wasm_bindgen(
+            static_method_of = Performance,
+            js_class = "performance",
+            getter = timeOrigin
+        )]
+        pub(super) fn time_origin() -> f64;
+
+        /// The number of milliseconds that have passed since `timeOrigin`.
+        #[wasm_bindgen(static_method_of = Performance, js_class = "performance")]
+        pub(super) fn now() -> f64;
+    }
+}
+
+#[cfg(feature = "now")]
+impl Utc {
+    /// Returns a `DateTime<Utc>` of the current date and time, read from a cheaper clock with a
+    /// lower resolution than [`Utc::now()`](#method.now).
+    ///
+    /// On 64-bit Linux this reads `CLOCK_REALTIME_COARSE`, which returns the time of the last
+    /// timer tick of the kernel (typically 1 to 4 milliseconds ago) without reading the hardware
+    /// clock. This is meant for code that asks for the time very often and doesn't need it to be
+    /// precise, such as logging and metrics.
+    ///
+    /// On other platforms, or if the coarse clock can't be read, this is the same as `Utc::now()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, Utc};
+    ///
+    /// let coarse = Utc::now_coarse();
+    /// assert!(Utc::now() - coarse < TimeDelta::seconds(1));
+    /// ```
+    #[must_use]
+    pub fn now_coarse() -> DateTime<Utc> {
+        #[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+        if let Some(now) = coarse::now() {
+            return now;
+        }
+        Utc::now()
+    }
+}
+
+#[cfg(all(feature = "now", target_os = "linux", target_pointer_width = "64"))]
+mod coarse {
+    use crate::{DateTime, Utc};
+
+    /// `CLOCK_REALTIME_COARSE` from `<linux/time.h>`, the same on all architectures.
+    const CLOCK_REALTIME_COARSE: i32 = 5;
+
+    /// `struct timespec`. Both `time_t` and `long` are 64 bits on 64-bit Linux.
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: i64,
+        tv_nsec: i64,
+    }
+
+    extern "C" {
+        fn clock_gettime(clock_id: i32, tp: *mut Timespec) -> i32;
+    }
+
+    pub(super) fn now() -> Option<DateTime<Utc>> {
+        let mut ts = Timespec { tv_sec: 0, tv_nsec: 0 };
+        // SAFETY: `ts` is a valid `struct timespec` for `clock_gettime` to write to.
+        if unsafe { clock_gettime(CLOCK_REALTIME_COARSE, &mut ts) } != 0 {
+            return None;
+        }
+        DateTime::from_timestamp(ts.tv_sec, u32::try_from(ts.tv_nsec).ok()?)
+    }
+}
+
+impl Utc {
+    /// Returns a `DateTime<Utc>` which corresponds to the current date and time, as read from a
+    /// real-time clock.
+    ///
+    /// Unlike [`Utc::now()`](#method.now) this does not need an operating system, so it can be
+    /// used on bare metal. See the [`rtc`](crate::rtc) module for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if reading the clock fails.
+    pub fn now_with<R: RtcSource>(mut rtc: R) -> Result<DateTime<Utc>, R::Error> {
+        rtc.read().map(|dt| dt.and_utc())
+    }
 }
 
 impl TimeZone for Utc {
@@ -150,3 +273,23 @@ impl fmt::Display for Utc {
         write!(f, "UTC")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "now")]
+    fn test_now_coarse() {
+        use super::Utc;
+        use crate::TimeDelta;
+
+        let before = Utc::now();
+        let coarse = Utc::now_coarse();
+        let after = Utc::now();
+        // The coarse clock lags behind by at most a few timer ticks.
+        assert!(coarse <= after);
+        assert!(before - coarse < TimeDelta::seconds(1));
+
+        #[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+        assert!(super::coarse::now().is_some());
+    }
+}
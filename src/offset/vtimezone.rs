@@ -0,0 +1,86 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Export of time zone rules as an iCalendar (RFC 5545) `VTIMEZONE` component.
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops::RangeInclusive;
+
+use super::{FixedOffset, Offset, TimeZone};
+use crate::{NaiveDate, NaiveTime};
+
+/// Writes a `VTIMEZONE` component with one observance for each transition of `tz` in `years`.
+///
+/// Observances switching to a larger offset are written as `DAYLIGHT`, the others as `STANDARD`.
+/// A time zone without transitions is written as a single `STANDARD` observance starting in 1970,
+/// with the offset at the start of `years`.
+pub(super) fn to_vtimezone<Tz: TimeZone>(
+    tz: &Tz,
+    tzid: &str,
+    years: RangeInclusive<i32>,
+) -> String {
+    let mut out = String::new();
+    write_line(&mut out, format_args!("BEGIN:VTIMEZONE"));
+    write_line(&mut out, format_args!("TZID:{}", tzid));
+
+    let mut empty = true;
+    for year in years.clone() {
+        for (instant, before, after) in tz.transitions_in_year(year) {
+            let (from, to) = (before.fix(), after.fix());
+            let kind = match to.local_minus_utc() > from.local_minus_utc() {
+                true => "DAYLIGHT",
+                false => "STANDARD",
+            };
+            // `DTSTART` is the local time before the transition
+            let start = instant.naive_utc().overflowing_add_offset(from);
+            write_observance(&mut out, kind, &start.format("%Y%m%dT%H%M%S"), from, to);
+            empty = false;
+        }
+    }
+    if empty {
+        let start = NaiveDate::from_ymd_opt(*years.start(), 1, 1).unwrap_or_default();
+        let offset = tz.offset_from_utc_datetime(&start.and_time(NaiveTime::MIN)).fix();
+        write_observance(&mut out, "STANDARD", &"19700101T000000", offset, offset);
+    }
+
+    write_line(&mut out, format_args!("END:VTIMEZONE"));
+    out
+}
+
+fn write_observance(
+    out: &mut String,
+    kind: &str,
+    start: &dyn fmt::Display,
+    from: FixedOffset,
+    to: FixedOffset,
+) {
+    write_line(out, format_args!("BEGIN:{}", kind));
+    write_line(out, format_args!("DTSTART:{}", start));
+    write_line(out, format_args!("TZOFFSETFROM:{}", UtcOffset(from)));
+    write_line(out, format_args!("TZOFFSETTO:{}", UtcOffset(to)));
+    write_line(out, format_args!("END:{}", kind));
+}
+
+// Content lines are delimited by a CRLF sequence.
+fn write_line(out: &mut String, line: fmt::Arguments) {
+    // Writing to a `String` never fails.
+    let _ = out.write_fmt(line);
+    out.push_str("\r\n");
+}
+
+/// An offset in the `UTC-OFFSET` value format of RFC 5545, such as `+0200` or `-013015`.
+struct UtcOffset(FixedOffset);
+
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.0.local_minus_utc();
+        let sign = if secs < 0 { '-' } else { '+' };
+        let secs = secs.unsigned_abs();
+        write!(f, "{}{:02}{:02}", sign, secs / 3600, secs / 60 % 60)?;
+        if secs % 60 != 0 {
+            write!(f, "{:02}", secs % 60)?;
+        }
+        Ok(())
+    }
+}
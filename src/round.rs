@@ -362,9 +362,78 @@ impl std::error::Error for RoundingError {
     }
 }
 
+/// Returns the index of the bucket of size `width` that contains `dt`, counting from the bucket
+/// that starts at `origin`.
+///
+/// Buckets are half-open: a bucket contains its start but not its end. Timestamps before `origin`
+/// have a negative index, so the bucket just before `origin` has index -1 (the division rounds
+/// towards negative infinity, not towards zero).
+///
+/// # Errors
+///
+/// Returns `None` if `width` is zero or negative, or if the index does not fit in an `i64`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::round::{bucket_index, bucket_start};
+/// use chrono::{TimeDelta, TimeZone, Utc};
+///
+/// let origin = Utc.with_ymd_and_hms(2024, 5, 7, 0, 0, 0).unwrap();
+/// let width = TimeDelta::minutes(15);
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 5, 7, 1, 20, 0).unwrap();
+/// assert_eq!(bucket_index(&dt, &origin, width), Some(5));
+/// assert_eq!(
+///     bucket_start(5, &origin, width),
+///     Some(Utc.with_ymd_and_hms(2024, 5, 7, 1, 15, 0).unwrap())
+/// );
+///
+/// let before = Utc.with_ymd_and_hms(2024, 5, 6, 23, 59, 59).unwrap();
+/// assert_eq!(bucket_index(&before, &origin, width), Some(-1));
+/// ```
+#[must_use]
+pub fn bucket_index<Tz: TimeZone, Tz2: TimeZone>(
+    dt: &DateTime<Tz>,
+    origin: &DateTime<Tz2>,
+    width: TimeDelta,
+) -> Option<i64> {
+    let width = width.as_nanos_i128();
+    if width <= 0 {
+        return None;
+    }
+    let since_origin = dt.naive_utc().signed_duration_since(origin.naive_utc()).as_nanos_i128();
+    i64::try_from(since_origin.div_euclid(width)).ok()
+}
+
+/// Returns the start of the bucket with index `index`, the inverse of [`bucket_index`].
+///
+/// The result is `origin + index * width`, in the time zone of `origin`.
+///
+/// # Errors
+///
+/// Returns `None` if `width` is zero or negative, or if the result would be out of range for a
+/// `DateTime`.
+#[must_use]
+pub fn bucket_start<Tz: TimeZone>(
+    index: i64,
+    origin: &DateTime<Tz>,
+    width: TimeDelta,
+) -> Option<DateTime<Tz>> {
+    let width = width.as_nanos_i128();
+    if width <= 0 {
+        return None;
+    }
+    // Can not overflow: both factors are less than 2^64 in magnitude.
+    let offset = i128::from(index) * width;
+    let secs = i64::try_from(offset.div_euclid(1_000_000_000)).ok()?;
+    let nanos = offset.rem_euclid(1_000_000_000) as u32;
+    origin.clone().checked_add_signed(TimeDelta::new(secs, nanos)?)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{DurationRound, RoundingError, SubsecRound, TimeDelta};
+    use super::{DurationRound, RoundingError, SubsecRound, TimeDelta, bucket_index, bucket_start};
     use crate::Timelike;
     use crate::offset::{FixedOffset, TimeZone, Utc};
     use crate::{DateTime, NaiveDate};
@@ -1079,4 +1148,49 @@ mod tests {
         let dt = DateTime::from_timestamp_nanos(i64::MIN + 2);
         assert_eq!(dt.duration_round_up(span).unwrap(), DateTime::UNIX_EPOCH);
     }
+
+    #[test]
+    fn test_bucket_index() {
+        let origin = Utc.with_ymd_and_hms(2024, 5, 7, 0, 0, 0).unwrap();
+        let width = TimeDelta::try_minutes(15).unwrap();
+        let at = |h, m, s| Utc.with_ymd_and_hms(2024, 5, 7, h, m, s).unwrap();
+
+        assert_eq!(bucket_index(&origin, &origin, width), Some(0));
+        assert_eq!(bucket_index(&at(0, 14, 59), &origin, width), Some(0));
+        assert_eq!(bucket_index(&at(0, 15, 0), &origin, width), Some(1));
+        let before = origin - TimeDelta::nanoseconds(1);
+        assert_eq!(bucket_index(&before, &origin, width), Some(-1));
+        let before = origin - width;
+        assert_eq!(bucket_index(&before, &origin, width), Some(-1));
+        assert_eq!(bucket_index(&(before - TimeDelta::nanoseconds(1)), &origin, width), Some(-2));
+
+        // time zones don't matter, only instants
+        let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        assert_eq!(bucket_index(&at(0, 15, 0).with_timezone(&tz), &origin, width), Some(1));
+
+        assert_eq!(bucket_index(&origin, &origin, TimeDelta::zero()), None);
+        assert_eq!(bucket_index(&origin, &origin, -width), None);
+        let (min, max) = (DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC);
+        assert_eq!(bucket_index(&max, &min, TimeDelta::nanoseconds(1)), None);
+        assert!(bucket_index(&max, &min, TimeDelta::try_seconds(1).unwrap()).is_some());
+
+        for index in [-97, -1, 0, 1, 96] {
+            let start = bucket_start(index, &origin, width).unwrap();
+            assert_eq!(bucket_index(&start, &origin, width), Some(index));
+            assert_eq!(
+                bucket_index(&(start - TimeDelta::nanoseconds(1)), &origin, width),
+                Some(index - 1)
+            );
+        }
+        let start = bucket_start(-3, &origin.with_timezone(&tz), width).unwrap();
+        assert_eq!(start.offset(), &tz);
+        assert_eq!(start, at(0, 0, 0) - TimeDelta::try_minutes(45).unwrap());
+
+        assert_eq!(bucket_start(1, &origin, TimeDelta::zero()), None);
+        assert_eq!(bucket_start(i64::MAX, &origin, width), None);
+        assert_eq!(
+            bucket_start(i64::MIN, &origin, TimeDelta::nanoseconds(1)),
+            Some(origin + TimeDelta::nanoseconds(i64::MIN))
+        );
+    }
 }
@@ -0,0 +1,223 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Integration with hardware real-time clocks.
+//!
+//! On bare metal there is no operating system to ask for the current time. Instead a board
+//! usually has a real-time clock (RTC) peripheral or chip that keeps a calendar date and time.
+//! Implementing [`RtcSource`] for such a clock lets it be used with [`Utc::now_with`], without
+//! requiring the `std` or `now` features.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::rtc::RtcSource;
+//! use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+//!
+//! /// A clock that only counts seconds, like the ones found in many microcontrollers.
+//! struct CounterRtc {
+//!     seconds: u32,
+//! }
+//!
+//! impl RtcSource for CounterRtc {
+//!     type Error = ();
+//!
+//!     fn read(&mut self) -> Result<NaiveDateTime, ()> {
+//!         DateTime::from_timestamp(self.seconds.into(), 0).map(|dt| dt.naive_utc()).ok_or(())
+//!     }
+//!
+//!     fn write(&mut self, datetime: NaiveDateTime) -> Result<(), ()> {
+//!         self.seconds = datetime.and_utc().timestamp().try_into().map_err(|_| ())?;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut rtc = CounterRtc { seconds: 0 };
+//! let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(12, 0, 0).unwrap();
+//! rtc.write(dt).unwrap();
+//! assert_eq!(Utc::now_with(&mut rtc), Ok(dt.and_utc()));
+//! ```
+
+use crate::{FixedOffset, NaiveDateTime};
+
+/// A source of the current date and time, such as a hardware real-time clock.
+///
+/// The date and time read from and written to the clock are in UTC. Use [`LocalRtc`] for clocks
+/// that are set to local time.
+pub trait RtcSource {
+    /// The error returned when communicating with the clock fails.
+    type Error;
+
+    /// Reads the current date and time from the clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the clock could not be read, or does not hold a valid date and time.
+    fn read(&mut self) -> Result<NaiveDateTime, Self::Error>;
+
+    /// Sets the clock to the given date and time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the clock could not be written, or can not represent `datetime`.
+    fn write(&mut self, datetime: NaiveDateTime) -> Result<(), Self::Error>;
+}
+
+impl<R: RtcSource + ?Sized> RtcSource for &mut R {
+    type Error = R::Error;
+
+    fn read(&mut self) -> Result<NaiveDateTime, Self::Error> {
+        (**self).read()
+    }
+
+    fn write(&mut self, datetime: NaiveDateTime) -> Result<(), Self::Error> {
+        (**self).write(datetime)
+    }
+}
+
+/// An adapter for a real-time clock that keeps local time at a fixed offset from UTC.
+///
+/// Battery-backed clocks are often set to the local time of the place they are installed. This
+/// adapter converts between that local time and the UTC time expected by [`RtcSource`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::rtc::{LocalRtc, RtcSource};
+/// use chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+///
+/// struct Rtc(NaiveDateTime);
+///
+/// impl RtcSource for Rtc {
+///     type Error = core::convert::Infallible;
+///
+///     fn read(&mut self) -> Result<NaiveDateTime, Self::Error> {
+///         Ok(self.0)
+///     }
+///
+///     fn write(&mut self, datetime: NaiveDateTime) -> Result<(), Self::Error> {
+///         self.0 = datetime;
+///         Ok(())
+///     }
+/// }
+///
+/// let local = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(14, 0, 0).unwrap();
+/// let mut rtc = LocalRtc::new(Rtc(local), FixedOffset::east_opt(2 * 3600).unwrap());
+/// assert_eq!(rtc.read().unwrap(), local - chrono::TimeDelta::hours(2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocalRtc<R> {
+    rtc: R,
+    offset: FixedOffset,
+}
+
+impl<R> LocalRtc<R> {
+    /// Wraps a clock that is set to local time at `offset` from UTC.
+    pub const fn new(rtc: R, offset: FixedOffset) -> LocalRtc<R> {
+        LocalRtc { rtc, offset }
+    }
+
+    /// Returns the offset from UTC of the wrapped clock.
+    pub const fn offset(&self) -> FixedOffset {
+        self.offset
+    }
+
+    /// Returns the wrapped clock.
+    pub fn into_inner(self) -> R {
+        self.rtc
+    }
+}
+
+impl<R: RtcSource> RtcSource for LocalRtc<R> {
+    type Error = LocalRtcError<R::Error>;
+
+    fn read(&mut self) -> Result<NaiveDateTime, Self::Error> {
+        let local = self.rtc.read().map_err(LocalRtcError::Rtc)?;
+        local.checked_sub_offset(self.offset).ok_or(LocalRtcError::OutOfRange)
+    }
+
+    fn write(&mut self, datetime: NaiveDateTime) -> Result<(), Self::Error> {
+        let local = datetime.checked_add_offset(self.offset).ok_or(LocalRtcError::OutOfRange)?;
+        self.rtc.write(local).map_err(LocalRtcError::Rtc)
+    }
+}
+
+/// The error type of [`LocalRtc`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalRtcError<E> {
+    /// The wrapped clock returned an error.
+    Rtc(E),
+    /// Applying the offset gives a date and time out of range for `NaiveDateTime`.
+    OutOfRange,
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for LocalRtcError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LocalRtcError::Rtc(e) => e.fmt(f),
+            LocalRtcError::OutOfRange => f.write_str("date and time out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for LocalRtcError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LocalRtcError::Rtc(e) => Some(e),
+            LocalRtcError::OutOfRange => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LocalRtc, LocalRtcError, RtcSource};
+    use crate::{FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+    #[derive(Debug, PartialEq)]
+    struct TestRtc(Option<NaiveDateTime>);
+
+    impl RtcSource for TestRtc {
+        type Error = &'static str;
+
+        fn read(&mut self) -> Result<NaiveDateTime, Self::Error> {
+            self.0.ok_or("not set")
+        }
+
+        fn write(&mut self, datetime: NaiveDateTime) -> Result<(), Self::Error> {
+            self.0 = Some(datetime);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_now_with() {
+        let dt = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(23, 30, 0).unwrap();
+        let mut rtc = TestRtc(None);
+        assert_eq!(Utc::now_with(&mut rtc), Err("not set"));
+        rtc.write(dt).unwrap();
+        assert_eq!(Utc::now_with(&mut rtc), Ok(dt.and_utc()));
+        assert_eq!(Utc::now_with(rtc), Ok(dt.and_utc()));
+    }
+
+    #[test]
+    fn test_local_rtc() {
+        let utc = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap().and_hms_opt(23, 30, 0).unwrap();
+        let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+        let mut rtc = LocalRtc::new(TestRtc(None), offset);
+        assert_eq!(rtc.read(), Err(LocalRtcError::Rtc("not set")));
+
+        rtc.write(utc).unwrap();
+        assert_eq!(rtc.read(), Ok(utc));
+        assert_eq!(Utc::now_with(&mut rtc), Ok(utc.and_utc()));
+        assert_eq!(rtc.offset(), offset);
+        let local = NaiveDate::from_ymd_opt(2024, 5, 8).unwrap().and_hms_opt(4, 30, 0).unwrap();
+        assert_eq!(rtc.into_inner(), TestRtc(Some(local)));
+
+        let mut rtc = LocalRtc::new(TestRtc(None), offset);
+        assert_eq!(rtc.write(NaiveDateTime::MAX), Err(LocalRtcError::OutOfRange));
+        let mut rtc = LocalRtc::new(TestRtc(Some(NaiveDateTime::MIN)), offset);
+        assert_eq!(rtc.read(), Err(LocalRtcError::OutOfRange));
+    }
+}
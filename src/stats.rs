@@ -0,0 +1,175 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Summary statistics over iterators of `DateTime`s.
+
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::offset::TimeZone;
+use crate::{DateTime, Interval, TimeDelta};
+
+/// Summary statistics for iterators of [`DateTime`]s.
+///
+/// This trait is implemented for every iterator over `DateTime<Tz>`. The results are in the time
+/// zone of the first item.
+///
+/// All computations work on the difference to the first item in nanoseconds, so unlike summing
+/// UNIX timestamps they can not overflow for any realistic number of items.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeDelta, TimeZone, TimestampStats, Utc};
+///
+/// let at = |min| Utc.with_ymd_and_hms(2024, 5, 7, 12, min, 0).unwrap();
+/// let events = [at(10), at(0), at(5), at(45)];
+///
+/// assert_eq!(events.iter().copied().mean_datetime(), Some(at(15)));
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(events.iter().copied().median_datetime(), Some(at(7) + TimeDelta::seconds(30)));
+///
+/// let span = events.iter().copied().span().unwrap();
+/// assert_eq!((*span.start(), *span.end()), (at(0), at(45)));
+/// assert_eq!(span.duration(), TimeDelta::minutes(45));
+/// ```
+pub trait TimestampStats<Tz: TimeZone>: Iterator<Item = DateTime<Tz>> + Sized {
+    /// Returns the arithmetic mean of the items, rounded down to the nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the iterator is empty, or if there are so many items that the sum of
+    /// their distances to the first item overflows an `i128` of nanoseconds.
+    fn mean_datetime(mut self) -> Option<DateTime<Tz>> {
+        let first = self.next()?;
+        let mut count = 1i128;
+        let mut sum = 0i128;
+        for dt in self {
+            sum = sum.checked_add(nanos_since(&dt, &first))?;
+            count += 1;
+        }
+        first.checked_add_signed(from_nanos(sum.div_euclid(count)))
+    }
+
+    /// Returns the median of the items.
+    ///
+    /// For an even number of items this is the midpoint between the two middle items, rounded
+    /// down to the nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[cfg(feature = "alloc")]
+    fn median_datetime(self) -> Option<DateTime<Tz>> {
+        let mut items: Vec<DateTime<Tz>> = self.collect();
+        if items.is_empty() {
+            return None;
+        }
+        let mid = items.len() / 2;
+        // Keep the time zone of the first item.
+        let first = items[0].clone();
+        items.sort_unstable();
+        let upper = nanos_since(&items[mid], &first);
+        let median = match items.len() % 2 {
+            0 => {
+                let lower = nanos_since(&items[mid - 1], &first);
+                lower + (upper - lower).div_euclid(2)
+            }
+            _ => upper,
+        };
+        first.checked_add_signed(from_nanos(median))
+    }
+
+    /// Returns the [`Interval`] from the earliest to the latest item.
+    ///
+    /// Note that an `Interval` does not include its end, which is the latest item.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the iterator is empty.
+    fn span(mut self) -> Option<Interval<Tz>> {
+        let first = self.next()?;
+        let (mut min, mut max) = (first.clone(), first.clone());
+        for dt in self {
+            if dt < min {
+                min = dt;
+            } else if dt > max {
+                max = dt;
+            }
+        }
+        // Keep the time zone of the first item.
+        let tz = first.timezone();
+        Interval::new(min.with_timezone(&tz), max.with_timezone(&tz))
+    }
+}
+
+impl<Tz: TimeZone, I: Iterator<Item = DateTime<Tz>>> TimestampStats<Tz> for I {}
+
+fn nanos_since<Tz: TimeZone>(dt: &DateTime<Tz>, base: &DateTime<Tz>) -> i128 {
+    dt.naive_utc().signed_duration_since(base.naive_utc()).as_nanos_i128()
+}
+
+// The result is always between two `DateTime`s, so it fits in a `TimeDelta`.
+fn from_nanos(nanos: i128) -> TimeDelta {
+    let secs = nanos.div_euclid(1_000_000_000) as i64;
+    let nanos = nanos.rem_euclid(1_000_000_000) as u32;
+    TimeDelta::new(secs, nanos).unwrap_or(TimeDelta::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimestampStats;
+    use crate::{DateTime, FixedOffset, TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn test_mean_datetime() {
+        let at = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+        assert_eq!(core::iter::empty::<DateTime<Utc>>().mean_datetime(), None);
+        assert_eq!([at(7)].into_iter().mean_datetime(), Some(at(7)));
+        assert_eq!(
+            [at(1), at(2)].into_iter().mean_datetime(),
+            Some(at(1) + TimeDelta::milliseconds(500))
+        );
+        assert_eq!(
+            [at(-1), at(-2)].into_iter().mean_datetime(),
+            Some(at(-2) + TimeDelta::milliseconds(500))
+        );
+
+        // no overflow at the ends of the range
+        let items = [DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC, DateTime::<Utc>::MAX_UTC];
+        let mean = items.into_iter().mean_datetime().unwrap();
+        assert!(mean > DateTime::<Utc>::MIN_UTC && mean < DateTime::<Utc>::MAX_UTC);
+
+        // the result has the time zone of the first item
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let mean =
+            [at(0).with_timezone(&tz), at(10).with_timezone(&tz)].into_iter().mean_datetime();
+        assert_eq!(mean.map(|dt| dt.offset().local_minus_utc()), Some(3600));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_median_datetime() {
+        let at = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+        assert_eq!(core::iter::empty::<DateTime<Utc>>().median_datetime(), None);
+        assert_eq!([at(3), at(1), at(100)].into_iter().median_datetime(), Some(at(3)));
+        assert_eq!(
+            [at(3), at(1), at(100), at(2)].into_iter().median_datetime(),
+            Some(at(2) + TimeDelta::milliseconds(500))
+        );
+        assert_eq!([at(5), at(5)].into_iter().median_datetime(), Some(at(5)));
+    }
+
+    #[test]
+    fn test_span() {
+        let at = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+        assert!(core::iter::empty::<DateTime<Utc>>().span().is_none());
+
+        let span = [at(5), at(-3), at(12), at(0)].into_iter().span().unwrap();
+        assert_eq!((*span.start(), *span.end()), (at(-3), at(12)));
+        assert_eq!(span.duration(), TimeDelta::seconds(15));
+
+        let span = [at(5)].into_iter().span().unwrap();
+        assert!(span.is_empty());
+    }
+}
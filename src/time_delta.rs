@@ -10,12 +10,15 @@
 
 //! Temporal quantification
 
-use core::fmt;
-use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::string::String;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
 use core::time::Duration;
+use core::{fmt, str};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::format::{INVALID, OUT_OF_RANGE, ParseError, ParseResult, TOO_SHORT};
 use crate::{expect, try_opt};
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
@@ -337,6 +340,12 @@ impl TimeDelta {
         secs_part.checked_add(nanos_part as i64)
     }
 
+    /// Returns the total number of nanoseconds in the `TimeDelta` as an `i128`, which can not
+    /// overflow.
+    pub(crate) const fn as_nanos_i128(&self) -> i128 {
+        self.secs as i128 * NANOS_PER_SEC as i128 + self.nanos as i128
+    }
+
     /// Add two `TimeDelta`s, returning `None` if overflow occurred.
     #[must_use]
     pub const fn checked_add(&self, rhs: &TimeDelta) -> Option<TimeDelta> {
@@ -399,6 +408,33 @@ impl TimeDelta {
         Some(TimeDelta { secs, nanos })
     }
 
+    /// Calculates the remainder of dividing a `TimeDelta` by another `TimeDelta`, returning `None`
+    /// if `rhs` is zero.
+    ///
+    /// Like the `%` operator on integers the result has the same sign as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::TimeDelta;
+    /// let step = TimeDelta::minutes(5);
+    /// assert_eq!(TimeDelta::minutes(17).checked_rem(&step), Some(TimeDelta::minutes(2)));
+    /// assert_eq!(TimeDelta::minutes(-17).checked_rem(&step), Some(TimeDelta::minutes(-2)));
+    /// assert_eq!(TimeDelta::minutes(17).checked_rem(&TimeDelta::zero()), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_rem(&self, rhs: &TimeDelta) -> Option<TimeDelta> {
+        let rhs_nanos = rhs.as_nanos_i128();
+        if rhs_nanos == 0 {
+            return None;
+        }
+        let rem = self.as_nanos_i128() % rhs_nanos;
+        // The remainder is never larger than `self`, so it is always in range.
+        let secs = rem.div_euclid(NANOS_PER_SEC as i128) as i64;
+        let nanos = rem.rem_euclid(NANOS_PER_SEC as i128) as i32;
+        Some(TimeDelta { secs, nanos })
+    }
+
     /// Returns the `TimeDelta` as an absolute (non-negative) value.
     #[inline]
     pub const fn abs(&self) -> TimeDelta {
@@ -475,6 +511,152 @@ impl TimeDelta {
 
     /// The maximum possible `TimeDelta`: `i64::MAX` milliseconds.
     pub const MAX: Self = MAX;
+
+    /// Returns a hash of the duration that is the same on every platform and in every version of
+    /// chrono, see [`NaiveDate::stable_hash`](crate::NaiveDate::stable_hash).
+    ///
+    /// It is the 64-bit FNV-1a hash of [`TimeDelta::num_seconds`] and
+    /// [`TimeDelta::subsec_nanos`], each as a little-endian `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(-1500).stable_hash(), 0xb918_e614_050a_5b0c);
+    /// ```
+    #[must_use]
+    pub const fn stable_hash(&self) -> u64 {
+        crate::stable_hash(&[self.num_seconds(), self.subsec_nanos() as i64])
+    }
+
+    /// Returns a value that displays the duration in the language of `locale`, such as
+    /// `2 hours 3 minutes` or `2 Stunden 3 Minuten`.
+    ///
+    /// The duration is written in days, hours, minutes, seconds and milliseconds. Units that are
+    /// zero are left out, and `width` selects between narrow, short and long unit names. Locales
+    /// without translated unit names fall back to English.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{Locale, UnitWidth};
+    /// use chrono::TimeDelta;
+    ///
+    /// let d = TimeDelta::hours(2) + TimeDelta::minutes(3);
+    /// let long = UnitWidth::Long;
+    /// assert_eq!(d.format_localized(Locale::de_DE, long).to_string(), "2 Stunden 3 Minuten");
+    /// assert_eq!(d.format_localized(Locale::fr_FR, long).to_string(), "2 heures 3 minutes");
+    /// assert_eq!(d.format_localized(Locale::en_US, UnitWidth::Narrow).to_string(), "2h 3m");
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    #[must_use]
+    pub const fn format_localized(
+        &self,
+        locale: crate::format::Locale,
+        width: crate::format::UnitWidth,
+    ) -> crate::format::LocalizedDuration {
+        crate::format::LocalizedDuration::new(*self, locale, width)
+    }
+
+    /// Parses an iCalendar `DURATION` value, such as `PT1H30M`, `P2W` or `-P1DT12H`.
+    ///
+    /// The format is defined in [RFC 5545, section 3.3.6]. It is a subset of the ISO 8601
+    /// durations accepted by the [`FromStr`](str::FromStr) implementation, with the addition of
+    /// weeks. Days and weeks are taken to be exactly 24 hours and 7 days long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid duration, if it has a fraction, or if it is out of
+    /// range for `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::parse_ical("PT1H30M"), Ok(TimeDelta::minutes(90)));
+    /// assert_eq!(TimeDelta::parse_ical("-P2W"), Ok(TimeDelta::weeks(-2)));
+    /// assert_eq!(TimeDelta::parse_ical("P1DT12H"), Ok(TimeDelta::hours(36)));
+    ///
+    /// assert!(TimeDelta::parse_ical("PT1.5S").is_err());
+    /// assert!(TimeDelta::parse_ical("P1W2D").is_err());
+    /// ```
+    ///
+    /// [RFC 5545, section 3.3.6]: https://www.rfc-editor.org/rfc/rfc5545#section-3.3.6
+    pub fn parse_ical(s: &str) -> ParseResult<TimeDelta> {
+        if s.contains(['.', ',']) {
+            return Err(INVALID);
+        }
+        let (negative, rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let weeks = rest.strip_prefix('P').and_then(|r| r.strip_suffix('W'));
+        match weeks {
+            Some(weeks) if !weeks.is_empty() && weeks.bytes().all(|b| b.is_ascii_digit()) => {
+                let weeks = weeks.parse::<i64>().map_err(|_| OUT_OF_RANGE)?;
+                let secs = weeks.checked_mul(SECS_PER_WEEK).ok_or(OUT_OF_RANGE)?;
+                let delta = TimeDelta::try_seconds(secs).ok_or(OUT_OF_RANGE)?;
+                Ok(if negative { -delta } else { delta })
+            }
+            _ => s.parse(),
+        }
+    }
+
+    /// Returns the duration as an iCalendar `DURATION` value, such as `PT1H30M` or `P2W`.
+    ///
+    /// iCalendar has no fractional seconds, so they are dropped. A whole number of weeks is
+    /// written in weeks, anything else in days, hours, minutes and seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(90).to_ical_string(), "PT1H30M");
+    /// assert_eq!(TimeDelta::weeks(-2).to_ical_string(), "-P2W");
+    /// assert_eq!((TimeDelta::days(1) + TimeDelta::seconds(5)).to_ical_string(), "P1DT5S");
+    /// assert_eq!(TimeDelta::zero().to_ical_string(), "PT0S");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_ical_string(&self) -> String {
+        use core::fmt::Write;
+
+        let mut result = String::new();
+        let secs = self.num_seconds();
+        if secs < 0 {
+            result.push('-');
+        }
+        let secs = secs.unsigned_abs();
+        result.push('P');
+        // Writing to a `String` never fails.
+        if secs != 0 && secs % SECS_PER_WEEK as u64 == 0 {
+            let _ = write!(result, "{}W", secs / SECS_PER_WEEK as u64);
+            return result;
+        }
+        let days = secs / SECS_PER_DAY as u64;
+        if days > 0 {
+            let _ = write!(result, "{}D", days);
+        }
+        // RFC 5545 doesn't allow to skip a unit between hours and seconds.
+        let time = [(secs / 3600 % 24, 'H'), (secs / 60 % 60, 'M'), (secs % 60, 'S')];
+        let first = time.iter().position(|&(n, _)| n != 0);
+        let last = time.iter().rposition(|&(n, _)| n != 0);
+        match (first, last) {
+            (Some(first), Some(last)) => {
+                result.push('T');
+                for (n, unit) in &time[first..=last] {
+                    let _ = write!(result, "{}{}", n, unit);
+                }
+            }
+            _ if days == 0 => result.push_str("T0S"),
+            _ => {}
+        }
+        result
+    }
 }
 
 impl Neg for TimeDelta {
@@ -536,6 +718,14 @@ impl Div<i32> for TimeDelta {
     }
 }
 
+impl Rem for TimeDelta {
+    type Output = TimeDelta;
+
+    fn rem(self, rhs: TimeDelta) -> TimeDelta {
+        self.checked_rem(&rhs).expect("`TimeDelta` is zero")
+    }
+}
+
 impl<'a> core::iter::Sum<&'a TimeDelta> for TimeDelta {
     fn sum<I: Iterator<Item = &'a TimeDelta>>(iter: I) -> TimeDelta {
         iter.fold(TimeDelta::zero(), |acc, x| acc + *x)
@@ -585,6 +775,101 @@ impl fmt::Display for TimeDelta {
     }
 }
 
+/// Parsing a `str` into a `TimeDelta` accepts an [ISO 8601] duration with days, hours, minutes
+/// and seconds, such as `P1DT2H3M4.5S`, and an optional sign. This includes the output of the
+/// `Display` implementation.
+///
+/// Days are always 24 hours long. Years, months and weeks are rejected, because their length
+/// depends on the date they are applied to.
+///
+/// # Example
+///
+/// ```
+/// use chrono::TimeDelta;
+///
+/// assert_eq!("PT1.5S".parse(), Ok(TimeDelta::milliseconds(1500)));
+/// assert_eq!("P1DT2H".parse(), Ok(TimeDelta::hours(26)));
+/// assert_eq!("-PT90M".parse(), Ok(TimeDelta::minutes(-90)));
+/// assert_eq!("P0D".parse(), Ok(TimeDelta::zero()));
+///
+/// assert!("P1M".parse::<TimeDelta>().is_err());
+/// assert!("PT".parse::<TimeDelta>().is_err());
+/// ```
+///
+/// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+impl str::FromStr for TimeDelta {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<TimeDelta> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let mut s = s.strip_prefix('P').ok_or(INVALID)?;
+        let mut total = TimeDelta::zero();
+        let mut in_time = false;
+        let mut has_value = false;
+        let mut prev_unit = 0;
+        while !s.is_empty() {
+            if let Some(rest) = s.strip_prefix('T') {
+                if in_time || rest.is_empty() {
+                    return Err(if in_time { INVALID } else { TOO_SHORT });
+                }
+                in_time = true;
+                s = rest;
+                continue;
+            }
+
+            let end = s.bytes().position(|b| !b.is_ascii_digit()).unwrap_or(s.len());
+            if end == 0 {
+                return Err(INVALID);
+            }
+            let value = s[..end].parse::<i64>().map_err(|_| OUT_OF_RANGE)?;
+            s = &s[end..];
+
+            let mut nanos = 0;
+            let fraction = matches!(s.as_bytes().first(), Some(b'.' | b','));
+            if fraction {
+                let rest = &s[1..];
+                let end = rest.bytes().position(|b| !b.is_ascii_digit()).unwrap_or(rest.len());
+                if end == 0 {
+                    return Err(INVALID);
+                }
+                // digits beyond nanosecond precision are ignored
+                for (i, b) in rest.bytes().take(end.min(9)).enumerate() {
+                    nanos += u32::from(b - b'0') * 10u32.pow(8 - i as u32);
+                }
+                s = &rest[end..];
+            }
+
+            let (unit, secs) = match (s.as_bytes().first(), in_time) {
+                (Some(b'D'), false) => (1, SECS_PER_DAY),
+                (Some(b'H'), true) => (2, SECS_PER_HOUR),
+                (Some(b'M'), true) => (3, SECS_PER_MINUTE),
+                (Some(b'S'), true) => (4, 1),
+                (None, _) => return Err(TOO_SHORT),
+                _ => return Err(INVALID),
+            };
+            // units must be in order, and only seconds can have a fraction
+            if unit <= prev_unit || (fraction && unit != 4) {
+                return Err(INVALID);
+            }
+            prev_unit = unit;
+            has_value = true;
+            s = &s[1..];
+
+            let secs = value.checked_mul(secs).ok_or(OUT_OF_RANGE)?;
+            let delta = TimeDelta::new(secs, nanos).ok_or(OUT_OF_RANGE)?;
+            total = total.checked_add(&delta).ok_or(OUT_OF_RANGE)?;
+        }
+        if !has_value {
+            return Err(TOO_SHORT);
+        }
+        Ok(if negative { -total } else { total })
+    }
+}
+
 /// Represents error when converting `TimeDelta` to/from a standard library
 /// implementation
 ///
@@ -632,20 +917,280 @@ impl arbitrary::Arbitrary<'_> for TimeDelta {
 }
 
 #[cfg(feature = "serde")]
-mod serde {
+pub(crate) mod serde {
     use super::TimeDelta;
-    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+    use core::fmt;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
+    /// Serialize a `TimeDelta` as a tuple of seconds and nanoseconds.
+    ///
+    /// See [the `serde` module](crate::serde) for alternate serializations.
     impl Serialize for TimeDelta {
         fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
             <(i64, i32) as Serialize>::serialize(&(self.secs, self.nanos), serializer)
         }
     }
 
+    struct TimeDeltaVisitor;
+
+    impl<'de> de::Visitor<'de> for TimeDeltaVisitor {
+        type Value = TimeDelta;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a tuple of seconds and nanoseconds or an ISO 8601 duration")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<TimeDelta, E>
+        where
+            E: de::Error,
+        {
+            value.parse().map_err(E::custom)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<TimeDelta, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let secs: i64 =
+                seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let nanos: i32 =
+                seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            TimeDelta::new(secs, nanos as u32).ok_or(de::Error::custom("TimeDelta out of bounds"))
+        }
+    }
+
+    /// Deserialize a `TimeDelta` from a tuple of seconds and nanoseconds.
+    ///
+    /// Human-readable formats also accept an ISO 8601 duration string, such as `PT1.5S`.
     impl<'de> Deserialize<'de> for TimeDelta {
         fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-            let (secs, nanos) = <(i64, i32) as Deserialize>::deserialize(deserializer)?;
-            TimeDelta::new(secs, nanos as u32).ok_or(Error::custom("TimeDelta out of bounds"))
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(TimeDeltaVisitor)
+            } else {
+                deserializer.deserialize_tuple(2, TimeDeltaVisitor)
+            }
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an ISO 8601 duration string, such as `PT86400.5S`.
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::duration_iso8601;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "duration_iso8601")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::milliseconds(1500) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":"PT1.5S"}"#);
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{"timeout":"P1DT2H"}"#)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::hours(26));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod duration_iso8601 {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` as an ISO 8601 duration string.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.collect_str(delta)
+        }
+
+        /// Deserialize a `TimeDelta` from an ISO 8601 duration string.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_str(Iso8601Visitor)
+        }
+
+        struct Iso8601Visitor;
+
+        impl de::Visitor<'_> for Iso8601Visitor {
+            type Value = TimeDelta;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an ISO 8601 duration")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                value.parse().map_err(E::custom)
+            }
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of seconds.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serialization drops any fractional
+    /// seconds, rounding towards zero.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::duration_seconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "duration_seconds")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::minutes(5) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":300}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::minutes(5));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod duration_seconds {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` as an integer number of seconds.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_seconds())
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of seconds.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(SecondsVisitor)
+        }
+
+        struct SecondsVisitor;
+
+        impl de::Visitor<'_> for SecondsVisitor {
+            type Value = TimeDelta;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a duration in seconds")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                TimeDelta::try_seconds(value).ok_or(E::custom("TimeDelta out of bounds"))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value)
+                    .map_err(|_| E::custom("TimeDelta out of bounds"))
+                    .and_then(|v| self.visit_i64(v))
+            }
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of milliseconds.
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serialization drops any fractional
+    /// milliseconds, rounding towards zero.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::duration_milliseconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "duration_milliseconds")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::milliseconds(1500) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":1500}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::milliseconds(1500));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod duration_milliseconds {
+        use core::fmt;
+        use serde::{de, ser};
+
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` as an integer number of milliseconds.
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_milliseconds())
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of milliseconds.
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(MillisecondsVisitor)
+        }
+
+        struct MillisecondsVisitor;
+
+        impl de::Visitor<'_> for MillisecondsVisitor {
+            type Value = TimeDelta;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a duration in milliseconds")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                TimeDelta::try_milliseconds(value).ok_or(E::custom("TimeDelta out of bounds"))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<TimeDelta, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value)
+                    .map_err(|_| E::custom("TimeDelta out of bounds"))
+                    .and_then(|v| self.visit_i64(v))
+            }
         }
     }
 
@@ -663,6 +1208,55 @@ mod serde {
             );
         }
 
+        #[test]
+        fn test_serde_iso8601_string() {
+            let delta = TimeDelta::milliseconds(-1500);
+            let from_json = |s| serde_json::from_str::<TimeDelta>(s).unwrap();
+            assert_eq!(serde_json::to_string(&delta).unwrap(), "[-2,500000000]");
+            assert_eq!(from_json("[-2,500000000]"), delta);
+            assert_eq!(from_json(r#""-PT1.5S""#), delta);
+            assert!(serde_json::from_str::<TimeDelta>(r#""P1M""#).is_err());
+            assert!(serde_json::from_str::<TimeDelta>("[1]").is_err());
+            assert!(serde_json::from_str::<TimeDelta>("1").is_err());
+
+            let encoded = bincode::serialize(&delta).unwrap();
+            assert_eq!(bincode::deserialize::<TimeDelta>(&encoded).unwrap(), delta);
+        }
+
+        #[test]
+        fn test_serde_duration_modules() {
+            #[derive(Debug, PartialEq, serde_derive::Deserialize, serde_derive::Serialize)]
+            struct S {
+                #[serde(with = "super::duration_iso8601")]
+                iso: TimeDelta,
+                #[serde(with = "super::duration_seconds")]
+                secs: TimeDelta,
+                #[serde(with = "super::duration_milliseconds")]
+                millis: TimeDelta,
+            }
+
+            let delta = TimeDelta::new(86_400, 123_456_789).unwrap();
+            let s = S { iso: delta, secs: delta, millis: delta };
+            let json = r#"{"iso":"PT86400.123456789S","secs":86400,"millis":86400123}"#;
+            assert_eq!(serde_json::to_string(&s).unwrap(), json);
+            let de = serde_json::from_str::<S>(json).unwrap();
+            assert_eq!(de.iso, delta);
+            assert_eq!(de.secs, TimeDelta::seconds(86_400));
+            assert_eq!(de.millis, TimeDelta::milliseconds(86_400_123));
+
+            let neg = -delta;
+            let json = serde_json::to_string(&S { iso: neg, secs: neg, millis: neg }).unwrap();
+            assert_eq!(json, r#"{"iso":"-PT86400.123456789S","secs":-86400,"millis":-86400123}"#);
+
+            let encoded = bincode::serialize(&s).unwrap();
+            assert_eq!(bincode::deserialize::<S>(&encoded).unwrap().iso, delta);
+
+            let json = r#"{"iso":"PT0S","secs":9223372036854775807,"millis":0}"#;
+            assert!(serde_json::from_str::<S>(json).is_err());
+            let json = r#"{"iso":"PT0S","secs":0,"millis":18446744073709551615}"#;
+            assert!(serde_json::from_str::<S>(json).is_err());
+        }
+
         #[test]
         #[should_panic(expected = "TimeDelta out of bounds")]
         fn test_serde_oob_panic() {
@@ -1210,6 +1804,29 @@ mod tests {
         assert_eq!(TimeDelta::try_seconds(-4).unwrap() / -3, TimeDelta::nanoseconds(1_333_333_333));
     }
 
+    #[test]
+    fn test_duration_rem() {
+        let seconds = |s| TimeDelta::try_seconds(s).unwrap();
+        let milliseconds = |ms| TimeDelta::try_milliseconds(ms).unwrap();
+
+        assert_eq!(TimeDelta::zero() % seconds(1), TimeDelta::zero());
+        assert_eq!(seconds(7) % seconds(3), seconds(1));
+        assert_eq!(seconds(7) % seconds(-3), seconds(1));
+        assert_eq!(seconds(-7) % seconds(3), seconds(-1));
+        assert_eq!(seconds(-7) % seconds(-3), seconds(-1));
+        assert_eq!(milliseconds(1500) % seconds(1), milliseconds(500));
+        assert_eq!(milliseconds(-1500) % seconds(1), milliseconds(-500));
+        assert_eq!(
+            TimeDelta::nanoseconds(1_000_000_007) % TimeDelta::nanoseconds(10),
+            TimeDelta::nanoseconds(7)
+        );
+        assert_eq!(TimeDelta::MAX % TimeDelta::MAX, TimeDelta::zero());
+        assert_eq!(TimeDelta::MIN % TimeDelta::MAX, TimeDelta::zero());
+        assert_eq!(TimeDelta::MAX % milliseconds(1000), milliseconds(807));
+        assert_eq!(TimeDelta::MIN % milliseconds(1000), milliseconds(-807));
+        assert!(seconds(1).checked_rem(&TimeDelta::zero()).is_none());
+    }
+
     #[test]
     fn test_duration_sum() {
         let duration_list_1 = [TimeDelta::zero(), TimeDelta::try_seconds(1).unwrap()];
@@ -1262,6 +1879,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_from_str() {
+        let secs = |s| TimeDelta::try_seconds(s).unwrap();
+        let valid = [
+            ("P0D", TimeDelta::zero()),
+            ("-P0D", TimeDelta::zero()),
+            ("PT0S", TimeDelta::zero()),
+            ("PT42S", secs(42)),
+            ("+PT42S", secs(42)),
+            ("-PT86401S", secs(-86_401)),
+            ("PT0.042S", TimeDelta::milliseconds(42)),
+            ("PT0,042S", TimeDelta::milliseconds(42)),
+            ("PT0.000000042S", TimeDelta::nanoseconds(42)),
+            ("PT0.0000000429S", TimeDelta::nanoseconds(42)),
+            ("-PT0.000000001S", TimeDelta::nanoseconds(-1)),
+            ("P2D", secs(2 * 86_400)),
+            ("P1DT1H1M1.5S", secs(90_061) + TimeDelta::milliseconds(500)),
+            ("PT36H", secs(36 * 3600)),
+            ("PT90M", secs(90 * 60)),
+            ("P1DT2M", secs(86_520)),
+            ("PT9223372036854775S", secs(9_223_372_036_854_775)),
+        ];
+        for (s, delta) in valid {
+            assert_eq!(s.parse::<TimeDelta>(), Ok(delta), "{}", s);
+        }
+
+        let invalid = [
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "0D",
+            "1S",
+            "PT1",
+            "P1H",
+            "PT1D",
+            "P1Y",
+            "P1M",
+            "P1W",
+            "PT1S1M",
+            "PT1M1M",
+            "P1.5D",
+            "PT1.5M",
+            "PT1.S",
+            "PT.5S",
+            "PTT1S",
+            "P-1D",
+            "--PT1S",
+            "PT1S ",
+            " PT1S",
+            "PT9223372036854776S",
+            "P106751991167301D",
+            "PT99999999999999999999S",
+        ];
+        for s in invalid {
+            assert!(s.parse::<TimeDelta>().is_err(), "{}", s);
+        }
+
+        for delta in [MIN, MAX, TimeDelta::nanoseconds(-1_500_000_001), secs(-86_400)] {
+            assert_eq!(delta.to_string().parse::<TimeDelta>(), Ok(delta));
+        }
+    }
+
+    #[test]
+    fn test_duration_ical() {
+        let secs = |s| TimeDelta::try_seconds(s).unwrap();
+        let valid = [
+            ("PT0S", TimeDelta::zero()),
+            ("P0W", TimeDelta::zero()),
+            ("P2W", secs(14 * 86_400)),
+            ("-P2W", secs(-14 * 86_400)),
+            ("+P1D", secs(86_400)),
+            ("P1DT12H", secs(129_600)),
+            ("-PT15M", secs(-900)),
+            ("PT1H0M5S", secs(3605)),
+            ("PT1H5S", secs(3605)),
+        ];
+        for (s, expected) in valid {
+            assert_eq!(TimeDelta::parse_ical(s), Ok(expected), "{}", s);
+        }
+        for s in
+            ["", "P", "PT", "W", "P1W2D", "P1DW", "PW", "P-1W", "PT1.5S", "PT1,5S", "P1Y", "P1M"]
+        {
+            assert!(TimeDelta::parse_ical(s).is_err(), "{}", s);
+        }
+        assert!(TimeDelta::parse_ical("P99999999999999W").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_duration_to_ical_string() {
+        let secs = |s| TimeDelta::try_seconds(s).unwrap();
+        let cases = [
+            (TimeDelta::zero(), "PT0S"),
+            (TimeDelta::milliseconds(-500), "PT0S"),
+            (secs(14 * 86_400), "P2W"),
+            (secs(-7 * 86_400), "-P1W"),
+            (secs(8 * 86_400), "P8D"),
+            (secs(86_400 + 3600), "P1DT1H"),
+            (secs(-5), "-PT5S"),
+            (secs(3605), "PT1H0M5S"),
+            (secs(65), "PT1M5S"),
+            (TimeDelta::milliseconds(61_999), "PT1M1S"),
+        ];
+        for (delta, s) in cases {
+            assert_eq!(delta.to_ical_string(), s);
+            assert_eq!(TimeDelta::parse_ical(s), Ok(secs(delta.num_seconds())));
+        }
+    }
+
     #[test]
     fn test_to_std() {
         assert_eq!(TimeDelta::try_seconds(1).unwrap().to_std(), Ok(Duration::new(1, 0)));
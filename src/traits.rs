@@ -1,4 +1,6 @@
-use crate::{IsoWeek, Weekday};
+use crate::calendar_math::DAYS_IN_400_YEAR_CYCLE;
+use crate::naive::{DateFields, TimeFields};
+use crate::{FixedOffset, IsoWeek, Weekday};
 
 /// The common set of methods for date component.
 ///
@@ -48,6 +50,14 @@ pub trait Datelike: Sized {
         (self.month() - 1).div_euclid(3) + 1
     }
 
+    /// Returns the half-year number starting from 1.
+    ///
+    /// The return value is 1 for January to June, and 2 for July to December.
+    #[inline]
+    fn half_year(&self) -> u32 {
+        if self.month() <= 6 { 1 } else { 2 }
+    }
+
     /// Returns the month number starting from 1.
     ///
     /// The return value ranges from 1 to 12.
@@ -181,6 +191,63 @@ pub trait Datelike: Sized {
     /// [`DateTime<Tz>`]: crate::DateTime
     fn with_month0(&self, month0: u32) -> Option<Self>;
 
+    /// Makes a new value with the quarter (starting from 1) changed.
+    ///
+    /// The month within the quarter and the day of the month stay the same, so the 15th of
+    /// February changes to the 15th of May in the second quarter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when:
+    ///
+    /// - The resulting date does not exist (for example `with_quarter(2)` on the 31st of March).
+    /// - In case of [`DateTime<Tz>`] if the resulting date and time fall within a timezone
+    ///   transition such as from DST to standard time.
+    /// - The value for `quarter` is out of range.
+    ///
+    /// [`DateTime<Tz>`]: crate::DateTime
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+    /// assert_eq!(date.with_quarter(2), NaiveDate::from_ymd_opt(2024, 5, 15));
+    /// assert_eq!(date.with_quarter(5), None);
+    /// ```
+    #[inline]
+    fn with_quarter(&self, quarter: u32) -> Option<Self> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        self.with_month((quarter - 1) * 3 + self.month0() % 3 + 1)
+    }
+
+    /// Makes a new value at the first day of the current quarter.
+    ///
+    /// In case of [`DateTime<Tz>`] the time of day stays the same.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` in case of [`DateTime<Tz>`] if the resulting date and time fall within a
+    /// timezone transition such as from DST to standard time.
+    ///
+    /// [`DateTime<Tz>`]: crate::DateTime
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 8, 31).unwrap();
+    /// assert_eq!(date.start_of_quarter(), NaiveDate::from_ymd_opt(2024, 7, 1));
+    /// ```
+    #[inline]
+    fn start_of_quarter(&self) -> Option<Self> {
+        self.with_day(1)?.with_month(self.quarter() * 3 - 2)
+    }
+
     /// Makes a new value with the day of month (starting from 1) changed.
     ///
     /// # Errors
@@ -259,7 +326,7 @@ pub trait Datelike: Sized {
         if year < 0 {
             let excess = 1 + (-year) / 400;
             year += excess * 400;
-            ndays -= excess * 146_097;
+            ndays -= excess * DAYS_IN_400_YEAR_CYCLE;
         }
         let div_100 = year / 100;
         ndays += ((year * 1461) >> 2) - div_100 + (div_100 >> 2);
@@ -331,10 +398,68 @@ pub trait Timelike: Sized {
     }
 }
 
+/// Read-only access to the fields of any date and time type.
+///
+/// [`Datelike`] and [`Timelike`] can not be used as trait objects, because their `with_*` methods
+/// return `Self`. This trait can: a plugin or formatting layer can accept a `&dyn CalendarFields`
+/// and handle a [`NaiveDate`](crate::NaiveDate), [`NaiveTime`](crate::NaiveTime),
+/// [`NaiveDateTime`](crate::NaiveDateTime) or [`DateTime`](crate::DateTime) alike.
+///
+/// # Example
+///
+/// ```
+/// use chrono::naive::{DateFields, TimeFields};
+/// use chrono::{CalendarFields, NaiveDate, NaiveTime, TimeZone, Utc};
+///
+/// fn describe(value: &dyn CalendarFields) -> String {
+///     match (value.date_fields(), value.time_fields()) {
+///         (Some(DateFields { year, month, day }), None) => format!("{}/{}/{}", day, month, year),
+///         (None, Some(TimeFields { hour, minute, .. })) => format!("{}:{:02}", hour, minute),
+///         (Some(DateFields { day, .. }), Some(TimeFields { hour, .. })) => {
+///             format!("day {}, hour {}", day, hour)
+///         }
+///         (None, None) => String::new(),
+///     }
+/// }
+///
+/// assert_eq!(describe(&NaiveDate::from_ymd_opt(2024, 5, 7).unwrap()), "7/5/2024");
+/// assert_eq!(describe(&NaiveTime::from_hms_opt(9, 5, 0).unwrap()), "9:05");
+/// assert_eq!(describe(&Utc.with_ymd_and_hms(2024, 5, 7, 13, 0, 0).unwrap()), "day 7, hour 13");
+/// ```
+pub trait CalendarFields {
+    /// Returns the year, month and day, or `None` if this type has no date.
+    fn date_fields(&self) -> Option<DateFields>;
+
+    /// Returns the hour, minute, second and nanosecond, or `None` if this type has no time.
+    fn time_fields(&self) -> Option<TimeFields>;
+
+    /// Returns the offset from UTC, or `None` if this type has no time zone.
+    fn utc_offset(&self) -> Option<FixedOffset>;
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Datelike;
-    use crate::{Days, NaiveDate};
+    use super::{CalendarFields, Datelike};
+    use crate::naive::{DateFields, TimeFields};
+    use crate::{Days, FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+    #[test]
+    fn test_calendar_fields() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+        let time = NaiveTime::from_hms_nano_opt(23, 30, 5, 7).unwrap();
+        let tz = FixedOffset::west_opt(3600).unwrap();
+        let dt = tz.from_local_datetime(&date.and_time(time)).unwrap();
+        let date_fields = DateFields { year: 2024, month: 5, day: 7 };
+        let time_fields = TimeFields { hour: 23, minute: 30, second: 5, nanosecond: 7 };
+
+        let values: [&dyn CalendarFields; 4] = [&date, &time, &date.and_time(time), &dt];
+        let fields: [_; 4] = values.map(|v| (v.date_fields(), v.time_fields(), v.utc_offset()));
+        assert_eq!(fields[0], (Some(date_fields), None, None));
+        assert_eq!(fields[1], (None, Some(time_fields), None));
+        assert_eq!(fields[2], (Some(date_fields), Some(time_fields), None));
+        // the local fields, not the UTC ones
+        assert_eq!(fields[3], (Some(date_fields), Some(time_fields), Some(tz)));
+    }
 
     /// Tests `Datelike::num_days_from_ce` against an alternative implementation.
     ///
@@ -390,4 +515,33 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_quarter_and_half_year() {
+        use crate::{TimeZone, Utc};
+
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        for (month, quarter, half) in
+            [(1, 1, 1), (3, 1, 1), (4, 2, 1), (6, 2, 1), (7, 3, 2), (12, 4, 2)]
+        {
+            assert_eq!(ymd(2024, month, 1).quarter(), quarter);
+            assert_eq!(ymd(2024, month, 1).half_year(), half);
+        }
+
+        assert_eq!(ymd(2024, 2, 15).with_quarter(1), Some(ymd(2024, 2, 15)));
+        assert_eq!(ymd(2024, 2, 15).with_quarter(4), Some(ymd(2024, 11, 15)));
+        assert_eq!(ymd(2024, 12, 31).with_quarter(1), Some(ymd(2024, 3, 31)));
+        assert_eq!(ymd(2024, 3, 31).with_quarter(2), None);
+        assert_eq!(ymd(2024, 3, 31).with_quarter(0), None);
+        assert_eq!(ymd(2024, 3, 31).with_quarter(5), None);
+
+        assert_eq!(ymd(2024, 1, 1).start_of_quarter(), Some(ymd(2024, 1, 1)));
+        assert_eq!(ymd(2024, 3, 31).start_of_quarter(), Some(ymd(2024, 1, 1)));
+        assert_eq!(ymd(2024, 5, 31).start_of_quarter(), Some(ymd(2024, 4, 1)));
+        assert_eq!(ymd(2024, 12, 31).start_of_quarter(), Some(ymd(2024, 10, 1)));
+
+        let dt = Utc.with_ymd_and_hms(2024, 8, 20, 13, 4, 5).unwrap();
+        assert_eq!(dt.start_of_quarter(), Utc.with_ymd_and_hms(2024, 7, 1, 13, 4, 5).single());
+        assert_eq!(dt.with_quarter(1), Utc.with_ymd_and_hms(2024, 2, 20, 13, 4, 5).single());
+    }
 }
@@ -256,7 +256,7 @@ impl fmt::Debug for ParseWeekdayError {
 // the actual `FromStr` implementation is in the `format` module to leverage the existing code
 
 #[cfg(feature = "serde")]
-mod weekday_serde {
+pub(crate) mod weekday_serde {
     use super::Weekday;
     use core::fmt;
     use serde::{de, ser};
@@ -295,6 +295,121 @@ mod weekday_serde {
             deserializer.deserialize_str(WeekdayVisitor)
         }
     }
+
+    /// Ser/de a `Weekday` to/from a number, where Monday is 0 and Sunday is 6
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::Weekday;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::weekday::as_number_monday0;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "as_number_monday0")]
+    ///     weekday: Weekday,
+    /// }
+    ///
+    /// let my_s = S { weekday: Weekday::Sun };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"weekday":6}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.weekday, Weekday::Sun);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod as_number_monday0 {
+        use serde::{de, ser};
+
+        use crate::Weekday;
+
+        /// Serialize a `Weekday` as the number of days since Monday
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(weekday: &Weekday, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_u8(weekday.num_days_from_monday() as u8)
+        }
+
+        /// Deserialize a `Weekday` from the number of days since Monday
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<Weekday, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let value = <u8 as de::Deserialize>::deserialize(d)?;
+            Weekday::try_from(value).map_err(|_| {
+                de::Error::invalid_value(
+                    de::Unexpected::Unsigned(value.into()),
+                    &"a weekday number from 0 (Monday) to 6 (Sunday)",
+                )
+            })
+        }
+    }
+
+    /// Ser/de a `Weekday` to/from a number, where Sunday is 1 and Saturday is 7
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::Weekday;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::weekday::as_number_sunday1;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "as_number_sunday1")]
+    ///     weekday: Weekday,
+    /// }
+    ///
+    /// let my_s = S { weekday: Weekday::Mon };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"weekday":2}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.weekday, Weekday::Mon);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod as_number_sunday1 {
+        use serde::{de, ser};
+
+        use crate::Weekday;
+
+        /// Serialize a `Weekday` as its number starting from Sunday as 1
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(weekday: &Weekday, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_u8(weekday.number_from_sunday() as u8)
+        }
+
+        /// Deserialize a `Weekday` from its number starting from Sunday as 1
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<Weekday, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let value = <u8 as de::Deserialize>::deserialize(d)?;
+            match value {
+                // `Weekday::try_from` counts from Monday as 0
+                1..=7 => Weekday::try_from((value + 5) % 7).ok(),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                de::Error::invalid_value(
+                    de::Unexpected::Unsigned(value.into()),
+                    &"a weekday number from 1 (Sunday) to 7 (Saturday)",
+                )
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -397,6 +512,42 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_as_number() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Numbers {
+            #[serde(with = "crate::serde::weekday::as_number_monday0")]
+            monday0: Weekday,
+            #[serde(with = "crate::serde::weekday::as_number_sunday1")]
+            sunday1: Weekday,
+        }
+
+        for i in 0..7 {
+            let weekday = Weekday::try_from(i).unwrap();
+            let numbers = Numbers { monday0: weekday, sunday1: weekday };
+            let expected = format!(
+                r#"{{"monday0":{},"sunday1":{}}}"#,
+                weekday.num_days_from_monday(),
+                weekday.number_from_sunday()
+            );
+            assert_eq!(serde_json::to_string(&numbers).unwrap(), expected);
+            assert_eq!(serde_json::from_str::<Numbers>(&expected).unwrap(), numbers);
+        }
+
+        for invalid in [
+            r#"{"monday0":7,"sunday1":1}"#,
+            r#"{"monday0":0,"sunday1":0}"#,
+            r#"{"monday0":0,"sunday1":8}"#,
+            r#"{"monday0":-1,"sunday1":1}"#,
+            r#"{"monday0":"Mon","sunday1":1}"#,
+        ] {
+            assert!(serde_json::from_str::<Numbers>(invalid).is_err(), "{}", invalid);
+        }
+    }
+
     #[test]
     #[cfg(feature = "rkyv-validation")]
     fn test_rkyv_validation() {
@@ -1,5 +1,6 @@
 //! Run this test with:
 //! `env TZ="$(date +%z)" NOW="$(date +%s)" wasm-pack test --node -- --features wasmbind`
+//! and again with `--features wasm-perf-clock` to test the `performance` based clock.
 //!
 //! The `TZ` and `NOW` variables are used to compare the results inside the WASM environment with
 //! the host system.
@@ -87,3 +88,16 @@ fn convert_all_parts_with_milliseconds() {
     assert_eq!(js_date.get_utc_seconds(), 55);
     assert_eq!(js_date.get_utc_milliseconds(), 974);
 }
+
+#[wasm_bindgen_test]
+fn local_js_date_roundtrip() {
+    let js_date = js_sys::Date::new_with_year_month_day_hr_min_sec(2021, 6, 1, 12, 30, 15);
+    let local = DateTime::<Local>::from(&js_date);
+
+    assert_eq!(js_date.get_time() as i64, local.timestamp_millis());
+    assert_eq!(local.offset().local_minus_utc(), -(js_date.get_timezone_offset() as i32) * 60);
+    assert_eq!((local.year(), local.month(), local.day()), (2021, 7, 1));
+    assert_eq!((local.hour(), local.minute(), local.second()), (12, 30, 15));
+
+    assert_eq!(js_sys::Date::from(local).get_time(), js_date.get_time());
+}